@@ -0,0 +1,108 @@
+// Copyright 2022 Kata Contributors
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use anyhow::{anyhow, Context, Result};
+use nix::errno::Errno;
+use nix::unistd::gettid;
+use std::fs::{self, OpenOptions};
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+
+const APPARMOR_ENABLED_PATH: &str = "/sys/module/apparmor/parameters/enabled";
+const APPARMOR_SECURITYFS_PATH: &str = "/sys/kernel/security/apparmor";
+
+pub fn is_enabled() -> Result<bool> {
+    if !Path::new(APPARMOR_SECURITYFS_PATH).exists() {
+        return Ok(false);
+    }
+
+    match fs::read_to_string(APPARMOR_ENABLED_PATH) {
+        Ok(buf) => Ok(buf.starts_with('Y')),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn get_exec_attr_path() -> PathBuf {
+    // Use the AppArmor specific interface if it is available, which is
+    // not shared with the other LSMs.
+    let thread_self = Path::new("/proc/thread-self/attr");
+    if thread_self.exists() {
+        let path = thread_self.join("apparmor/exec");
+        if path.exists() {
+            return path;
+        }
+        return thread_self.join("exec");
+    }
+
+    // Fall back to the old convention
+    let task = Path::new("/proc/self/task")
+        .join(gettid().to_string())
+        .join("attr");
+    let path = task.join("apparmor/exec");
+    if path.exists() {
+        return path;
+    }
+    task.join("exec")
+}
+
+// apply_profile sets the AppArmor profile which is applied to the process
+// on the next execve(2).
+pub fn apply_profile(profile: &str) -> Result<()> {
+    let attr_path = get_exec_attr_path();
+    let mut file = OpenOptions::new()
+        .write(true)
+        .truncate(true)
+        .open(&attr_path)
+        .with_context(|| format!("failed to open {:?}", attr_path))?;
+
+    file.write_all(format!("exec {}", profile).as_bytes())
+        .map_err(|e| match e.raw_os_error().map(Errno::from_i32) {
+            Some(Errno::ENOENT) => anyhow!(
+                "AppArmor profile {:?} is not loaded on the running kernel",
+                profile
+            ),
+            _ => anyhow!(e).context(format!("failed to apply AppArmor profile {:?}", profile)),
+        })?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_PROFILE: &str = "kata-test-profile-not-loaded";
+
+    #[test]
+    fn test_is_enabled() {
+        let ret = is_enabled();
+        assert!(ret.is_ok(), "Expecting Ok, Got {:?}", ret);
+    }
+
+    #[test]
+    fn test_apply_profile() {
+        if !is_enabled().unwrap_or(false) {
+            println!("INFO: skipping {} which needs AppArmor", module_path!());
+            return;
+        }
+
+        // The test profile is never loaded, so applying it must fail.
+        let ret = apply_profile(TEST_PROFILE);
+        assert!(ret.is_err(), "Expecting error, Got {:?}", ret);
+
+        // The unconfined profile always exists, and it must be the profile of
+        // the process executed by this thread after applying it.
+        let ret = apply_profile("unconfined");
+        assert!(ret.is_ok(), "Expecting Ok, Got {:?}", ret);
+
+        let output = std::process::Command::new("cat")
+            .arg("/proc/self/attr/current")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "unconfined");
+    }
+}
@@ -30,7 +30,7 @@ use oci::{
 use protobuf::MessageField;
 use protocols::agent::{
     BlkioStats, BlkioStatsEntry, CgroupStats, CpuStats, CpuUsage, HugetlbStats, MemoryData,
-    MemoryStats, PidsStats, ThrottlingData,
+    MemoryStats, PSIStats, PidsStats, ThrottlingData,
 };
 use std::any::Any;
 use std::collections::HashMap;
@@ -129,6 +129,17 @@ impl CgroupManager for Manager {
     }
 
     fn get_stats(&self) -> Result<CgroupStats> {
+        // pressure stall information is only exposed by cgroup v2
+        let cg_path = if self.cgroup.v2() {
+            Some(self.get_cgroup_path("")?)
+        } else {
+            None
+        };
+        let psi = |resource: &str| match &cg_path {
+            Some(p) => get_psi_stats(&Path::new(p).join(format!("{}.pressure", resource))),
+            None => MessageField::none(),
+        };
+
         // CpuStats
         let cpu_usage = get_cpuacct_stats(&self.cgroup);
 
@@ -137,18 +148,25 @@ impl CgroupManager for Manager {
         let cpu_stats = MessageField::some(CpuStats {
             cpu_usage,
             throttling_data,
+            psi: psi("cpu"),
             ..Default::default()
         });
 
         // Memorystats
-        let memory_stats = get_memory_stats(&self.cgroup);
+        let mut memory_stats = get_memory_stats(&self.cgroup);
+        if let Some(m) = memory_stats.as_mut() {
+            m.psi = psi("memory");
+        }
 
         // PidsStats
         let pids_stats = get_pids_stats(&self.cgroup);
 
         // BlkioStats
         // note that virtiofs has no blkio stats
-        let blkio_stats = get_blkio_stats(&self.cgroup);
+        let mut blkio_stats = get_blkio_stats(&self.cgroup);
+        if let Some(b) = blkio_stats.as_mut() {
+            b.psi = psi("io");
+        }
 
         // HugetlbStats
         let hugetlb_stats = get_hugetlb_stats(&self.cgroup);
@@ -159,6 +177,7 @@ impl CgroupManager for Manager {
             pids_stats,
             blkio_stats,
             hugetlb_stats,
+            cgroup_version: if self.cgroup.v2() { 2 } else { 1 },
             ..Default::default()
         })
     }
@@ -660,10 +679,16 @@ fn get_cpu_stats(cg: &cgroups::Cgroup) -> MessageField<ThrottlingData> {
     let stat = cpu_controller.cpu().stat;
     let h = lines_to_map(&stat);
 
+    // cgroup v2 reports the throttled time in microseconds, v1 in nanoseconds
+    let throttled_time = match h.get("throttled_usec") {
+        Some(usec) => usec * 1000,
+        None => *h.get("throttled_time").unwrap_or(&0),
+    };
+
     MessageField::some(ThrottlingData {
         periods: *h.get("nr_periods").unwrap_or(&0),
         throttled_periods: *h.get("nr_throttled").unwrap_or(&0),
-        throttled_time: *h.get("throttled_time").unwrap_or(&0),
+        throttled_time,
         ..Default::default()
     })
 }
@@ -691,13 +716,14 @@ fn get_cpuacct_stats(cg: &cgroups::Cgroup) -> MessageField<CpuUsage> {
         });
     }
 
-    // try to get from cpu controller
+    // try to get from cpu controller, converting the cgroup v2
+    // microsecond counters to the nanoseconds used by cpuacct
     let cpu_controller: &CpuController = get_controller_or_return_singular_none!(cg);
     let stat = cpu_controller.cpu().stat;
     let h = lines_to_map(&stat);
-    let usage_in_usermode = *h.get("user_usec").unwrap_or(&0);
-    let usage_in_kernelmode = *h.get("system_usec").unwrap_or(&0);
-    let total_usage = *h.get("usage_usec").unwrap_or(&0);
+    let usage_in_usermode = *h.get("user_usec").unwrap_or(&0) * 1000;
+    let usage_in_kernelmode = *h.get("system_usec").unwrap_or(&0) * 1000;
+    let total_usage = *h.get("usage_usec").unwrap_or(&0) * 1000;
     let percpu_usage = vec![];
 
     MessageField::some(CpuUsage {
@@ -925,6 +951,55 @@ fn get_blkio_stats(cg: &cgroups::Cgroup) -> MessageField<BlkioStats> {
     MessageField::some(m)
 }
 
+/*
+examples(cgroup v2 cpu.pressure, memory.pressure, io.pressure):
+
+    some avg10=0.00 avg60=0.00 avg300=0.00 total=0
+    full avg10=0.00 avg60=0.00 avg300=0.00 total=0
+
+the "full" line is absent from cpu.pressure on kernels older than 5.13.
+*/
+
+fn get_psi_stats(path: &Path) -> MessageField<PSIStats> {
+    match fs::read_to_string(path) {
+        Ok(content) => MessageField::some(parse_psi_stats(&content)),
+        Err(e) => {
+            // PSI can be disabled with psi=0 on the guest kernel command line
+            debug!(sl!(), "failed to read {:?}: {:?}", path, e);
+            MessageField::none()
+        }
+    }
+}
+
+fn parse_psi_stats(content: &str) -> PSIStats {
+    let mut psi = PSIStats::new();
+
+    for line in content.lines() {
+        let mut fields = line.split_whitespace();
+        let data = match fields.next() {
+            Some("some") => psi.some.mut_or_insert_default(),
+            Some("full") => psi.full.mut_or_insert_default(),
+            _ => continue,
+        };
+
+        for field in fields {
+            let (key, value) = match field.split_once('=') {
+                Some(kv) => kv,
+                None => continue,
+            };
+            match key {
+                "avg10" => data.avg10 = value.parse().unwrap_or(0.0),
+                "avg60" => data.avg60 = value.parse().unwrap_or(0.0),
+                "avg300" => data.avg300 = value.parse().unwrap_or(0.0),
+                "total" => data.total = value.parse().unwrap_or(0),
+                _ => (),
+            }
+        }
+    }
+
+    psi
+}
+
 fn get_hugetlb_stats(cg: &cgroups::Cgroup) -> HashMap<String, HugetlbStats> {
     let mut h = HashMap::new();
 
@@ -939,6 +1014,8 @@ fn get_hugetlb_stats(cg: &cgroups::Cgroup) -> HashMap<String, HugetlbStats> {
         let usage = hugetlb_controller.usage_in_bytes(&size).unwrap_or(0);
         let max_usage = hugetlb_controller.max_usage_in_bytes(&size).unwrap_or(0);
         let failcnt = hugetlb_controller.failcnt(&size).unwrap_or(0);
+        // the cgroup v2 "max", i.e. no limit, isn't a number
+        let limit = hugetlb_controller.limit_in_bytes(&size).unwrap_or(u64::MAX);
 
         h.insert(
             size.to_string(),
@@ -946,6 +1023,7 @@ fn get_hugetlb_stats(cg: &cgroups::Cgroup) -> HashMap<String, HugetlbStats> {
                 usage,
                 max_usage,
                 failcnt,
+                limit,
                 ..Default::default()
             },
         );
@@ -1088,6 +1166,7 @@ fn convert_memory_swap_to_v2_value(memory_swap: i64, memory: i64) -> Result<i64>
 #[cfg(test)]
 mod tests {
     use super::*;
+    use protocols::agent::PSIData;
 
     #[test]
     fn test_line_to_vec() {
@@ -1135,4 +1214,44 @@ mod tests {
             );
         }
     }
+    #[test]
+    fn test_parse_psi_stats() {
+        let psi_data = |avg10, avg60, avg300, total| {
+            MessageField::some(PSIData {
+                avg10,
+                avg60,
+                avg300,
+                total,
+                ..Default::default()
+            })
+        };
+
+        let test_cases = vec![
+            (
+                "some avg10=1.50 avg60=0.75 avg300=0.10 total=123456\nfull avg10=0.50 avg60=0.25 avg300=0.00 total=6543\n",
+                PSIStats {
+                    some: psi_data(1.5, 0.75, 0.1, 123456),
+                    full: psi_data(0.5, 0.25, 0.0, 6543),
+                    ..Default::default()
+                },
+            ),
+            (
+                "some avg10=0.00 avg60=0.00 avg300=0.00 total=42",
+                PSIStats {
+                    some: psi_data(0.0, 0.0, 0.0, 42),
+                    ..Default::default()
+                },
+            ),
+            ("", PSIStats::new()),
+        ];
+
+        for test_case in test_cases {
+            let result = parse_psi_stats(test_case.0);
+            assert_eq!(
+                result, test_case.1,
+                "except: {:?} for input {}",
+                test_case.1, test_case.0
+            );
+        }
+    }
 }
@@ -64,8 +64,8 @@ impl SystemdInterface for DBusClient {
         ];
 
         match *cg_hierarchy {
-            CgroupHierarchy::Legacy => properties.push(("IOAccounting", Value::Bool(true))),
-            CgroupHierarchy::Unified => properties.push(("BlockIOAccounting", Value::Bool(true))),
+            CgroupHierarchy::Legacy => properties.push(("BlockIOAccounting", Value::Bool(true))),
+            CgroupHierarchy::Unified => properties.push(("IOAccounting", Value::Bool(true))),
         }
 
         if unit_name.ends_with(SLICE_SUFFIX) {
@@ -5,13 +5,15 @@
 
 use crate::cgroups::Manager as CgroupManager;
 use crate::protocols::agent::CgroupStats;
-use anyhow::Result;
+use anyhow::{anyhow, Context, Result};
 use cgroups::freezer::FreezerState;
 use libc::{self, pid_t};
-use oci::LinuxResources;
+use oci::{LinuxHugepageLimit, LinuxResources, LinuxThrottleDevice};
 use std::any::Any;
 use std::collections::HashMap;
 use std::convert::TryInto;
+use std::fs;
+use std::path::Path;
 use std::string::String;
 use std::vec;
 
@@ -21,7 +23,9 @@ use super::cgroups_path::CgroupsPath;
 use super::common::{CgroupHierarchy, Properties};
 use super::dbus_client::{DBusClient, SystemdInterface};
 use super::subsystem::transformer::Transformer;
-use super::subsystem::{cpu::Cpu, cpuset::CpuSet, memory::Memory, pids::Pids};
+use super::subsystem::{
+    cpu::Cpu, cpuset::CpuSet, io::Io, memory::Memory, pids::Pids, unified::Unified,
+};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Manager {
@@ -56,18 +60,34 @@ impl CgroupManager for Manager {
     }
 
     fn set(&self, r: &LinuxResources, _: bool) -> Result<()> {
-        let mut properties: Properties = vec![];
+        let systemd_version = set_unit_resources(
+            &self.dbus_client,
+            self.unit_name.as_str(),
+            r,
+            &self.cg_hierarchy,
+        )?;
 
-        let systemd_version = self.dbus_client.get_version()?;
-        let systemd_version_str = systemd_version.as_str();
+        // systemd has no property for the resources below, so they are
+        // written to the unit's cgroup directly.
+        self.set_hugepages(&r.hugepage_limits)?;
 
-        Cpu::apply(r, &mut properties, &self.cg_hierarchy, systemd_version_str)?;
-        Memory::apply(r, &mut properties, &self.cg_hierarchy, systemd_version_str)?;
-        Pids::apply(r, &mut properties, &self.cg_hierarchy, systemd_version_str)?;
-        CpuSet::apply(r, &mut properties, &self.cg_hierarchy, systemd_version_str)?;
+        if let CgroupHierarchy::Legacy = self.cg_hierarchy {
+            if let Some(blkio) = &r.block_io {
+                self.set_legacy_iops(
+                    "blkio.throttle.read_iops_device",
+                    &blkio.throttle_read_iops_device,
+                )?;
+                self.set_legacy_iops(
+                    "blkio.throttle.write_iops_device",
+                    &blkio.throttle_write_iops_device,
+                )?;
+            }
+        }
 
-        self.dbus_client
-            .set_properties(self.unit_name.as_str(), &properties)?;
+        // The unified keys were validated before the unit properties were set.
+        for (key, value) in Unified::cgroupfs_entries(&r.unified, systemd_version.as_str()) {
+            self.write_cgroup_file("", key, value)?;
+        }
 
         Ok(())
     }
@@ -107,7 +127,70 @@ impl CgroupManager for Manager {
     }
 }
 
+// Converts the resources to unit properties and applies them through the
+// systemd interface, returning the systemd version they were built for.
+fn set_unit_resources(
+    client: &dyn SystemdInterface,
+    unit_name: &str,
+    r: &LinuxResources,
+    cg_hierarchy: &CgroupHierarchy,
+) -> Result<String> {
+    let mut properties: Properties = vec![];
+
+    let systemd_version = client.get_version()?;
+    let systemd_version_str = systemd_version.as_str();
+
+    Cpu::apply(r, &mut properties, cg_hierarchy, systemd_version_str)?;
+    Memory::apply(r, &mut properties, cg_hierarchy, systemd_version_str)?;
+    Pids::apply(r, &mut properties, cg_hierarchy, systemd_version_str)?;
+    CpuSet::apply(r, &mut properties, cg_hierarchy, systemd_version_str)?;
+    Io::apply(r, &mut properties, cg_hierarchy, systemd_version_str)?;
+    Unified::apply(r, &mut properties, cg_hierarchy, systemd_version_str)?;
+
+    client.set_properties(unit_name, &properties)?;
+
+    Ok(systemd_version)
+}
+
 impl Manager {
+    fn cgroup_dir(&self, subsystem: &str) -> Result<String> {
+        match self.cg_hierarchy {
+            CgroupHierarchy::Unified => Ok(format!("/sys/fs/cgroup/{}", self.cpath)),
+            CgroupHierarchy::Legacy => self
+                .paths
+                .get(subsystem)
+                .cloned()
+                .ok_or_else(|| anyhow!("cgroup subsystem {} is not mounted", subsystem)),
+        }
+    }
+
+    fn write_cgroup_file(&self, subsystem: &str, file: &str, value: &str) -> Result<()> {
+        let path = Path::new(&self.cgroup_dir(subsystem)?).join(file);
+        fs::write(&path, value).with_context(|| format!("failed to write {:?}", path))
+    }
+
+    // hugetlb.<size>.limit_in_bytes (v1) or hugetlb.<size>.max (v2)
+    fn set_hugepages(&self, limits: &[LinuxHugepageLimit]) -> Result<()> {
+        for l in limits.iter() {
+            let file = match self.cg_hierarchy {
+                CgroupHierarchy::Legacy => format!("hugetlb.{}.limit_in_bytes", l.page_size),
+                CgroupHierarchy::Unified => format!("hugetlb.{}.max", l.page_size),
+            };
+            self.write_cgroup_file("hugetlb", &file, &l.limit.to_string())?;
+        }
+
+        Ok(())
+    }
+
+    fn set_legacy_iops(&self, file: &str, devices: &[LinuxThrottleDevice]) -> Result<()> {
+        for d in devices.iter() {
+            let value = format!("{}:{} {}", d.blk.major, d.blk.minor, d.rate);
+            self.write_cgroup_file("blkio", file, &value)?;
+        }
+
+        Ok(())
+    }
+
     pub fn new(cgroups_path_str: &str) -> Result<Self> {
         let cgroups_path = CgroupsPath::new(cgroups_path_str)?;
         let (parent_slice, unit_name) = cgroups_path.parse()?;
@@ -131,3 +214,158 @@ impl Manager {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::common::{CgroupHierarchy, Properties};
+    use super::super::dbus_client::SystemdInterface;
+    use super::set_unit_resources;
+    use anyhow::Result;
+    use oci::{LinuxBlockIo, LinuxBlockIoDevice, LinuxResources, LinuxThrottleDevice};
+    use std::cell::RefCell;
+    use zbus::zvariant::Value;
+
+    // Records the properties instead of sending them over D-Bus.
+    struct MockDBusClient {
+        version: String,
+        properties: RefCell<Vec<(String, String, Value<'static>)>>,
+    }
+
+    impl MockDBusClient {
+        fn new(version: &str) -> Self {
+            MockDBusClient {
+                version: version.to_string(),
+                properties: RefCell::new(vec![]),
+            }
+        }
+
+        fn property(&self, name: &str) -> Option<Value<'static>> {
+            self.properties
+                .borrow()
+                .iter()
+                .find(|(_, n, _)| n == name)
+                .map(|(_, _, v)| v.clone())
+        }
+    }
+
+    impl SystemdInterface for MockDBusClient {
+        fn start_unit(&self, _: i32, _: &str, _: &str, _: &CgroupHierarchy) -> Result<()> {
+            Ok(())
+        }
+
+        fn set_properties(&self, unit_name: &str, properties: &Properties) -> Result<()> {
+            for (name, value) in properties.iter() {
+                self.properties.borrow_mut().push((
+                    unit_name.to_string(),
+                    name.to_string(),
+                    value.to_owned().into(),
+                ));
+            }
+            Ok(())
+        }
+
+        fn stop_unit(&self, _: &str) -> Result<()> {
+            Ok(())
+        }
+
+        fn get_version(&self) -> Result<String> {
+            Ok(self.version.clone())
+        }
+
+        fn unit_exists(&self, _: &str) -> Result<bool> {
+            Ok(true)
+        }
+
+        fn add_process(&self, _: i32, _: &str) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn resources() -> LinuxResources {
+        LinuxResources {
+            block_io: Some(LinuxBlockIo {
+                weight: Some(100),
+                throttle_write_bps_device: vec![LinuxThrottleDevice {
+                    blk: LinuxBlockIoDevice { major: 8, minor: 0 },
+                    rate: 1048576,
+                }],
+                throttle_read_iops_device: vec![LinuxThrottleDevice {
+                    blk: LinuxBlockIoDevice { major: 8, minor: 0 },
+                    rate: 500,
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_set_unit_resources_unified() {
+        let client = MockDBusClient::new("249");
+        let mut r = resources();
+        r.unified
+            .insert("memory.high".to_string(), "268435456".to_string());
+        r.unified
+            .insert("memory.oom.group".to_string(), "1".to_string());
+
+        let version =
+            set_unit_resources(&client, "kata-test.scope", &r, &CgroupHierarchy::Unified).unwrap();
+        assert_eq!("249", version);
+
+        assert!(client
+            .properties
+            .borrow()
+            .iter()
+            .all(|(unit, _, _)| unit == "kata-test.scope"));
+        assert_eq!(Some(Value::U64(910)), client.property("IOWeight"));
+        assert_eq!(
+            Some(Value::Array(
+                vec![("/dev/block/8:0".to_string(), 1048576u64)].into()
+            )),
+            client.property("IOWriteBandwidthMax")
+        );
+        assert_eq!(
+            Some(Value::Array(
+                vec![("/dev/block/8:0".to_string(), 500u64)].into()
+            )),
+            client.property("IOReadIOPSMax")
+        );
+        assert_eq!(Some(Value::U64(268435456)), client.property("MemoryHigh"));
+        assert_eq!(None, client.property("BlockIOWeight"));
+    }
+
+    #[test]
+    fn test_set_unit_resources_legacy() {
+        let client = MockDBusClient::new("239");
+        let mut r = resources();
+
+        set_unit_resources(&client, "kata-test.scope", &r, &CgroupHierarchy::Legacy).unwrap();
+
+        assert_eq!(Some(Value::U64(100)), client.property("BlockIOWeight"));
+        assert!(client.property("BlockIOWriteBandwidth").is_some());
+        assert_eq!(None, client.property("IOReadIOPSMax"));
+
+        // unified resources can't be applied on cgroup v1
+        let client = MockDBusClient::new("239");
+        r.unified.insert("pids.max".to_string(), "10".to_string());
+        assert!(
+            set_unit_resources(&client, "kata-test.scope", &r, &CgroupHierarchy::Legacy).is_err()
+        );
+        assert!(client.properties.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_set_unit_resources_invalid_unified_key() {
+        let client = MockDBusClient::new("249");
+        let mut r = resources();
+        r.unified
+            .insert("memory.high".to_string(), "268435456".to_string());
+        r.unified
+            .insert("../../memory.max".to_string(), "1".to_string());
+
+        assert!(
+            set_unit_resources(&client, "kata-test.scope", &r, &CgroupHierarchy::Unified).is_err()
+        );
+        assert!(client.properties.borrow().is_empty());
+    }
+}
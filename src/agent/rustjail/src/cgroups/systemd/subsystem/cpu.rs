@@ -112,7 +112,7 @@ fn shares_to_weight(shares: u64) -> u64 {
     1 + ((shares - 2) * 9999) / 262142
 }
 
-fn resolve_cpuquota(quota: i64, period: u64) -> u64 {
+pub(super) fn resolve_cpuquota(quota: i64, period: u64) -> u64 {
     let mut cpu_quota_per_sec_usec = u64::MAX;
     if quota > 0 {
         cpu_quota_per_sec_usec = (quota as u64) * SEC2MICROSEC / period;
@@ -61,7 +61,7 @@ impl CpuSet {
     }
 }
 
-struct BitMask(Vec<u8>);
+pub(super) struct BitMask(pub(super) Vec<u8>);
 
 impl TryFrom<&str> for BitMask {
     type Error = anyhow::Error;
@@ -0,0 +1,236 @@
+// Copyright 2021-2022 Kata Contributors
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use super::super::common::{CgroupHierarchy, Properties};
+
+use super::transformer::Transformer;
+
+use anyhow::Result;
+use oci::{LinuxBlockIo, LinuxBlockIoDevice, LinuxResources, LinuxThrottleDevice};
+use zbus::zvariant::Value;
+
+pub struct Io {}
+
+impl Transformer for Io {
+    fn apply(
+        r: &LinuxResources,
+        properties: &mut Properties,
+        cgroup_hierarchy: &CgroupHierarchy,
+        _: &str,
+    ) -> Result<()> {
+        if let Some(blkio_resources) = &r.block_io {
+            match cgroup_hierarchy {
+                CgroupHierarchy::Legacy => Self::legacy_apply(blkio_resources, properties)?,
+                CgroupHierarchy::Unified => Self::unified_apply(blkio_resources, properties)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Io {
+    // v1:
+    // blkio.weight <-> BlockIOWeight
+    // blkio.weight_device <-> BlockIODeviceWeight
+    // blkio.throttle.read_bps_device <-> BlockIOReadBandwidth
+    // blkio.throttle.write_bps_device <-> BlockIOWriteBandwidth
+    // blkio.throttle.{read,write}_iops_device have no systemd property and are
+    // written through cgroupfs by the manager.
+    fn legacy_apply(blkio_resources: &LinuxBlockIo, properties: &mut Properties) -> Result<()> {
+        if let Some(weight) = blkio_resources.weight {
+            if weight != 0 {
+                properties.push(("BlockIOWeight", Value::U64(weight as u64)));
+            }
+        }
+
+        let weight_devices: Vec<(String, u64)> = blkio_resources
+            .weight_device
+            .iter()
+            .filter_map(|d| match d.weight {
+                Some(w) if w != 0 => Some((device_path(&d.blk), w as u64)),
+                _ => None,
+            })
+            .collect();
+        if !weight_devices.is_empty() {
+            properties.push(("BlockIODeviceWeight", Value::Array(weight_devices.into())));
+        }
+
+        push_throttle(
+            properties,
+            "BlockIOReadBandwidth",
+            &blkio_resources.throttle_read_bps_device,
+        );
+        push_throttle(
+            properties,
+            "BlockIOWriteBandwidth",
+            &blkio_resources.throttle_write_bps_device,
+        );
+
+        Ok(())
+    }
+
+    // v2:
+    // blkio.weight <-> IOWeight
+    // blkio.weight_device <-> IODeviceWeight
+    // blkio.throttle.read_bps_device <-> IOReadBandwidthMax
+    // blkio.throttle.write_bps_device <-> IOWriteBandwidthMax
+    // blkio.throttle.read_iops_device <-> IOReadIOPSMax
+    // blkio.throttle.write_iops_device <-> IOWriteIOPSMax
+    fn unified_apply(blkio_resources: &LinuxBlockIo, properties: &mut Properties) -> Result<()> {
+        if let Some(weight) = blkio_resources.weight {
+            if weight != 0 {
+                properties.push(("IOWeight", Value::U64(blkio_weight_to_io_weight(weight))));
+            }
+        }
+
+        let weight_devices: Vec<(String, u64)> = blkio_resources
+            .weight_device
+            .iter()
+            .filter_map(|d| match d.weight {
+                Some(w) if w != 0 => Some((device_path(&d.blk), blkio_weight_to_io_weight(w))),
+                _ => None,
+            })
+            .collect();
+        if !weight_devices.is_empty() {
+            properties.push(("IODeviceWeight", Value::Array(weight_devices.into())));
+        }
+
+        push_throttle(
+            properties,
+            "IOReadBandwidthMax",
+            &blkio_resources.throttle_read_bps_device,
+        );
+        push_throttle(
+            properties,
+            "IOWriteBandwidthMax",
+            &blkio_resources.throttle_write_bps_device,
+        );
+        push_throttle(
+            properties,
+            "IOReadIOPSMax",
+            &blkio_resources.throttle_read_iops_device,
+        );
+        push_throttle(
+            properties,
+            "IOWriteIOPSMax",
+            &blkio_resources.throttle_write_iops_device,
+        );
+
+        Ok(())
+    }
+}
+
+fn push_throttle(properties: &mut Properties, name: &'static str, devices: &[LinuxThrottleDevice]) {
+    if devices.is_empty() {
+        return;
+    }
+
+    let devices: Vec<(String, u64)> = devices
+        .iter()
+        .map(|d| (device_path(&d.blk), d.rate))
+        .collect();
+    properties.push((name, Value::Array(devices.into())));
+}
+
+// systemd identifies block devices by path, use the sysfs-style
+// /dev/block/<major>:<minor> link so no device node lookup is needed.
+fn device_path(blk: &LinuxBlockIoDevice) -> String {
+    format!("/dev/block/{}:{}", blk.major, blk.minor)
+}
+
+// ref: https://github.com/opencontainers/runc/blob/main/libcontainer/cgroups/utils.go
+// [10-1000] to [1-10000]
+fn blkio_weight_to_io_weight(weight: u16) -> u64 {
+    let weight = weight.clamp(10, 1000) as u64;
+    1 + (weight - 10) * 9999 / 990
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blkio_weight_to_io_weight, Io};
+    use super::{Properties, Value};
+    use oci::{LinuxBlockIo, LinuxBlockIoDevice, LinuxThrottleDevice, LinuxWeightDevice};
+
+    fn throttle(major: i64, minor: i64, rate: u64) -> LinuxThrottleDevice {
+        LinuxThrottleDevice {
+            blk: LinuxBlockIoDevice { major, minor },
+            rate,
+        }
+    }
+
+    #[test]
+    fn test_blkio_weight_to_io_weight() {
+        assert_eq!(1, blkio_weight_to_io_weight(10));
+        assert_eq!(10000, blkio_weight_to_io_weight(1000));
+        assert_eq!(5000, blkio_weight_to_io_weight(505));
+        assert_eq!(1, blkio_weight_to_io_weight(1));
+    }
+
+    #[test]
+    fn test_unified_io() {
+        let blkio_resources = LinuxBlockIo {
+            weight: Some(1000),
+            weight_device: vec![LinuxWeightDevice {
+                blk: LinuxBlockIoDevice { major: 8, minor: 0 },
+                weight: Some(10),
+                leaf_weight: None,
+            }],
+            throttle_read_bps_device: vec![throttle(8, 0, 1048576)],
+            throttle_write_bps_device: vec![throttle(8, 16, 2097152)],
+            throttle_read_iops_device: vec![throttle(8, 0, 100)],
+            throttle_write_iops_device: vec![throttle(8, 0, 200)],
+            ..Default::default()
+        };
+        let mut properties: Properties = vec![];
+
+        assert!(Io::unified_apply(&blkio_resources, &mut properties).is_ok());
+
+        let names: Vec<&str> = properties.iter().map(|(n, _)| *n).collect();
+        assert_eq!(
+            names,
+            vec![
+                "IOWeight",
+                "IODeviceWeight",
+                "IOReadBandwidthMax",
+                "IOWriteBandwidthMax",
+                "IOReadIOPSMax",
+                "IOWriteIOPSMax",
+            ]
+        );
+        assert_eq!(Value::U64(10000), properties[0].1);
+        assert_eq!(
+            Value::Array(vec![("/dev/block/8:0".to_string(), 1u64)].into()),
+            properties[1].1
+        );
+        assert_eq!(
+            Value::Array(vec![("/dev/block/8:16".to_string(), 2097152u64)].into()),
+            properties[3].1
+        );
+    }
+
+    #[test]
+    fn test_legacy_io() {
+        let blkio_resources = LinuxBlockIo {
+            weight: Some(500),
+            throttle_read_bps_device: vec![throttle(253, 1, 4096)],
+            throttle_read_iops_device: vec![throttle(253, 1, 100)],
+            ..Default::default()
+        };
+        let mut properties: Properties = vec![];
+
+        assert!(Io::legacy_apply(&blkio_resources, &mut properties).is_ok());
+
+        assert_eq!(2, properties.len());
+        assert_eq!(("BlockIOWeight", Value::U64(500)), properties[0]);
+        assert_eq!(
+            (
+                "BlockIOReadBandwidth",
+                Value::Array(vec![("/dev/block/253:1".to_string(), 4096u64)].into())
+            ),
+            properties[1]
+        );
+    }
+}
@@ -5,6 +5,8 @@
 
 pub mod cpu;
 pub mod cpuset;
+pub mod io;
 pub mod memory;
 pub mod pids;
 pub mod transformer;
+pub mod unified;
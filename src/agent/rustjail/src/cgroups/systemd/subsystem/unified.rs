@@ -0,0 +1,286 @@
+// Copyright 2021-2022 Kata Contributors
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use super::super::common::{CgroupHierarchy, Properties};
+
+use super::cpu::resolve_cpuquota;
+use super::cpuset::BitMask;
+use super::transformer::Transformer;
+
+use anyhow::{anyhow, bail, Context, Result};
+use oci::LinuxResources;
+use std::collections::HashMap;
+use std::convert::TryInto;
+use zbus::zvariant::Value;
+
+const CPU_QUOTA_PERIOD_SYSTEMD_VERSION: &str = "242";
+const CPUSET_SYSTEMD_VERSION: &str = "244";
+
+pub struct Unified {}
+
+impl Transformer for Unified {
+    fn apply(
+        r: &LinuxResources,
+        properties: &mut Properties,
+        cgroup_hierarchy: &CgroupHierarchy,
+        systemd_version: &str,
+    ) -> Result<()> {
+        if r.unified.is_empty() {
+            return Ok(());
+        }
+
+        if let CgroupHierarchy::Legacy = cgroup_hierarchy {
+            bail!("unified resources are only supported on cgroup v2");
+        }
+
+        // The entries without a property are written to the unit's cgroup
+        // afterwards, so reject any invalid key before anything is applied.
+        for key in r.unified.keys() {
+            validate_key(key)?;
+        }
+
+        for (key, value) in r.unified.iter() {
+            properties.extend(Self::convert(key, value, systemd_version)?);
+        }
+
+        Ok(())
+    }
+}
+
+impl Unified {
+    // Returns the unified entries systemd has no property for. Those are
+    // written to the unit's cgroup directly.
+    pub fn cgroupfs_entries<'a>(
+        unified: &'a HashMap<String, String>,
+        systemd_version: &str,
+    ) -> Vec<(&'a String, &'a String)> {
+        unified
+            .iter()
+            .filter(|(key, value)| {
+                Self::convert(key, value, systemd_version)
+                    .map(|p| p.is_empty())
+                    .unwrap_or(false)
+            })
+            .collect()
+    }
+
+    // v2:
+    // cpu.weight <-> CPUWeight
+    // cpu.max <-> CPUQuotaPerSecUSec & CPUQuotaPeriodUSec
+    // cpuset.cpus <-> AllowedCPUs (v244)
+    // cpuset.mems <-> AllowedMemoryNodes (v244)
+    // memory.min <-> MemoryMin
+    // memory.low <-> MemoryLow
+    // memory.high <-> MemoryHigh
+    // memory.max <-> MemoryMax
+    // memory.swap.max <-> MemorySwapMax
+    // pids.max <-> TasksMax
+    // io.weight <-> IOWeight
+    fn convert(key: &str, value: &str, systemd_version: &str) -> Result<Properties<'static>> {
+        let value = value.trim();
+        let mut properties: Properties = vec![];
+
+        match key {
+            "cpu.weight" => {
+                properties.push(("CPUWeight", Value::U64(parse_u64(key, value)?)));
+            }
+            "cpu.max" => {
+                let mut fields = value.split_whitespace();
+                let quota = fields
+                    .next()
+                    .ok_or_else(|| anyhow!("invalid {}: {:?}", key, value))?;
+                let period = match fields.next() {
+                    Some(p) => Some(parse_u64(key, p)?),
+                    None => None,
+                };
+                let quota = match quota {
+                    "max" => -1,
+                    q => q
+                        .parse::<i64>()
+                        .with_context(|| format!("invalid {}: {:?}", key, value))?,
+                };
+
+                if let Some(period) = period {
+                    if period == 0 {
+                        bail!("invalid {}: period must be positive", key);
+                    }
+                    if systemd_version >= CPU_QUOTA_PERIOD_SYSTEMD_VERSION {
+                        properties.push(("CPUQuotaPeriodUSec", Value::U64(period)));
+                    }
+                }
+
+                let period = period.unwrap_or(100 * 1000);
+                properties.push((
+                    "CPUQuotaPerSecUSec",
+                    Value::U64(resolve_cpuquota(quota, period)),
+                ));
+            }
+            "cpuset.cpus" | "cpuset.mems" if systemd_version >= CPUSET_SYSTEMD_VERSION => {
+                let name = if key == "cpuset.cpus" {
+                    "AllowedCPUs"
+                } else {
+                    "AllowedMemoryNodes"
+                };
+                let mask: BitMask = value.try_into()?;
+                properties.push((name, Value::Array(mask.0.into())));
+            }
+            "memory.min" | "memory.low" | "memory.high" | "memory.max" | "memory.swap.max" => {
+                let name = match key {
+                    "memory.min" => "MemoryMin",
+                    "memory.low" => "MemoryLow",
+                    "memory.high" => "MemoryHigh",
+                    "memory.max" => "MemoryMax",
+                    _ => "MemorySwapMax",
+                };
+                properties.push((name, Value::U64(parse_max(key, value)?)));
+            }
+            "pids.max" => {
+                properties.push(("TasksMax", Value::U64(parse_max(key, value)?)));
+            }
+            // only the default weight has a property, per-device weights
+            // ("<major>:<minor> <weight>") go through cgroupfs
+            "io.weight" => {
+                let weight = value.strip_prefix("default").unwrap_or(value).trim();
+                if let Ok(weight) = weight.parse::<u64>() {
+                    properties.push(("IOWeight", Value::U64(weight)));
+                }
+            }
+            _ => {}
+        }
+
+        Ok(properties)
+    }
+}
+
+// A unified key is a file name of the cgroup directory, e.g. "memory.high".
+fn validate_key(key: &str) -> Result<()> {
+    if key.contains('/') || key.starts_with('.') || !key.contains('.') {
+        bail!("invalid unified resource key {:?}", key);
+    }
+
+    Ok(())
+}
+
+fn parse_u64(key: &str, value: &str) -> Result<u64> {
+    value
+        .parse::<u64>()
+        .with_context(|| format!("invalid {}: {:?}", key, value))
+}
+
+fn parse_max(key: &str, value: &str) -> Result<u64> {
+    if value == "max" {
+        return Ok(u64::MAX);
+    }
+
+    parse_u64(key, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::common::{CgroupHierarchy, Properties};
+    use super::super::transformer::Transformer;
+    use super::Unified;
+    use super::Value;
+    use std::collections::HashMap;
+
+    fn resources(entries: &[(&str, &str)]) -> oci::LinuxResources {
+        oci::LinuxResources {
+            unified: entries
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn property<'a>(properties: &'a Properties, name: &str) -> Option<&'a Value<'a>> {
+        properties.iter().find(|(n, _)| *n == name).map(|(_, v)| v)
+    }
+
+    #[test]
+    fn test_unified_apply() {
+        let r = resources(&[
+            ("cpu.weight", "200"),
+            ("cpu.max", "50000 100000"),
+            ("memory.high", "1073741824"),
+            ("memory.swap.max", "max"),
+            ("pids.max", "64"),
+            ("io.weight", "default 300"),
+        ]);
+        let mut properties: Properties = vec![];
+
+        assert!(Unified::apply(&r, &mut properties, &CgroupHierarchy::Unified, "249").is_ok());
+
+        assert_eq!(Some(&Value::U64(200)), property(&properties, "CPUWeight"));
+        assert_eq!(
+            Some(&Value::U64(100000)),
+            property(&properties, "CPUQuotaPeriodUSec")
+        );
+        assert_eq!(
+            Some(&Value::U64(500000)),
+            property(&properties, "CPUQuotaPerSecUSec")
+        );
+        assert_eq!(
+            Some(&Value::U64(1073741824)),
+            property(&properties, "MemoryHigh")
+        );
+        assert_eq!(
+            Some(&Value::U64(u64::MAX)),
+            property(&properties, "MemorySwapMax")
+        );
+        assert_eq!(Some(&Value::U64(64)), property(&properties, "TasksMax"));
+        assert_eq!(Some(&Value::U64(300)), property(&properties, "IOWeight"));
+    }
+
+    #[test]
+    fn test_unified_invalid() {
+        let mut properties: Properties = vec![];
+
+        let r = resources(&[("memory.max", "lots")]);
+        assert!(Unified::apply(&r, &mut properties, &CgroupHierarchy::Unified, "249").is_err());
+
+        let r = resources(&[("pids.max", "10")]);
+        assert!(Unified::apply(&r, &mut properties, &CgroupHierarchy::Legacy, "249").is_err());
+
+        for key in ["../memory.max", "memory", ".memory", "io/weight"] {
+            let r = resources(&[("cpu.weight", "100"), (key, "1")]);
+            let mut properties: Properties = vec![];
+            assert!(
+                Unified::apply(&r, &mut properties, &CgroupHierarchy::Unified, "249").is_err(),
+                "{}",
+                key
+            );
+            assert!(properties.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_cgroupfs_entries() {
+        let unified: HashMap<String, String> = [
+            ("cpu.weight", "100"),
+            ("cpuset.cpus", "0-1"),
+            ("io.weight", "8:0 100"),
+            ("memory.oom.group", "1"),
+        ]
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+        let mut keys: Vec<&str> = Unified::cgroupfs_entries(&unified, "249")
+            .into_iter()
+            .map(|(k, _)| k.as_str())
+            .collect();
+        keys.sort_unstable();
+        assert_eq!(keys, vec!["io.weight", "memory.oom.group"]);
+
+        // systemd < 244 can't set AllowedCPUs
+        let mut keys: Vec<&str> = Unified::cgroupfs_entries(&unified, "239")
+            .into_iter()
+            .map(|(k, _)| k.as_str())
+            .collect();
+        keys.sort_unstable();
+        assert_eq!(keys, vec!["cpuset.cpus", "io.weight", "memory.oom.group"]);
+    }
+}
@@ -39,6 +39,8 @@ pub fn setup_master_console(socket_fd: RawFd) -> Result<()> {
     let cmsg = socket::ControlMessage::ScmRights(&fds);
 
     socket::sendmsg::<()>(socket_fd, &iov, &[cmsg], socket::MsgFlags::empty(), None)?;
+    // The master is only used by the receiver of the console socket.
+    unistd::close(pseudo.master)?;
 
     unistd::setsid()?;
     let ret = unsafe { libc::ioctl(pseudo.slave, libc::TIOCSCTTY) };
@@ -47,6 +49,11 @@ pub fn setup_master_console(socket_fd: RawFd) -> Result<()> {
     dup2(pseudo.slave, std::io::stdin().as_raw_fd())?;
     dup2(pseudo.slave, std::io::stdout().as_raw_fd())?;
     dup2(pseudo.slave, std::io::stderr().as_raw_fd())?;
+    // Keep the slave open only through stdio, otherwise the processes of the
+    // container inherit it and the master never reports EOF.
+    if pseudo.slave > std::io::stderr().as_raw_fd() {
+        unistd::close(pseudo.slave)?;
+    }
 
     unistd::close(socket_fd)?;
 
@@ -56,6 +63,9 @@ pub fn setup_master_console(socket_fd: RawFd) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use nix::sys::wait::{waitpid, WaitStatus};
+    use nix::unistd::ForkResult;
+    use std::io::IoSliceMut;
     use std::os::unix::net::UnixListener;
     use tempfile::{self, tempdir};
 
@@ -74,4 +84,69 @@ mod tests {
 
         assert!(ret.is_ok());
     }
+
+    // Returns true if a pty fd other than stdio is still open in the process.
+    fn has_extra_pty_fds() -> bool {
+        std::fs::read_dir("/proc/self/fd")
+            .unwrap()
+            .flatten()
+            .filter(|e| {
+                let fd = e.file_name().to_str().and_then(|n| n.parse::<RawFd>().ok());
+                matches!(fd, Some(fd) if fd > std::io::stderr().as_raw_fd())
+            })
+            .filter_map(|e| std::fs::read_link(e.path()).ok())
+            .any(|p| p.starts_with("/dev/pts") || p == Path::new("/dev/ptmx"))
+    }
+
+    #[test]
+    fn test_setup_master_console() {
+        let (fd, child_fd) = socket::socketpair(
+            socket::AddressFamily::Unix,
+            socket::SockType::Stream,
+            None,
+            socket::SockFlag::SOCK_CLOEXEC,
+        )
+        .unwrap();
+
+        match unsafe { unistd::fork() }.unwrap() {
+            ForkResult::Child => {
+                let _ = unistd::close(fd);
+                // Only the stdio of the process may refer to the pty afterwards,
+                // otherwise the master never reports EOF to the receiver.
+                let code = match setup_master_console(child_fd) {
+                    Ok(()) if !has_extra_pty_fds() => 0,
+                    Ok(()) => 1,
+                    Err(_) => 2,
+                };
+                unsafe { libc::_exit(code) };
+            }
+            ForkResult::Parent { child } => {
+                unistd::close(child_fd).unwrap();
+
+                let mut buf = [0u8; 64];
+                let mut iov = [IoSliceMut::new(&mut buf)];
+                let mut cmsg_buf = nix::cmsg_space!([RawFd; 1]);
+                let msg = socket::recvmsg::<()>(
+                    fd,
+                    &mut iov,
+                    Some(&mut cmsg_buf),
+                    socket::MsgFlags::empty(),
+                )
+                .unwrap();
+                let master = msg
+                    .cmsgs()
+                    .find_map(|cmsg| match cmsg {
+                        socket::ControlMessageOwned::ScmRights(fds) => fds.first().copied(),
+                        _ => None,
+                    })
+                    .unwrap();
+                assert!(unistd::isatty(master).unwrap());
+
+                assert_eq!(waitpid(child, None).unwrap(), WaitStatus::Exited(child, 0));
+
+                unistd::close(master).unwrap();
+                unistd::close(fd).unwrap();
+            }
+        }
+    }
 }
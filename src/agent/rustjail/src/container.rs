@@ -17,6 +17,7 @@ use std::time::SystemTime;
 
 use cgroups::freezer::FreezerState;
 
+use crate::apparmor;
 use crate::capabilities;
 #[cfg(not(test))]
 use crate::cgroups::fs::Manager as FsManager;
@@ -34,7 +35,7 @@ use crate::selinux;
 use crate::specconv::CreateOpts;
 use crate::{mount, validator};
 
-use protocols::agent::StatsContainerResponse;
+use protocols::agent::{NetworkStats, StatsContainerResponse};
 
 use nix::errno::Errno;
 use nix::fcntl::{self, OFlag};
@@ -410,6 +411,18 @@ fn do_init_child(cwfd: RawFd) -> Result<()> {
     }
     let linux = spec.linux.as_ref().unwrap();
 
+    // Connect to the seccomp agent in advance, since the listener path is not
+    // reachable after the container's rootfs is set up. Also get the pid of this
+    // process in the parent's pid namespace to be sent to the seccomp agent.
+    #[cfg(feature = "seccomp")]
+    let seccomp_listener = match linux.seccomp.as_ref() {
+        Some(scmp) => match seccomp::connect_listener(scmp)? {
+            Some(fd) => Some((fd, get_proc_self_pid()?)),
+            None => None,
+        },
+        None => None,
+    };
+
     // get namespace vector to join/new
     let nses = get_namespaces(linux);
 
@@ -536,6 +549,7 @@ fn do_init_child(cwfd: RawFd) -> Result<()> {
     }
 
     let selinux_enabled = selinux::is_enabled()?;
+    let apparmor_enabled = apparmor::is_enabled()?;
 
     sched::unshare(to_new & !CloneFlags::CLONE_NEWUSER)?;
 
@@ -650,6 +664,18 @@ fn do_init_child(cwfd: RawFd) -> Result<()> {
         selinux::set_exec_label(&oci_process.selinux_label)?;
     }
 
+    // Set AppArmor profile
+    if !oci_process.apparmor_profile.is_empty() {
+        if !apparmor_enabled {
+            return Err(anyhow!(
+                "AppArmor profile for the process is provided but AppArmor is not enabled on the running kernel"
+            ));
+        }
+
+        log_child!(cfd_log, "Set AppArmor profile to the container process");
+        apparmor::apply_profile(&oci_process.apparmor_profile)?;
+    }
+
     // Log unknown seccomp system calls in advance before the log file descriptor closes.
     #[cfg(feature = "seccomp")]
     if let Some(ref scmp) = linux.seccomp {
@@ -664,7 +690,7 @@ fn do_init_child(cwfd: RawFd) -> Result<()> {
     #[cfg(feature = "seccomp")]
     if !oci_process.no_new_privileges {
         if let Some(ref scmp) = linux.seccomp {
-            seccomp::init_seccomp(scmp)?;
+            setup_seccomp(scmp, seccomp_listener, &state)?;
         }
     }
 
@@ -757,7 +783,7 @@ fn do_init_child(cwfd: RawFd) -> Result<()> {
         state.status = oci::ContainerState::Created;
         if let Some(hooks) = spec.hooks.as_ref() {
             let mut start_container_states = HookStates::new();
-            start_container_states.execute_hooks(&hooks.start_container, Some(state))?;
+            start_container_states.execute_hooks(&hooks.start_container, Some(state.clone()))?;
         }
     }
 
@@ -767,13 +793,43 @@ fn do_init_child(cwfd: RawFd) -> Result<()> {
     #[cfg(feature = "seccomp")]
     if oci_process.no_new_privileges {
         if let Some(ref scmp) = linux.seccomp {
-            seccomp::init_seccomp(scmp)?;
+            setup_seccomp(scmp, seccomp_listener, &state)?;
         }
     }
 
     do_exec(&args);
 }
 
+// setup_seccomp loads the seccomp filter, and sends the seccomp notify fd to
+// the seccomp agent if the filter has SCMP_ACT_NOTIFY rules.
+#[cfg(feature = "seccomp")]
+fn setup_seccomp(
+    scmp: &oci::LinuxSeccomp,
+    listener: Option<(RawFd, i32)>,
+    state: &oci::State,
+) -> Result<()> {
+    if let Some(notify_fd) = seccomp::init_seccomp(scmp)? {
+        let (socket_fd, pid) =
+            listener.ok_or_else(|| anyhow!("seccomp listener is not connected"))?;
+        seccomp::send_notify_fd(socket_fd, notify_fd, pid, &scmp.listener_metadata, state)?;
+    }
+
+    Ok(())
+}
+
+// get_proc_self_pid returns the pid of the current process in the pid namespace
+// of the procfs mounted on /proc.
+#[cfg(feature = "seccomp")]
+fn get_proc_self_pid() -> Result<i32> {
+    let link = fs::read_link("/proc/self")?;
+    let pid = link
+        .to_str()
+        .ok_or_else(|| anyhow!("invalid /proc/self link: {:?}", link))?
+        .parse::<i32>()?;
+
+    Ok(pid)
+}
+
 // set_stdio_permissions fixes the permissions of PID 1's STDIO
 // within the container to the specified user.
 // The ownership needs to match because it is created outside of
@@ -872,10 +928,20 @@ impl BaseContainer for LinuxContainer {
     }
 
     fn stats(&self) -> Result<StatsContainerResponse> {
-        // what about network interface stats?
+        // network interface stats are read from the container's network
+        // namespace, they are best effort and never fail the request.
+        let network_stats = if self.init_process_pid > 0 {
+            get_network_stats(self.init_process_pid).unwrap_or_else(|e| {
+                warn!(self.logger, "failed to get network stats: {:?}", e);
+                Vec::new()
+            })
+        } else {
+            Vec::new()
+        };
 
         Ok(StatsContainerResponse {
             cgroup_stats: MessageField::some(self.cgroup_manager.as_ref().get_stats()?),
+            network_stats,
             ..Default::default()
         })
     }
@@ -1585,6 +1651,56 @@ fn set_sysctls(sysctls: &HashMap<String, String>) -> Result<()> {
     Ok(())
 }
 
+fn get_network_stats(pid: pid_t) -> Result<Vec<NetworkStats>> {
+    let path = format!("/proc/{}/net/dev", pid);
+    let content = fs::read_to_string(&path).context(format!("read {}", path))?;
+
+    Ok(parse_net_dev(&content))
+}
+
+/*
+example(/proc/<pid>/net/dev):
+
+Inter-|   Receive                                                |  Transmit
+ face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed
+    lo:     240       4    0    0    0     0          0         0      240       4    0    0    0     0       0          0
+  eth0:    1836      22    0    0    0     0          0         0     1178      15    0    0    0     0       0          0
+*/
+fn parse_net_dev(content: &str) -> Vec<NetworkStats> {
+    content
+        .lines()
+        .skip(2)
+        .filter_map(|line| {
+            let (name, counters) = line.split_once(':')?;
+            let name = name.trim();
+            if name == "lo" {
+                return None;
+            }
+
+            let v = counters
+                .split_whitespace()
+                .map(|x| x.parse::<u64>().ok())
+                .collect::<Option<Vec<u64>>>()?;
+            if v.len() < 16 {
+                return None;
+            }
+
+            Some(NetworkStats {
+                name: name.to_string(),
+                rx_bytes: v[0],
+                rx_packets: v[1],
+                rx_errors: v[2],
+                rx_dropped: v[3],
+                tx_bytes: v[8],
+                tx_packets: v[9],
+                tx_errors: v[10],
+                tx_dropped: v[11],
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1868,6 +1984,33 @@ mod tests {
         assert!(ret.is_ok(), "Expecting Ok, Got {:?}", ret);
     }
 
+    #[test]
+    fn test_parse_net_dev() {
+        let content = "Inter-|   Receive                                                |  Transmit
+ face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed
+    lo:     240       4    0    0    0     0          0         0      240       4    0    0    0     0       0          0
+  eth0:    1836      22    1    2    0     0          0         0     1178      15    3    4    0     0       0          0
+  eth1: bad
+";
+
+        let stats = parse_net_dev(content);
+        assert_eq!(
+            stats,
+            vec![NetworkStats {
+                name: "eth0".to_string(),
+                rx_bytes: 1836,
+                rx_packets: 22,
+                rx_errors: 1,
+                rx_dropped: 2,
+                tx_bytes: 1178,
+                tx_packets: 15,
+                tx_errors: 3,
+                tx_dropped: 4,
+                ..Default::default()
+            }]
+        );
+    }
+
     #[test]
     fn test_linuxcontainer_set() {
         let ret = new_linux_container_and_then(|mut c: LinuxContainer| {
@@ -28,6 +28,7 @@ extern crate oci;
 extern crate path_absolutize;
 extern crate regex;
 
+pub mod apparmor;
 pub mod capabilities;
 pub mod cgroups;
 #[cfg(feature = "standard-oci-runtime")]
@@ -348,6 +349,7 @@ pub fn resources_grpc_to_oci(res: &grpc::LinuxResources) -> oci::LinuxResources
         hugepage_limits,
         network,
         rdma: HashMap::new(),
+        unified: res.Unified.clone(),
     }
 }
 
@@ -387,6 +389,8 @@ fn seccomp_grpc_to_oci(sec: &grpc::LinuxSeccomp) -> oci::LinuxSeccomp {
         default_action: sec.DefaultAction.clone(),
         architectures: sec.Architectures.clone(),
         flags: sec.Flags.clone(),
+        listener_path: sec.ListenerPath.clone(),
+        listener_metadata: sec.ListenerMetadata.clone(),
         syscalls,
     }
 }
@@ -3,9 +3,16 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, Context, Result};
 use libseccomp::*;
-use oci::{LinuxSeccomp, LinuxSeccompArg};
+use nix::sys::socket;
+use nix::unistd;
+use oci::{
+    ContainerProcessState, LinuxSeccomp, LinuxSeccompArg, State, ACTNOTIFY, SECCOMP_FD_NAME,
+};
+use std::io::IoSlice;
+use std::os::unix::io::RawFd;
+use std::path::Path;
 use std::str::FromStr;
 
 fn get_filter_attr_from_flag(flag: &str) -> Result<ScmpFilterAttr> {
@@ -60,9 +67,90 @@ pub fn get_unknown_syscalls(scmp: &LinuxSeccomp) -> Option<Vec<String>> {
     }
 }
 
+// is_notify_used returns true if any rule uses the SCMP_ACT_NOTIFY action.
+pub fn is_notify_used(scmp: &LinuxSeccomp) -> bool {
+    scmp.syscalls.iter().any(|s| s.action == ACTNOTIFY)
+}
+
+fn validate_notify(scmp: &LinuxSeccomp) -> Result<()> {
+    if scmp.default_action == ACTNOTIFY {
+        return Err(anyhow!(
+            "SCMP_ACT_NOTIFY cannot be used as the default action"
+        ));
+    }
+    if is_notify_used(scmp) && scmp.listener_path.is_empty() {
+        return Err(anyhow!(
+            "SCMP_ACT_NOTIFY is used but the seccomp listener path is not set"
+        ));
+    }
+    Ok(())
+}
+
+// connect_listener connects to the seccomp agent listening on the listener path.
+// It must be called before the container's mount namespace is set up, since the
+// listener path is not reachable from the container's rootfs.
+pub fn connect_listener(scmp: &LinuxSeccomp) -> Result<Option<RawFd>> {
+    validate_notify(scmp)?;
+    if !is_notify_used(scmp) {
+        return Ok(None);
+    }
+
+    let socket_fd = socket::socket(
+        socket::AddressFamily::Unix,
+        socket::SockType::Stream,
+        socket::SockFlag::SOCK_CLOEXEC,
+        None,
+    )?;
+
+    let addr = socket::UnixAddr::new(Path::new(&scmp.listener_path))?;
+    socket::connect(socket_fd, &addr).map_err(|e| {
+        let _ = unistd::close(socket_fd);
+        anyhow!(
+            "failed to connect to seccomp listener {}: {}",
+            scmp.listener_path,
+            e
+        )
+    })?;
+
+    Ok(Some(socket_fd))
+}
+
+// send_notify_fd sends the seccomp notify fd with the container process state
+// to the seccomp agent as defined in the OCI runtime spec, and closes both fds.
+pub fn send_notify_fd(
+    socket_fd: RawFd,
+    notify_fd: RawFd,
+    pid: i32,
+    metadata: &str,
+    state: &State,
+) -> Result<()> {
+    let process_state = ContainerProcessState {
+        version: state.version.clone(),
+        fds: vec![SECCOMP_FD_NAME.to_string()],
+        pid,
+        metadata: metadata.to_string(),
+        state: state.clone(),
+    };
+    let msg = serde_json::to_vec(&process_state)?;
+
+    let iov = [IoSlice::new(&msg)];
+    let fds = [notify_fd];
+    let cmsg = socket::ControlMessage::ScmRights(&fds);
+    let ret = socket::sendmsg::<()>(socket_fd, &iov, &[cmsg], socket::MsgFlags::empty(), None)
+        .context("failed to send seccomp notify fd");
+
+    let _ = unistd::close(notify_fd);
+    let _ = unistd::close(socket_fd);
+
+    ret.map(drop)
+}
+
 // init_seccomp creates a seccomp filter and loads it for the current process
-// including all the child processes.
-pub fn init_seccomp(scmp: &LinuxSeccomp) -> Result<()> {
+// including all the child processes. If any rule uses SCMP_ACT_NOTIFY, the filter
+// is loaded with SECCOMP_FILTER_FLAG_NEW_LISTENER and the notify fd is returned.
+pub fn init_seccomp(scmp: &LinuxSeccomp) -> Result<Option<RawFd>> {
+    validate_notify(scmp)?;
+
     let def_action = ScmpAction::from_str(scmp.default_action.as_str(), Some(libc::EPERM))?;
 
     // Create a new filter context
@@ -113,18 +201,26 @@ pub fn init_seccomp(scmp: &LinuxSeccomp) -> Result<()> {
         filter.set_filter_attr(scmp_attr, 1)?;
     }
 
-    // Load the filter
+    // Load the filter. libseccomp sets SECCOMP_FILTER_FLAG_NEW_LISTENER
+    // when the filter contains SCMP_ACT_NOTIFY rules.
     filter.load()?;
 
-    Ok(())
+    if is_notify_used(scmp) {
+        return Ok(Some(filter.get_notify_fd()?));
+    }
+
+    Ok(None)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use libc::{dup3, process_vm_readv, EPERM, O_CLOEXEC};
-    use std::io::Error;
+    use std::io::{Error, IoSliceMut};
+    use std::os::unix::io::AsRawFd;
+    use std::os::unix::net::UnixListener;
     use std::ptr::null;
+    use tempfile::tempdir;
     use test_utils::skip_if_not_root;
 
     macro_rules! syscall_assert {
@@ -215,6 +311,76 @@ mod tests {
         assert_eq!(get_filter_attr_from_flag("ERROR").is_err(), true);
     }
 
+    #[test]
+    fn test_validate_notify() {
+        let mut scmp: oci::LinuxSeccomp = serde_json::from_str(TEST_DATA).unwrap();
+        assert!(!is_notify_used(&scmp));
+        assert!(validate_notify(&scmp).is_ok());
+        assert_eq!(connect_listener(&scmp).unwrap(), None);
+
+        scmp.syscalls[0].action = ACTNOTIFY.to_string();
+        assert!(is_notify_used(&scmp));
+        assert!(validate_notify(&scmp).is_err());
+
+        scmp.listener_path = "/run/seccomp-agent.sock".to_string();
+        assert!(validate_notify(&scmp).is_ok());
+
+        scmp.default_action = ACTNOTIFY.to_string();
+        assert!(validate_notify(&scmp).is_err());
+    }
+
+    #[test]
+    fn test_send_notify_fd() {
+        let dir = tempdir().unwrap();
+        let listener_path = dir.path().join("seccomp-agent.sock");
+        let listener = UnixListener::bind(&listener_path).unwrap();
+
+        let mut scmp: oci::LinuxSeccomp = serde_json::from_str(TEST_DATA).unwrap();
+        scmp.syscalls[0].action = ACTNOTIFY.to_string();
+        scmp.listener_path = listener_path.to_str().unwrap().to_string();
+        let socket_fd = connect_listener(&scmp).unwrap().unwrap();
+
+        // Use a pipe in place of the seccomp notify fd.
+        let (notify_fd, _w) = unistd::pipe().unwrap();
+        let state = oci::State {
+            version: "1.0.2".to_string(),
+            id: "test".to_string(),
+            status: oci::ContainerState::Creating,
+            pid: 100,
+            bundle: "/bundle".to_string(),
+            annotations: Default::default(),
+        };
+        send_notify_fd(socket_fd, notify_fd, 100, "test-metadata", &state).unwrap();
+
+        let (stream, _) = listener.accept().unwrap();
+        let mut buf = vec![0u8; 4096];
+        let mut iov = [IoSliceMut::new(&mut buf)];
+        let mut cmsg_buf = nix::cmsg_space!([RawFd; 1]);
+        let msg = socket::recvmsg::<()>(
+            stream.as_raw_fd(),
+            &mut iov,
+            Some(&mut cmsg_buf),
+            socket::MsgFlags::empty(),
+        )
+        .unwrap();
+        let received_fds: Vec<RawFd> = msg
+            .cmsgs()
+            .filter_map(|c| match c {
+                socket::ControlMessageOwned::ScmRights(fds) => Some(fds),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        let len = msg.bytes;
+        assert_eq!(received_fds.len(), 1);
+
+        let process_state: ContainerProcessState = serde_json::from_slice(&buf[..len]).unwrap();
+        assert_eq!(process_state.fds, vec![SECCOMP_FD_NAME.to_string()]);
+        assert_eq!(process_state.pid, 100);
+        assert_eq!(process_state.metadata, "test-metadata");
+        assert_eq!(process_state.state, state);
+    }
+
     #[test]
     fn test_get_unknown_syscalls() {
         let scmp: oci::LinuxSeccomp = serde_json::from_str(TEST_DATA).unwrap();
@@ -247,7 +413,7 @@ mod tests {
 
         scmp.architectures.append(&mut arch);
 
-        init_seccomp(&scmp).unwrap();
+        assert_eq!(init_seccomp(&scmp).unwrap(), None);
 
         // Basic syscall with simple rule
         syscall_assert!(unsafe { dup3(0, 1, O_CLOEXEC) }, -EPERM);
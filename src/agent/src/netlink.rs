@@ -7,14 +7,17 @@ use anyhow::{anyhow, Context, Result};
 use futures::{future, StreamExt, TryStreamExt};
 use ipnetwork::{IpNetwork, Ipv4Network, Ipv6Network};
 use nix::errno::Errno;
-use protocols::types::{ARPNeighbor, IPAddress, IPFamily, Interface, Route};
+use protocols::types::{ARPNeighbor, IPAddress, IPFamily, IPv6Sysctls, Interface, Route};
 use rtnetlink::{new_connection, packet, IpVersion};
 use std::convert::{TryFrom, TryInto};
 use std::fmt;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::ops::Deref;
+use std::path::Path;
 use std::str::{self, FromStr};
 
+const SYSCTL_NET_PATH: &str = "/proc/sys/net";
+
 /// Search criteria to use when looking for a link in `find_link`.
 pub enum LinkFilter<'a> {
     /// Find by link name.
@@ -80,6 +83,12 @@ impl Handle {
             .await?;
         self.delete_addresses(addresses).await?;
 
+        // The IPv6 addresses and routes come from the host, the link is set up like the host
+        // one, e.g. to not configure its own from router advertisements
+        if let Some(sysctls) = iface.ipv6_sysctls.as_ref() {
+            setup_ipv6_link(Path::new(SYSCTL_NET_PATH), &link.name(), sysctls)?;
+        }
+
         // Add new ip addresses from request
         for ip_address in &iface.IPAddresses {
             let ip = IpAddr::from_str(ip_address.address())?;
@@ -529,7 +538,7 @@ impl Handle {
 
         // Import rtnetlink objects that make sense only for this function
         use packet::constants::{
-            NDA_UNSPEC, NLM_F_ACK, NLM_F_CREATE, NLM_F_REPLACE, NLM_F_REQUEST,
+            NDA_UNSPEC, NLM_F_ACK, NLM_F_CREATE, NLM_F_REPLACE, NLM_F_REQUEST, NTF_PROXY,
         };
         use packet::neighbour::{NeighbourHeader, NeighbourMessage};
         use packet::nlas::neighbour::Nla;
@@ -582,10 +591,46 @@ impl Handle {
             }
         }
 
+        // IPv6 proxy entries are only answered for once proxy NDP is enabled on the link
+        if neigh.flags as u8 & NTF_PROXY != 0 {
+            enable_neigh_proxy(Path::new(SYSCTL_NET_PATH), &neigh.device, &ip)?;
+        }
+
         Ok(())
     }
 }
 
+/// Sets a per link sysctl under `root`, e.g. `ipv6/conf/eth0/accept_ra`.
+fn set_link_sysctl(root: &Path, family: &str, device: &str, name: &str, value: &str) -> Result<()> {
+    let path = root.join(family).join("conf").join(device).join(name);
+    std::fs::write(&path, value).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Makes the kernel answer for the proxy neighbour entries of `ip`'s family on a link.
+///
+/// IPv6 proxy entries need proxy_ndp, which only enables answering for them. IPv4 proxy entries
+/// are answered without any sysctl: proxy_arp is not their counterpart, it makes the link answer
+/// for every address routed through another link, so it is left alone. In both cases the link
+/// must be forwarding, as set from the host sysctls.
+fn enable_neigh_proxy(root: &Path, device: &str, ip: &IpAddr) -> Result<()> {
+    match ip {
+        IpAddr::V4(_) => Ok(()),
+        IpAddr::V6(_) => set_link_sysctl(root, "ipv6", device, "proxy_ndp", "1"),
+    }
+}
+
+/// Applies the IPv6 sysctls of the host link to a link.
+fn setup_ipv6_link(root: &Path, device: &str, sysctls: &IPv6Sysctls) -> Result<()> {
+    for (name, value) in [
+        ("disable_ipv6", sysctls.disable_ipv6),
+        ("accept_ra", sysctls.accept_ra),
+        ("forwarding", sysctls.forwarding),
+    ] {
+        set_link_sysctl(root, "ipv6", device, name, &value.to_string())?;
+    }
+    Ok(())
+}
+
 fn format_address(data: &[u8]) -> Result<String> {
     match data.len() {
         4 => {
@@ -1020,4 +1065,32 @@ mod tests {
 
         clean_env_for_test_add_one_arp_neighbor(dummy_name, to_ip);
     }
+
+    #[test]
+    fn test_link_sysctls() {
+        let root = tempfile::tempdir().unwrap();
+        for family in ["ipv4", "ipv6"] {
+            std::fs::create_dir_all(root.path().join(family).join("conf/eth0")).unwrap();
+        }
+        let read = |path: &str| std::fs::read_to_string(root.path().join(path)).unwrap();
+
+        let sysctls = IPv6Sysctls {
+            disable_ipv6: 0,
+            accept_ra: 2,
+            forwarding: 1,
+            ..Default::default()
+        };
+        setup_ipv6_link(root.path(), "eth0", &sysctls).unwrap();
+        assert_eq!(read("ipv6/conf/eth0/disable_ipv6"), "0");
+        assert_eq!(read("ipv6/conf/eth0/accept_ra"), "2");
+        assert_eq!(read("ipv6/conf/eth0/forwarding"), "1");
+
+        enable_neigh_proxy(root.path(), "eth0", &IpAddr::from_str("10.0.0.1").unwrap()).unwrap();
+        assert!(!root.path().join("ipv4/conf/eth0/proxy_arp").exists());
+        enable_neigh_proxy(root.path(), "eth0", &IpAddr::from_str("fd00::1").unwrap()).unwrap();
+        assert_eq!(read("ipv6/conf/eth0/proxy_ndp"), "1");
+
+        // the link doesn't exist
+        assert!(setup_ipv6_link(root.path(), "eth1", &sysctls).is_err());
+    }
 }
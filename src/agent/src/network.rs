@@ -3,14 +3,17 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, Context, Result};
 use nix::mount::{self, MsFlags};
+use protocols::agent::{DNSConfig, HostEntry};
 use slog::Logger;
 use std::fs;
 use std::path;
 
 const KATA_GUEST_SANDBOX_DNS_FILE: &str = "/run/kata-containers/sandbox/resolv.conf";
 const GUEST_DNS_FILE: &str = "/etc/resolv.conf";
+const KATA_GUEST_SANDBOX_HOSTS_FILE: &str = "/run/kata-containers/sandbox/hosts";
+const GUEST_HOSTS_FILE: &str = "/etc/hosts";
 
 // Network describes a sandbox network, includings its dns
 // related information.
@@ -38,13 +41,75 @@ pub fn setup_guest_dns(logger: Logger, dns_list: Vec<String>) -> Result<()> {
     )
 }
 
+pub fn setup_guest_hosts(logger: Logger, hosts: &[HostEntry]) -> Result<()> {
+    do_setup_guest_file(
+        logger,
+        hosts_lines(hosts),
+        KATA_GUEST_SANDBOX_HOSTS_FILE,
+        GUEST_HOSTS_FILE,
+    )
+}
+
+/// Rewrites the resolv.conf and hosts files of the sandbox which have been
+/// set up before, the guest files are bind mounted so they are updated too.
+pub fn update_guest_dns(logger: Logger, dns_list: Vec<String>, hosts: &[HostEntry]) -> Result<()> {
+    do_update_guest_file(
+        logger.clone(),
+        dns_list,
+        KATA_GUEST_SANDBOX_DNS_FILE,
+        GUEST_DNS_FILE,
+    )?;
+    do_update_guest_file(
+        logger,
+        hosts_lines(hosts),
+        KATA_GUEST_SANDBOX_HOSTS_FILE,
+        GUEST_HOSTS_FILE,
+    )
+}
+
+/// Generates the resolv.conf lines of a DNS configuration.
+pub fn dns_config_lines(config: &DNSConfig) -> Vec<String> {
+    let mut lines: Vec<String> = config
+        .nameservers
+        .iter()
+        .map(|ns| format!("nameserver {}", ns))
+        .collect();
+    if !config.searches.is_empty() {
+        lines.push(format!("search {}", config.searches.join(" ")));
+    }
+    if !config.options.is_empty() {
+        lines.push(format!("options {}", config.options.join(" ")));
+    }
+    lines
+}
+
+fn hosts_lines(hosts: &[HostEntry]) -> Vec<String> {
+    hosts
+        .iter()
+        .filter(|h| !h.ip.is_empty() && !h.names.is_empty())
+        .map(|h| format!("{}\t{}", h.ip, h.names.join(" ")))
+        .collect()
+}
+
+fn file_content(lines: &[String]) -> String {
+    lines
+        .iter()
+        .map(|x| x.trim())
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
 fn do_setup_guest_dns(logger: Logger, dns_list: Vec<String>, src: &str, dst: &str) -> Result<()> {
+    do_setup_guest_file(logger, dns_list, src, dst).context("failed to setup guest DNS")
+}
+
+fn do_setup_guest_file(logger: Logger, lines: Vec<String>, src: &str, dst: &str) -> Result<()> {
     let logger = logger.new(o!( "subsystem" => "network"));
 
-    if dns_list.is_empty() {
+    if lines.is_empty() {
         info!(
             logger,
-            "Did not set sandbox DNS as DNS not received as part of request."
+            "Did not set sandbox {} as it was not received as part of request.", dst
         );
         return Ok(());
     }
@@ -56,15 +121,11 @@ fn do_setup_guest_dns(logger: Logger, dns_list: Vec<String>, src: &str, dst: &st
     }
 
     if attr.unwrap().is_dir() {
-        return Err(anyhow!("{} is a directory", GUEST_DNS_FILE));
+        return Err(anyhow!("{} is a directory", dst));
     }
 
-    // write DNS to file
-    let content = dns_list
-        .iter()
-        .map(|x| x.trim())
-        .collect::<Vec<&str>>()
-        .join("\n");
+    // write the lines to file
+    let content = file_content(&lines);
 
     // make sure the src file's parent path exist.
     let file_path = path::Path::new(src);
@@ -73,13 +134,23 @@ fn do_setup_guest_dns(logger: Logger, dns_list: Vec<String>, src: &str, dst: &st
     }
     fs::write(src, content)?;
 
-    // bind mount to /etc/resolv.conf
+    // bind mount to the guest file
     mount::mount(Some(src), dst, Some("bind"), MsFlags::MS_BIND, None::<&str>)
-        .map_err(|err| anyhow!(err).context("failed to setup guest DNS"))?;
+        .map_err(|err| anyhow!(err).context(format!("failed to bind mount {}", dst)))?;
 
     Ok(())
 }
 
+fn do_update_guest_file(logger: Logger, lines: Vec<String>, src: &str, dst: &str) -> Result<()> {
+    if !path::Path::new(src).exists() {
+        return do_setup_guest_file(logger, lines, src, dst);
+    }
+
+    // write in place, the bind mount refers to the inode of src. The file is emptied when
+    // there are no lines, the host file became empty or unreadable.
+    fs::write(src, file_content(&lines)).with_context(|| format!("failed to update {}", src))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,4 +212,81 @@ mod tests {
         // umount /etc/resolv.conf
         let _ = mount::umount(dst_filename);
     }
+
+    #[test]
+    fn test_update_guest_file() {
+        let logger = slog::Logger::root(slog::Discard, o!());
+        let dir = tempdir().expect("failed to create tmpdir");
+        let src = dir.path().join("hosts");
+        let src = src.to_str().unwrap();
+        let dst = dir.path().join("etc-hosts");
+        let dst = dst.to_str().unwrap();
+
+        // never set up, there is nothing to update
+        do_update_guest_file(logger.clone(), vec![], src, dst).unwrap();
+        assert!(!path::Path::new(src).exists());
+
+        fs::write(src, "10.0.0.1\tfoo").unwrap();
+        let lines = vec!["10.0.0.2\tbar".to_string(), "10.0.0.3\tbaz".to_string()];
+        do_update_guest_file(logger.clone(), lines, src, dst).unwrap();
+        assert_eq!(
+            fs::read_to_string(src).unwrap(),
+            "10.0.0.2\tbar\n10.0.0.3\tbaz"
+        );
+
+        do_update_guest_file(logger, vec![], src, dst).unwrap();
+        assert_eq!(fs::read_to_string(src).unwrap(), "");
+    }
+
+    #[test]
+    fn test_dns_config_lines() {
+        let config = DNSConfig {
+            nameservers: vec!["10.96.0.10".to_string(), "fd00::10".to_string()],
+            searches: vec![
+                "default.svc.cluster.local".to_string(),
+                "svc.cluster.local".to_string(),
+            ],
+            options: vec!["ndots:5".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            dns_config_lines(&config),
+            vec![
+                "nameserver 10.96.0.10",
+                "nameserver fd00::10",
+                "search default.svc.cluster.local svc.cluster.local",
+                "options ndots:5",
+            ]
+        );
+
+        let config = DNSConfig {
+            nameservers: vec!["10.96.0.10".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(dns_config_lines(&config), vec!["nameserver 10.96.0.10"]);
+    }
+
+    #[test]
+    fn test_hosts_lines() {
+        let hosts = vec![
+            HostEntry {
+                ip: "127.0.0.1".to_string(),
+                names: vec!["localhost".to_string()],
+                ..Default::default()
+            },
+            HostEntry {
+                ip: "fd00::5".to_string(),
+                names: vec!["pod".to_string(), "pod.ns".to_string()],
+                ..Default::default()
+            },
+            HostEntry {
+                ip: "10.0.0.5".to_string(),
+                ..Default::default()
+            },
+        ];
+        assert_eq!(
+            hosts_lines(&hosts),
+            vec!["127.0.0.1\tlocalhost", "fd00::5\tpod pod.ns"]
+        );
+    }
 }
@@ -56,7 +56,7 @@ use crate::linux_abi::*;
 use crate::metrics::get_metrics;
 use crate::mount::{add_storages, baremount, update_ephemeral_mounts, STORAGE_HANDLER_LIST};
 use crate::namespace::{NSTYPEIPC, NSTYPEPID, NSTYPEUTS};
-use crate::network::setup_guest_dns;
+use crate::network::{dns_config_lines, setup_guest_dns, setup_guest_hosts, update_guest_dns};
 use crate::pci;
 use crate::random;
 use crate::sandbox::Sandbox;
@@ -1267,19 +1267,42 @@ impl agent_ttrpc::AgentService for AgentService {
             Err(e) => return Err(ttrpc_error!(ttrpc::Code::INTERNAL, e)),
         };
 
-        match setup_guest_dns(sl!(), req.dns.to_vec()) {
+        let dns = match req.dns_config.as_ref() {
+            Some(config) => dns_config_lines(config),
+            None => req.dns.to_vec(),
+        };
+        match setup_guest_dns(sl!(), dns.clone()) {
             Ok(_) => {
                 let sandbox = self.sandbox.clone();
                 let mut s = sandbox.lock().await;
-                let _dns = req
-                    .dns
-                    .to_vec()
-                    .iter()
-                    .map(|dns| s.network.set_dns(dns.to_string()));
+                for d in dns.iter() {
+                    s.network.set_dns(d.to_string());
+                }
             }
             Err(e) => return Err(ttrpc_error!(ttrpc::Code::INTERNAL, e)),
         };
 
+        setup_guest_hosts(sl!(), &req.hosts).map_err(|e| ttrpc_error!(ttrpc::Code::INTERNAL, e))?;
+
+        Ok(Empty::new())
+    }
+
+    async fn update_sandbox_dns(
+        &self,
+        ctx: &TtrpcContext,
+        req: protocols::agent::UpdateSandboxDNSRequest,
+    ) -> ttrpc::Result<Empty> {
+        trace_rpc_call!(ctx, "update_sandbox_dns", req);
+        is_allowed!(req);
+
+        let dns = req
+            .dns_config
+            .as_ref()
+            .map(dns_config_lines)
+            .unwrap_or_default();
+        update_guest_dns(sl!(), dns, &req.hosts)
+            .map_err(|e| ttrpc_error!(ttrpc::Code::INTERNAL, e))?;
+
         Ok(Empty::new())
     }
 
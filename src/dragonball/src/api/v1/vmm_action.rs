@@ -27,6 +27,8 @@ pub use crate::device_manager::blk_dev_mgr::{
 pub use crate::device_manager::fs_dev_mgr::{
     FsDeviceConfigInfo, FsDeviceConfigUpdateInfo, FsDeviceError, FsDeviceMgr, FsMountConfigInfo,
 };
+#[cfg(feature = "virtio-rng")]
+pub use crate::device_manager::rng_dev_mgr::{RngDeviceConfigInfo, RngDeviceError};
 #[cfg(feature = "virtio-net")]
 pub use crate::device_manager::virtio_net_dev_mgr::{
     VirtioNetDeviceConfigInfo, VirtioNetDeviceConfigUpdateInfo, VirtioNetDeviceError,
@@ -38,6 +40,8 @@ pub use crate::device_manager::vsock_dev_mgr::{VsockDeviceConfigInfo, VsockDevic
 #[cfg(feature = "hotplug")]
 pub use crate::vcpu::{VcpuResizeError, VcpuResizeInfo};
 
+pub use crate::vm::{GuestMemoryDumpError, GuestMemoryDumpInfo};
+
 use super::*;
 
 /// Wrapper for all errors associated with VMM actions.
@@ -78,6 +82,11 @@ pub enum VmmActionError {
     #[error("failed to add virtio-vsock device: {0}")]
     Vsock(#[source] VsockDeviceError),
 
+    #[cfg(feature = "virtio-rng")]
+    /// The action `InsertRngDevice` failed either because of bad user input or an internal error.
+    #[error("failed to add virtio-rng device: {0}")]
+    Rng(#[source] RngDeviceError),
+
     #[cfg(feature = "virtio-blk")]
     /// Block device related errors.
     #[error("virtio-blk device error: {0}")]
@@ -97,6 +106,10 @@ pub enum VmmActionError {
     /// The action `ResizeVcpu` Failed
     #[error("vcpu resize error : {0}")]
     ResizeVcpu(#[source] VcpuResizeError),
+
+    /// Failed to dump the guest memory.
+    #[error("failed to dump guest memory: {0}")]
+    DumpGuestMemory(#[source] GuestMemoryDumpError),
 }
 
 /// This enum represents the public interface of the VMM. Each action contains various
@@ -128,6 +141,12 @@ pub enum VmmAction {
     /// booted. The response is sent using the `OutcomeSender`.
     InsertVsockDevice(VsockDeviceConfigInfo),
 
+    #[cfg(feature = "virtio-rng")]
+    /// Add a new virtio-rng device or update one that already exists using the
+    /// `RngDeviceConfigInfo` as input. This action can only be called before the microVM has
+    /// booted.
+    InsertRngDevice(RngDeviceConfigInfo),
+
     #[cfg(feature = "virtio-blk")]
     /// Add a new block device or update one that already exists using the `BlockDeviceConfig` as
     /// input. This action can only be called before the microVM has booted.
@@ -172,6 +191,9 @@ pub enum VmmAction {
     #[cfg(feature = "hotplug")]
     /// Resize Vcpu number in the guest.
     ResizeVcpu(VcpuResizeInfo),
+
+    /// Dump the guest memory into an ELF core file, after microVM start.
+    DumpGuestMemory(GuestMemoryDumpInfo),
 }
 
 /// The enum represents the response sent by the VMM in case of success. The response is either
@@ -241,6 +263,8 @@ impl VmmService {
             }
             #[cfg(feature = "virtio-vsock")]
             VmmAction::InsertVsockDevice(vsock_cfg) => self.add_vsock_device(vmm, vsock_cfg),
+            #[cfg(feature = "virtio-rng")]
+            VmmAction::InsertRngDevice(rng_cfg) => self.add_rng_device(vmm, rng_cfg),
             #[cfg(feature = "virtio-blk")]
             VmmAction::InsertBlockDevice(block_device_config) => {
                 self.add_block_device(vmm, event_mgr, block_device_config)
@@ -274,6 +298,7 @@ impl VmmService {
             }
             #[cfg(feature = "hotplug")]
             VmmAction::ResizeVcpu(vcpu_resize_cfg) => self.resize_vcpu(vmm, vcpu_resize_cfg),
+            VmmAction::DumpGuestMemory(dump_cfg) => self.dump_guest_memory(vmm, dump_cfg),
         };
 
         debug!("send vmm response: {:?}", response);
@@ -467,6 +492,28 @@ impl VmmService {
             .map_err(VmmActionError::Vsock)
     }
 
+    #[cfg(feature = "virtio-rng")]
+    fn add_rng_device(&self, vmm: &mut Vmm, config: RngDeviceConfigInfo) -> VmmRequestResult {
+        let vm = vmm.get_vm_mut().ok_or(VmmActionError::InvalidVMID)?;
+        if vm.is_vm_initialized() {
+            return Err(VmmActionError::Rng(
+                RngDeviceError::UpdateNotAllowedPostBoot,
+            ));
+        }
+
+        info!("add_rng_device: {:?}", config);
+        let ctx = vm.create_device_op_context(None).map_err(|e| {
+            info!("create device op context error: {:?}", e);
+            VmmActionError::Rng(RngDeviceError::UpdateNotAllowedPostBoot)
+        })?;
+
+        vm.device_manager_mut()
+            .rng_manager
+            .insert_device(ctx, config)
+            .map(|_| VmmData::Empty)
+            .map_err(VmmActionError::Rng)
+    }
+
     #[cfg(feature = "virtio-blk")]
     // Only call this function as part of the API.
     // If the drive_id does not exist, a new Block Device Config is added to the list.
@@ -644,6 +691,20 @@ impl VmmService {
 
         Ok(VmmData::Empty)
     }
+
+    fn dump_guest_memory(&self, vmm: &mut Vmm, config: GuestMemoryDumpInfo) -> VmmRequestResult {
+        let vm = vmm.get_vm_mut().ok_or(VmmActionError::InvalidVMID)?;
+
+        if !vm.is_vm_initialized() {
+            return Err(VmmActionError::DumpGuestMemory(
+                GuestMemoryDumpError::MicroVMNotRunning,
+            ));
+        }
+
+        vm.dump_guest_memory(&config)
+            .map(|_| VmmData::Empty)
+            .map_err(VmmActionError::DumpGuestMemory)
+    }
 }
 
 fn handle_cpu_topology(
@@ -1089,6 +1150,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_vmm_action_dump_guest_memory() {
+        skip_if_not_root!();
+
+        let tests = &mut [
+            // invalid state
+            TestData::new(
+                VmmAction::DumpGuestMemory(GuestMemoryDumpInfo {
+                    path: std::path::PathBuf::from("/tmp/vmcore.elf"),
+                }),
+                InstanceState::Uninitialized,
+                &|result| {
+                    assert!(matches!(
+                        result,
+                        Err(VmmActionError::DumpGuestMemory(
+                            GuestMemoryDumpError::MicroVMNotRunning
+                        ))
+                    ));
+                    let err_string = format!("{}", result.unwrap_err());
+                    let expected_err =
+                        String::from("failed to dump guest memory: the microVM is not running");
+                    assert_eq!(err_string, expected_err);
+                },
+            ),
+        ];
+
+        for t in tests.iter_mut() {
+            t.check_request();
+        }
+    }
+
     #[cfg(feature = "virtio-blk")]
     #[test]
     fn test_vmm_action_insert_block_device() {
@@ -1452,4 +1544,66 @@ mod tests {
             t.check_request();
         }
     }
+
+    #[cfg(feature = "virtio-rng")]
+    #[test]
+    fn test_vmm_action_insert_rng_device() {
+        skip_if_not_root!();
+
+        let tests = &mut [
+            // invalid state
+            TestData::new(
+                VmmAction::InsertRngDevice(RngDeviceConfigInfo::default()),
+                InstanceState::Running,
+                &|result| {
+                    assert!(matches!(
+                        result,
+                        Err(VmmActionError::Rng(
+                            RngDeviceError::UpdateNotAllowedPostBoot
+                        ))
+                    ));
+                    let err_string = format!("{}", result.unwrap_err());
+                    let expected_err = String::from(
+                        "failed to add virtio-rng device: \
+                    update operation is not allowed after boot",
+                    );
+                    assert_eq!(err_string, expected_err);
+                },
+            ),
+            // invalid entropy source
+            TestData::new(
+                VmmAction::InsertRngDevice(RngDeviceConfigInfo {
+                    id: "rng0".to_string(),
+                    entropy_source: "/nonexistent/entropy".to_string(),
+                    ..Default::default()
+                }),
+                InstanceState::Uninitialized,
+                &|result| {
+                    assert!(matches!(
+                        result,
+                        Err(VmmActionError::Rng(RngDeviceError::InvalidEntropySource(
+                            _,
+                            _
+                        )))
+                    ));
+                },
+            ),
+            // success
+            TestData::new(
+                VmmAction::InsertRngDevice(RngDeviceConfigInfo {
+                    id: "rng0".to_string(),
+                    entropy_source: "/dev/urandom".to_string(),
+                    ..Default::default()
+                }),
+                InstanceState::Uninitialized,
+                &|result| {
+                    assert!(result.is_ok());
+                },
+            ),
+        ];
+
+        for t in tests.iter_mut() {
+            t.check_request();
+        }
+    }
 }
@@ -358,6 +358,7 @@ impl MutEventSubscriber for ConsoleEpollHandler {
 pub struct DmesgWriter {
     buf: BytesMut,
     logger: slog::Logger,
+    tee: Option<Box<dyn io::Write + Send>>,
 }
 
 impl DmesgWriter {
@@ -366,6 +367,22 @@ impl DmesgWriter {
         Self {
             buf: BytesMut::with_capacity(1024),
             logger: logger.new(slog::o!("subsystem" => "dmesg")),
+            tee: None,
+        }
+    }
+
+    /// Copy every dmesg line, terminated by '\n', to `tee` besides logging it.
+    pub fn set_tee(&mut self, tee: Box<dyn io::Write + Send>) {
+        self.tee = Some(tee);
+    }
+
+    fn log_line(&mut self, line: &str) {
+        slog::info!(self.logger, "{}", line);
+
+        if let Some(tee) = self.tee.as_mut() {
+            if let Err(e) = tee.write_all(format!("{}\n", line).as_bytes()) {
+                slog::warn!(self.logger, "failed to copy dmesg: {:?}", e);
+            }
         }
     }
 }
@@ -386,20 +403,19 @@ impl io::Write for DmesgWriter {
         for (i, sub) in arr.iter().enumerate() {
             if sub.is_empty() {
                 if !self.buf.is_empty() {
-                    slog::info!(
-                        self.logger,
-                        "{}",
-                        String::from_utf8_lossy(self.buf.as_ref()).trim_end()
-                    );
+                    let line = String::from_utf8_lossy(self.buf.as_ref())
+                        .trim_end()
+                        .to_string();
+                    self.log_line(&line);
                     self.buf.clear();
                 }
             } else if sub.len() < buf.len() && i < count - 1 {
-                slog::info!(
-                    self.logger,
+                let line = format!(
                     "{}{}",
                     String::from_utf8_lossy(self.buf.as_ref()).trim_end(),
                     String::from_utf8_lossy(sub).trim_end(),
                 );
+                self.log_line(&line);
                 self.buf.clear();
             } else {
                 self.buf.put_slice(sub);
@@ -432,6 +448,7 @@ mod tests {
         let mut writer = DmesgWriter {
             buf: Default::default(),
             logger: create_logger(),
+            tee: None,
         };
 
         writer.flush().unwrap();
@@ -445,5 +462,39 @@ mod tests {
         writer.flush().unwrap();
     }
 
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_dmesg_writer_tee() {
+        let tee = SharedBuf::default();
+        let mut writer = DmesgWriter::new(&create_logger());
+        writer.set_tee(Box::new(tee.clone()));
+
+        writer
+            .write_all("[    0.000000] Linux\r\n".as_bytes())
+            .unwrap();
+        writer
+            .write_all("[    1.234567] Kernel pa".as_bytes())
+            .unwrap();
+        writer.write_all("nic\r\n\n".as_bytes()).unwrap();
+        writer.write_all("incomplete".as_bytes()).unwrap();
+
+        assert_eq!(
+            String::from_utf8(tee.0.lock().unwrap().clone()).unwrap(),
+            "[    0.000000] Linux\n[    1.234567] Kernel panic\n"
+        );
+    }
+
     // TODO: add unit tests for console manager
 }
@@ -67,6 +67,14 @@ pub mod vsock_dev_mgr;
 #[cfg(feature = "virtio-vsock")]
 use self::vsock_dev_mgr::VsockDeviceMgr;
 
+#[cfg(feature = "virtio-rng")]
+/// Device manager for virtio-rng devices.
+pub mod rng_dev_mgr;
+#[cfg(feature = "virtio-rng")]
+use self::rng_dev_mgr::RngDeviceMgr;
+#[cfg(feature = "virtio-rng")]
+mod virtio_rng;
+
 #[cfg(feature = "virtio-blk")]
 /// virtio-block device manager
 pub mod blk_dev_mgr;
@@ -481,6 +489,9 @@ pub struct DeviceManager {
 
     #[cfg(feature = "virtio-fs")]
     fs_manager: Arc<Mutex<FsDeviceMgr>>,
+
+    #[cfg(feature = "virtio-rng")]
+    pub(crate) rng_manager: RngDeviceMgr,
 }
 
 impl DeviceManager {
@@ -511,6 +522,8 @@ impl DeviceManager {
             virtio_net_manager: VirtioNetDeviceMgr::default(),
             #[cfg(feature = "virtio-fs")]
             fs_manager: Arc::new(Mutex::new(FsDeviceMgr::default())),
+            #[cfg(feature = "virtio-rng")]
+            rng_manager: RngDeviceMgr::default(),
         }
     }
 
@@ -672,6 +685,9 @@ impl DeviceManager {
         #[cfg(feature = "virtio-vsock")]
         self.vsock_manager.attach_devices(&mut ctx)?;
 
+        #[cfg(feature = "virtio-rng")]
+        self.rng_manager.attach_devices(&mut ctx)?;
+
         #[cfg(feature = "virtio-blk")]
         self.block_manager
             .generate_kernel_boot_args(kernel_config)
@@ -1039,6 +1055,8 @@ mod tests {
                 virtio_net_manager: VirtioNetDeviceMgr::default(),
                 #[cfg(feature = "virtio-vsock")]
                 vsock_manager: VsockDeviceMgr::default(),
+                #[cfg(feature = "virtio-rng")]
+                rng_manager: RngDeviceMgr::default(),
                 #[cfg(target_arch = "aarch64")]
                 mmio_device_info: HashMap::new(),
 
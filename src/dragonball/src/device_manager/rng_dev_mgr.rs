@@ -0,0 +1,219 @@
+// Copyright (C) 2023 Kata Contributors. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::convert::TryInto;
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::os::unix::fs::OpenOptionsExt;
+
+use dbs_virtio_devices as virtio;
+use serde_derive::{Deserialize, Serialize};
+
+use super::virtio_rng::VirtioRng;
+use super::StartMicroVmError;
+use crate::config_manager::{
+    ConfigItem, DeviceConfigInfo, DeviceConfigInfos, RateLimiterConfigInfo,
+};
+use crate::device_manager::{DeviceManager, DeviceOpContext};
+
+const SUBSYSTEM: &str = "rng_dev_mgr";
+// The flag of whether to use the shared irq.
+const USE_SHARED_IRQ: bool = true;
+// The flag of whether to use the generic irq.
+const USE_GENERIC_IRQ: bool = true;
+
+/// Errors associated with `RngDeviceConfigInfo`.
+#[derive(Debug, thiserror::Error)]
+pub enum RngDeviceError {
+    /// The device ID is already in use.
+    #[error("the device ID {0} already exists")]
+    DeviceIDAlreadyExist(String),
+
+    /// The update is not allowed after booting the microvm.
+    #[error("update operation is not allowed after boot")]
+    UpdateNotAllowedPostBoot,
+
+    /// The entropy source can't be opened.
+    #[error("invalid entropy source {0}: {1}")]
+    InvalidEntropySource(String, #[source] std::io::Error),
+}
+
+/// Configuration information for a virtio-rng device.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+pub struct RngDeviceConfigInfo {
+    /// ID of the rng device.
+    pub id: String,
+    /// Host file the random bytes are read from, e.g. `/dev/urandom`.
+    pub entropy_source: String,
+    /// Rate limiter of the entropy read by the guest, unlimited if None.
+    pub rate_limiter: Option<RateLimiterConfigInfo>,
+    /// Use shared irq
+    pub use_shared_irq: Option<bool>,
+    /// Use generic irq
+    pub use_generic_irq: Option<bool>,
+}
+
+impl ConfigItem for RngDeviceConfigInfo {
+    type Err = RngDeviceError;
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn check_conflicts(&self, other: &Self) -> Result<(), RngDeviceError> {
+        if self.id == other.id {
+            return Err(RngDeviceError::DeviceIDAlreadyExist(self.id.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Rng Device Info
+pub type RngDeviceInfo = DeviceConfigInfo<RngDeviceConfigInfo>;
+
+/// Device manager to manage all virtio-rng devices.
+pub struct RngDeviceMgr {
+    pub(crate) info_list: DeviceConfigInfos<RngDeviceConfigInfo>,
+    pub(crate) use_shared_irq: bool,
+}
+
+impl RngDeviceMgr {
+    /// Insert or update a virtio-rng device into the manager.
+    pub fn insert_device(
+        &mut self,
+        ctx: DeviceOpContext,
+        config: RngDeviceConfigInfo,
+    ) -> std::result::Result<(), RngDeviceError> {
+        if ctx.is_hotplug {
+            slog::error!(
+                ctx.logger(),
+                "no support of virtio-rng device hotplug";
+                "subsystem" => SUBSYSTEM,
+                "id" => &config.id,
+            );
+
+            return Err(RngDeviceError::UpdateNotAllowedPostBoot);
+        }
+
+        // fail early on an invalid entropy source, rather than at boot time
+        open_entropy_source(&config.entropy_source)
+            .map_err(|e| RngDeviceError::InvalidEntropySource(config.entropy_source.clone(), e))?;
+
+        slog::info!(
+            ctx.logger(),
+            "add virtio-rng device configuration";
+            "subsystem" => SUBSYSTEM,
+            "id" => &config.id,
+            "entropy_source" => &config.entropy_source,
+        );
+
+        self.info_list.insert_or_update(&config)?;
+
+        Ok(())
+    }
+
+    /// Attach all configured virtio-rng devices to the virtual machine instance.
+    pub fn attach_devices(
+        &mut self,
+        ctx: &mut DeviceOpContext,
+    ) -> std::result::Result<(), StartMicroVmError> {
+        let epoll_mgr = ctx
+            .epoll_mgr
+            .clone()
+            .ok_or(StartMicroVmError::CreateRngDevice(
+                virtio::Error::InvalidInput,
+            ))?;
+
+        for info in self.info_list.iter_mut() {
+            slog::info!(
+                ctx.logger(),
+                "attach virtio-rng device";
+                "subsystem" => SUBSYSTEM,
+                "id" => &info.config.id,
+                "entropy_source" => &info.config.entropy_source,
+            );
+
+            let entropy_source = open_entropy_source(&info.config.entropy_source)
+                .map_err(virtio::Error::IOError)
+                .map_err(StartMicroVmError::CreateRngDevice)?;
+            let rate_limiter = match info.config.rate_limiter.as_ref() {
+                Some(config) => Some(
+                    config
+                        .try_into()
+                        .map_err(virtio::Error::IOError)
+                        .map_err(StartMicroVmError::CreateRngDevice)?,
+                ),
+                None => None,
+            };
+            let device = Box::new(VirtioRng::new(
+                entropy_source,
+                rate_limiter,
+                epoll_mgr.clone(),
+                ctx.logger(),
+            ));
+            let device = DeviceManager::create_mmio_virtio_device(
+                device,
+                ctx,
+                info.config.use_shared_irq.unwrap_or(self.use_shared_irq),
+                info.config.use_generic_irq.unwrap_or(USE_GENERIC_IRQ),
+            )
+            .map_err(StartMicroVmError::RegisterRngDevice)?;
+            info.device = Some(device);
+        }
+
+        Ok(())
+    }
+}
+
+// The entropy source is read from the epoll thread, it must not block when the source runs out
+// of random bytes, e.g. /dev/random.
+fn open_entropy_source(path: &str) -> io::Result<File> {
+    OpenOptions::new()
+        .read(true)
+        .custom_flags(libc::O_NONBLOCK)
+        .open(path)
+}
+
+impl Default for RngDeviceMgr {
+    /// Create a new virtio-rng device manager.
+    fn default() -> Self {
+        RngDeviceMgr {
+            info_list: DeviceConfigInfos::new(),
+            use_shared_irq: USE_SHARED_IRQ,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_config_conflicts() {
+        let config = RngDeviceConfigInfo {
+            id: "rng0".to_string(),
+            entropy_source: "/dev/urandom".to_string(),
+            ..Default::default()
+        };
+        let mut other = config.clone();
+        assert!(matches!(
+            config.check_conflicts(&other),
+            Err(RngDeviceError::DeviceIDAlreadyExist(_))
+        ));
+
+        other.id = "rng1".to_string();
+        assert!(config.check_conflicts(&other).is_ok());
+    }
+
+    #[test]
+    fn test_open_entropy_source() {
+        use std::os::unix::io::AsRawFd;
+
+        let file = open_entropy_source("/dev/urandom").unwrap();
+        let flags = unsafe { libc::fcntl(file.as_raw_fd(), libc::F_GETFL) };
+        assert_ne!(flags & libc::O_NONBLOCK, 0);
+
+        assert!(open_entropy_source("/dev/nonexistent-entropy").is_err());
+    }
+}
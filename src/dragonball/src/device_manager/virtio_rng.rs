@@ -0,0 +1,310 @@
+// Copyright (C) 2023 Kata Contributors. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Virtio entropy device, which fills the guest buffers with bytes read from a host entropy
+//! source such as `/dev/urandom`.
+
+use std::any::Any;
+use std::fs::File;
+use std::io;
+use std::ops::Deref;
+use std::os::unix::io::AsRawFd;
+use std::sync::Arc;
+
+use dbs_device::resources::ResourceConstraint;
+use dbs_utils::epoll_manager::{
+    EpollManager, EventOps, EventSet, Events, MutEventSubscriber, SubscriberId,
+};
+use dbs_utils::rate_limiter::{RateLimiter, TokenType};
+use dbs_virtio_devices::{
+    ActivateError, ActivateResult, VirtioDevice, VirtioDeviceConfig, VirtioDeviceInfo,
+};
+use virtio_queue::{QueueOwnedT, QueueSync, QueueT};
+use vm_memory::{Bytes, GuestAddressSpace, GuestMemoryError, GuestRegionMmap};
+
+use crate::address_space_manager::GuestAddressSpaceImpl;
+
+/// Virtio device type of the entropy device.
+pub const TYPE_RNG: u32 = 4;
+/// Number and sizes of the virtio queues of the entropy device.
+pub const QUEUE_SIZES: &[u16] = &[256];
+
+const RNG_DRIVER_NAME: &str = "virtio-rng";
+const VIRTIO_F_VERSION_1: u32 = 32;
+// Event of the request queue.
+const QUEUE_AVAIL_EVENT: u32 = 0;
+// Event of the rate limiter, when the guest may request entropy again.
+const RATE_LIMITER_EVENT: u32 = 1;
+// Event of the entropy source, when it has random bytes again after running out.
+const ENTROPY_SOURCE_EVENT: u32 = 2;
+
+type RngDeviceConfig = VirtioDeviceConfig<GuestAddressSpaceImpl, QueueSync, GuestRegionMmap>;
+
+/// Virtio entropy device backed by a host entropy source.
+pub struct VirtioRng {
+    device_info: VirtioDeviceInfo,
+    entropy_source: File,
+    rate_limiter: Option<RateLimiter>,
+    subscriber_id: Option<SubscriberId>,
+    logger: slog::Logger,
+}
+
+impl VirtioRng {
+    /// Create a virtio entropy device reading from `entropy_source`, which must be opened in
+    /// non-blocking mode so that an exhausted source never blocks the epoll thread.
+    pub fn new(
+        entropy_source: File,
+        rate_limiter: Option<RateLimiter>,
+        epoll_mgr: EpollManager,
+        logger: &slog::Logger,
+    ) -> Self {
+        VirtioRng {
+            device_info: VirtioDeviceInfo::new(
+                RNG_DRIVER_NAME.to_string(),
+                1u64 << VIRTIO_F_VERSION_1,
+                Arc::new(QUEUE_SIZES.to_vec()),
+                Vec::new(),
+                epoll_mgr,
+            ),
+            entropy_source,
+            rate_limiter,
+            subscriber_id: None,
+            logger: logger.new(slog::o!("subsystem" => "virtio_rng")),
+        }
+    }
+}
+
+impl VirtioDevice<GuestAddressSpaceImpl, QueueSync, GuestRegionMmap> for VirtioRng {
+    fn device_type(&self) -> u32 {
+        TYPE_RNG
+    }
+
+    fn queue_max_sizes(&self) -> &[u16] {
+        QUEUE_SIZES
+    }
+
+    fn get_avail_features(&self, page: u32) -> u32 {
+        self.device_info.get_avail_features(page)
+    }
+
+    fn set_acked_features(&mut self, page: u32, value: u32) {
+        self.device_info.set_acked_features(page, value)
+    }
+
+    fn read_config(&mut self, offset: u64, data: &mut [u8]) {
+        self.device_info.read_config(offset, data)
+    }
+
+    fn write_config(&mut self, offset: u64, data: &[u8]) {
+        self.device_info.write_config(offset, data)
+    }
+
+    fn activate(&mut self, config: RngDeviceConfig) -> ActivateResult {
+        if config.queues.len() != QUEUE_SIZES.len() {
+            slog::error!(
+                self.logger,
+                "invalid number of queues: {}",
+                config.queues.len()
+            );
+            return Err(ActivateError::InvalidParam);
+        }
+
+        let entropy_source = self.entropy_source.try_clone().map_err(|e| {
+            slog::error!(self.logger, "failed to clone entropy source: {:?}", e);
+            ActivateError::InternalError
+        })?;
+        let handler = RngEpollHandler {
+            config,
+            entropy_source,
+            rate_limiter: self.rate_limiter.take().unwrap_or_default(),
+            waiting_entropy: false,
+            logger: self.logger.clone(),
+        };
+        self.subscriber_id = Some(self.device_info.register_event_handler(Box::new(handler)));
+
+        Ok(())
+    }
+
+    fn remove(&mut self) {
+        if let Some(id) = self.subscriber_id.take() {
+            let _ = self.device_info.remove_event_handler(id);
+        }
+    }
+
+    fn get_resource_requirements(
+        &self,
+        requests: &mut Vec<ResourceConstraint>,
+        use_generic_irq: bool,
+    ) {
+        requests.push(ResourceConstraint::LegacyIrq { irq: None });
+        if use_generic_irq {
+            requests.push(ResourceConstraint::GenericIrq {
+                size: (QUEUE_SIZES.len() + 1) as u32,
+            });
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+// The outcome of filling the guest buffers.
+#[derive(Debug, PartialEq, Eq)]
+enum QueueState {
+    // All available guest buffers were filled.
+    Done,
+    // The rate limiter is out of budget, its timer fires when there is budget again.
+    RateLimited,
+    // The entropy source has no bytes available right now.
+    EntropyExhausted,
+}
+
+struct RngEpollHandler {
+    config: RngDeviceConfig,
+    entropy_source: File,
+    rate_limiter: RateLimiter,
+    // whether the entropy source is registered to the epoll, after running out of bytes
+    waiting_entropy: bool,
+    logger: slog::Logger,
+}
+
+impl RngEpollHandler {
+    // Fill the available guest buffers, return whether any was used and why it stopped.
+    fn process_queue(&mut self) -> (bool, QueueState) {
+        let mem = self.config.vm_as.memory();
+        let queue = &mut self.config.queues[0];
+        let mut used = false;
+
+        while let Ok(Some(mut desc_chain)) = queue.get_next_descriptor(mem.deref()) {
+            let head_index = desc_chain.head_index();
+            let descs: Vec<_> = desc_chain.by_ref().filter(|d| d.is_write_only()).collect();
+            let size: u64 = descs.iter().map(|d| u64::from(d.len())).sum();
+
+            if !self.rate_limiter.consume(1, TokenType::Ops) {
+                queue.queue_mut().lock().go_to_previous_position();
+                return (used, QueueState::RateLimited);
+            }
+            if !self.rate_limiter.consume(size, TokenType::Bytes) {
+                self.rate_limiter.manual_replenish(1, TokenType::Ops);
+                queue.queue_mut().lock().go_to_previous_position();
+                return (used, QueueState::RateLimited);
+            }
+
+            let mut len = 0;
+            let mut exhausted = false;
+            for desc in descs {
+                match mem.read_from(desc.addr(), &mut self.entropy_source, desc.len() as usize) {
+                    Ok(count) => len += count as u32,
+                    Err(GuestMemoryError::IOError(e)) if e.kind() == io::ErrorKind::WouldBlock => {
+                        exhausted = true;
+                        break;
+                    }
+                    Err(e) => {
+                        slog::error!(self.logger, "failed to read entropy source: {:?}", e);
+                        break;
+                    }
+                }
+            }
+
+            if len == 0 && exhausted {
+                // retry the request once the entropy source is readable, rather than
+                // completing it empty and have the guest ask again right away
+                self.rate_limiter.manual_replenish(1, TokenType::Ops);
+                self.rate_limiter.manual_replenish(size, TokenType::Bytes);
+                queue.queue_mut().lock().go_to_previous_position();
+                return (used, QueueState::EntropyExhausted);
+            }
+
+            if u64::from(len) < size {
+                self.rate_limiter
+                    .manual_replenish(size - u64::from(len), TokenType::Bytes);
+            }
+            queue.add_used(mem.deref(), head_index, len);
+            used = true;
+        }
+
+        (used, QueueState::Done)
+    }
+
+    // Fill the guest buffers and notify the guest, wait for the entropy source if it's empty.
+    fn handle_queue(&mut self, ops: &mut EventOps) {
+        let (used, state) = self.process_queue();
+        if used {
+            if let Err(e) = self.config.queues[0].notify() {
+                slog::error!(self.logger, "failed to notify guest: {:?}", e);
+            }
+        }
+
+        let waiting_entropy = state == QueueState::EntropyExhausted;
+        if waiting_entropy != self.waiting_entropy {
+            let events =
+                Events::with_data(&self.entropy_source, ENTROPY_SOURCE_EVENT, EventSet::IN);
+            let result = if waiting_entropy {
+                ops.add(events)
+            } else {
+                ops.remove(events)
+            };
+            match result {
+                Ok(_) => self.waiting_entropy = waiting_entropy,
+                Err(e) => {
+                    slog::error!(
+                        self.logger,
+                        "failed to update entropy source event: {:?}",
+                        e
+                    )
+                }
+            }
+        }
+    }
+}
+
+impl MutEventSubscriber for RngEpollHandler {
+    fn process(&mut self, events: Events, ops: &mut EventOps) {
+        match events.data() {
+            QUEUE_AVAIL_EVENT => {
+                if let Err(e) = self.config.queues[0].consume_event() {
+                    slog::error!(self.logger, "failed to get queue event: {:?}", e);
+                    return;
+                }
+                // the rate limiter or the entropy source event resumes the queue
+                if !self.rate_limiter.is_blocked() && !self.waiting_entropy {
+                    self.handle_queue(ops);
+                }
+            }
+            RATE_LIMITER_EVENT => match self.rate_limiter.event_handler() {
+                Ok(_) => self.handle_queue(ops),
+                Err(e) => slog::error!(self.logger, "failed to get rate limiter event: {:?}", e),
+            },
+            ENTROPY_SOURCE_EVENT => self.handle_queue(ops),
+            data => slog::error!(self.logger, "unknown event data {}", data),
+        }
+    }
+
+    fn init(&mut self, ops: &mut EventOps) {
+        let events = Events::with_data(
+            self.config.queues[0].eventfd.as_ref(),
+            QUEUE_AVAIL_EVENT,
+            EventSet::IN,
+        );
+        if let Err(e) = ops.add(events) {
+            slog::error!(self.logger, "failed to register queue event: {:?}", e);
+        }
+
+        let rate_limiter_fd = self.rate_limiter.as_raw_fd();
+        if rate_limiter_fd >= 0 {
+            let events = Events::with_data_raw(rate_limiter_fd, RATE_LIMITER_EVENT, EventSet::IN);
+            if let Err(e) = ops.add(events) {
+                slog::error!(
+                    self.logger,
+                    "failed to register rate limiter event: {:?}",
+                    e
+                );
+            }
+        }
+    }
+}
@@ -156,6 +156,16 @@ pub enum StartMicroVmError {
     #[error("failure while registering virtio-vsock device: {0}")]
     RegisterVsockDevice(#[source] device_manager::DeviceMgrError),
 
+    #[cfg(feature = "virtio-rng")]
+    /// Failed to create the virtio-rng device.
+    #[error("cannot create virtio-rng device: {0}")]
+    CreateRngDevice(#[source] VirtIoError),
+
+    #[cfg(feature = "virtio-rng")]
+    /// Cannot initialize a MMIO virtio-rng device or add a device to the MMIO Bus.
+    #[error("failure while registering virtio-rng device: {0}")]
+    RegisterRngDevice(#[source] device_manager::DeviceMgrError),
+
     /// Address space manager related error, e.g.cannot access guest address space manager.
     #[error("address space manager related error: {0}")]
     AddressManagerError(#[source] address_space_manager::AddressManagerError),
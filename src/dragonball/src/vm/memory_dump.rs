@@ -0,0 +1,238 @@
+// Copyright (C) 2023 Kata Contributors. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Dump the guest memory into an ELF core file.
+//!
+//! The generated file only holds the guest physical memory: one `PT_LOAD` segment per guest memory
+//! region, with the guest physical address as `p_paddr`. Unlike the vmcore produced by QEMU's
+//! `dump-guest-memory`, there is no `PT_NOTE` segment, so neither the vcpu registers
+//! (`NT_PRSTATUS`) nor the guest `VMCOREINFO` are saved. Tools like `crash` need the guest
+//! `vmlinux` to find the kernel symbols in the dump.
+
+use std::convert::TryFrom;
+use std::fs::OpenOptions;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use serde_derive::{Deserialize, Serialize};
+use vm_memory::{Address, Bytes, GuestAddress, GuestMemory, GuestMemoryRegion};
+
+use crate::vcpu::VcpuManagerError;
+
+const ELF_MAGIC: [u8; 4] = [0x7f, b'E', b'L', b'F'];
+const ELF_CLASS_64: u8 = 2;
+const ELF_DATA_LSB: u8 = 1;
+const ELF_VERSION_CURRENT: u8 = 1;
+const ELF_TYPE_CORE: u16 = 4;
+#[cfg(target_arch = "x86_64")]
+const ELF_MACHINE: u16 = 62; // EM_X86_64
+#[cfg(target_arch = "aarch64")]
+const ELF_MACHINE: u16 = 183; // EM_AARCH64
+
+const ELF_HEADER_SIZE: u16 = 64;
+const ELF_PROGRAM_HEADER_SIZE: u16 = 56;
+
+const PT_LOAD: u32 = 1;
+const PF_RWX: u32 = 0x7;
+
+/// Errors associated with dumping the guest memory.
+#[derive(Debug, thiserror::Error)]
+pub enum GuestMemoryDumpError {
+    /// The guest memory can only be dumped once the microVM is running.
+    #[error("the microVM is not running")]
+    MicroVMNotRunning,
+
+    /// The guest memory is not initialized.
+    #[error("the guest memory is not initialized")]
+    GuestMemoryNotInitialized,
+
+    /// Too many guest memory regions to describe in the ELF header.
+    #[error("too many guest memory regions: {0}")]
+    TooManyRegions(usize),
+
+    /// Failed to pause or resume the vcpus around the dump.
+    #[error("failed to pause or resume vcpus: {0}")]
+    Vcpu(#[source] VcpuManagerError),
+
+    /// Failed to create the dump file.
+    #[error("failed to create dump file {0:?}: {1}")]
+    CreateFile(PathBuf, #[source] io::Error),
+
+    /// Failed to write the ELF headers.
+    #[error("failed to write dump file: {0}")]
+    WriteFile(#[source] io::Error),
+
+    /// Failed to copy the guest memory into the dump file.
+    #[error("failed to copy guest memory: {0}")]
+    CopyGuestMemory(#[source] vm_memory::GuestMemoryError),
+}
+
+type Result<T> = std::result::Result<T, GuestMemoryDumpError>;
+
+/// Configuration information for dumping the guest memory.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct GuestMemoryDumpInfo {
+    /// Host path of the ELF core file to create, it must not exist.
+    pub path: PathBuf,
+}
+
+/// Create the ELF core file at `path` and dump all guest memory regions into it.
+pub(crate) fn dump_guest_memory<M: GuestMemory>(vm_memory: &M, path: &Path) -> Result<()> {
+    let file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .map_err(|e| GuestMemoryDumpError::CreateFile(path.to_path_buf(), e))?;
+    let mut writer = BufWriter::new(file);
+
+    write_elf_core(vm_memory, &mut writer)?;
+    writer.flush().map_err(GuestMemoryDumpError::WriteFile)
+}
+
+/// Write the ELF header, the program headers and the content of all guest memory regions.
+pub(crate) fn write_elf_core<M: GuestMemory, W: Write>(
+    vm_memory: &M,
+    writer: &mut W,
+) -> Result<()> {
+    let regions: Vec<(u64, u64)> = vm_memory
+        .iter()
+        .map(|r| (r.start_addr().raw_value(), r.len()))
+        .collect();
+    let phnum = u16::try_from(regions.len())
+        .map_err(|_| GuestMemoryDumpError::TooManyRegions(regions.len()))?;
+
+    let mut headers = elf_header(phnum);
+    let mut offset = ELF_HEADER_SIZE as u64 + ELF_PROGRAM_HEADER_SIZE as u64 * phnum as u64;
+    for (addr, len) in regions.iter() {
+        headers.extend_from_slice(&load_program_header(offset, *addr, *len));
+        offset += len;
+    }
+    writer
+        .write_all(&headers)
+        .map_err(GuestMemoryDumpError::WriteFile)?;
+
+    for (addr, len) in regions {
+        vm_memory
+            .write_all_to(GuestAddress(addr), writer, len as usize)
+            .map_err(GuestMemoryDumpError::CopyGuestMemory)?;
+    }
+
+    Ok(())
+}
+
+fn elf_header(phnum: u16) -> Vec<u8> {
+    let mut h = Vec::with_capacity(ELF_HEADER_SIZE as usize);
+
+    // e_ident
+    h.extend_from_slice(&ELF_MAGIC);
+    h.extend_from_slice(&[ELF_CLASS_64, ELF_DATA_LSB, ELF_VERSION_CURRENT]);
+    h.extend_from_slice(&[0u8; 9]);
+    h.extend_from_slice(&ELF_TYPE_CORE.to_le_bytes());
+    h.extend_from_slice(&ELF_MACHINE.to_le_bytes());
+    // e_version
+    h.extend_from_slice(&(ELF_VERSION_CURRENT as u32).to_le_bytes());
+    // e_entry
+    h.extend_from_slice(&0u64.to_le_bytes());
+    // e_phoff, program headers follow the ELF header
+    h.extend_from_slice(&(ELF_HEADER_SIZE as u64).to_le_bytes());
+    // e_shoff, no section headers
+    h.extend_from_slice(&0u64.to_le_bytes());
+    // e_flags
+    h.extend_from_slice(&0u32.to_le_bytes());
+    h.extend_from_slice(&ELF_HEADER_SIZE.to_le_bytes());
+    h.extend_from_slice(&ELF_PROGRAM_HEADER_SIZE.to_le_bytes());
+    h.extend_from_slice(&phnum.to_le_bytes());
+    // e_shentsize, e_shnum, e_shstrndx
+    h.extend_from_slice(&[0u8; 6]);
+
+    h
+}
+
+fn load_program_header(offset: u64, addr: u64, len: u64) -> Vec<u8> {
+    let mut h = Vec::with_capacity(ELF_PROGRAM_HEADER_SIZE as usize);
+
+    h.extend_from_slice(&PT_LOAD.to_le_bytes());
+    h.extend_from_slice(&PF_RWX.to_le_bytes());
+    h.extend_from_slice(&offset.to_le_bytes());
+    // p_vaddr, the guest page tables are not walked
+    h.extend_from_slice(&0u64.to_le_bytes());
+    // p_paddr
+    h.extend_from_slice(&addr.to_le_bytes());
+    // p_filesz and p_memsz
+    h.extend_from_slice(&len.to_le_bytes());
+    h.extend_from_slice(&len.to_le_bytes());
+    // p_align
+    h.extend_from_slice(&0u64.to_le_bytes());
+
+    h
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use vm_memory::GuestMemoryMmap;
+    use vmm_sys_util::tempfile::TempFile;
+
+    use super::*;
+
+    fn read_u16(buf: &[u8], off: usize) -> u16 {
+        u16::from_le_bytes(buf[off..off + 2].try_into().unwrap())
+    }
+
+    fn read_u64(buf: &[u8], off: usize) -> u64 {
+        u64::from_le_bytes(buf[off..off + 8].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_write_elf_core() {
+        let mem = GuestMemoryMmap::<()>::from_ranges(&[
+            (GuestAddress(0), 0x1000),
+            (GuestAddress(0x10_0000), 0x2000),
+        ])
+        .unwrap();
+        mem.write_obj(0xdead_beef_u32, GuestAddress(0x10)).unwrap();
+        mem.write_obj(0xcafe_u16, GuestAddress(0x10_1000)).unwrap();
+
+        let mut core = Vec::new();
+        write_elf_core(&mem, &mut core).unwrap();
+
+        let data_offset = 64 + 2 * 56;
+        assert_eq!(core.len(), data_offset + 0x3000);
+        assert_eq!(&core[0..4], &ELF_MAGIC);
+        assert_eq!(read_u16(&core, 16), ELF_TYPE_CORE);
+        assert_eq!(read_u16(&core, 18), ELF_MACHINE);
+        assert_eq!(read_u64(&core, 32), 64);
+        assert_eq!(read_u16(&core, 56), 2);
+
+        // second PT_LOAD describes the region at 1MiB
+        let ph = 64 + 56;
+        assert_eq!(read_u64(&core, ph + 8), (data_offset + 0x1000) as u64);
+        assert_eq!(read_u64(&core, ph + 24), 0x10_0000);
+        assert_eq!(read_u64(&core, ph + 32), 0x2000);
+
+        assert_eq!(
+            &core[data_offset + 0x10..data_offset + 0x14],
+            &0xdead_beef_u32.to_le_bytes()
+        );
+        let off = data_offset + 0x1000 + 0x1000;
+        assert_eq!(&core[off..off + 2], &0xcafe_u16.to_le_bytes());
+    }
+
+    #[test]
+    fn test_dump_guest_memory() {
+        let mem = GuestMemoryMmap::<()>::from_ranges(&[(GuestAddress(0), 0x1000)]).unwrap();
+        let file = TempFile::new().unwrap();
+        let path = file.as_path().to_path_buf();
+
+        // an existing file is never overwritten
+        assert!(matches!(
+            dump_guest_memory(&mem, &path),
+            Err(GuestMemoryDumpError::CreateFile(_, _))
+        ));
+
+        std::fs::remove_file(&path).unwrap();
+        dump_guest_memory(&mem, &path).unwrap();
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), 64 + 56 + 0x1000);
+    }
+}
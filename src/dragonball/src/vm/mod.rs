@@ -48,6 +48,9 @@ use dbs_arch::gic::Error as GICError;
 mod kernel_config;
 pub use self::kernel_config::KernelConfigInfo;
 
+mod memory_dump;
+pub use self::memory_dump::{GuestMemoryDumpError, GuestMemoryDumpInfo};
+
 #[cfg(target_arch = "aarch64")]
 #[path = "aarch64.rs"]
 mod aarch64;
@@ -184,6 +187,7 @@ pub struct Vm {
     address_space: AddressSpaceMgr,
     device_manager: DeviceManager,
     dmesg_fifo: Option<Box<dyn io::Write + Send>>,
+    dmesg_tee: Option<Box<dyn io::Write + Send>>,
     kernel_config: Option<KernelConfigInfo>,
     logger: slog::Logger,
     reset_eventfd: Option<EventFd>,
@@ -232,6 +236,7 @@ impl Vm {
             address_space: AddressSpaceMgr::default(),
             device_manager,
             dmesg_fifo: None,
+            dmesg_tee: None,
             kernel_config: None,
             logger,
             reset_eventfd: None,
@@ -447,6 +452,30 @@ impl Vm {
         Ok(())
     }
 
+    /// Dump the guest memory into an ELF core file, for post-mortem analysis of guest kernel
+    /// crashes. All vcpus are paused while the guest memory is copied.
+    pub fn dump_guest_memory(
+        &self,
+        config: &GuestMemoryDumpInfo,
+    ) -> std::result::Result<(), GuestMemoryDumpError> {
+        let vm_as = self
+            .vm_as()
+            .ok_or(GuestMemoryDumpError::GuestMemoryNotInitialized)?;
+
+        info!(self.logger, "VM: dump guest memory to {:?}", config.path);
+        self.vcpu_manager()
+            .and_then(|mut mgr| mgr.pause_all_vcpus())
+            .map_err(GuestMemoryDumpError::Vcpu)?;
+
+        let result = memory_dump::dump_guest_memory(&*vm_as.memory(), &config.path);
+
+        self.vcpu_manager()
+            .and_then(|mut mgr| mgr.resume_all_vcpus())
+            .map_err(GuestMemoryDumpError::Vcpu)?;
+
+        result
+    }
+
     pub(crate) fn init_devices(
         &mut self,
         epoll_manager: EpollManager,
@@ -516,14 +545,24 @@ impl Vm {
         self.device_manager.reset_console()
     }
 
+    /// Copy the guest kernel log lines to `tee` besides logging them, must be called before the
+    /// microVM starts.
+    pub fn set_guest_kernel_log_tee(&mut self, tee: Box<dyn io::Write + Send>) {
+        self.dmesg_tee = Some(tee);
+    }
+
     pub(crate) fn init_dmesg_logger(&mut self) {
         let writer = self.dmesg_logger();
         self.dmesg_fifo = Some(writer);
     }
 
     /// dmesg write to logger
-    fn dmesg_logger(&self) -> Box<dyn io::Write + Send> {
-        Box::new(DmesgWriter::new(&self.logger))
+    fn dmesg_logger(&mut self) -> Box<dyn io::Write + Send> {
+        let mut writer = DmesgWriter::new(&self.logger);
+        if let Some(tee) = self.dmesg_tee.take() {
+            writer.set_tee(tee);
+        }
+        Box::new(writer)
     }
 
     pub(crate) fn init_guest_memory(&mut self) -> std::result::Result<(), StartMicroVmError> {
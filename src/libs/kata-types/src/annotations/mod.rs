@@ -307,6 +307,10 @@ pub const KATA_ANNO_CFG_DISABLE_NEW_NETNS: &str =
     "io.katacontainers.config.runtime.disable_new_netns";
 /// A sandbox annotation to specify how attached VFIO devices should be treated.
 pub const KATA_ANNO_CFG_VFIO_MODE: &str = "io.katacontainers.config.runtime.vfio_mode";
+/// A sandbox annotation to specify the vhost-user network sockets, a comma separated list of
+/// `<interface>=<socket path>` entries.
+pub const KATA_ANNO_CFG_VHOST_USER_NET_SOCKETS: &str =
+    "io.katacontainers.config.runtime.vhost_user_net_sockets";
 
 /// A sandbox annotation used to specify prefetch_files.list host path container image
 /// being used,
@@ -950,6 +954,10 @@ impl Annotation {
                     KATA_ANNO_CFG_VFIO_MODE => {
                         config.runtime.vfio_mode = value.to_string();
                     }
+                    KATA_ANNO_CFG_VHOST_USER_NET_SOCKETS => {
+                        config.runtime.vhost_user_net_sockets =
+                            value.split(',').map(str::to_string).collect();
+                    }
                     _ => {
                         warn!(sl!(), "Annotation {} not enabled", key);
                     }
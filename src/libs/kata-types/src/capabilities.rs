@@ -17,6 +17,8 @@ pub enum CapabilityBits {
     MultiQueueSupport,
     /// hypervisor supports filesystem share
     FsSharingSupport,
+    /// hypervisor supports vhost-user network device
+    VhostUserNetSupport,
 }
 
 /// Capabilities describe a virtcontainers hypervisor capabilities through a bit mask.
@@ -64,6 +66,11 @@ impl Capabilities {
     pub fn is_fs_sharing_supported(&self) -> bool {
         self.flags.and(CapabilityBits::FsSharingSupport) != 0
     }
+
+    /// is_vhost_user_net_supported tells if an hypervisor supports vhost-user network devices.
+    pub fn is_vhost_user_net_supported(&self) -> bool {
+        self.flags.and(CapabilityBits::VhostUserNetSupport) != 0
+    }
 }
 
 #[cfg(test)]
@@ -102,6 +109,11 @@ mod tests {
                 | CapabilityBits::MultiQueueSupport
                 | CapabilityBits::FsSharingSupport,
         );
-        assert!(cap.is_fs_sharing_supported())
+        assert!(cap.is_fs_sharing_supported());
+        assert!(!cap.is_vhost_user_net_supported());
+
+        // test set vhost-user network device support
+        cap.set(CapabilityBits::FsSharingSupport | CapabilityBits::VhostUserNetSupport);
+        assert!(cap.is_vhost_user_net_supported())
     }
 }
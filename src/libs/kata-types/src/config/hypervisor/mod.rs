@@ -631,6 +631,7 @@ pub struct NetworkInfo {
     /// Use rx Rate Limiter to control network I/O inbound bandwidth(size in bits/sec for SB/VM).
     ///
     /// In Qemu, we use classful qdiscs HTB(Hierarchy Token Bucket) to discipline traffic.
+    /// In Dragonball, the rate limiter of the virtio-net device is used.
     /// Default 0-sized value means unlimited rate.
     #[serde(default)]
     pub rx_rate_limiter_max_rate: u64,
@@ -639,6 +640,7 @@ pub struct NetworkInfo {
     ///
     /// In Qemu, we use classful qdiscs HTB(Hierarchy Token Bucket) and ifb(Intermediate Functional
     /// Block) to discipline traffic.
+    /// In Dragonball, the rate limiter of the virtio-net device is used.
     /// Default 0-sized value means unlimited rate.
     #[serde(default)]
     pub tx_rate_limiter_max_rate: u64,
@@ -30,7 +30,9 @@ pub use self::hypervisor::{
 };
 
 mod runtime;
-pub use self::runtime::{Runtime, RuntimeVendor, RUNTIME_NAME_VIRTCONTAINER};
+pub use self::runtime::{
+    split_sandbox_bind_mount, Runtime, RuntimeVendor, RUNTIME_NAME_VIRTCONTAINER,
+};
 
 pub use self::agent::AGENT_NAME_KATA;
 
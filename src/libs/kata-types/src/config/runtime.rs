@@ -13,6 +13,11 @@ use crate::{eother, resolve_path, validate_path};
 /// Type of runtime VirtContainer.
 pub const RUNTIME_NAME_VIRTCONTAINER: &str = "virt_container";
 
+/// Suffix of a read only `sandbox_bind_mounts` entry, the default mode.
+pub const SANDBOX_BIND_MOUNTS_RO: &str = ":ro";
+/// Suffix of a read write `sandbox_bind_mounts` entry.
+pub const SANDBOX_BIND_MOUNTS_RW: &str = ":rw";
+
 /// Kata runtime configuration information.
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct Runtime {
@@ -71,6 +76,9 @@ pub struct Runtime {
     /// available in the guest at `/run/kata-containers/shared/containers/passthrough/sandbox-mounts`.
     /// These will not be exposed to the container workloads, and are only provided for potential
     /// guest services.
+    ///
+    /// A path may be suffixed with `:ro` or `:rw` to select the mount mode, it's read only by
+    /// default.
     #[serde(default)]
     pub sandbox_bind_mounts: Vec<String>,
 
@@ -97,6 +105,10 @@ pub struct Runtime {
     /// The password to be used if basic auth is required for Jaeger.
     #[serde(default)]
     pub jaeger_password: String,
+    /// The address of the OpenTelemetry collector to export the traces to with OTLP over gRPC.
+    /// If set, it is used instead of Jaeger.
+    #[serde(default)]
+    pub otlp_endpoint: String,
 
     /// If enabled, user can run pprof tools with shim v2 process through kata-monitor.
     #[serde(default)]
@@ -127,6 +139,15 @@ pub struct Runtime {
     #[serde(default)]
     pub vfio_mode: String,
 
+    /// Vhost-user network sockets of the sandbox, in the `<interface>=<socket path>` format.
+    ///
+    /// A network interface with a matching entry is attached to the VM as a vhost-user-net
+    /// device served by a userspace datapath (like OVS-DPDK), instead of through a tap device.
+    /// Sockets found at `/tmp/vhostuser_<IPv4 address>/vhu.sock` are used without configuration.
+    /// Ignored if the hypervisor doesn't support vhost-user-net devices.
+    #[serde(default)]
+    pub vhost_user_net_sockets: Vec<String>,
+
     /// Vendor customized runtime configuration.
     #[serde(default, flatten)]
     pub vendor: RuntimeVendor,
@@ -146,7 +167,11 @@ impl ConfigOps for Runtime {
         }
 
         for bind in conf.runtime.sandbox_bind_mounts.iter_mut() {
-            resolve_path!(*bind, "sandbox bind mount `{}` is invalid: {}")?;
+            let (path, _) = split_sandbox_bind_mount(bind);
+            let suffix = bind[path.len()..].to_string();
+            let mut path = path.to_string();
+            resolve_path!(path, "sandbox bind mount `{}` is invalid: {}")?;
+            *bind = path + &suffix;
         }
 
         Ok(())
@@ -175,14 +200,38 @@ impl ConfigOps for Runtime {
             ));
         }
 
+        for socket in conf.runtime.vhost_user_net_sockets.iter() {
+            match socket.split_once('=') {
+                Some((iface, path)) if !iface.is_empty() && path.starts_with('/') => {}
+                _ => {
+                    return Err(eother!(
+                        "Invalid vhost_user_net_sockets entry `{}` in configuration file",
+                        socket
+                    ))
+                }
+            }
+        }
+
         for bind in conf.runtime.sandbox_bind_mounts.iter() {
-            validate_path!(*bind, "sandbox bind mount `{}` is invalid: {}")?;
+            let (path, _) = split_sandbox_bind_mount(bind);
+            validate_path!(path, "sandbox bind mount `{}` is invalid: {}")?;
         }
 
         Ok(())
     }
 }
 
+/// Split a `sandbox_bind_mounts` entry into the host path and whether it's mounted read only.
+pub fn split_sandbox_bind_mount(bind: &str) -> (&str, bool) {
+    if let Some(path) = bind.strip_suffix(SANDBOX_BIND_MOUNTS_RW) {
+        (path, false)
+    } else if let Some(path) = bind.strip_suffix(SANDBOX_BIND_MOUNTS_RO) {
+        (path, true)
+    } else {
+        (bind, true)
+    }
+}
+
 impl Runtime {
     /// Check whether experiment `feature` is enabled or not.
     pub fn is_experiment_enabled(&self, feature: &str) -> bool {
@@ -255,6 +304,22 @@ vfio_mode = "vfio,guest-kernel"
 [runtime]
 enable_debug = true
 vfio_mode = "guest_kernel"
+"#;
+        let config: TomlConfig = TomlConfig::load(content).unwrap();
+        config.validate().unwrap_err();
+
+        let content = r#"
+[runtime]
+enable_debug = true
+vhost_user_net_sockets = ["eth0"]
+"#;
+        let config: TomlConfig = TomlConfig::load(content).unwrap();
+        config.validate().unwrap_err();
+
+        let content = r#"
+[runtime]
+enable_debug = true
+vhost_user_net_sockets = ["eth0=vhu.sock"]
 "#;
         let config: TomlConfig = TomlConfig::load(content).unwrap();
         config.validate().unwrap_err();
@@ -278,6 +343,7 @@ jaeger_password = "pw"
 enable_pprof = true
 disable_guest_seccomp = true
 vfio_mode = "vfio"
+vhost_user_net_sockets = ["eth0=/run/vhost-user/eth0.sock"]
 field_should_be_ignored = true
 "#;
         let config: TomlConfig = TomlConfig::load(content).unwrap();
@@ -290,10 +356,39 @@ field_should_be_ignored = true
         assert_eq!(&config.runtime.internetworking_model, "macvtap");
         assert!(config.runtime.disable_new_netns);
         assert_eq!(config.runtime.sandbox_bind_mounts.len(), 0);
+        assert_eq!(
+            &config.runtime.vhost_user_net_sockets[0],
+            "eth0=/run/vhost-user/eth0.sock"
+        );
         assert!(config.runtime.sandbox_cgroup_only);
         assert!(config.runtime.enable_tracing);
         assert!(config.runtime.is_experiment_enabled("a"));
         assert!(config.runtime.is_experiment_enabled("b"));
         assert!(!config.runtime.is_experiment_enabled("c"));
     }
+
+    #[test]
+    fn test_sandbox_bind_mounts() {
+        assert_eq!(split_sandbox_bind_mount("/etc/conf"), ("/etc/conf", true));
+        assert_eq!(
+            split_sandbox_bind_mount("/etc/conf:ro"),
+            ("/etc/conf", true)
+        );
+        assert_eq!(
+            split_sandbox_bind_mount("/etc/conf:rw"),
+            ("/etc/conf", false)
+        );
+
+        let content = r#"
+[runtime]
+sandbox_bind_mounts = ["/tmp/../tmp:rw", "/tmp"]
+"#;
+        let mut config: TomlConfig = TomlConfig::load(content).unwrap();
+        config.validate().unwrap();
+        assert_eq!(config.runtime.sandbox_bind_mounts[0], "/tmp:rw");
+        assert_eq!(config.runtime.sandbox_bind_mounts[1], "/tmp");
+
+        config.runtime.sandbox_bind_mounts = vec!["/nonexist:ro".to_string()];
+        config.validate().unwrap_err();
+    }
 }
@@ -488,6 +488,8 @@ pub struct LinuxResources {
     pub network: Option<LinuxNetwork>,
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub rdma: HashMap<String, LinuxRdma>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub unified: HashMap<String, String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
@@ -735,6 +737,18 @@ pub struct LinuxSeccomp {
     pub architectures: Vec<Arch>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub flags: Vec<LinuxSeccompFlag>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        rename = "listenerPath"
+    )]
+    pub listener_path: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        rename = "listenerMetadata"
+    )]
+    pub listener_metadata: String,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub syscalls: Vec<LinuxSyscall>,
 }
@@ -773,6 +787,7 @@ pub const ACTERRNO: &str = "SCMP_ACT_ERRNO";
 pub const ACTTRACE: &str = "SCMP_ACT_TRACE";
 pub const ACTALLOW: &str = "SCMP_ACT_ALLOW";
 pub const ACTLOG: &str = "SCMP_ACT_LOG";
+pub const ACTNOTIFY: &str = "SCMP_ACT_NOTIFY";
 
 pub type LinuxSeccompOperator = String;
 
@@ -847,6 +862,24 @@ pub struct State {
     pub annotations: HashMap<String, String>,
 }
 
+// The name of the seccomp notify fd in ContainerProcessState.fds
+pub const SECCOMP_FD_NAME: &str = "seccompFd";
+
+// ContainerProcessState is sent to the seccomp agent listening on
+// LinuxSeccomp.listener_path along with the seccomp notify fd.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ContainerProcessState {
+    #[serde(rename = "ociVersion")]
+    pub version: String,
+    #[serde(default)]
+    pub fds: Vec<String>,
+    #[serde(default)]
+    pub pid: i32,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub metadata: String,
+    pub state: State,
+}
+
 #[cfg(test)]
 mod tests {
     use std::vec;
@@ -881,6 +914,42 @@ mod tests {
         assert_eq!(expected, current);
     }
 
+    #[test]
+    fn test_deserialize_container_process_state() {
+        let data = r#"{
+            "ociVersion": "0.2.0",
+            "fds": [
+                "seccompFd"
+            ],
+            "pid": 4422,
+            "metadata": "MKNOD=/dev/null,/dev/net/tun;BPF_MAP_TYPES=hash,array",
+            "state": {
+                "ociVersion": "0.2.0",
+                "id": "oci-container1",
+                "status": "creating",
+                "pid": 4422,
+                "bundle": "/containers/redis"
+            }
+        }"#;
+        let expected = ContainerProcessState {
+            version: "0.2.0".to_string(),
+            fds: vec![SECCOMP_FD_NAME.to_string()],
+            pid: 4422,
+            metadata: "MKNOD=/dev/null,/dev/net/tun;BPF_MAP_TYPES=hash,array".to_string(),
+            state: State {
+                version: "0.2.0".to_string(),
+                id: "oci-container1".to_string(),
+                status: ContainerState::Creating,
+                pid: 4422,
+                bundle: "/containers/redis".to_string(),
+                annotations: HashMap::new(),
+            },
+        };
+
+        let current: crate::ContainerProcessState = serde_json::from_str(data).unwrap();
+        assert_eq!(expected, current);
+    }
+
     #[test]
     fn test_deserialize_spec() {
         let data = r#"{
@@ -1558,6 +1627,7 @@ mod tests {
                         ],
                     }),
                     rdma: Default::default(),
+                    unified: Default::default(),
                 }),
                 cgroups_path: "/myRuntime/myContainer".to_string(),
                 namespaces: vec![
@@ -1614,6 +1684,8 @@ mod tests {
                     default_action: "SCMP_ACT_ALLOW".to_string(),
                     architectures: vec!["SCMP_ARCH_X86".to_string(), "SCMP_ARCH_X32".to_string()],
                     flags: vec![],
+                    listener_path: "".to_string(),
+                    listener_metadata: "".to_string(),
                     syscalls: vec![crate::LinuxSyscall {
                         names: vec!["getcwd".to_string(), "chmod".to_string()],
                         action: "SCMP_ACT_ERRNO".to_string(),
@@ -9,6 +9,7 @@ pub mod agent;
 pub mod agent_ttrpc;
 #[cfg(feature = "async")]
 pub mod agent_ttrpc_async;
+pub mod cgroups_v2;
 pub mod csi;
 pub mod empty;
 mod gogo;
@@ -228,6 +228,7 @@ impl From<oci::LinuxResources> for crate::oci::LinuxResources {
             BlockIO: from_option(from.block_io),
             HugepageLimits: from_vec(from.hugepage_limits),
             Network: from_option(from.network),
+            Unified: from.unified,
             ..Default::default()
         }
     }
@@ -354,6 +355,8 @@ impl From<oci::LinuxSeccomp> for crate::oci::LinuxSeccomp {
             Architectures: from.architectures,
             Syscalls: from_vec(from.syscalls),
             Flags: from.flags,
+            ListenerPath: from.listener_path,
+            ListenerMetadata: from.listener_metadata,
             ..Default::default()
         }
     }
@@ -702,6 +705,7 @@ impl From<crate::oci::LinuxResources> for oci::LinuxResources {
         }
 
         let rdma = HashMap::new();
+        let unified = from.take_Unified();
 
         LinuxResources {
             devices,
@@ -712,6 +716,7 @@ impl From<crate::oci::LinuxResources> for oci::LinuxResources {
             hugepage_limits,
             network,
             rdma,
+            unified,
         }
     }
 }
@@ -768,6 +773,8 @@ impl From<crate::oci::LinuxSeccomp> for oci::LinuxSeccomp {
             architectures: from.take_Architectures().to_vec(),
             syscalls,
             flags: from.take_Flags().to_vec(),
+            listener_path: from.take_ListenerPath(),
+            listener_metadata: from.take_ListenerMetadata(),
         }
     }
 }
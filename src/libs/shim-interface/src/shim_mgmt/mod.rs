@@ -20,5 +20,15 @@ pub const IP_TABLE_URL: &str = "/iptables";
 pub const IP6_TABLE_URL: &str = "/ip6tables";
 /// URL for querying metrics inside shim
 pub const METRICS_URL: &str = "/metrics";
+/// URL for a cpu profile of the shim, served when `enable_pprof` is set
+pub const PPROF_PROFILE_URL: &str = "/debug/pprof/profile";
+/// URL for the heap statistics of the shim, served when `enable_pprof` is set
+pub const PPROF_HEAP_URL: &str = "/debug/pprof/heap";
+/// URL for the tokio runtime metrics of the shim, served when `enable_pprof` is set
+pub const PPROF_RUNTIME_URL: &str = "/debug/pprof/runtime";
+/// The key for the duration of a cpu profile, in seconds
+pub const PPROF_SECONDS_KEY: &str = "seconds";
+/// The key for the format of a cpu profile, `pprof` or `flamegraph`
+pub const PPROF_FORMAT_KEY: &str = "format";
 
 pub const ERR_NO_SHIM_SERVER: &str = "Failed to create shim management server";
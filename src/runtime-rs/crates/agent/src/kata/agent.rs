@@ -4,8 +4,13 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
+use std::collections::HashMap;
+
 use anyhow::{Context, Result};
 use async_trait::async_trait;
+use opentelemetry::global;
+use tracing::{info_span, Instrument, Span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 use ttrpc::context as ttrpc_ctx;
 
 use kata_types::config::Agent as AgentConfig;
@@ -15,9 +20,24 @@ use crate::{kata::KataAgent, Agent, AgentManager, HealthService};
 /// millisecond to nanosecond
 const MILLISECOND_TO_NANOSECOND: i64 = 1_000_000;
 
-/// new ttrpc context with timeout
+/// new ttrpc context with timeout, the trace context of the current span
+/// is put into the metadata so the agent could join its spans to the trace
 fn new_ttrpc_ctx(timeout: i64) -> ttrpc_ctx::Context {
-    ttrpc_ctx::with_timeout(timeout)
+    let mut ctx = ttrpc_ctx::with_timeout(timeout);
+    for (k, v) in trace_carrier(&Span::current()) {
+        ctx.add(k, v);
+    }
+    ctx
+}
+
+/// inject the context of span into a carrier with the global propagator,
+/// the carrier is empty if tracing is not enabled
+fn trace_carrier(span: &Span) -> HashMap<String, String> {
+    let mut carrier = HashMap::new();
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&span.context(), &mut carrier)
+    });
+    carrier
 }
 
 #[async_trait]
@@ -55,10 +75,15 @@ macro_rules! impl_health_service {
         #[async_trait]
         impl HealthService for KataAgent {
             $(async fn $name(&self, req: $req) -> Result<$resp> {
-                let r = req.into();
-                let (client, timeout, _) = self.get_health_client().await.context("get health client")?;
-                let resp = client.$name(new_ttrpc_ctx(timeout * MILLISECOND_TO_NANOSECOND), &r).await?;
-                Ok(resp.into())
+                let span = info_span!(stringify!($name), "mod" = "agent");
+                async move {
+                    let r = req.into();
+                    let (client, timeout, _) = self.get_health_client().await.context("get health client")?;
+                    let resp = client.$name(new_ttrpc_ctx(timeout * MILLISECOND_TO_NANOSECOND), &r).await?;
+                    Ok(resp.into())
+                }
+                .instrument(span)
+                .await
             })*
         }
     };
@@ -74,16 +99,21 @@ macro_rules! impl_agent {
         #[async_trait]
         impl Agent for KataAgent {
             $(async fn $name(&self, req: $req) -> Result<$resp> {
-                let r = req.into();
-                let (client, mut timeout, _) = self.get_agent_client().await.context("get client")?;
+                let span = info_span!(stringify!($name), "mod" = "agent");
+                async move {
+                    let r = req.into();
+                    let (client, mut timeout, _) = self.get_agent_client().await.context("get client")?;
 
-                // update new timeout
-                if let Some(v) = $new_timeout {
-                    timeout = v;
-                }
+                    // update new timeout
+                    if let Some(v) = $new_timeout {
+                        timeout = v;
+                    }
 
-                let resp = client.$name(new_ttrpc_ctx(timeout * MILLISECOND_TO_NANOSECOND), &r).await?;
-                Ok(resp.into())
+                    let resp = client.$name(new_ttrpc_ctx(timeout * MILLISECOND_TO_NANOSECOND), &r).await?;
+                    Ok(resp.into())
+                }
+                .instrument(span)
+                .await
             })*
         }
     };
@@ -112,10 +142,52 @@ impl_agent!(
     list_routes | crate::Empty | crate::Routes | None,
     create_sandbox | crate::CreateSandboxRequest | crate::Empty | None,
     destroy_sandbox | crate::Empty | crate::Empty | None,
+    update_sandbox_dns | crate::UpdateSandboxDnsRequest | crate::Empty | None,
     copy_file | crate::CopyFileRequest | crate::Empty | None,
     get_oom_event | crate::Empty | crate::OomEventResponse | Some(0),
     get_ip_tables | crate::GetIPTablesRequest | crate::GetIPTablesResponse | None,
     set_ip_tables | crate::SetIPTablesRequest | crate::SetIPTablesResponse | None,
     get_volume_stats | crate::VolumeStatsRequest | crate::VolumeStatsResponse | None,
-    resize_volume | crate::ResizeVolumeRequest | crate::Empty | None
+    resize_volume | crate::ResizeVolumeRequest | crate::Empty | None,
+    reseed_random_dev | crate::ReseedRandomDevRequest | crate::Empty | None
 );
+
+#[cfg(test)]
+mod tests {
+    use opentelemetry::global;
+    use opentelemetry::sdk::propagation::TraceContextPropagator;
+    use opentelemetry::sdk::trace::TracerProvider;
+    use opentelemetry::trace::TracerProvider as _;
+    use tracing::info_span;
+    use tracing_opentelemetry::OpenTelemetryLayer;
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::Registry;
+
+    use super::{new_ttrpc_ctx, trace_carrier};
+
+    #[test]
+    fn test_trace_context_in_ttrpc_ctx() {
+        global::set_text_map_propagator(TraceContextPropagator::new());
+
+        // the tracer only holds a weak reference to its provider
+        let provider = TracerProvider::builder().build();
+        let tracer = provider.get_tracer("test", None);
+        let subscriber = Registry::default().with(OpenTelemetryLayer::new(tracer));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = info_span!("create_container");
+            let carrier = trace_carrier(&span);
+            let traceparent = carrier.get("traceparent").expect("no traceparent");
+            // version-traceid-spanid-flags
+            assert_eq!(traceparent.split('-').count(), 4);
+
+            let _enter = span.enter();
+            let ctx = new_ttrpc_ctx(1000);
+            assert_eq!(
+                ctx.metadata.get("traceparent"),
+                Some(&vec![traceparent.clone()])
+            );
+            assert_eq!(ctx.timeout_nano, 1000);
+        });
+    }
+}
@@ -16,16 +16,17 @@ use crate::{
         ARPNeighbor, ARPNeighbors, AddArpNeighborRequest, AgentDetails, BlkioStats,
         BlkioStatsEntry, CgroupStats, CheckRequest, CloseStdinRequest, ContainerID,
         CopyFileRequest, CpuStats, CpuUsage, CreateContainerRequest, CreateSandboxRequest, Device,
-        Empty, ExecProcessRequest, FSGroup, FSGroupChangePolicy, GetIPTablesRequest,
-        GetIPTablesResponse, GuestDetailsResponse, HealthCheckResponse, HugetlbStats, IPAddress,
-        IPFamily, Interface, Interfaces, KernelModule, MemHotplugByProbeRequest, MemoryData,
-        MemoryStats, NetworkStats, OnlineCPUMemRequest, PidsStats, ReadStreamRequest,
-        ReadStreamResponse, RemoveContainerRequest, ReseedRandomDevRequest, ResizeVolumeRequest,
-        Route, Routes, SetGuestDateTimeRequest, SetIPTablesRequest, SetIPTablesResponse,
-        SignalProcessRequest, StatsContainerResponse, Storage, StringUser, ThrottlingData,
-        TtyWinResizeRequest, UpdateContainerRequest, UpdateInterfaceRequest, UpdateRoutesRequest,
-        VersionCheckResponse, VolumeStatsRequest, VolumeStatsResponse, WaitProcessRequest,
-        WriteStreamRequest,
+        DnsConfig, Empty, ExecProcessRequest, FSGroup, FSGroupChangePolicy, GetIPTablesRequest,
+        GetIPTablesResponse, GuestDetailsResponse, HealthCheckResponse, HostEntry, HugetlbStats,
+        IPAddress, IPFamily, IPv6Sysctls, Interface, Interfaces, KernelModule,
+        MemHotplugByProbeRequest, MemoryData, MemoryStats, NetworkStats, OnlineCPUMemRequest,
+        PidsStats, PsiData, PsiStats, ReadStreamRequest, ReadStreamResponse,
+        RemoveContainerRequest, ReseedRandomDevRequest, ResizeVolumeRequest, Route, Routes,
+        SetGuestDateTimeRequest, SetIPTablesRequest, SetIPTablesResponse, SignalProcessRequest,
+        StatsContainerResponse, Storage, StringUser, ThrottlingData, TtyWinResizeRequest,
+        UpdateContainerRequest, UpdateInterfaceRequest, UpdateRoutesRequest,
+        UpdateSandboxDnsRequest, VersionCheckResponse, VolumeStatsRequest, VolumeStatsResponse,
+        WaitProcessRequest, WriteStreamRequest,
     },
     OomEventResponse, WaitProcessResponse, WriteStreamResponse,
 };
@@ -178,6 +179,7 @@ impl From<Interface> for types::Interface {
             pciPath: from.pci_addr,
             type_: from.field_type,
             raw_flags: from.raw_flags,
+            ipv6_sysctls: from_option(from.ipv6_sysctls),
             ..Default::default()
         }
     }
@@ -194,6 +196,28 @@ impl From<types::Interface> for Interface {
             pci_addr: src.pciPath,
             field_type: src.type_,
             raw_flags: src.raw_flags,
+            ipv6_sysctls: into_option(src.ipv6_sysctls),
+        }
+    }
+}
+
+impl From<IPv6Sysctls> for types::IPv6Sysctls {
+    fn from(from: IPv6Sysctls) -> Self {
+        Self {
+            disable_ipv6: from.disable_ipv6,
+            accept_ra: from.accept_ra,
+            forwarding: from.forwarding,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<types::IPv6Sysctls> for IPv6Sysctls {
+    fn from(src: types::IPv6Sysctls) -> Self {
+        Self {
+            disable_ipv6: src.disable_ipv6,
+            accept_ra: src.accept_ra,
+            forwarding: src.forwarding,
         }
     }
 }
@@ -427,11 +451,32 @@ impl From<agent::ThrottlingData> for ThrottlingData {
     }
 }
 
+impl From<agent::PSIData> for PsiData {
+    fn from(src: agent::PSIData) -> Self {
+        Self {
+            avg10: src.avg10,
+            avg60: src.avg60,
+            avg300: src.avg300,
+            total: src.total,
+        }
+    }
+}
+
+impl From<agent::PSIStats> for PsiStats {
+    fn from(src: agent::PSIStats) -> Self {
+        Self {
+            some: into_option(src.some),
+            full: into_option(src.full),
+        }
+    }
+}
+
 impl From<agent::CpuStats> for CpuStats {
     fn from(src: agent::CpuStats) -> Self {
         Self {
             cpu_usage: into_option(src.cpu_usage),
             throttling_data: into_option(src.throttling_data),
+            psi: into_option(src.psi),
         }
     }
 }
@@ -456,6 +501,7 @@ impl From<agent::MemoryStats> for MemoryStats {
             kernel_usage: into_option(src.kernel_usage),
             use_hierarchy: src.use_hierarchy,
             stats: into_hash_map(src.stats),
+            psi: into_option(src.psi),
         }
     }
 }
@@ -491,6 +537,7 @@ impl From<agent::BlkioStats> for BlkioStats {
             io_merged_recursive: trans_vec(src.io_merged_recursive),
             io_time_recursive: trans_vec(src.io_time_recursive),
             sectors_recursive: trans_vec(src.sectors_recursive),
+            psi: into_option(src.psi),
         }
     }
 }
@@ -501,6 +548,7 @@ impl From<agent::HugetlbStats> for HugetlbStats {
             usage: src.usage,
             max_usage: src.max_usage,
             failcnt: src.failcnt,
+            limit: src.limit,
         }
     }
 }
@@ -513,6 +561,7 @@ impl From<agent::CgroupStats> for CgroupStats {
             pids_stats: into_option(src.pids_stats),
             blkio_stats: into_option(src.blkio_stats),
             hugetlb_stats: into_hash_map(src.hugetlb_stats),
+            cgroup_version: src.cgroup_version,
         }
     }
 }
@@ -657,6 +706,39 @@ impl From<CreateSandboxRequest> for agent::CreateSandboxRequest {
             sandbox_id: from.sandbox_id,
             guest_hook_path: from.guest_hook_path,
             kernel_modules: trans_vec(from.kernel_modules),
+            dns_config: from_option(from.dns_config),
+            hosts: trans_vec(from.hosts),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<DnsConfig> for agent::DNSConfig {
+    fn from(from: DnsConfig) -> Self {
+        Self {
+            nameservers: from.nameservers,
+            searches: from.searches,
+            options: from.options,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<HostEntry> for agent::HostEntry {
+    fn from(from: HostEntry) -> Self {
+        Self {
+            ip: from.ip,
+            names: from.names,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<UpdateSandboxDnsRequest> for agent::UpdateSandboxDNSRequest {
+    fn from(from: UpdateSandboxDnsRequest) -> Self {
+        Self {
+            dns_config: from_option(from.dns_config),
+            hosts: trans_vec(from.hosts),
             ..Default::default()
         }
     }
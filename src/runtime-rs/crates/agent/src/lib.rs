@@ -16,15 +16,16 @@ pub mod types;
 pub use types::{
     ARPNeighbor, ARPNeighbors, AddArpNeighborRequest, BlkioStatsEntry, CheckRequest,
     CloseStdinRequest, ContainerID, ContainerProcessID, CopyFileRequest, CreateContainerRequest,
-    CreateSandboxRequest, Empty, ExecProcessRequest, GetGuestDetailsRequest, GetIPTablesRequest,
-    GetIPTablesResponse, GuestDetailsResponse, HealthCheckResponse, IPAddress, IPFamily, Interface,
-    Interfaces, ListProcessesRequest, MemHotplugByProbeRequest, OnlineCPUMemRequest,
-    OomEventResponse, ReadStreamRequest, ReadStreamResponse, RemoveContainerRequest,
-    ReseedRandomDevRequest, ResizeVolumeRequest, Route, Routes, SetGuestDateTimeRequest,
-    SetIPTablesRequest, SetIPTablesResponse, SignalProcessRequest, StatsContainerResponse, Storage,
+    CreateSandboxRequest, DnsConfig, Empty, ExecProcessRequest, GetGuestDetailsRequest,
+    GetIPTablesRequest, GetIPTablesResponse, GuestDetailsResponse, HealthCheckResponse, HostEntry,
+    IPAddress, IPFamily, IPv6Sysctls, Interface, Interfaces, ListProcessesRequest,
+    MemHotplugByProbeRequest, OnlineCPUMemRequest, OomEventResponse, PsiData, PsiStats,
+    ReadStreamRequest, ReadStreamResponse, RemoveContainerRequest, ReseedRandomDevRequest,
+    ResizeVolumeRequest, Route, Routes, SetGuestDateTimeRequest, SetIPTablesRequest,
+    SetIPTablesResponse, SignalProcessRequest, StatsContainerResponse, Storage,
     TtyWinResizeRequest, UpdateContainerRequest, UpdateInterfaceRequest, UpdateRoutesRequest,
-    VersionCheckResponse, VolumeStatsRequest, VolumeStatsResponse, WaitProcessRequest,
-    WaitProcessResponse, WriteStreamRequest, WriteStreamResponse,
+    UpdateSandboxDnsRequest, VersionCheckResponse, VolumeStatsRequest, VolumeStatsResponse,
+    WaitProcessRequest, WaitProcessResponse, WriteStreamRequest, WriteStreamResponse,
 };
 
 use anyhow::Result;
@@ -34,6 +35,14 @@ use kata_types::config::Agent as AgentConfig;
 
 pub const AGENT_KATA: &str = "kata";
 
+/// Whether the agent doesn't know the request, e.g. an older agent.
+pub fn is_unimplemented(err: &anyhow::Error) -> bool {
+    err.chain().any(|e| {
+        matches!(e.downcast_ref::<ttrpc::Error>(),
+            Some(ttrpc::Error::RpcStatus(s)) if s.code() == ttrpc::Code::UNIMPLEMENTED)
+    })
+}
+
 #[async_trait]
 pub trait AgentManager: Send + Sync {
     async fn start(&self, address: &str) -> Result<()>;
@@ -54,6 +63,7 @@ pub trait Agent: AgentManager + HealthService + Send + Sync {
     // sandbox
     async fn create_sandbox(&self, req: CreateSandboxRequest) -> Result<Empty>;
     async fn destroy_sandbox(&self, req: Empty) -> Result<Empty>;
+    async fn update_sandbox_dns(&self, req: UpdateSandboxDnsRequest) -> Result<Empty>;
 
     // network
     async fn add_arp_neighbors(&self, req: AddArpNeighborRequest) -> Result<Empty>;
@@ -90,4 +100,22 @@ pub trait Agent: AgentManager + HealthService + Send + Sync {
     async fn set_ip_tables(&self, req: SetIPTablesRequest) -> Result<SetIPTablesResponse>;
     async fn get_volume_stats(&self, req: VolumeStatsRequest) -> Result<VolumeStatsResponse>;
     async fn resize_volume(&self, req: ResizeVolumeRequest) -> Result<Empty>;
+    async fn reseed_random_dev(&self, req: ReseedRandomDevRequest) -> Result<Empty>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn test_is_unimplemented() {
+        let err = |code| -> anyhow::Error {
+            let r: Result<()> = Err(ttrpc::Error::RpcStatus(ttrpc::get_status(code, "")).into());
+            r.context("update sandbox dns").unwrap_err()
+        };
+        assert!(is_unimplemented(&err(ttrpc::Code::UNIMPLEMENTED)));
+        assert!(!is_unimplemented(&err(ttrpc::Code::INTERNAL)));
+        assert!(!is_unimplemented(&anyhow::anyhow!("failed")));
+    }
 }
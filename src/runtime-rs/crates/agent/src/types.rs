@@ -97,6 +97,15 @@ pub struct Interface {
     pub field_type: String,
     #[serde(default)]
     pub raw_flags: u32,
+    #[serde(default)]
+    pub ipv6_sysctls: Option<IPv6Sysctls>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct IPv6Sysctls {
+    pub disable_ipv6: u32,
+    pub accept_ra: u32,
+    pub forwarding: u32,
 }
 
 #[derive(PartialEq, Clone, Default)]
@@ -276,10 +285,25 @@ pub struct LoadData {
     pub fifteen: String,
 }
 
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct PsiData {
+    pub avg10: f64,
+    pub avg60: f64,
+    pub avg300: f64,
+    pub total: u64,
+}
+
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct PsiStats {
+    pub some: Option<PsiData>,
+    pub full: Option<PsiData>,
+}
+
 #[derive(PartialEq, Clone, Default, Debug)]
 pub struct CpuStats {
     pub cpu_usage: Option<CpuUsage>,
     pub throttling_data: Option<ThrottlingData>,
+    pub psi: Option<PsiStats>,
 }
 
 #[derive(PartialEq, Clone, Default, Debug)]
@@ -298,6 +322,7 @@ pub struct MemoryStats {
     pub kernel_usage: Option<MemoryData>,
     pub use_hierarchy: bool,
     pub stats: ::std::collections::HashMap<String, u64>,
+    pub psi: Option<PsiStats>,
 }
 
 #[derive(PartialEq, Clone, Default, Debug)]
@@ -324,6 +349,7 @@ pub struct BlkioStats {
     pub io_merged_recursive: Vec<BlkioStatsEntry>,
     pub io_time_recursive: Vec<BlkioStatsEntry>,
     pub sectors_recursive: Vec<BlkioStatsEntry>,
+    pub psi: Option<PsiStats>,
 }
 
 #[derive(PartialEq, Clone, Default, Debug)]
@@ -331,6 +357,7 @@ pub struct HugetlbStats {
     pub usage: u64,
     pub max_usage: u64,
     pub failcnt: u64,
+    pub limit: u64,
 }
 
 #[derive(PartialEq, Clone, Default, Debug)]
@@ -340,6 +367,7 @@ pub struct CgroupStats {
     pub pids_stats: Option<PidsStats>,
     pub blkio_stats: Option<BlkioStats>,
     pub hugetlb_stats: ::std::collections::HashMap<String, HugetlbStats>,
+    pub cgroup_version: u32,
 }
 
 #[derive(PartialEq, Clone, Default, Debug)]
@@ -483,6 +511,27 @@ pub struct CreateSandboxRequest {
     pub sandbox_id: String,
     pub guest_hook_path: String,
     pub kernel_modules: Vec<KernelModule>,
+    pub dns_config: Option<DnsConfig>,
+    pub hosts: Vec<HostEntry>,
+}
+
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct DnsConfig {
+    pub nameservers: Vec<String>,
+    pub searches: Vec<String>,
+    pub options: Vec<String>,
+}
+
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct HostEntry {
+    pub ip: String,
+    pub names: Vec<String>,
+}
+
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct UpdateSandboxDnsRequest {
+    pub dns_config: Option<DnsConfig>,
+    pub hosts: Vec<HostEntry>,
 }
 
 #[derive(PartialEq, Clone, Default)]
@@ -2,11 +2,16 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{DeviceConfig, FsConfig, VmConfig};
+use crate::{
+    DeviceConfig, DiskConfig, FsConfig, NetConfig, VmConfig, VmInfo, VmRemoveDeviceData,
+    VmSnapshotConfig,
+};
 use anyhow::{anyhow, Result};
-use api_client::simple_api_full_command_and_response;
+use api_client::{
+    simple_api_full_command_and_response, simple_api_full_command_with_fds_and_response,
+};
 
-use std::os::unix::net::UnixStream;
+use std::os::unix::{io::RawFd, net::UnixStream};
 use tokio::task;
 
 pub async fn cloud_hypervisor_vmm_ping(mut socket: UnixStream) -> Result<Option<String>> {
@@ -69,6 +74,57 @@ pub async fn cloud_hypervisor_vm_stop(mut socket: UnixStream) -> Result<Option<S
     .await?
 }
 
+pub async fn cloud_hypervisor_vm_pause(mut socket: UnixStream) -> Result<Option<String>> {
+    task::spawn_blocking(move || -> Result<Option<String>> {
+        let response = simple_api_full_command_and_response(&mut socket, "PUT", "vm.pause", None)
+            .map_err(|e| anyhow!(e))?;
+
+        Ok(response)
+    })
+    .await?
+}
+
+pub async fn cloud_hypervisor_vm_resume(mut socket: UnixStream) -> Result<Option<String>> {
+    task::spawn_blocking(move || -> Result<Option<String>> {
+        let response = simple_api_full_command_and_response(&mut socket, "PUT", "vm.resume", None)
+            .map_err(|e| anyhow!(e))?;
+
+        Ok(response)
+    })
+    .await?
+}
+
+pub async fn cloud_hypervisor_vm_snapshot(
+    mut socket: UnixStream,
+    snapshot_config: VmSnapshotConfig,
+) -> Result<Option<String>> {
+    task::spawn_blocking(move || -> Result<Option<String>> {
+        let response = simple_api_full_command_and_response(
+            &mut socket,
+            "PUT",
+            "vm.snapshot",
+            Some(&serde_json::to_string(&snapshot_config)?),
+        )
+        .map_err(|e| anyhow!(e))?;
+
+        Ok(response)
+    })
+    .await?
+}
+
+pub async fn cloud_hypervisor_vm_info(mut socket: UnixStream) -> Result<VmInfo> {
+    task::spawn_blocking(move || -> Result<VmInfo> {
+        let response = simple_api_full_command_and_response(&mut socket, "GET", "vm.info", None)
+            .map_err(|e| anyhow!(e))?
+            .ok_or_else(|| anyhow!("empty vm.info response"))?;
+
+        let info: VmInfo = serde_json::from_str(&response)?;
+
+        Ok(info)
+    })
+    .await?
+}
+
 #[allow(dead_code)]
 pub async fn cloud_hypervisor_vm_device_add(mut socket: UnixStream) -> Result<Option<String>> {
     let device_config = DeviceConfig::default();
@@ -104,3 +160,284 @@ pub async fn cloud_hypervisor_vm_fs_add(
     })
     .await?
 }
+
+pub async fn cloud_hypervisor_vm_netdev_add(
+    mut socket: UnixStream,
+    net_config: NetConfig,
+) -> Result<Option<String>> {
+    task::spawn_blocking(move || -> Result<Option<String>> {
+        let response = simple_api_full_command_and_response(
+            &mut socket,
+            "PUT",
+            "vm.add-net",
+            Some(&serde_json::to_string(&net_config)?),
+        )
+        .map_err(|e| anyhow!(e))?;
+
+        Ok(response)
+    })
+    .await?
+}
+
+pub async fn cloud_hypervisor_vm_netdev_add_with_fds(
+    mut socket: UnixStream,
+    net_config: NetConfig,
+    request_fds: Vec<RawFd>,
+) -> Result<Option<String>> {
+    task::spawn_blocking(move || -> Result<Option<String>> {
+        let response = simple_api_full_command_with_fds_and_response(
+            &mut socket,
+            "PUT",
+            "vm.add-net",
+            Some(&serde_json::to_string(&net_config)?),
+            request_fds,
+        )
+        .map_err(|e| anyhow!(e))?;
+
+        Ok(response)
+    })
+    .await?
+}
+
+pub async fn cloud_hypervisor_vm_blockdev_add(
+    mut socket: UnixStream,
+    disk_config: DiskConfig,
+) -> Result<Option<String>> {
+    task::spawn_blocking(move || -> Result<Option<String>> {
+        let response = simple_api_full_command_and_response(
+            &mut socket,
+            "PUT",
+            "vm.add-disk",
+            Some(&serde_json::to_string(&disk_config)?),
+        )
+        .map_err(|e| anyhow!(e))?;
+
+        Ok(response)
+    })
+    .await?
+}
+
+pub async fn cloud_hypervisor_vm_device_remove(
+    mut socket: UnixStream,
+    id: String,
+) -> Result<Option<String>> {
+    let remove_data = VmRemoveDeviceData { id };
+
+    task::spawn_blocking(move || -> Result<Option<String>> {
+        let response = simple_api_full_command_and_response(
+            &mut socket,
+            "PUT",
+            "vm.remove-device",
+            Some(&serde_json::to_string(&remove_data)?),
+        )
+        .map_err(|e| anyhow!(e))?;
+
+        Ok(response)
+    })
+    .await?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MacAddr, PciDeviceInfo, VmState};
+    use std::io::{BufRead, BufReader, Write};
+    use std::path::PathBuf;
+    use std::thread;
+
+    #[derive(Debug)]
+    struct Request {
+        method: String,
+        path: String,
+        body: String,
+    }
+
+    // Serve a single request on one end of a unix socket pair the way the
+    // CH API server would, returning the client end and the request seen.
+    fn mock_api_server(
+        status: &'static str,
+        body: &'static str,
+    ) -> (UnixStream, thread::JoinHandle<Request>) {
+        let (client, server) = UnixStream::pair().unwrap();
+
+        let handle = thread::spawn(move || {
+            let mut reader = BufReader::new(server.try_clone().unwrap());
+
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut fields = request_line.split_whitespace();
+            let method = fields.next().unwrap().to_string();
+            let path = fields.next().unwrap().to_string();
+
+            let mut content_length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                let header = header.trim_end();
+                if header.is_empty() {
+                    break;
+                }
+                if let Some(len) = header.strip_prefix("Content-Length: ") {
+                    content_length = len.parse().unwrap();
+                }
+            }
+
+            let mut request_body = vec![0u8; content_length];
+            std::io::Read::read_exact(&mut reader, &mut request_body).unwrap();
+
+            let mut server = server;
+            let response = if body.is_empty() {
+                format!("HTTP/1.1 {}\r\n\r\n", status)
+            } else {
+                format!(
+                    "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+            };
+            server.write_all(response.as_bytes()).unwrap();
+
+            Request {
+                method,
+                path,
+                body: String::from_utf8(request_body).unwrap(),
+            }
+        });
+
+        (client, handle)
+    }
+
+    #[tokio::test]
+    async fn test_vm_netdev_add() {
+        let (socket, server) = mock_api_server("200 OK", r#"{"id":"eth0","bdf":"0000:00:05.0"}"#);
+
+        let net_config = NetConfig {
+            tap: Some("tap0_kata".into()),
+            mac: MacAddr {
+                bytes: [0x02, 0x42, 0xac, 0x11, 0x00, 0x02],
+            },
+            id: Some("eth0".into()),
+            ..Default::default()
+        };
+
+        let response = cloud_hypervisor_vm_netdev_add(socket, net_config.clone())
+            .await
+            .unwrap();
+
+        let request = server.join().unwrap();
+        assert_eq!(request.method, "PUT");
+        assert_eq!(request.path, "/api/v1/vm.add-net");
+
+        let sent: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+        assert_eq!(sent["tap"], "tap0_kata");
+        assert_eq!(sent["mac"], "02:42:ac:11:00:02");
+        assert_eq!(sent["id"], "eth0");
+
+        let info: PciDeviceInfo = serde_json::from_str(&response.unwrap()).unwrap();
+        assert_eq!(info.bdf, "0000:00:05.0");
+    }
+
+    #[tokio::test]
+    async fn test_vm_blockdev_add() {
+        let (socket, server) =
+            mock_api_server("200 OK", r#"{"id":"drive_1","bdf":"0000:00:06.0"}"#);
+
+        let disk_config = DiskConfig {
+            path: Some(PathBuf::from("/dev/dm-3")),
+            readonly: true,
+            num_queues: 1,
+            queue_size: 128,
+            id: Some("drive_1".into()),
+            ..Default::default()
+        };
+
+        let response = cloud_hypervisor_vm_blockdev_add(socket, disk_config.clone())
+            .await
+            .unwrap();
+
+        let request = server.join().unwrap();
+        assert_eq!(request.path, "/api/v1/vm.add-disk");
+
+        let sent: DiskConfig = serde_json::from_str(&request.body).unwrap();
+        assert_eq!(sent, disk_config);
+
+        let info: PciDeviceInfo = serde_json::from_str(&response.unwrap()).unwrap();
+        assert_eq!(info.id, "drive_1");
+    }
+
+    #[tokio::test]
+    async fn test_vm_device_remove() {
+        let (socket, server) = mock_api_server("204 No Content", "");
+
+        let response = cloud_hypervisor_vm_device_remove(socket, "drive_1".into())
+            .await
+            .unwrap();
+        assert!(response.is_none());
+
+        let request = server.join().unwrap();
+        assert_eq!(request.path, "/api/v1/vm.remove-device");
+        assert_eq!(request.body, r#"{"id":"drive_1"}"#);
+    }
+
+    #[tokio::test]
+    async fn test_vm_device_remove_error() {
+        let (socket, server) = mock_api_server("500 Internal Server Error", "");
+
+        let result = cloud_hypervisor_vm_device_remove(socket, "unknown".into()).await;
+        assert!(result.is_err());
+
+        server.join().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_vm_pause_resume() {
+        let (socket, server) = mock_api_server("204 No Content", "");
+        assert!(cloud_hypervisor_vm_pause(socket).await.unwrap().is_none());
+        let request = server.join().unwrap();
+        assert_eq!(request.method, "PUT");
+        assert_eq!(request.path, "/api/v1/vm.pause");
+
+        let (socket, server) = mock_api_server("204 No Content", "");
+        assert!(cloud_hypervisor_vm_resume(socket).await.unwrap().is_none());
+        let request = server.join().unwrap();
+        assert_eq!(request.path, "/api/v1/vm.resume");
+    }
+
+    #[tokio::test]
+    async fn test_vm_snapshot() {
+        let (socket, server) = mock_api_server("204 No Content", "");
+
+        let snapshot_config = VmSnapshotConfig {
+            destination_url: "file:///run/kata/sandbox/snapshot".into(),
+        };
+
+        cloud_hypervisor_vm_snapshot(socket, snapshot_config)
+            .await
+            .unwrap();
+
+        let request = server.join().unwrap();
+        assert_eq!(request.path, "/api/v1/vm.snapshot");
+        assert_eq!(
+            request.body,
+            r#"{"destination_url":"file:///run/kata/sandbox/snapshot"}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_vm_info() {
+        let (socket, server) = mock_api_server(
+            "200 OK",
+            r#"{"config":{"cpus":{"boot_vcpus":2}},"state":"Paused","memory_actual_size":2147483648}"#,
+        );
+
+        let info = cloud_hypervisor_vm_info(socket).await.unwrap();
+
+        let request = server.join().unwrap();
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/api/v1/vm.info");
+
+        assert_eq!(info.state, VmState::Paused);
+        assert_eq!(info.memory_actual_size, Some(2147483648));
+    }
+}
@@ -3,12 +3,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::net_util::MAC_ADDR_LEN;
-use crate::NamedHypervisorConfig;
 use crate::VmConfig;
 use crate::{
     ConsoleConfig, ConsoleOutputMode, CpuFeatures, CpuTopology, CpusConfig, DiskConfig, MacAddr,
-    MemoryConfig, PayloadConfig, PlatformConfig, PmemConfig, RngConfig, VsockConfig,
+    MemoryConfig, NetConfig, PayloadConfig, PlatformConfig, PmemConfig, RngConfig, VhostMode,
+    VsockConfig,
 };
+use crate::{NamedBlockDevice, NamedHypervisorConfig, NamedNetDevice, NamedVhostUserNetDevice};
 use anyhow::{anyhow, Context, Result};
 use kata_types::config::default::DEFAULT_CH_ENTROPY_SOURCE;
 use kata_types::config::hypervisor::{CpuInfo, MachineInfo, MemoryInfo};
@@ -28,6 +29,9 @@ const DEFAULT_CH_MAX_PHYS_BITS: u8 = 46;
 
 const DEFAULT_VSOCK_CID: u64 = 3;
 
+const DEFAULT_DISK_NUM_QUEUES: usize = 1;
+const DEFAULT_DISK_QUEUE_SIZE: u16 = 128;
+
 impl TryFrom<NamedHypervisorConfig> for VmConfig {
     type Error = VmConfigError;
 
@@ -58,6 +62,7 @@ impl TryFrom<NamedHypervisorConfig> for VmConfig {
         };
 
         let fs = n.shared_fs_devices;
+        let net = n.network_devices;
 
         let cpus = CpusConfig::try_from(cfg.cpu_info).map_err(VmConfigError::CPUError)?;
 
@@ -129,6 +134,7 @@ impl TryFrom<NamedHypervisorConfig> for VmConfig {
             console,
             payload,
             fs,
+            net,
             pmem,
             disks,
             vsock: Some(vsock),
@@ -361,6 +367,115 @@ impl TryFrom<BootInfo> for DiskConfig {
     }
 }
 
+impl TryFrom<NamedBlockDevice> for DiskConfig {
+    type Error = DiskConfigError;
+
+    fn try_from(dev: NamedBlockDevice) -> Result<Self, Self::Error> {
+        if dev.path.is_empty() {
+            return Err(DiskConfigError::MissingPath);
+        }
+
+        // The ID is needed to remove the device again.
+        if dev.id.is_empty() {
+            return Err(DiskConfigError::MissingId);
+        }
+
+        let disk = DiskConfig {
+            path: Some(PathBuf::from(dev.path)),
+            readonly: dev.readonly,
+            direct: dev.direct,
+            num_queues: DEFAULT_DISK_NUM_QUEUES,
+            queue_size: DEFAULT_DISK_QUEUE_SIZE,
+            id: Some(dev.id),
+
+            ..Default::default()
+        };
+
+        Ok(disk)
+    }
+}
+
+impl TryFrom<NamedNetDevice> for NetConfig {
+    type Error = NetConfigError;
+
+    fn try_from(dev: NamedNetDevice) -> Result<Self, Self::Error> {
+        if dev.id.is_empty() {
+            return Err(NetConfigError::MissingId);
+        }
+
+        if dev.tap.is_empty() && dev.fds.is_empty() {
+            return Err(NetConfigError::MissingTap);
+        }
+
+        let mac = dev
+            .guest_mac
+            .map(|bytes| MacAddr { bytes })
+            .unwrap_or_default();
+
+        if !dev.fds.is_empty() {
+            // Each fd is a queue pair of the opened device.
+            return Ok(NetConfig {
+                mac,
+                num_queues: dev.fds.len() * 2,
+                id: Some(dev.id),
+                fds: Some(dev.fds),
+
+                ..Default::default()
+            });
+        }
+
+        // CH uses one queue for RX and one for TX per queue pair.
+        let num_queues = dev.queue_pairs as usize * 2;
+
+        let cfg = NetConfig {
+            tap: Some(dev.tap),
+            mac,
+            num_queues,
+            id: Some(dev.id),
+
+            ..Default::default()
+        };
+
+        Ok(cfg)
+    }
+}
+
+impl TryFrom<NamedVhostUserNetDevice> for NetConfig {
+    type Error = NetConfigError;
+
+    fn try_from(dev: NamedVhostUserNetDevice) -> Result<Self, Self::Error> {
+        if dev.id.is_empty() {
+            return Err(NetConfigError::MissingId);
+        }
+
+        if dev.socket_path.is_empty() {
+            return Err(NetConfigError::MissingVhostSocket);
+        }
+
+        let mac = dev
+            .guest_mac
+            .map(|bytes| MacAddr { bytes })
+            .unwrap_or_default();
+
+        // CH uses one queue for RX and one for TX per queue pair.
+        let num_queues = dev.queue_pairs as usize * 2;
+
+        // The userspace datapath owns the socket, CH connects to it.
+        let cfg = NetConfig {
+            mac,
+            num_queues,
+            vhost_user: true,
+            vhost_socket: Some(dev.socket_path),
+            vhost_mode: VhostMode::Client,
+            id: Some(dev.id),
+
+            ..Default::default()
+        };
+
+        Ok(cfg)
+    }
+}
+
 impl From<MachineInfo> for RngConfig {
     fn from(m: MachineInfo) -> Self {
         let entropy_source = if !m.entropy_source.is_empty() {
@@ -1468,6 +1583,211 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_named_block_device_to_diskconfig() {
+        #[derive(Debug)]
+        struct TestData {
+            dev: NamedBlockDevice,
+            result: Result<DiskConfig, DiskConfigError>,
+        }
+
+        let path = "/dev/some/where";
+        let id = "drive_1";
+
+        let tests = &[
+            TestData {
+                dev: NamedBlockDevice::default(),
+                result: Err(DiskConfigError::MissingPath),
+            },
+            TestData {
+                dev: NamedBlockDevice {
+                    path: path.into(),
+                    ..Default::default()
+                },
+                result: Err(DiskConfigError::MissingId),
+            },
+            TestData {
+                dev: NamedBlockDevice {
+                    id: id.into(),
+                    path: path.into(),
+                    readonly: true,
+                    direct: true,
+                },
+                result: Ok(DiskConfig {
+                    path: Some(PathBuf::from(path)),
+                    readonly: true,
+                    direct: true,
+                    num_queues: DEFAULT_DISK_NUM_QUEUES,
+                    queue_size: DEFAULT_DISK_QUEUE_SIZE,
+                    id: Some(id.into()),
+
+                    ..Default::default()
+                }),
+            },
+        ];
+
+        for (i, d) in tests.iter().enumerate() {
+            let msg = format!("test[{}]: {:?}", i, d);
+
+            let result = DiskConfig::try_from(d.dev.clone());
+
+            let msg = format!("{}: actual result: {:?}", msg, result);
+
+            if std::env::var("DEBUG").is_ok() {
+                eprintln!("DEBUG: {}", msg);
+            }
+
+            assert_eq!(result, d.result, "{}", msg);
+        }
+    }
+
+    #[test]
+    fn test_named_net_device_to_netconfig() {
+        #[derive(Debug)]
+        struct TestData {
+            dev: NamedNetDevice,
+            result: Result<NetConfig, NetConfigError>,
+        }
+
+        let id = "eth0";
+        let tap = "tap0_kata";
+        let mac = [0x02, 0x42, 0xac, 0x11, 0x00, 0x02];
+
+        let tests = &[
+            TestData {
+                dev: NamedNetDevice::default(),
+                result: Err(NetConfigError::MissingId),
+            },
+            TestData {
+                dev: NamedNetDevice {
+                    id: id.into(),
+                    ..Default::default()
+                },
+                result: Err(NetConfigError::MissingTap),
+            },
+            TestData {
+                dev: NamedNetDevice {
+                    id: id.into(),
+                    tap: tap.into(),
+                    guest_mac: Some(mac),
+                    queue_pairs: 2,
+                    ..Default::default()
+                },
+                result: Ok(NetConfig {
+                    tap: Some(tap.into()),
+                    mac: MacAddr { bytes: mac },
+                    num_queues: 4,
+                    id: Some(id.into()),
+
+                    ..Default::default()
+                }),
+            },
+            TestData {
+                dev: NamedNetDevice {
+                    id: id.into(),
+                    tap: tap.into(),
+                    ..Default::default()
+                },
+                result: Ok(NetConfig {
+                    tap: Some(tap.into()),
+                    id: Some(id.into()),
+
+                    ..Default::default()
+                }),
+            },
+            TestData {
+                dev: NamedNetDevice {
+                    id: id.into(),
+                    tap: tap.into(),
+                    guest_mac: Some(mac),
+                    queue_pairs: 1,
+                    fds: vec![10, 11],
+                },
+                result: Ok(NetConfig {
+                    mac: MacAddr { bytes: mac },
+                    num_queues: 4,
+                    id: Some(id.into()),
+                    fds: Some(vec![10, 11]),
+
+                    ..Default::default()
+                }),
+            },
+        ];
+
+        for (i, d) in tests.iter().enumerate() {
+            let msg = format!("test[{}]: {:?}", i, d);
+
+            let result = NetConfig::try_from(d.dev.clone());
+
+            let msg = format!("{}: actual result: {:?}", msg, result);
+
+            if std::env::var("DEBUG").is_ok() {
+                eprintln!("DEBUG: {}", msg);
+            }
+
+            assert_eq!(result, d.result, "{}", msg);
+        }
+    }
+
+    #[test]
+    fn test_named_vhost_user_net_device_to_netconfig() {
+        #[derive(Debug)]
+        struct TestData {
+            dev: NamedVhostUserNetDevice,
+            result: Result<NetConfig, NetConfigError>,
+        }
+
+        let id = "eth0";
+        let socket_path = "/tmp/vhostuser_10.0.0.2/vhu.sock";
+        let mac = [0x02, 0x42, 0xac, 0x11, 0x00, 0x02];
+
+        let tests = &[
+            TestData {
+                dev: NamedVhostUserNetDevice::default(),
+                result: Err(NetConfigError::MissingId),
+            },
+            TestData {
+                dev: NamedVhostUserNetDevice {
+                    id: id.into(),
+                    ..Default::default()
+                },
+                result: Err(NetConfigError::MissingVhostSocket),
+            },
+            TestData {
+                dev: NamedVhostUserNetDevice {
+                    id: id.into(),
+                    socket_path: socket_path.into(),
+                    guest_mac: Some(mac),
+                    queue_pairs: 1,
+                },
+                result: Ok(NetConfig {
+                    mac: MacAddr { bytes: mac },
+                    num_queues: 2,
+                    vhost_user: true,
+                    vhost_socket: Some(socket_path.into()),
+                    vhost_mode: VhostMode::Client,
+                    id: Some(id.into()),
+
+                    ..Default::default()
+                }),
+            },
+        ];
+
+        for (i, d) in tests.iter().enumerate() {
+            let msg = format!("test[{}]: {:?}", i, d);
+
+            let result = NetConfig::try_from(d.dev.clone());
+
+            let msg = format!("{}: actual result: {:?}", msg, result);
+
+            if std::env::var("DEBUG").is_ok() {
+                eprintln!("DEBUG: {}", msg);
+            }
+
+            assert_eq!(result, d.result, "{}", msg);
+        }
+    }
+
     #[test]
     fn test_named_hypervisor_config_to_vmconfig() {
         #[derive(Debug)]
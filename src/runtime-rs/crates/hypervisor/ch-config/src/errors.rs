@@ -54,6 +54,9 @@ pub enum DiskConfigError {
     #[error("Need path for DiskConfig")]
     MissingPath,
 
+    #[error("Need device ID for DiskConfig")]
+    MissingId,
+
     #[error("Found unexpected path for DiskConfig with TDX: {0}")]
     UnexpectedPathForTDX(String),
 }
@@ -100,6 +103,18 @@ pub enum MemoryConfigError {
     SysInfoFail(#[source] nix::errno::Errno),
 }
 
+#[derive(Error, Debug, PartialEq)]
+pub enum NetConfigError {
+    #[error("Need device ID for NetConfig")]
+    MissingId,
+
+    #[error("Need tap device name for NetConfig")]
+    MissingTap,
+
+    #[error("Need vhost-user socket path for NetConfig")]
+    MissingVhostSocket,
+}
+
 #[derive(Error, Debug, PartialEq)]
 pub enum VsockConfigError {
     #[error("Missing VSOCK socket path")]
@@ -13,7 +13,7 @@ mod virtio_devices;
 
 use crate::virtio_devices::RateLimiterConfig;
 use kata_types::config::hypervisor::Hypervisor as HypervisorConfig;
-pub use net_util::MacAddr;
+pub use net_util::{MacAddr, MAC_ADDR_LEN};
 
 pub const MAX_NUM_PCI_SEGMENTS: u16 = 16;
 
@@ -320,6 +320,12 @@ pub struct PayloadConfig {
     pub initramfs: Option<PathBuf>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, Default)]
+pub struct PciDeviceInfo {
+    pub id: String,
+    pub bdf: String,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, Default)]
 pub struct PlatformConfig {
     //#[serde(default = "default_platformconfig_num_pci_segments")]
@@ -451,6 +457,34 @@ pub struct VmConfig {
     pub platform: Option<PlatformConfig>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, Default)]
+pub struct VmInfo {
+    pub state: VmState,
+    #[serde(default)]
+    pub memory_actual_size: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, Default)]
+pub struct VmRemoveDeviceData {
+    pub id: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, Default)]
+pub struct VmSnapshotConfig {
+    /// The snapshot destination URL, e.g. `file:///some/dir`
+    pub destination_url: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, Default)]
+pub enum VmState {
+    #[default]
+    Created,
+    Running,
+    Shutdown,
+    Paused,
+    BreakPoint,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, Default)]
 pub struct VsockConfig {
     pub cid: u64,
@@ -492,4 +526,33 @@ pub struct NamedHypervisorConfig {
     pub tdx_enabled: bool,
 
     pub shared_fs_devices: Option<Vec<FsConfig>>,
+    pub network_devices: Option<Vec<NetConfig>>,
+}
+
+// Types used to simplify conversion from the generic hypervisor devices
+// to the CH specific device configs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NamedNetDevice {
+    pub id: String,
+    pub tap: String,
+    pub guest_mac: Option<[u8; MAC_ADDR_LEN]>,
+    pub queue_pairs: u32,
+    // queues of an opened host device, used instead of the tap
+    pub fds: Vec<i32>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NamedVhostUserNetDevice {
+    pub id: String,
+    pub socket_path: String,
+    pub guest_mac: Option<[u8; MAC_ADDR_LEN]>,
+    pub queue_pairs: u32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NamedBlockDevice {
+    pub id: String,
+    pub path: String,
+    pub readonly: bool,
+    pub direct: bool,
 }
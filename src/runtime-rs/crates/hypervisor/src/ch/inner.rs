@@ -12,6 +12,7 @@ use kata_types::capabilities::{Capabilities, CapabilityBits};
 use kata_types::config::hypervisor::Hypervisor as HypervisorConfig;
 use kata_types::config::hypervisor::HYPERVISOR_NAME_CH;
 use persist::sandbox_persist::Persist;
+use std::collections::HashMap;
 use std::os::unix::net::UnixStream;
 use tokio::process::Child;
 use tokio::sync::watch::{channel, Receiver, Sender};
@@ -46,6 +47,9 @@ pub struct CloudHypervisorInner {
     /// List of devices that will be added to the VM once it boots
     pub(crate) pending_devices: Option<Vec<Device>>,
 
+    /// Guest PCI paths of the hotplugged devices, by device id
+    pub(crate) device_pci_paths: HashMap<String, String>,
+
     pub(crate) _capabilities: Capabilities,
 
     pub(crate) shutdown_tx: Option<Sender<bool>>,
@@ -82,6 +86,7 @@ impl CloudHypervisorInner {
             run_dir: String::default(),
             netns: None,
             pending_devices: None,
+            device_pci_paths: HashMap::new(),
             _capabilities: capabilities,
             shutdown_tx: Some(tx),
             shutdown_rx: Some(rx),
@@ -5,14 +5,24 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::inner::CloudHypervisorInner;
-use crate::device::{Device, ShareFsDeviceConfig};
+use super::utils::get_pci_path;
+use crate::device::{BlockConfig, Device, NetworkConfig, ShareFsDeviceConfig, VhostUserNetConfig};
 use crate::HybridVsockConfig;
 use crate::VmmState;
 use anyhow::{anyhow, Context, Result};
-use ch_config::ch_api::cloud_hypervisor_vm_fs_add;
-use ch_config::FsConfig;
+use ch_config::ch_api::{
+    cloud_hypervisor_vm_blockdev_add, cloud_hypervisor_vm_device_remove,
+    cloud_hypervisor_vm_fs_add, cloud_hypervisor_vm_netdev_add,
+    cloud_hypervisor_vm_netdev_add_with_fds,
+};
+use ch_config::{
+    DiskConfig, FsConfig, NamedBlockDevice, NamedNetDevice, NamedVhostUserNetDevice, NetConfig,
+    PciDeviceInfo,
+};
 use safe_path::scoped_join;
 use std::convert::TryFrom;
+use std::os::unix::io::AsRawFd;
+use std::os::unix::net::UnixStream;
 use std::path::PathBuf;
 
 const VIRTIO_FS: &str = "virtio-fs";
@@ -42,13 +52,15 @@ impl CloudHypervisorInner {
         match device {
             Device::ShareFsDevice(cfg) => self.handle_share_fs_device(cfg).await,
             Device::HybridVsock(cfg) => self.handle_hvsock_device(&cfg).await,
+            Device::Network(cfg) => self.handle_network_device(cfg).await,
+            Device::VhostUserNetwork(cfg) => self.handle_vhost_user_network_device(cfg).await,
+            Device::Block(cfg) => self.handle_block_device(cfg).await,
             _ => return Err(anyhow!("unhandled device: {:?}", device)),
         }
     }
 
     /// Add the device that were requested to be added before the VMM was
     /// started.
-    #[allow(dead_code)]
     pub(crate) async fn handle_pending_devices_after_boot(&mut self) -> Result<()> {
         if self.state != VmmState::VmRunning {
             return Err(anyhow!(
@@ -66,10 +78,160 @@ impl CloudHypervisorInner {
         Ok(())
     }
 
-    pub(crate) async fn remove_device(&mut self, _device: Device) -> Result<()> {
+    pub(crate) async fn remove_device(&mut self, device: Device) -> Result<()> {
+        let id = match device {
+            Device::Network(cfg) => cfg.id,
+            Device::VhostUserNetwork(cfg) => cfg.id,
+            Device::Block(cfg) => cfg.id,
+            _ => return Ok(()),
+        };
+
+        if self.state != VmmState::VmRunning {
+            // The device may still be waiting to be added.
+            if let Some(devices) = self.pending_devices.as_mut() {
+                devices.retain(|d| match d {
+                    Device::Network(cfg) => cfg.id != id,
+                    Device::VhostUserNetwork(cfg) => cfg.id != id,
+                    Device::Block(cfg) => cfg.id != id,
+                    _ => true,
+                });
+            }
+
+            return Ok(());
+        }
+
+        let response = cloud_hypervisor_vm_device_remove(self.api_socket_clone()?, id.clone())
+            .await
+            .with_context(|| format!("failed to remove device {}", id))?;
+
+        self.device_pci_paths.remove(&id);
+
+        if let Some(detail) = response {
+            debug!(sl!(), "device remove response: {:?}", detail);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) async fn update_device(&mut self, device: Device) -> Result<()> {
+        Err(anyhow!("update device {} is not supported", device))
+    }
+
+    pub(crate) fn get_device_pci_path(&self, id: &str) -> Result<Option<String>> {
+        Ok(self.device_pci_paths.get(id).cloned())
+    }
+
+    pub(crate) fn api_socket_clone(&self) -> Result<UnixStream> {
+        let socket = self
+            .api_socket
+            .as_ref()
+            .ok_or("missing socket")
+            .map_err(|e| anyhow!(e))?;
+
+        socket.try_clone().context("failed to clone socket")
+    }
+
+    async fn handle_network_device(&mut self, cfg: NetworkConfig) -> Result<()> {
+        let net_config = self.net_config(&cfg)?;
+        let socket = self.api_socket_clone()?;
+
+        // the queue files are sent along with the request, CH has its own copy once it returns
+        let response = if cfg.queue_files.is_empty() {
+            cloud_hypervisor_vm_netdev_add(socket, net_config).await
+        } else {
+            let fds = net_config.fds.clone().unwrap_or_default();
+            cloud_hypervisor_vm_netdev_add_with_fds(socket, net_config, fds).await
+        }
+        .context("failed to add network device")?;
+
+        self.handle_pci_device_info("network", response)
+    }
+
+    async fn handle_vhost_user_network_device(&mut self, cfg: VhostUserNetConfig) -> Result<()> {
+        let net_config = self.vhost_user_net_config(cfg)?;
+
+        let response = cloud_hypervisor_vm_netdev_add(self.api_socket_clone()?, net_config)
+            .await
+            .context("failed to add vhost-user network device")?;
+
+        self.handle_pci_device_info("vhost-user network", response)
+    }
+
+    async fn handle_block_device(&mut self, cfg: BlockConfig) -> Result<()> {
+        let direct = self
+            .config
+            .as_ref()
+            .map(|c| c.blockdev_info.block_device_cache_direct)
+            .unwrap_or_default();
+
+        let disk_config = DiskConfig::try_from(NamedBlockDevice {
+            id: cfg.id,
+            path: cfg.path_on_host,
+            readonly: cfg.is_readonly,
+            direct,
+        })?;
+
+        let response = cloud_hypervisor_vm_blockdev_add(self.api_socket_clone()?, disk_config)
+            .await
+            .context("failed to add block device")?;
+
+        self.handle_pci_device_info("block", response)
+    }
+
+    // Record the guest PCI path of the device added by CH, which the agent
+    // needs to find the device in the guest.
+    fn handle_pci_device_info(&mut self, kind: &str, response: Option<String>) -> Result<()> {
+        if let Some(detail) = response {
+            let info: PciDeviceInfo = serde_json::from_str(&detail)
+                .with_context(|| format!("invalid {} device add response: {:?}", kind, detail))?;
+
+            info!(
+                sl!(),
+                "added {} device {} at PCI address {}", kind, info.id, info.bdf
+            );
+
+            let pci_path = get_pci_path(&info.bdf)?;
+            self.device_pci_paths.insert(info.id, pci_path);
+        }
+
         Ok(())
     }
 
+    fn net_config(&self, cfg: &NetworkConfig) -> Result<NetConfig> {
+        let queue_pairs = self
+            .config
+            .as_ref()
+            .map(|c| c.network_info.network_queues)
+            .unwrap_or_default();
+
+        let net_config = NetConfig::try_from(NamedNetDevice {
+            id: cfg.id.clone(),
+            tap: cfg.host_dev_name.clone(),
+            guest_mac: cfg.guest_mac.as_ref().map(|mac| mac.0),
+            queue_pairs,
+            fds: cfg.queue_files.iter().map(|f| f.as_raw_fd()).collect(),
+        })?;
+
+        Ok(net_config)
+    }
+
+    fn vhost_user_net_config(&self, cfg: VhostUserNetConfig) -> Result<NetConfig> {
+        let queue_pairs = self
+            .config
+            .as_ref()
+            .map(|c| c.network_info.network_queues)
+            .unwrap_or_default();
+
+        let net_config = NetConfig::try_from(NamedVhostUserNetDevice {
+            id: cfg.id,
+            socket_path: cfg.socket_path,
+            guest_mac: cfg.guest_mac.map(|mac| mac.0),
+            queue_pairs,
+        })?;
+
+        Ok(net_config)
+    }
+
     async fn handle_share_fs_device(&mut self, cfg: ShareFsDeviceConfig) -> Result<()> {
         if cfg.fs_type != VIRTIO_FS {
             return Err(anyhow!("cannot handle share fs type: {:?}", cfg.fs_type));
@@ -124,13 +286,21 @@ impl CloudHypervisorInner {
         Ok(())
     }
 
-    pub(crate) async fn get_shared_fs_devices(&mut self) -> Result<Option<Vec<FsConfig>>> {
+    /// Take the pending devices that are part of the VM config at boot
+    /// time. Any other devices stay pending and are hotplugged once the VM
+    /// is running.
+    pub(crate) async fn get_boot_devices(
+        &mut self,
+    ) -> Result<(Option<Vec<FsConfig>>, Option<Vec<NetConfig>>)> {
         let pending_root_devices = self.pending_devices.take();
 
         let mut root_devices = Vec::<FsConfig>::new();
+        let mut network_devices = Vec::<NetConfig>::new();
+        let mut remaining = Vec::<Device>::new();
 
         if let Some(devices) = pending_root_devices {
-            for dev in devices {
+            // Pending devices are stored newest first.
+            for dev in devices.into_iter().rev() {
                 match dev {
                     Device::ShareFsDevice(dev) => {
                         let settings = ShareFsSettings::new(dev, self.vm_path.clone());
@@ -139,13 +309,30 @@ impl CloudHypervisorInner {
 
                         root_devices.push(fs_cfg);
                     }
-                    _ => continue,
+                    // the queue files can only be sent along with a hotplug request
+                    Device::Network(dev) if dev.queue_files.is_empty() => {
+                        network_devices.push(self.net_config(&dev)?)
+                    }
+                    Device::VhostUserNetwork(dev) => {
+                        network_devices.push(self.vhost_user_net_config(dev)?)
+                    }
+                    _ => remaining.insert(0, dev),
                 };
             }
 
-            Ok(Some(root_devices))
+            if !remaining.is_empty() {
+                self.pending_devices = Some(remaining);
+            }
+
+            let network_devices = if network_devices.is_empty() {
+                None
+            } else {
+                Some(network_devices)
+            };
+
+            Ok((Some(root_devices), network_devices))
         } else {
-            Ok(None)
+            Ok((None, None))
         }
     }
 }
@@ -5,25 +5,29 @@
 
 use super::inner::CloudHypervisorInner;
 use crate::ch::utils::get_api_socket_path;
-use crate::ch::utils::{get_jailer_root, get_sandbox_path, get_vsock_path};
+use crate::ch::utils::{get_jailer_root, get_sandbox_path, get_vcpu_thread_ids, get_vsock_path};
 use crate::kernel_param::KernelParams;
+use crate::utils::get_child_threads;
 use crate::Device;
 use crate::VsockConfig;
 use crate::VM_ROOTFS_DRIVER_PMEM;
 use crate::{VcpuThreadIds, VmmState};
 use anyhow::{anyhow, Context, Result};
 use ch_config::ch_api::{
-    cloud_hypervisor_vm_create, cloud_hypervisor_vm_start, cloud_hypervisor_vmm_ping,
-    cloud_hypervisor_vmm_shutdown,
+    cloud_hypervisor_vm_create, cloud_hypervisor_vm_info, cloud_hypervisor_vm_pause,
+    cloud_hypervisor_vm_resume, cloud_hypervisor_vm_snapshot, cloud_hypervisor_vm_start,
+    cloud_hypervisor_vmm_ping, cloud_hypervisor_vmm_shutdown,
 };
-use ch_config::{NamedHypervisorConfig, VmConfig};
+use ch_config::{NamedHypervisorConfig, VmConfig, VmSnapshotConfig, VmState};
 use core::future::poll_fn;
 use futures::executor::block_on;
 use futures::future::join_all;
 use kata_types::capabilities::{Capabilities, CapabilityBits};
 use kata_types::config::default::DEFAULT_CH_ROOTFS_TYPE;
+use std::collections::HashSet;
 use std::convert::TryFrom;
 use std::fs::create_dir_all;
+use std::iter::FromIterator;
 use std::os::unix::net::UnixStream;
 use std::path::Path;
 use std::process::Stdio;
@@ -40,6 +44,9 @@ const CH_NAME: &str = "cloud-hypervisor";
 /// Number of milliseconds to wait before retrying a CH operation.
 const CH_POLL_TIME_MS: u64 = 50;
 
+/// Directory below the sandbox path that VM snapshots are written to.
+const CH_SNAPSHOT_DIR: &str = "snapshot";
+
 impl CloudHypervisorInner {
     async fn start_hypervisor(&mut self, timeout_secs: i32) -> Result<()> {
         self.cloud_hypervisor_launch(timeout_secs)
@@ -108,7 +115,7 @@ impl CloudHypervisorInner {
     }
 
     async fn boot_vm(&mut self) -> Result<()> {
-        let shared_fs_devices = self.get_shared_fs_devices().await?;
+        let (shared_fs_devices, network_devices) = self.get_boot_devices().await?;
 
         let socket = self
             .api_socket
@@ -148,6 +155,7 @@ impl CloudHypervisorInner {
             cfg: hypervisor_config.clone(),
             tdx_enabled,
             shared_fs_devices,
+            network_devices,
         };
 
         let cfg = VmConfig::try_from(named_cfg)?;
@@ -172,6 +180,10 @@ impl CloudHypervisorInner {
 
         self.state = VmmState::VmRunning;
 
+        self.handle_pending_devices_after_boot()
+            .await
+            .context("failed to add pending devices")?;
+
         Ok(())
     }
 
@@ -461,15 +473,78 @@ impl CloudHypervisorInner {
         Ok(())
     }
 
-    pub(crate) fn pause_vm(&self) -> Result<()> {
+    pub(crate) async fn pause_vm(&self) -> Result<()> {
+        info!(sl!(), "do pause vm");
+
+        let response = cloud_hypervisor_vm_pause(self.api_socket_clone()?)
+            .await
+            .context("pause vm")?;
+
+        if let Some(detail) = response {
+            debug!(sl!(), "vm pause response: {:?}", detail);
+        }
+
         Ok(())
     }
 
-    pub(crate) fn resume_vm(&self) -> Result<()> {
+    pub(crate) async fn resume_vm(&self) -> Result<()> {
+        info!(sl!(), "do resume vm");
+
+        let response = cloud_hypervisor_vm_resume(self.api_socket_clone()?)
+            .await
+            .context("resume vm")?;
+
+        if let Some(detail) = response {
+            debug!(sl!(), "vm resume response: {:?}", detail);
+        }
+
         Ok(())
     }
 
+    // CH can only snapshot a paused VM, so a running VM is paused for the
+    // duration of the snapshot and resumed afterwards.
     pub(crate) async fn save_vm(&self) -> Result<()> {
+        let snapshot_dir = Path::new(&self.vm_path).join(CH_SNAPSHOT_DIR);
+
+        create_dir_all(&snapshot_dir)
+            .with_context(|| format!("failed to create snapshot directory {:?}", snapshot_dir))?;
+
+        let info = cloud_hypervisor_vm_info(self.api_socket_clone()?)
+            .await
+            .context("get vm info")?;
+
+        let was_running = info.state == VmState::Running;
+        if was_running {
+            self.pause_vm().await?;
+        }
+
+        let snapshot_config = VmSnapshotConfig {
+            destination_url: format!("file://{}", snapshot_dir.display()),
+        };
+
+        let result = cloud_hypervisor_vm_snapshot(self.api_socket_clone()?, snapshot_config)
+            .await
+            .context("snapshot vm");
+
+        if was_running {
+            if let Err(err) = self.resume_vm().await {
+                return Err(match result {
+                    Ok(_) => err,
+                    Err(snapshot_err) => anyhow!(
+                        "failed to resume vm: {:?} after failing to snapshot it: {:?}",
+                        err,
+                        snapshot_err
+                    ),
+                });
+            }
+        }
+
+        if let Some(detail) = result? {
+            debug!(sl!(), "vm snapshot response: {:?}", detail);
+        }
+
+        info!(sl!(), "saved vm snapshot to {:?}", snapshot_dir);
+
         Ok(())
     }
 
@@ -488,15 +563,54 @@ impl CloudHypervisorInner {
     }
 
     pub(crate) async fn get_thread_ids(&self) -> Result<VcpuThreadIds> {
-        Ok(VcpuThreadIds::default())
+        let pid = self
+            .pid
+            .ok_or(format!("{} not running", CH_NAME))
+            .map_err(|e| anyhow!(e))?;
+
+        let vcpu_thread_ids = VcpuThreadIds {
+            vcpus: get_vcpu_thread_ids(pid)?,
+        };
+
+        info!(sl!(), "get thread ids {:?}", vcpu_thread_ids);
+
+        Ok(vcpu_thread_ids)
     }
 
     pub(crate) async fn cleanup(&self) -> Result<()> {
-        Ok(())
+        if self.vm_path.is_empty() {
+            return Ok(());
+        }
+
+        match std::fs::remove_dir_all(&self.vm_path) {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => {
+                Err(err).with_context(|| format!("failed to remove dir all for {}", &self.vm_path))
+            }
+            _ => Ok(()),
+        }
     }
 
     pub(crate) async fn get_pids(&self) -> Result<Vec<u32>> {
-        Ok(Vec::<u32>::new())
+        let pid = match self.pid {
+            Some(pid) => pid,
+            None => return Ok(Vec::<u32>::new()),
+        };
+
+        let mut pids = HashSet::new();
+        pids.insert(pid);
+
+        for tid in get_child_threads(pid) {
+            pids.insert(tid);
+        }
+
+        // remove vcpus
+        for tid in get_vcpu_thread_ids(pid)?.values() {
+            pids.remove(tid);
+        }
+
+        info!(sl!(), "get pids {:?}", pids);
+
+        Ok(Vec::from_iter(pids.into_iter()))
     }
 
     pub(crate) async fn get_vmm_master_tid(&self) -> Result<u32> {
@@ -517,7 +631,14 @@ impl CloudHypervisorInner {
     }
 
     pub(crate) async fn check(&self) -> Result<()> {
-        Ok(())
+        let info = cloud_hypervisor_vm_info(self.api_socket_clone()?)
+            .await
+            .context("get vm info")?;
+
+        match info.state {
+            VmState::Running | VmState::Paused => Ok(()),
+            state => Err(anyhow!("unexpected {} VM state: {:?}", CH_NAME, state)),
+        }
     }
 
     pub(crate) async fn get_jailer_root(&self) -> Result<String> {
@@ -530,7 +651,7 @@ impl CloudHypervisorInner {
 
     pub(crate) async fn capabilities(&self) -> Result<Capabilities> {
         let mut caps = Capabilities::default();
-        caps.set(CapabilityBits::FsSharingSupport);
+        caps.set(CapabilityBits::FsSharingSupport | CapabilityBits::VhostUserNetSupport);
         Ok(caps)
     }
 }
@@ -2,8 +2,10 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::Result;
+use anyhow::{anyhow, Context, Result};
 use shim_interface::KATA_PATH;
+use std::collections::HashMap;
+use std::fs;
 
 // The socket used to connect to CH. This is used for CH API communications.
 const CH_API_SOCKET_NAME: &str = "ch-api.sock";
@@ -14,6 +16,9 @@ const CH_VM_SOCKET_NAME: &str = "ch-vm.sock";
 
 const CH_JAILER_DIR: &str = "root";
 
+// CH names each vCPU thread "vcpu<index>".
+const CH_VCPU_THREAD_PREFIX: &str = "vcpu";
+
 // Return the path for a _hypothetical_ sandbox: the path does *not* exist
 // yet, and for this reason safe-path cannot be used.
 pub fn get_sandbox_path(id: &str) -> Result<String> {
@@ -51,3 +56,119 @@ pub fn get_jailer_root(id: &str) -> Result<String> {
 
     Ok(path)
 }
+
+// Return a map of vCPU index to thread ID for the specified CH process,
+// found by inspecting the thread names below /proc/<pid>/task.
+pub fn get_vcpu_thread_ids(pid: u32) -> Result<HashMap<u32, u32>> {
+    let task_dir = format!("/proc/{}/task", pid);
+
+    let mut vcpus = HashMap::new();
+
+    let entries =
+        fs::read_dir(&task_dir).with_context(|| format!("failed to read {}", task_dir))?;
+
+    for entry in entries {
+        let entry = entry?;
+
+        let tid = match entry.file_name().to_str().map(|s| s.parse::<u32>()) {
+            Some(Ok(tid)) => tid,
+            _ => continue,
+        };
+
+        // The thread may have exited since the directory was read.
+        let comm = match fs::read_to_string(entry.path().join("comm")) {
+            Ok(comm) => comm,
+            Err(_) => continue,
+        };
+
+        if let Some(index) = comm
+            .trim_end()
+            .strip_prefix(CH_VCPU_THREAD_PREFIX)
+            .and_then(|i| i.parse::<u32>().ok())
+        {
+            vcpus.insert(index, tid);
+        }
+    }
+
+    Ok(vcpus)
+}
+
+// Return the guest PCI path ("<slot>.<function>") of the device at the
+// specified PCI address ("<domain>:<bus>:<slot>.<function>"). CH puts all the
+// hotplugged devices on the root bus, so the path has a single element.
+pub fn get_pci_path(bdf: &str) -> Result<String> {
+    let fields: Vec<&str> = bdf.split(':').collect();
+
+    let (bus, slot_fn) = match fields.as_slice() {
+        [_domain, bus, slot_fn] => (*bus, *slot_fn),
+        [bus, slot_fn] => (*bus, *slot_fn),
+        _ => return Err(anyhow!("invalid PCI address {:?}", bdf)),
+    };
+
+    if u8::from_str_radix(bus, 16).with_context(|| format!("invalid PCI bus in {:?}", bdf))? != 0 {
+        return Err(anyhow!("PCI device {:?} is not on the root bus", bdf));
+    }
+
+    let (slot, function) = slot_fn
+        .split_once('.')
+        .ok_or_else(|| anyhow!("invalid PCI slot and function in {:?}", bdf))?;
+    let slot =
+        u8::from_str_radix(slot, 16).with_context(|| format!("invalid PCI slot in {:?}", bdf))?;
+    let function = u8::from_str_radix(function, 16)
+        .with_context(|| format!("invalid PCI function in {:?}", bdf))?;
+
+    if slot > 0x1f || function > 7 {
+        return Err(anyhow!("invalid PCI address {:?}", bdf));
+    }
+
+    Ok(format!("{:02x}.{:x}", slot, function))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::channel;
+    use std::thread;
+
+    #[test]
+    fn test_get_vcpu_thread_ids() {
+        let (tid_tx, tid_rx) = channel();
+        let (done_tx, done_rx) = channel::<()>();
+
+        let handle = thread::Builder::new()
+            .name(format!("{}7", CH_VCPU_THREAD_PREFIX))
+            .spawn(move || {
+                tid_tx.send(nix::unistd::gettid().as_raw() as u32).unwrap();
+                done_rx.recv().ok();
+            })
+            .unwrap();
+
+        let tid = tid_rx.recv().unwrap();
+
+        let vcpus = get_vcpu_thread_ids(std::process::id()).unwrap();
+
+        done_tx.send(()).unwrap();
+        handle.join().unwrap();
+
+        assert_eq!(vcpus.get(&7), Some(&tid));
+        assert!(get_vcpu_thread_ids(u32::MAX).is_err());
+    }
+
+    #[test]
+    fn test_get_pci_path() {
+        assert_eq!(get_pci_path("0000:00:05.0").unwrap(), "05.0");
+        assert_eq!(get_pci_path("0000:00:1f.7").unwrap(), "1f.7");
+        assert_eq!(get_pci_path("00:0a.1").unwrap(), "0a.1");
+
+        for bdf in [
+            "",
+            "0000:00:05",
+            "0000:01:05.0",
+            "0000:00:20.0",
+            "0000:00:05.8",
+            "xx:05.0",
+        ] {
+            assert!(get_pci_path(bdf).is_err(), "{:?} must be rejected", bdf);
+        }
+    }
+}
@@ -4,7 +4,24 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-#[derive(Debug)]
+/// I/O limits of a block device, enforced by the hypervisor so that the guest
+/// cannot bypass them. A value of 0 means unlimited.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BlockIoLimits {
+    /// Bandwidth limit, in bytes per second.
+    pub bytes_per_sec: u64,
+
+    /// I/O operations limit, per second.
+    pub ops_per_sec: u64,
+}
+
+impl BlockIoLimits {
+    pub fn is_unlimited(&self) -> bool {
+        self.bytes_per_sec == 0 && self.ops_per_sec == 0
+    }
+}
+
+#[derive(Clone, Debug)]
 pub struct BlockConfig {
     /// Unique identifier of the drive.
     pub id: String,
@@ -21,4 +38,7 @@ pub struct BlockConfig {
 
     /// device index
     pub index: u64,
+
+    /// I/O limits of the drive.
+    pub io_limits: BlockIoLimits,
 }
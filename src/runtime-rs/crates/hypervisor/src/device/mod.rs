@@ -5,9 +5,9 @@
 //
 
 mod block;
-pub use block::BlockConfig;
+pub use block::{BlockConfig, BlockIoLimits};
 mod network;
-pub use network::{Address, NetworkConfig};
+pub use network::{Address, NetworkConfig, VhostUserNetConfig};
 mod share_fs_device;
 pub use share_fs_device::ShareFsDeviceConfig;
 mod vfio;
@@ -23,6 +23,7 @@ use std::fmt;
 pub enum Device {
     Block(BlockConfig),
     Network(NetworkConfig),
+    VhostUserNetwork(VhostUserNetConfig),
     ShareFsDevice(ShareFsDeviceConfig),
     Vfio(VfioConfig),
     ShareFsMount(ShareFsMountConfig),
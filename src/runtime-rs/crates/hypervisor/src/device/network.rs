@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-use std::fmt;
+use std::{fmt, fs::File};
 
 pub struct Address(pub [u8; 6]);
 
@@ -29,4 +29,21 @@ pub struct NetworkConfig {
 
     /// Guest MAC address.
     pub guest_mac: Option<Address>,
+
+    /// Opened queues of the host device, e.g. a macvtap device, which the hypervisor can't
+    /// open by name. `host_dev_name` is opened if it's empty. Only cloud-hypervisor takes
+    /// them, dragonball opens its taps by name.
+    pub queue_files: Vec<File>,
+}
+
+#[derive(Debug)]
+pub struct VhostUserNetConfig {
+    /// Unique identifier of the device
+    pub id: String,
+
+    /// Host path of the vhost-user socket served by the userspace datapath.
+    pub socket_path: String,
+
+    /// Guest MAC address.
+    pub guest_mac: Option<Address>,
 }
@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-use super::vmm_instance::VmmInstance;
+use super::{inner_device::token_bucket, vmm_instance::VmmInstance};
 use crate::{
     device::Device, hypervisor_persist::HypervisorState, kernel_param::KernelParams, VmmState,
     DEV_HUGEPAGES, HUGETLBFS, HYPERVISOR_DRAGONBALL, SHMEM, VM_ROOTFS_DRIVER_BLK,
@@ -12,7 +12,8 @@ use crate::{
 use anyhow::{anyhow, Context, Result};
 use async_trait::async_trait;
 use dragonball::{
-    api::v1::{BlockDeviceConfigInfo, BootSourceConfig},
+    api::v1::{BlockDeviceConfigInfo, BootSourceConfig, RngDeviceConfigInfo},
+    config_manager::RateLimiterConfigInfo,
     vm::VmConfigInfo,
 };
 use kata_sys_util::mount;
@@ -26,6 +27,10 @@ use std::{collections::HashSet, fs::create_dir_all, path::PathBuf};
 
 const DRAGONBALL_KERNEL: &str = "vmlinux";
 const DRAGONBALL_ROOT_FS: &str = "rootfs";
+const DRAGONBALL_RNG_DEVICE: &str = "rng0";
+// The guest reads at most this many bytes per second from the host entropy source, so that it
+// can't exhaust the host entropy.
+const DRAGONBALL_RNG_MAX_BYTES_PER_SEC: u64 = 1024 * 1024;
 
 pub struct DragonballInner {
     /// sandbox id
@@ -103,6 +108,11 @@ impl DragonballInner {
             &rootfs_driver,
             &self.config.boot_info.rootfs_type,
         )?);
+        // the panic watcher reads the guest kernel log from ttyS1, and the guest must halt
+        // on panic instead of rebooting before its memory is dumped
+        if self.guest_dump().is_some() {
+            kernel_params.append(&mut KernelParams::from_string("console=ttyS1 panic=0"));
+        }
         kernel_params.append(&mut KernelParams::from_string(
             &self.config.boot_info.kernel_params,
         ));
@@ -134,6 +144,9 @@ impl DragonballInner {
         self.set_vm_rootfs(&image, &rootfs_driver)
             .context("set vm rootfs")?;
 
+        // add entropy device
+        self.set_vm_rng().context("set vm rng")?;
+
         // add pending devices
         while let Some(dev) = self.pending_devices.pop() {
             self.add_device(dev).await.context("add_device")?;
@@ -290,6 +303,28 @@ impl DragonballInner {
         }
     }
 
+    fn set_vm_rng(&mut self) -> Result<()> {
+        let entropy_source = self.config.machine_info.entropy_source.clone();
+        if entropy_source.is_empty() {
+            return Ok(());
+        }
+
+        info!(sl!(), "set vm rng {}", entropy_source);
+        let rng_cfg = RngDeviceConfigInfo {
+            id: DRAGONBALL_RNG_DEVICE.to_string(),
+            entropy_source,
+            rate_limiter: Some(RateLimiterConfigInfo {
+                bandwidth: token_bucket(DRAGONBALL_RNG_MAX_BYTES_PER_SEC),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        self.vmm_instance
+            .insert_rng_device(rng_cfg)
+            .context("insert rng device")
+    }
+
     fn start_vmm_instance(&mut self) -> Result<()> {
         info!(sl!(), "Starting VM");
         self.vmm_instance
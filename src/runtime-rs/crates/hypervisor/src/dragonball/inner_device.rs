@@ -9,20 +9,24 @@ use std::path::PathBuf;
 use anyhow::{anyhow, Context, Result};
 use dbs_utils::net::MacAddr;
 use dragonball::api::v1::{
-    BlockDeviceConfigInfo, FsDeviceConfigInfo, FsMountConfigInfo, VirtioNetDeviceConfigInfo,
-    VsockDeviceConfigInfo,
+    BlockDeviceConfigInfo, BlockDeviceConfigUpdateInfo, FsDeviceConfigInfo, FsMountConfigInfo,
+    VirtioNetDeviceConfigInfo, VsockDeviceConfigInfo,
 };
+use dragonball::config_manager::{RateLimiterConfigInfo, TokenBucketConfigInfo};
 
 use super::DragonballInner;
 use crate::{
-    device::Device, HybridVsockConfig, NetworkConfig, ShareFsDeviceConfig, ShareFsMountConfig,
-    ShareFsMountType, ShareFsOperation, VmmState,
+    device::Device, BlockIoLimits, HybridVsockConfig, NetworkConfig, ShareFsDeviceConfig,
+    ShareFsMountConfig, ShareFsMountType, ShareFsOperation, VmmState,
 };
 
 const MB_TO_B: u32 = 1024 * 1024;
 const DEFAULT_VIRTIO_FS_NUM_QUEUES: i32 = 1;
 const DEFAULT_VIRTIO_FS_QUEUE_SIZE: i32 = 1024;
 
+// Refill time of the rate limiter token buckets.
+const RATE_LIMITER_REFILL_TIME_MS: u64 = 1000;
+
 const VIRTIO_FS: &str = "virtio-fs";
 const INLINE_VIRTIO_FS: &str = "inline-virtio-fs";
 
@@ -30,6 +34,41 @@ pub(crate) fn drive_index_to_id(index: u64) -> String {
     format!("drive_{}", index)
 }
 
+// Build a rate limiter capping the bandwidth to max_rate bits per second, the
+// bucket being refilled every second. A max_rate of 0 means unlimited.
+fn bandwidth_rate_limiter(max_rate: u64) -> Option<RateLimiterConfigInfo> {
+    if max_rate == 0 {
+        return None;
+    }
+
+    Some(RateLimiterConfigInfo {
+        bandwidth: token_bucket(max_rate / 8),
+        ..Default::default()
+    })
+}
+
+// Build a rate limiter enforcing the I/O limits of a block device.
+fn block_rate_limiter(io_limits: &BlockIoLimits) -> Option<RateLimiterConfigInfo> {
+    if io_limits.is_unlimited() {
+        return None;
+    }
+
+    Some(RateLimiterConfigInfo {
+        bandwidth: token_bucket(io_limits.bytes_per_sec),
+        ops: token_bucket(io_limits.ops_per_sec),
+    })
+}
+
+// Token bucket allowing size tokens per refill time, a size of 0 disables
+// the bucket.
+pub(crate) fn token_bucket(size: u64) -> TokenBucketConfigInfo {
+    TokenBucketConfigInfo {
+        size,
+        one_time_burst: 0,
+        refill_time: RATE_LIMITER_REFILL_TIME_MS,
+    }
+}
+
 impl DragonballInner {
     pub(crate) async fn add_device(&mut self, device: Device) -> Result<()> {
         if self.state == VmmState::NotReady {
@@ -44,7 +83,11 @@ impl DragonballInner {
 
         info!(sl!(), "dragonball add device {:?}", &device);
         match device {
-            Device::Network(config) => self.add_net_device(&config).context("add net device"),
+            Device::Network(config) => self.add_net_device(config).context("add net device"),
+            Device::VhostUserNetwork(config) => Err(anyhow!(
+                "vhost-user network device {} is not supported",
+                config.id
+            )),
             Device::Vfio(_config) => {
                 todo!()
             }
@@ -54,6 +97,7 @@ impl DragonballInner {
                     config.id.as_str(),
                     config.is_readonly,
                     config.no_drop,
+                    &config.io_limits,
                 )
                 .context("add block device"),
             Device::HybridVsock(config) => self.add_hvsock(&config).context("add vsock"),
@@ -85,12 +129,51 @@ impl DragonballInner {
         }
     }
 
+    pub(crate) async fn update_device(&mut self, device: Device) -> Result<()> {
+        info!(sl!(), "dragonball update device {:?}", &device);
+
+        let config = match device {
+            Device::Block(config) => config,
+            _ => return Err(anyhow!("unsupported device {:?}", device)),
+        };
+
+        if self.state == VmmState::NotReady {
+            // the device is still pending, update the config it will be added with
+            let pending_config = self
+                .pending_devices
+                .iter_mut()
+                .find_map(|pending| match pending {
+                    Device::Block(pending_config) if pending_config.id == config.id => {
+                        Some(pending_config)
+                    }
+                    _ => None,
+                })
+                .ok_or_else(|| anyhow!("block device {} not found", config.id))?;
+            pending_config.io_limits = config.io_limits;
+
+            return Ok(());
+        }
+
+        if !self.cached_block_devices.contains(&config.id) {
+            return Err(anyhow!("block device {} not found", config.id));
+        }
+
+        self.update_block_device(config.id.as_str(), &config.io_limits)
+            .context("update block device")
+    }
+
+    // Dragonball attaches the devices through virtio-mmio.
+    pub(crate) fn get_device_pci_path(&self, _id: &str) -> Result<Option<String>> {
+        Ok(None)
+    }
+
     fn add_block_device(
         &mut self,
         path: &str,
         id: &str,
         read_only: bool,
         no_drop: bool,
+        io_limits: &BlockIoLimits,
     ) -> Result<()> {
         let jailed_drive = self.get_resource(path, id).context("get resource")?;
         self.cached_block_devices.insert(id.to_string());
@@ -101,6 +184,7 @@ impl DragonballInner {
             is_direct: self.config.blockdev_info.block_device_cache_direct,
             no_drop,
             is_read_only: read_only,
+            rate_limiter: block_rate_limiter(io_limits),
             ..Default::default()
         };
         self.vmm_instance
@@ -108,6 +192,18 @@ impl DragonballInner {
             .context("insert block device")
     }
 
+    fn update_block_device(&mut self, id: &str, io_limits: &BlockIoLimits) -> Result<()> {
+        let blk_update = BlockDeviceConfigUpdateInfo {
+            drive_id: id.to_string(),
+            // all zero token buckets disable the current rate limiter
+            rate_limiter: Some(block_rate_limiter(io_limits).unwrap_or_default()),
+        };
+
+        self.vmm_instance
+            .update_block_device(blk_update)
+            .context("update block device")
+    }
+
     fn remove_block_drive(&mut self, id: &str) -> Result<()> {
         self.vmm_instance
             .remove_block_device(id)
@@ -121,16 +217,18 @@ impl DragonballInner {
         Ok(())
     }
 
-    fn add_net_device(&mut self, config: &NetworkConfig) -> Result<()> {
-        let iface_cfg = VirtioNetDeviceConfigInfo {
-            iface_id: config.id.clone(),
-            host_dev_name: config.host_dev_name.clone(),
-            guest_mac: match &config.guest_mac {
-                Some(mac) => MacAddr::from_bytes(&mac.0).ok(),
-                None => None,
-            },
-            ..Default::default()
-        };
+    fn add_net_device(&mut self, config: NetworkConfig) -> Result<()> {
+        // The tap of a dragonball virtio-net device is always opened by name
+        // through /dev/net/tun, there's no way to hand it the queues opened
+        // by the endpoint, e.g. of a macvtap device.
+        if !config.queue_files.is_empty() {
+            return Err(anyhow!(
+                "dragonball can't attach {} by its queue files, use the tcfilter network model",
+                config.host_dev_name
+            ));
+        }
+
+        let iface_cfg = self.net_device_config(&config);
 
         info!(
             sl!(),
@@ -142,6 +240,22 @@ impl DragonballInner {
             .context("insert network device")
     }
 
+    fn net_device_config(&self, config: &NetworkConfig) -> VirtioNetDeviceConfigInfo {
+        let network_info = &self.config.network_info;
+
+        VirtioNetDeviceConfigInfo {
+            iface_id: config.id.clone(),
+            host_dev_name: config.host_dev_name.clone(),
+            guest_mac: match &config.guest_mac {
+                Some(mac) => MacAddr::from_bytes(&mac.0).ok(),
+                None => None,
+            },
+            rx_rate_limiter: bandwidth_rate_limiter(network_info.rx_rate_limiter_max_rate),
+            tx_rate_limiter: bandwidth_rate_limiter(network_info.tx_rate_limiter_max_rate),
+            ..Default::default()
+        }
+    }
+
     fn add_hvsock(&mut self, config: &HybridVsockConfig) -> Result<()> {
         let vsock_cfg = VsockDeviceConfigInfo {
             id: String::from("root"),
@@ -291,9 +405,15 @@ impl DragonballInner {
 
 #[cfg(test)]
 mod tests {
+    use std::fs::File;
+
     use dragonball::api::v1::FsDeviceConfigInfo;
+    use dragonball::config_manager::{RateLimiterConfigInfo, TokenBucketConfigInfo};
 
+    use super::block_rate_limiter;
+    use crate::device::Device;
     use crate::dragonball::DragonballInner;
+    use crate::{BlockConfig, BlockIoLimits, NetworkConfig};
 
     #[test]
     fn test_parse_inline_virtiofs_args() {
@@ -320,4 +440,113 @@ mod tests {
         assert!(fs_cfg.drop_sys_resource);
         assert!(fs_cfg.thread_pool_size == 128);
     }
+
+    #[test]
+    fn test_net_device_config() {
+        let mut dragonball = DragonballInner::new();
+        let config = NetworkConfig {
+            id: "eth0".to_string(),
+            host_dev_name: "tap0_kata".to_string(),
+            guest_mac: None,
+            queue_files: vec![],
+        };
+
+        // unlimited by default
+        let iface_cfg = dragonball.net_device_config(&config);
+        assert_eq!(iface_cfg.iface_id, "eth0");
+        assert_eq!(iface_cfg.host_dev_name, "tap0_kata");
+        assert!(iface_cfg.rx_rate_limiter.is_none());
+        assert!(iface_cfg.tx_rate_limiter.is_none());
+
+        // the queues opened by the endpoint, e.g. of a macvtap device, are
+        // rejected before reaching the vmm
+        let err = dragonball
+            .add_net_device(NetworkConfig {
+                id: "eth1".to_string(),
+                host_dev_name: "macvtap0_kata".to_string(),
+                guest_mac: None,
+                queue_files: vec![File::open("/dev/null").unwrap()],
+            })
+            .unwrap_err();
+        assert!(err.to_string().contains("macvtap0_kata"));
+
+        // 80Mbit/s inbound, 8Mbit/s outbound
+        dragonball.config.network_info.rx_rate_limiter_max_rate = 80_000_000;
+        dragonball.config.network_info.tx_rate_limiter_max_rate = 8_000_000;
+
+        let iface_cfg = dragonball.net_device_config(&config);
+        assert_eq!(
+            iface_cfg.rx_rate_limiter,
+            Some(RateLimiterConfigInfo {
+                bandwidth: TokenBucketConfigInfo {
+                    size: 10_000_000,
+                    one_time_burst: 0,
+                    refill_time: 1000,
+                },
+                ops: TokenBucketConfigInfo::default(),
+            })
+        );
+        assert_eq!(
+            iface_cfg
+                .tx_rate_limiter
+                .map(|rl| rl.bandwidth.size)
+                .unwrap_or_default(),
+            1_000_000
+        );
+    }
+
+    #[test]
+    fn test_block_rate_limiter() {
+        assert_eq!(block_rate_limiter(&BlockIoLimits::default()), None);
+
+        let limiter = block_rate_limiter(&BlockIoLimits {
+            bytes_per_sec: 0,
+            ops_per_sec: 500,
+        })
+        .unwrap();
+        assert_eq!(limiter.bandwidth.size, 0);
+        assert_eq!(limiter.ops.size, 500);
+        assert_eq!(limiter.ops.refill_time, 1000);
+
+        let limiter = block_rate_limiter(&BlockIoLimits {
+            bytes_per_sec: 1_048_576,
+            ops_per_sec: 0,
+        })
+        .unwrap();
+        assert_eq!(limiter.bandwidth.size, 1_048_576);
+        assert_eq!(limiter.ops.size, 0);
+    }
+
+    #[test]
+    fn test_update_pending_block_device() {
+        let mut dragonball = DragonballInner::new();
+        let block_config = |id: &str, ops_per_sec| BlockConfig {
+            id: id.to_string(),
+            path_on_host: "/dev/sdb".to_string(),
+            is_readonly: false,
+            no_drop: false,
+            index: 1,
+            io_limits: BlockIoLimits {
+                bytes_per_sec: 0,
+                ops_per_sec,
+            },
+        };
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        rt.block_on(dragonball.add_device(Device::Block(block_config("drive_1", 100))))
+            .unwrap();
+        rt.block_on(dragonball.update_device(Device::Block(block_config("drive_1", 500))))
+            .unwrap();
+        match &dragonball.pending_devices[0] {
+            Device::Block(config) => assert_eq!(config.io_limits.ops_per_sec, 500),
+            device => panic!("unexpected pending device {:?}", device),
+        }
+
+        // unknown device
+        assert!(rt
+            .block_on(dragonball.update_device(Device::Block(block_config("drive_2", 500))))
+            .is_err());
+    }
 }
@@ -7,6 +7,7 @@
 mod inner;
 mod inner_device;
 mod inner_hypervisor;
+mod panic_watcher;
 use super::HypervisorState;
 use inner::DragonballInner;
 use persist::sandbox_persist::Persist;
@@ -19,6 +20,7 @@ use async_trait::async_trait;
 use kata_types::capabilities::Capabilities;
 use kata_types::config::hypervisor::Hypervisor as HypervisorConfig;
 use tokio::sync::RwLock;
+use tracing::instrument;
 
 use crate::{device::Device, Hypervisor, VcpuThreadIds};
 
@@ -47,14 +49,36 @@ impl Dragonball {
 
 #[async_trait]
 impl Hypervisor for Dragonball {
+    #[instrument(skip(self))]
     async fn prepare_vm(&self, id: &str, netns: Option<String>) -> Result<()> {
         let mut inner = self.inner.write().await;
         inner.prepare_vm(id, netns).await
     }
 
+    #[instrument(skip(self))]
     async fn start_vm(&self, timeout: i32) -> Result<()> {
         let mut inner = self.inner.write().await;
-        inner.start_vm(timeout).await
+        let guest_dump = inner.guest_dump().map(|dump| {
+            let kernel_log = panic_watcher::tee_guest_kernel_log(&mut inner.vmm_instance);
+            (dump, kernel_log)
+        });
+        inner.start_vm(timeout).await?;
+
+        if let Some((dump, kernel_log)) = guest_dump {
+            let watcher = panic_watcher::watch_guest_panic(
+                self.inner.clone(),
+                inner.vmm_instance.requester()?,
+                kernel_log,
+                dump,
+            );
+            tokio::spawn(async move {
+                if let Err(err) = watcher.await {
+                    error!(sl!(), "failed to watch guest panic: {:?}", err);
+                }
+            });
+        }
+
+        Ok(())
     }
 
     async fn stop_vm(&self) -> Result<()> {
@@ -87,6 +111,16 @@ impl Hypervisor for Dragonball {
         inner.remove_device(device).await
     }
 
+    async fn update_device(&self, device: Device) -> Result<()> {
+        let mut inner = self.inner.write().await;
+        inner.update_device(device).await
+    }
+
+    async fn get_device_pci_path(&self, id: &str) -> Result<Option<String>> {
+        let inner = self.inner.read().await;
+        inner.get_device_pci_path(id)
+    }
+
     async fn get_agent_socket(&self) -> Result<String> {
         let inner = self.inner.read().await;
         inner.get_agent_socket().await
@@ -0,0 +1,243 @@
+// Copyright (c) 2023 Kata Contributors
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use std::{
+    collections::VecDeque,
+    fs::{create_dir_all, File},
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, Context, Result};
+use kata_types::config::hypervisor::Hypervisor as HypervisorConfig;
+use serde::Serialize;
+use tokio::sync::{mpsc, RwLock};
+
+use super::{
+    inner::DragonballInner,
+    vmm_instance::{VmmInstance, VmmRequester, DRAGONBALL_VERSION},
+};
+use crate::HYPERVISOR_DRAGONBALL;
+
+// printed by the guest kernel when it panics, see panic() in kernel/panic.c
+const KERNEL_PANIC_MESSAGE: &str = "Kernel panic - not syncing";
+// number of guest kernel log lines saved along with the dump
+const CONSOLE_HISTORY_LINES: usize = 100;
+// guest kernel log lines buffered for the watcher, more lines are dropped
+const KERNEL_LOG_BUFFER_LINES: usize = 1024;
+
+const VMCORE_FILE: &str = "vmcore.elf";
+const METADATA_FILE: &str = "metadata.json";
+
+/// Information saved next to the guest memory dump, to help analysing it.
+#[derive(Serialize)]
+struct GuestDumpMetadata<'a> {
+    sandbox_id: &'a str,
+    hypervisor: &'a str,
+    hypervisor_version: &'a str,
+    // seconds since the unix epoch
+    timestamp: u64,
+    vmcore: &'a Path,
+    // the last lines of the guest kernel log, up to the panic message
+    console: &'a VecDeque<String>,
+    hypervisor_config: &'a HypervisorConfig,
+}
+
+/// Where and what to dump when the guest kernel panics.
+pub(crate) struct GuestDump {
+    sandbox_id: String,
+    config: HypervisorConfig,
+    dump_dir: PathBuf,
+}
+
+/// The last lines printed by the guest kernel.
+#[derive(Default)]
+struct ConsoleHistory {
+    lines: VecDeque<String>,
+}
+
+impl ConsoleHistory {
+    /// Record a kernel log line, return true if it reports a guest kernel panic.
+    fn push(&mut self, raw: &[u8]) -> bool {
+        let line = String::from_utf8_lossy(raw).trim_end().to_string();
+        let panicked = line.contains(KERNEL_PANIC_MESSAGE);
+
+        if self.lines.len() == CONSOLE_HISTORY_LINES {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line);
+
+        panicked
+    }
+}
+
+/// Receives the guest kernel log lines from the vmm. It's called from the vmm event loop, so it
+/// never blocks: the lines are dropped if the watcher doesn't keep up.
+struct KernelLogWriter(mpsc::Sender<Vec<u8>>);
+
+impl io::Write for KernelLogWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // the watcher is gone once the guest memory is dumped
+        self.0.try_send(buf.to_vec()).ok();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Copy the guest kernel log to the returned receiver, must be called before the vmm server
+/// runs.
+pub(crate) fn tee_guest_kernel_log(vmm_instance: &mut VmmInstance) -> mpsc::Receiver<Vec<u8>> {
+    let (tx, rx) = mpsc::channel(KERNEL_LOG_BUFFER_LINES);
+    vmm_instance.set_guest_kernel_log_tee(Box::new(KernelLogWriter(tx)));
+    rx
+}
+
+/// Watch the guest kernel log until the VM stops, and dump the guest memory if the guest kernel
+/// panics. The guest is booted with `panic=0` so it halts instead of rebooting, the VM is stopped
+/// once the dump is done.
+pub(crate) async fn watch_guest_panic(
+    inner: Arc<RwLock<DragonballInner>>,
+    requester: VmmRequester,
+    mut kernel_log: mpsc::Receiver<Vec<u8>>,
+    dump: GuestDump,
+) -> Result<()> {
+    let mut history = ConsoleHistory::default();
+    loop {
+        match kernel_log.recv().await {
+            Some(line) if history.push(&line) => break,
+            Some(_) => {}
+            // the VM has stopped
+            None => return Ok(()),
+        }
+    }
+    drop(kernel_log);
+
+    error!(
+        sl!(),
+        "guest kernel panic, dump guest memory to {:?}", &dump.dump_dir
+    );
+    // dumping takes long, don't hold the hypervisor while the vcpus are paused
+    let result =
+        tokio::task::spawn_blocking(move || dump.dump_guest_memory(&requester, &history.lines))
+            .await
+            .map_err(|e| anyhow!("join dump task: {:?}", e))
+            .and_then(|r| r.context("dump guest memory"));
+    if result.is_ok() {
+        info!(sl!(), "guest memory dumped");
+    }
+
+    // the guest would have rebooted with panic=1, which stops the VM
+    inner.write().await.stop_vm().context("stop vm")?;
+
+    result
+}
+
+impl DragonballInner {
+    /// Where and what to dump when the guest kernel panics, None if the guest memory dump is
+    /// disabled.
+    pub(crate) fn guest_dump(&self) -> Option<GuestDump> {
+        let path = &self.config.debug_info.guest_memory_dump_path;
+        if path.is_empty() {
+            return None;
+        }
+
+        Some(GuestDump {
+            sandbox_id: self.id.clone(),
+            config: self.config.clone(),
+            dump_dir: Path::new(path).join(&self.id),
+        })
+    }
+}
+
+impl GuestDump {
+    fn dump_guest_memory(
+        &self,
+        requester: &VmmRequester,
+        console: &VecDeque<String>,
+    ) -> Result<()> {
+        if self.config.debug_info.guest_memory_dump_paging {
+            warn!(
+                sl!(),
+                "dragonball doesn't support guest_memory_dump_paging, only dump physical memory"
+            );
+        }
+
+        let dump_dir = &self.dump_dir;
+        create_dir_all(dump_dir).with_context(|| format!("create dir {:?}", dump_dir))?;
+
+        // save the metadata first, it's still useful if dumping the memory fails
+        let vmcore = dump_dir.join(VMCORE_FILE);
+        let metadata = GuestDumpMetadata {
+            sandbox_id: &self.sandbox_id,
+            hypervisor: HYPERVISOR_DRAGONBALL,
+            hypervisor_version: DRAGONBALL_VERSION,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+            vmcore: &vmcore,
+            console,
+            hypervisor_config: &self.config,
+        };
+        let metadata_path = dump_dir.join(METADATA_FILE);
+        let file = File::create(&metadata_path)
+            .with_context(|| format!("create file {:?}", &metadata_path))?;
+        serde_json::to_writer_pretty(file, &metadata).context("write metadata")?;
+
+        requester
+            .dump_guest_memory(&vmcore)
+            .context("dump guest memory")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    #[test]
+    fn test_console_history() {
+        let mut history = ConsoleHistory::default();
+
+        assert!(!history.push(b"[    0.000000] Linux version 5.19.2\n"));
+        assert!(
+            history.push(b"[    1.234567] Kernel panic - not syncing: Attempted to kill init!\n")
+        );
+        // invalid utf-8 doesn't stop the watcher
+        assert!(!history.push(b"\xff\xfe\n"));
+        assert_eq!(
+            history.lines[1],
+            "[    1.234567] Kernel panic - not syncing: Attempted to kill init!"
+        );
+
+        for i in 0..CONSOLE_HISTORY_LINES {
+            history.push(format!("line {}\n", i).as_bytes());
+        }
+        assert_eq!(history.lines.len(), CONSOLE_HISTORY_LINES);
+        assert_eq!(history.lines[0], "line 0");
+    }
+
+    #[test]
+    fn test_kernel_log_writer() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let mut writer = KernelLogWriter(tx);
+
+        writer.write_all(b"line 1\n").unwrap();
+        // the channel is full, the line is dropped instead of blocking the vmm
+        writer.write_all(b"line 2\n").unwrap();
+        assert_eq!(rx.try_recv().unwrap(), b"line 1\n");
+        assert!(rx.try_recv().is_err());
+
+        // the watcher has exited
+        drop(rx);
+        writer.write_all(b"line 3\n").unwrap();
+    }
+}
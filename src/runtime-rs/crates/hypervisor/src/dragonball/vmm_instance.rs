@@ -6,7 +6,9 @@
 
 use std::{
     fs::{File, OpenOptions},
+    io,
     os::unix::{io::IntoRawFd, prelude::AsRawFd},
+    path::Path,
     sync::{Arc, Mutex, RwLock},
     thread,
 };
@@ -15,9 +17,10 @@ use anyhow::{anyhow, Context, Result};
 use crossbeam_channel::{unbounded, Receiver, Sender};
 use dragonball::{
     api::v1::{
-        BlockDeviceConfigInfo, BootSourceConfig, FsDeviceConfigInfo, FsMountConfigInfo,
-        InstanceInfo, InstanceState, VirtioNetDeviceConfigInfo, VmmAction, VmmActionError, VmmData,
-        VmmRequest, VmmResponse, VmmService, VsockDeviceConfigInfo,
+        BlockDeviceConfigInfo, BlockDeviceConfigUpdateInfo, BootSourceConfig, FsDeviceConfigInfo,
+        FsMountConfigInfo, GuestMemoryDumpInfo, InstanceInfo, InstanceState, RngDeviceConfigInfo,
+        VirtioNetDeviceConfigInfo, VmmAction, VmmActionError, VmmData, VmmRequest, VmmResponse,
+        VmmService, VsockDeviceConfigInfo,
     },
     vm::VmConfigInfo,
     Vmm,
@@ -32,18 +35,74 @@ pub enum Request {
     Sync(VmmAction),
 }
 
-const DRAGONBALL_VERSION: &str = env!("CARGO_PKG_VERSION");
+pub(crate) const DRAGONBALL_VERSION: &str = env!("CARGO_PKG_VERSION");
 const REQUEST_RETRY: u32 = 500;
 const KVM_DEVICE: &str = "/dev/kvm";
 
+/// Sends requests to the vmm thread, it can be cloned to send requests without holding the
+/// `VmmInstance`, e.g. for long running requests.
+#[derive(Clone)]
+pub(crate) struct VmmRequester {
+    to_vmm: Sender<VmmRequest>,
+    from_vmm: Receiver<VmmResponse>,
+    to_vmm_fd: Arc<EventFd>,
+    // a request and its response must not interleave with other requests
+    request_lock: Arc<Mutex<()>>,
+}
+
+impl VmmRequester {
+    fn send_request(&self, vmm_action: VmmAction) -> Result<VmmResponse> {
+        let _guard = self
+            .request_lock
+            .lock()
+            .map_err(|_| anyhow!("vmm request lock poisoned"))?;
+
+        self.to_vmm
+            .send(Box::new(vmm_action.clone()))
+            .with_context(|| format!("Failed to send  {:?} via channel ", vmm_action))?;
+
+        //notify vmm action
+        if let Err(e) = self.to_vmm_fd.write(1) {
+            return Err(anyhow!("failed to notify vmm: {}", e));
+        }
+
+        match self.from_vmm.recv() {
+            Err(e) => Err(anyhow!("vmm recv err: {}", e)),
+            Ok(vmm_outcome) => Ok(vmm_outcome),
+        }
+    }
+
+    fn handle_request(&self, req: Request) -> Result<VmmData> {
+        let Request::Sync(vmm_action) = req;
+        match self.send_request(vmm_action) {
+            Ok(vmm_outcome) => match *vmm_outcome {
+                Ok(vmm_data) => Ok(vmm_data),
+                Err(vmm_action_error) => Err(anyhow!("vmm action error: {:?}", vmm_action_error)),
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Dump the guest memory, all vcpus are paused until the dump completes.
+    pub(crate) fn dump_guest_memory(&self, path: &Path) -> Result<()> {
+        self.handle_request(Request::Sync(VmmAction::DumpGuestMemory(
+            GuestMemoryDumpInfo {
+                path: path.to_path_buf(),
+            },
+        )))
+        .with_context(|| format!("Failed to dump guest memory to {:?}", path))?;
+        Ok(())
+    }
+}
+
 pub struct VmmInstance {
     /// VMM instance info directly accessible from runtime
     vmm_shared_info: Arc<RwLock<InstanceInfo>>,
-    to_vmm: Option<Sender<VmmRequest>>,
-    from_vmm: Option<Receiver<VmmResponse>>,
-    to_vmm_fd: EventFd,
+    requester: Option<VmmRequester>,
+    to_vmm_fd: Arc<EventFd>,
     seccomp: BpfProgram,
     vmm_thread: Option<thread::JoinHandle<Result<i32>>>,
+    guest_kernel_log_tee: Option<Box<dyn io::Write + Send + Sync>>,
 }
 
 impl VmmInstance {
@@ -58,14 +117,26 @@ impl VmmInstance {
 
         VmmInstance {
             vmm_shared_info,
-            to_vmm: None,
-            from_vmm: None,
-            to_vmm_fd,
+            requester: None,
+            to_vmm_fd: Arc::new(to_vmm_fd),
             seccomp: vec![],
             vmm_thread: None,
+            guest_kernel_log_tee: None,
         }
     }
 
+    /// Copy the guest kernel log lines to `tee`, must be called before `run_vmm_server()`.
+    pub(crate) fn set_guest_kernel_log_tee(&mut self, tee: Box<dyn io::Write + Send + Sync>) {
+        self.guest_kernel_log_tee = Some(tee);
+    }
+
+    /// Get a requester to send requests to the running vmm.
+    pub(crate) fn requester(&self) -> Result<VmmRequester> {
+        self.requester
+            .clone()
+            .ok_or_else(|| anyhow!("vmm server is not running"))
+    }
+
     pub fn get_shared_info(&self) -> Arc<RwLock<InstanceInfo>> {
         self.vmm_shared_info.clone()
     }
@@ -104,11 +175,15 @@ impl VmmInstance {
 
         let vmm_service = VmmService::new(from_runtime, to_runtime);
 
-        self.to_vmm = Some(to_vmm);
-        self.from_vmm = Some(from_vmm);
+        self.requester = Some(VmmRequester {
+            to_vmm,
+            from_vmm,
+            to_vmm_fd: self.to_vmm_fd.clone(),
+            request_lock: Arc::new(Mutex::new(())),
+        });
 
         let api_event_fd2 = self.to_vmm_fd.try_clone().expect("Failed to dup eventfd");
-        let vmm = Vmm::new(
+        let mut vmm = Vmm::new(
             self.vmm_shared_info.clone(),
             api_event_fd2,
             self.seccomp.clone(),
@@ -116,6 +191,11 @@ impl VmmInstance {
             Some(kvm.into_raw_fd()),
         )
         .expect("Failed to start vmm");
+        if let Some(tee) = self.guest_kernel_log_tee.take() {
+            if let Some(vm) = vmm.get_vm_mut() {
+                vm.set_guest_kernel_log_tee(tee);
+            }
+        }
         let vmm_shared_info = self.get_shared_info();
 
         self.vmm_thread = Some(
@@ -207,6 +287,14 @@ impl VmmInstance {
         Ok(())
     }
 
+    pub fn update_block_device(&self, blk_update: BlockDeviceConfigUpdateInfo) -> Result<()> {
+        self.handle_request(Request::Sync(VmmAction::UpdateBlockDevice(
+            blk_update.clone(),
+        )))
+        .with_context(|| format!("Failed to update block device {:?}", blk_update))?;
+        Ok(())
+    }
+
     pub fn set_vm_configuration(&self, vm_config: VmConfigInfo) -> Result<()> {
         self.handle_request(Request::Sync(VmmAction::SetVmConfiguration(
             vm_config.clone(),
@@ -231,6 +319,12 @@ impl VmmInstance {
         Ok(())
     }
 
+    pub fn insert_rng_device(&self, rng_cfg: RngDeviceConfigInfo) -> Result<()> {
+        self.handle_request(Request::Sync(VmmAction::InsertRngDevice(rng_cfg.clone())))
+            .with_context(|| format!("Failed to insert rng device {:?}", rng_cfg))?;
+        Ok(())
+    }
+
     pub fn insert_fs(&self, fs_cfg: &FsDeviceConfigInfo) -> Result<()> {
         self.handle_request(Request::Sync(VmmAction::InsertFsDevice(fs_cfg.clone())))
             .with_context(|| format!("Failed to insert {} fs device {:?}", fs_cfg.mode, fs_cfg))?;
@@ -282,37 +376,16 @@ impl VmmInstance {
     }
 
     fn send_request(&self, vmm_action: VmmAction) -> Result<VmmResponse> {
-        if let Some(ref to_vmm) = self.to_vmm {
-            to_vmm
-                .send(Box::new(vmm_action.clone()))
-                .with_context(|| format!("Failed to send  {:?} via channel ", vmm_action))?;
-        } else {
-            return Err(anyhow!("to_vmm is None"));
-        }
-
-        //notify vmm action
-        if let Err(e) = self.to_vmm_fd.write(1) {
-            return Err(anyhow!("failed to notify vmm: {}", e));
-        }
-
-        if let Some(from_vmm) = self.from_vmm.as_ref() {
-            match from_vmm.recv() {
-                Err(e) => Err(anyhow!("vmm recv err: {}", e)),
-                Ok(vmm_outcome) => Ok(vmm_outcome),
-            }
-        } else {
-            Err(anyhow!("from_vmm is None"))
+        match self.requester.as_ref() {
+            Some(requester) => requester.send_request(vmm_action),
+            None => Err(anyhow!("vmm server is not running")),
         }
     }
 
     fn handle_request(&self, req: Request) -> Result<VmmData> {
-        let Request::Sync(vmm_action) = req;
-        match self.send_request(vmm_action) {
-            Ok(vmm_outcome) => match *vmm_outcome {
-                Ok(vmm_data) => Ok(vmm_data),
-                Err(vmm_action_error) => Err(anyhow!("vmm action error: {:?}", vmm_action_error)),
-            },
-            Err(e) => Err(e),
+        match self.requester.as_ref() {
+            Some(requester) => requester.handle_request(req),
+            None => Err(anyhow!("vmm server is not running")),
         }
     }
 
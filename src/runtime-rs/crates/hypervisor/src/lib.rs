@@ -31,7 +31,7 @@ use kata_types::config::hypervisor::Hypervisor as HypervisorConfig;
 pub use kata_types::config::hypervisor::HYPERVISOR_NAME_CH;
 
 // Config which driver to use as vm root dev
-const VM_ROOTFS_DRIVER_BLK: &str = "virtio-blk";
+pub const VM_ROOTFS_DRIVER_BLK: &str = "virtio-blk";
 const VM_ROOTFS_DRIVER_PMEM: &str = "virtio-pmem";
 
 //Configure the root corresponding to the driver
@@ -80,6 +80,10 @@ pub trait Hypervisor: Send + Sync {
     // device manager
     async fn add_device(&self, device: device::Device) -> Result<()>;
     async fn remove_device(&self, device: device::Device) -> Result<()>;
+    async fn update_device(&self, device: device::Device) -> Result<()>;
+    /// Get the guest PCI path of a hotplugged device, used by the agent to find
+    /// the device in the guest. None if the device is not a PCI device.
+    async fn get_device_pci_path(&self, id: &str) -> Result<Option<String>>;
 
     // utils
     async fn get_agent_socket(&self) -> Result<String>;
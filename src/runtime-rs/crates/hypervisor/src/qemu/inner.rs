@@ -3,7 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 
 use crate::{HypervisorConfig, VcpuThreadIds};
 use kata_types::capabilities::{Capabilities, CapabilityBits};
@@ -146,4 +146,14 @@ impl QemuInner {
         info!(sl!(), "QemuInner::remove_device() {} ", device);
         todo!()
     }
+
+    pub(crate) async fn update_device(&mut self, device: Device) -> Result<()> {
+        info!(sl!(), "QemuInner::update_device() {} ", device);
+        Err(anyhow!("QemuInner::update_device() is not supported"))
+    }
+
+    pub(crate) fn get_device_pci_path(&self, id: &str) -> Result<Option<String>> {
+        info!(sl!(), "QemuInner::get_device_pci_path() {}", id);
+        Ok(None)
+    }
 }
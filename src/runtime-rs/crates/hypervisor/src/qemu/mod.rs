@@ -17,6 +17,7 @@ use async_trait::async_trait;
 
 use std::sync::Arc;
 use tokio::sync::RwLock;
+use tracing::instrument;
 
 pub struct Qemu {
     inner: Arc<RwLock<QemuInner>>,
@@ -43,11 +44,13 @@ impl Qemu {
 
 #[async_trait]
 impl Hypervisor for Qemu {
+    #[instrument(skip(self))]
     async fn prepare_vm(&self, id: &str, netns: Option<String>) -> Result<()> {
         let mut inner = self.inner.write().await;
         inner.prepare_vm(id, netns).await
     }
 
+    #[instrument(skip(self))]
     async fn start_vm(&self, timeout: i32) -> Result<()> {
         let mut inner = self.inner.write().await;
         inner.start_vm(timeout).await
@@ -83,6 +86,16 @@ impl Hypervisor for Qemu {
         inner.remove_device(device).await
     }
 
+    async fn update_device(&self, device: Device) -> Result<()> {
+        let mut inner = self.inner.write().await;
+        inner.update_device(device).await
+    }
+
+    async fn get_device_pci_path(&self, id: &str) -> Result<Option<String>> {
+        let inner = self.inner.read().await;
+        inner.get_device_pci_path(id)
+    }
+
     async fn get_agent_socket(&self) -> Result<String> {
         let inner = self.inner.read().await;
         inner.get_agent_socket().await
@@ -15,14 +15,15 @@ use kata_types::config::TomlConfig;
 use kata_types::mount::Mount;
 use oci::LinuxResources;
 use persist::sandbox_persist::Persist;
-use tokio::runtime;
+use tokio::{runtime, task::JoinHandle};
+use tracing::{info_span, instrument, Instrument};
 
 use crate::{
     cgroups::{CgroupArgs, CgroupsResource},
     manager::ManagerArgs,
-    network::{self, Network},
+    network::{self, NetnsWatcher, Network, NetworkChanges},
     rootfs::{RootFsResource, Rootfs},
-    share_fs::{self, ShareFs},
+    share_fs::{self, SandboxBindMounts, ShareFs},
     volume::{Volume, VolumeResource},
     ResourceConfig,
 };
@@ -33,7 +34,10 @@ pub(crate) struct ResourceManagerInner {
     agent: Arc<dyn Agent>,
     hypervisor: Arc<dyn Hypervisor>,
     network: Option<Arc<dyn Network>>,
+    network_config: Option<NetworkConfig>,
+    netns_watcher: Option<JoinHandle<()>>,
     share_fs: Option<Arc<dyn ShareFs>>,
+    sandbox_bind_mounts: Option<SandboxBindMounts>,
 
     pub rootfs_resource: RootFsResource,
     pub volume_resource: VolumeResource,
@@ -54,7 +58,10 @@ impl ResourceManagerInner {
             agent,
             hypervisor,
             network: None,
+            network_config: None,
+            netns_watcher: None,
             share_fs: None,
+            sandbox_bind_mounts: None,
             rootfs_resource: RootFsResource::new(),
             volume_resource: VolumeResource::new(),
             cgroups_resource,
@@ -65,6 +72,7 @@ impl ResourceManagerInner {
         self.toml_config.clone()
     }
 
+    #[instrument(skip(self, device_configs))]
     pub async fn prepare_before_start_vm(
         &mut self,
         device_configs: Vec<ResourceConfig>,
@@ -81,8 +89,11 @@ impl ResourceManagerInner {
                         let share_fs = share_fs::new(&self.sid, &c).context("new share fs")?;
                         share_fs
                             .setup_device_before_start_vm(self.hypervisor.as_ref())
+                            .instrument(info_span!("setup_share_fs"))
                             .await
                             .context("setup share fs device before start vm")?;
+                        self.setup_sandbox_bind_mounts()
+                            .context("setup sandbox bind mounts")?;
                         Some(share_fs)
                     } else {
                         None
@@ -99,7 +110,22 @@ impl ResourceManagerInner {
         Ok(())
     }
 
+    fn setup_sandbox_bind_mounts(&mut self) -> Result<()> {
+        let binds = self.toml_config.runtime.sandbox_bind_mounts.clone();
+        if binds.is_empty() {
+            return Ok(());
+        }
+
+        let bind_mounts = SandboxBindMounts::new(&self.sid, binds);
+        bind_mounts.setup()?;
+        self.sandbox_bind_mounts = Some(bind_mounts);
+        Ok(())
+    }
+
+    #[instrument(skip(self, network_config))]
     pub async fn handle_network(&mut self, network_config: NetworkConfig) -> Result<()> {
+        self.network_config = Some(network_config.clone());
+
         // 1. When using Rust asynchronous programming, we use .await to
         //    allow other task to run instead of waiting for the completion of the current task.
         // 2. Also, when handling the pod network, we need to set the shim threads
@@ -172,10 +198,12 @@ impl ResourceManagerInner {
         Ok(())
     }
 
+    #[instrument(skip(self))]
     pub async fn setup_after_start_vm(&mut self) -> Result<()> {
         if let Some(share_fs) = self.share_fs.as_ref() {
             share_fs
                 .setup_device_after_start_vm(self.hypervisor.as_ref())
+                .instrument(info_span!("setup_share_fs"))
                 .await
                 .context("setup share fs device after start vm")?;
         }
@@ -190,6 +218,35 @@ impl ResourceManagerInner {
                 .context("handle neighbors")?;
             self.handle_routes(network).await.context("handle routes")?;
         }
+
+        self.start_netns_watcher().context("start netns watcher")?;
+        Ok(())
+    }
+
+    // Watch the netns for the interfaces the network plugin adds or removes
+    // after the VM started, e.g. additional networks attached later.
+    fn start_netns_watcher(&mut self) -> Result<()> {
+        let (network, netns_path) = match (self.network.as_ref(), self.network_config.as_ref()) {
+            (Some(network), Some(NetworkConfig::NetworkResourceWithNetNs(c)))
+                if !c.netns_path.is_empty() =>
+            {
+                (network.clone(), &c.netns_path)
+            }
+            _ => return Ok(()),
+        };
+
+        let mut watcher = NetnsWatcher::new(netns_path).context("new netns watcher")?;
+        let hypervisor = self.hypervisor.clone();
+        let agent = self.agent.clone();
+        self.netns_watcher = Some(tokio::spawn(async move {
+            while watcher.changed().await.is_some() {
+                if let Err(e) =
+                    sync_network(network.clone(), hypervisor.clone(), agent.as_ref()).await
+                {
+                    warn!(sl!(), "failed to sync network with netns: {:?}", e);
+                }
+            }
+        }));
         Ok(())
     }
 
@@ -202,6 +259,7 @@ impl ResourceManagerInner {
         Ok(storages)
     }
 
+    #[instrument(skip(self, root, rootfs_mounts))]
     pub async fn handler_rootfs(
         &self,
         cid: &str,
@@ -222,13 +280,14 @@ impl ResourceManagerInner {
             .await
     }
 
+    #[instrument(skip(self, spec))]
     pub async fn handler_volumes(
         &self,
         cid: &str,
         spec: &oci::Spec,
     ) -> Result<Vec<Arc<dyn Volume>>> {
         self.volume_resource
-            .handler_volumes(&self.share_fs, cid, spec)
+            .handler_volumes(&self.share_fs, &self.hypervisor, cid, spec)
             .await
     }
 
@@ -243,11 +302,21 @@ impl ResourceManagerInner {
     }
 
     pub async fn cleanup(&self) -> Result<()> {
+        // stop watching the netns, it's about to go away
+        if let Some(watcher) = &self.netns_watcher {
+            watcher.abort();
+        }
         // clean up cgroup
         self.cgroups_resource
             .delete()
             .await
             .context("delete cgroup")?;
+        // clean up sandbox bind mounts, before the share fs directories are removed
+        if let Some(bind_mounts) = &self.sandbox_bind_mounts {
+            bind_mounts
+                .cleanup()
+                .context("failed to cleanup sandbox bind mounts")?;
+        }
         // clean up share fs mount
         if let Some(share_fs) = &self.share_fs {
             share_fs
@@ -266,6 +335,85 @@ impl ResourceManagerInner {
     }
 }
 
+async fn sync_network(
+    network: Arc<dyn Network>,
+    hypervisor: Arc<dyn Hypervisor>,
+    agent: &dyn Agent,
+) -> Result<()> {
+    // the endpoints are created in the pod netns, see handle_network
+    let (net, h) = (network.clone(), hypervisor.clone());
+    let changes = tokio::task::spawn_blocking(move || -> Result<NetworkChanges> {
+        let rt = runtime::Builder::new_current_thread().enable_io().build()?;
+        rt.block_on(net.sync(h.as_ref()))
+    })
+    .await
+    .context("join sync network")?
+    .context("failed to sync network")?;
+
+    if changes.is_empty() {
+        return Ok(());
+    }
+
+    if !changes.removed.is_empty() {
+        // release the addresses of the interfaces before their device is unplugged
+        for i in changes.removed {
+            info!(sl!(), "remove interface {:?}", i);
+            let interface = agent::Interface {
+                ip_addresses: vec![],
+                ..i
+            };
+            if let Err(e) = agent
+                .update_interface(agent::UpdateInterfaceRequest {
+                    interface: Some(interface),
+                })
+                .await
+            {
+                warn!(sl!(), "failed to remove interface: {:?}", e);
+            }
+        }
+
+        let net = network.clone();
+        tokio::task::spawn_blocking(move || -> Result<()> {
+            let rt = runtime::Builder::new_current_thread().enable_io().build()?;
+            rt.block_on(net.detach_removed(hypervisor.as_ref()))
+        })
+        .await
+        .context("join detach endpoints")?
+        .context("failed to detach endpoints")?;
+    }
+
+    for i in changes.added {
+        info!(sl!(), "update interface {:?}", i);
+        agent
+            .update_interface(agent::UpdateInterfaceRequest { interface: Some(i) })
+            .await
+            .context("update interface")?;
+    }
+
+    if !changes.neighs.is_empty() {
+        info!(sl!(), "update neighbors {:?}", changes.neighs);
+        agent
+            .add_arp_neighbors(agent::AddArpNeighborRequest {
+                neighbors: Some(agent::ARPNeighbors {
+                    neighbors: changes.neighs,
+                }),
+            })
+            .await
+            .context("update neighbors")?;
+    }
+
+    let routes = network.routes().await.context("routes")?;
+    info!(sl!(), "update routes {:?}", routes);
+    agent
+        .update_routes(agent::UpdateRoutesRequest {
+            route: Some(agent::Routes { routes }),
+        })
+        .await
+        .context("update routes")?;
+
+    Ok(())
+}
+
 #[async_trait]
 impl Persist for ResourceManagerInner {
     type State = ResourceState;
@@ -282,7 +430,12 @@ impl Persist for ResourceManagerInner {
         let cgroup_state = self.cgroups_resource.save().await?;
         Ok(ResourceState {
             endpoint: endpoint_state,
+            network_config: self.network_config.clone(),
             cgroup_state: Some(cgroup_state),
+            sandbox_bind_mounts: self
+                .sandbox_bind_mounts
+                .as_ref()
+                .map(|m| m.bind_mounts().to_vec()),
         })
     }
 
@@ -295,12 +448,28 @@ impl Persist for ResourceManagerInner {
             sid: resource_args.sid.clone(),
             config: resource_args.config,
         };
-        Ok(Self {
+        let sandbox_bind_mounts = resource_state
+            .sandbox_bind_mounts
+            .map(|m| SandboxBindMounts::new(&args.sid, m));
+        // the endpoints attached before can't be rebuilt, the network only keeps track of the
+        // links added or removed from now on
+        let endpoint = &resource_state.endpoint;
+        let network = resource_state.network_config.as_ref().map(|c| {
+            let links = endpoint
+                .iter()
+                .filter_map(|e| e.if_name().map(String::from))
+                .collect();
+            network::restore(c, links)
+        });
+        let mut inner = Self {
             sid: resource_args.sid,
             agent: resource_args.agent,
             hypervisor: resource_args.hypervisor,
-            network: None,
+            network,
+            network_config: resource_state.network_config,
+            netns_watcher: None,
             share_fs: None,
+            sandbox_bind_mounts,
             rootfs_resource: RootFsResource::new(),
             volume_resource: VolumeResource::new(),
             cgroups_resource: CgroupsResource::restore(
@@ -309,6 +478,11 @@ impl Persist for ResourceManagerInner {
             )
             .await?,
             toml_config: Arc::new(TomlConfig::default()),
-        })
+        };
+        // the netns may be gone already if the sandbox is only restored to be cleaned up
+        if let Err(e) = inner.start_netns_watcher() {
+            warn!(sl!(), "failed to restart netns watcher: {:?}", e);
+        }
+        Ok(inner)
     }
 }
@@ -39,6 +39,18 @@ pub struct IpVlanEndpointState {
     pub network_qos: bool,
 }
 
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct TapEndpointState {
+    pub if_name: String,
+    pub hard_addr: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct VhostUserEndpointState {
+    pub if_name: String,
+    pub socket_path: String,
+}
+
 #[derive(Serialize, Deserialize, Clone, Default)]
 pub struct EndpointState {
     pub physical_endpoint: Option<PhysicalEndpointState>,
@@ -46,5 +58,32 @@ pub struct EndpointState {
     pub ipvlan_endpoint: Option<IpVlanEndpointState>,
     pub macvlan_endpoint: Option<MacvlanEndpointState>,
     pub vlan_endpoint: Option<VlanEndpointState>,
+    pub tap_endpoint: Option<TapEndpointState>,
+    pub vhost_user_endpoint: Option<VhostUserEndpointState>,
     // TODO : other endpoint
 }
+
+impl EndpointState {
+    // The name of the link in the netns, physical endpoints don't keep theirs as the link is
+    // gone once the device is bound to vfio.
+    pub fn if_name(&self) -> Option<&str> {
+        if let Some(s) = &self.veth_endpoint {
+            return Some(&s.if_name);
+        }
+        if let Some(s) = &self.ipvlan_endpoint {
+            return Some(&s.if_name);
+        }
+        if let Some(s) = &self.macvlan_endpoint {
+            return Some(&s.if_name);
+        }
+        if let Some(s) = &self.vlan_endpoint {
+            return Some(&s.if_name);
+        }
+        if let Some(s) = &self.tap_endpoint {
+            return Some(&s.if_name);
+        }
+        self.vhost_user_endpoint
+            .as_ref()
+            .map(|s| s.if_name.as_str())
+    }
+}
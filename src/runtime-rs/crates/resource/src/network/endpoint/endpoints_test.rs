@@ -10,17 +10,26 @@ mod tests {
     use netlink_packet_route::MACVLAN_MODE_PRIVATE;
     use scopeguard::defer;
 
-    use std::sync::Arc;
+    use std::{collections::HashMap, fs, net::IpAddr, os::unix::net::UnixListener, sync::Arc};
 
     use crate::network::{
-        endpoint::{IPVlanEndpoint, MacVlanEndpoint, VlanEndpoint},
+        endpoint::{
+            vhost_user_endpoint::{
+                parse_vhost_user_sockets, vhost_user_socket_path, vhost_user_socket_path_in,
+            },
+            IPVlanEndpoint, MacVlanEndpoint, TapEndpoint, VethEndpoint, VhostUserEndpoint,
+            VlanEndpoint,
+        },
         network_model::{
             self,
             tc_filter_model::{fetch_index, TcFilterModel},
-            NetworkModelType, TC_FILTER_NET_MODEL_STR,
+            NetworkModelType, MACVTAP_NET_MODEL_STR, TC_FILTER_NET_MODEL_STR,
+        },
+        network_pair::{get_link_by_name, NetworkInterface, NetworkPair, TapInterface},
+        utils::{
+            get_mac_addr,
+            link::{self, net_test_utils::delete_link},
         },
-        network_pair::{NetworkInterface, NetworkPair, TapInterface},
-        utils::link::net_test_utils::delete_link,
     };
 
     // this unit test tests the integrity of MacVlanEndpoint::new()
@@ -84,6 +93,7 @@ mod tests {
                                 },
                                 model: Arc::new(TcFilterModel::new().unwrap()), // impossible to panic
                                 network_qos: false,
+                                queues: 5,
                             },
                         };
 
@@ -207,6 +217,7 @@ mod tests {
                                 model: network_model::new(model_str)
                                     .expect("failed to create new network model"),
                                 network_qos: false,
+                                queues: 5,
                             },
                         };
 
@@ -307,6 +318,7 @@ mod tests {
                             },
                             model: Arc::new(TcFilterModel::new().unwrap()), // impossible to panic
                             network_qos: false,
+                            queues: 5,
                         },
                     };
 
@@ -355,4 +367,176 @@ mod tests {
             }
         }
     }
+
+    // this unit test tests the integrity of TapEndpoint::new()
+    #[actix_rt::test]
+    async fn test_tap_construction() {
+        let idx = 8195;
+        let manual_tap_iface_name = format!("tap{}", idx);
+
+        if let Ok((conn, handle, _)) =
+            rtnetlink::new_connection().context("failed to create netlink connection")
+        {
+            let thread_handler = tokio::spawn(conn);
+            defer!({
+                thread_handler.abort();
+            });
+
+            // the tap device is handed to us by the network plugin normally, so
+            // we have to manually create one.
+            if let Ok(()) = link::create_link(&manual_tap_iface_name, link::LinkType::Tap, 1)
+                .context("failed to create manual tap")
+            {
+                let mac_addr = get_link_by_name(&handle, &manual_tap_iface_name)
+                    .await
+                    .map(|l| get_mac_addr(&l.attrs().hardware_addr).unwrap())
+                    .expect("failed to get the manual tap");
+
+                if let Ok(result) = TapEndpoint::new(&handle, &manual_tap_iface_name)
+                    .await
+                    .context("failed to create new tap endpoint")
+                {
+                    assert_eq!(result.if_name, manual_tap_iface_name);
+                    assert_eq!(result.hard_addr, mac_addr);
+                }
+                // the tap device must not be bridged to anything
+                assert!(
+                    get_link_by_name(&handle, format!("tap{}_kata", idx).as_str())
+                        .await
+                        .is_err()
+                );
+                assert!(delete_link(&handle, manual_tap_iface_name.as_str())
+                    .await
+                    .is_ok());
+            }
+        }
+    }
+
+    // this unit test tests adding and removing the macvtap network model
+    #[actix_rt::test]
+    async fn test_macvtap_model() {
+        let idx = 8196;
+        let manual_virt_iface_name = format!("eth{}", idx);
+        let macvtap_iface_name = format!("tap{}_kata", idx); // create by macvtap model
+
+        if let Ok((conn, handle, _)) =
+            rtnetlink::new_connection().context("failed to create netlink connection")
+        {
+            let thread_handler = tokio::spawn(conn);
+            defer!({
+                thread_handler.abort();
+            });
+
+            if let Ok(()) = handle
+                .link()
+                .add()
+                .veth("bar".to_string(), manual_virt_iface_name.clone())
+                .execute()
+                .await
+                .context("failed to create manual veth pair")
+            {
+                if let Ok(result) = VethEndpoint::new(&handle, "", idx, MACVTAP_NET_MODEL_STR, 1)
+                    .await
+                    .context("failed to create new veth endpoint")
+                {
+                    match result.net_pair.model.model_type() {
+                        NetworkModelType::MacVtap => {}
+                        _ => unreachable!(),
+                    }
+                    // the macvtap is not created until the model is added
+                    assert!(get_link_by_name(&handle, macvtap_iface_name.as_str())
+                        .await
+                        .is_err());
+
+                    let virt_mac = result.net_pair.tap.tap_iface.hard_addr.clone();
+                    assert_eq!(virt_mac, result.net_pair.virt_iface.hard_addr);
+
+                    if result.net_pair.add_network_model().await.is_ok() {
+                        let macvtap = get_link_by_name(&handle, macvtap_iface_name.as_str())
+                            .await
+                            .expect("failed to get macvtap");
+                        assert_eq!(macvtap.r#type(), "macvtap");
+                        assert_eq!(
+                            get_mac_addr(&macvtap.attrs().hardware_addr).unwrap(),
+                            virt_mac
+                        );
+
+                        // the hypervisor gets the opened queues of the macvtap
+                        let config = result
+                            .attach_network_config()
+                            .expect("failed to get attach network config");
+                        assert_eq!(config.host_dev_name, macvtap_iface_name);
+                        assert_eq!(config.queue_files.len(), 1);
+
+                        assert!(result.net_pair.del_network_model().await.is_ok());
+                        assert!(get_link_by_name(&handle, macvtap_iface_name.as_str())
+                            .await
+                            .is_err());
+                        let virt = get_link_by_name(&handle, manual_virt_iface_name.as_str())
+                            .await
+                            .expect("failed to get virt iface");
+                        assert_eq!(get_mac_addr(&virt.attrs().hardware_addr).unwrap(), virt_mac);
+                    }
+                }
+                assert!(delete_link(&handle, manual_virt_iface_name.as_str())
+                    .await
+                    .is_ok());
+            }
+        }
+    }
+
+    // this unit test tests the integrity of VhostUserEndpoint::new()
+    #[test]
+    fn test_vhost_user_construction() {
+        let result = VhostUserEndpoint::new(
+            "eth0",
+            &[0x02, 0x42, 0xac, 0x11, 0x00, 0x02],
+            "/run/vhost-user/eth0.sock",
+        )
+        .expect("failed to create new vhost-user endpoint");
+        assert_eq!(result.if_name, "eth0");
+        assert_eq!(result.hard_addr, "02:42:ac:11:00:02");
+        assert_eq!(result.socket_path, "/run/vhost-user/eth0.sock");
+
+        assert!(VhostUserEndpoint::new("eth0", &[0x02], "/run/vhost-user/eth0.sock").is_err());
+    }
+
+    // this unit test tests how the vhost-user socket of an interface is found
+    #[test]
+    fn test_vhost_user_socket_path() {
+        let sockets = parse_vhost_user_sockets(&[
+            "eth0=/run/vhost-user/eth0.sock".to_string(),
+            "invalid".to_string(),
+        ]);
+        assert_eq!(sockets.len(), 1);
+
+        // the configured socket wins
+        assert_eq!(
+            vhost_user_socket_path(&sockets, "eth0", &[]),
+            Some("/run/vhost-user/eth0.sock".to_string())
+        );
+        assert_eq!(vhost_user_socket_path(&HashMap::new(), "eth1", &[]), None);
+
+        // the socket created by the datapath for the interface address
+        let root = tempfile::tempdir().unwrap();
+        let addr: IpAddr = "198.51.100.23".parse().unwrap();
+        let dir = root.path().join(format!("tmp/vhostuser_{}", addr));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("vhu.sock");
+
+        // not a socket
+        fs::write(&path, "").unwrap();
+        assert_eq!(
+            vhost_user_socket_path_in(root.path(), &sockets, "eth1", &[addr]),
+            None
+        );
+        fs::remove_file(&path).unwrap();
+
+        let _listener = UnixListener::bind(&path).unwrap();
+        let v6: IpAddr = "2001:db8::1".parse().unwrap();
+        assert_eq!(
+            vhost_user_socket_path_in(root.path(), &sockets, "eth1", &[v6, addr]),
+            Some(path.display().to_string())
+        );
+    }
 }
@@ -47,6 +47,7 @@ impl IPVlanEndpoint {
             id: self.net_pair.virt_iface.name.clone(),
             host_dev_name: iface.name.clone(),
             guest_mac: Some(guest_mac),
+            queue_files: vec![],
         })
     }
 }
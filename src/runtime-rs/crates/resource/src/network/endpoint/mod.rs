@@ -14,6 +14,10 @@ mod vlan_endpoint;
 pub use vlan_endpoint::VlanEndpoint;
 mod macvlan_endpoint;
 pub use macvlan_endpoint::MacVlanEndpoint;
+mod tap_endpoint;
+pub use tap_endpoint::TapEndpoint;
+pub(crate) mod vhost_user_endpoint;
+pub use vhost_user_endpoint::VhostUserEndpoint;
 pub mod endpoint_persist;
 mod endpoints_test;
 
@@ -0,0 +1,99 @@
+// Copyright (c) 2019-2022 Alibaba Cloud
+// Copyright (c) 2019-2022 Ant Group
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use std::io::{self, Error};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use hypervisor::{device::NetworkConfig, Device, Hypervisor};
+
+use super::endpoint_persist::{EndpointState, TapEndpointState};
+use super::Endpoint;
+use crate::network::{network_pair::get_link_by_name, utils};
+
+// TapEndpoint hands a tap (or tuntap) device which has been created in the
+// netns by the network plugin straight to the hypervisor, without bridging it
+// to another interface.
+#[derive(Debug)]
+pub struct TapEndpoint {
+    pub(crate) if_name: String,
+    pub(crate) hard_addr: String,
+}
+
+impl TapEndpoint {
+    pub async fn new(handle: &rtnetlink::Handle, name: &str) -> Result<Self> {
+        let link = get_link_by_name(handle, name)
+            .await
+            .context("get link by name")?;
+        let hard_addr = utils::get_mac_addr(&link.attrs().hardware_addr).context("get mac addr")?;
+
+        handle
+            .link()
+            .set(link.attrs().index)
+            .up()
+            .execute()
+            .await
+            .context("set link up")?;
+
+        Ok(TapEndpoint {
+            if_name: name.to_string(),
+            hard_addr,
+        })
+    }
+
+    fn get_network_config(&self) -> Result<NetworkConfig> {
+        let guest_mac = utils::parse_mac(&self.hard_addr).ok_or_else(|| {
+            Error::new(
+                io::ErrorKind::InvalidData,
+                format!("hard_addr {}", &self.hard_addr),
+            )
+        })?;
+        Ok(NetworkConfig {
+            id: self.if_name.clone(),
+            host_dev_name: self.if_name.clone(),
+            guest_mac: Some(guest_mac),
+            queue_files: vec![],
+        })
+    }
+}
+
+#[async_trait]
+impl Endpoint for TapEndpoint {
+    async fn name(&self) -> String {
+        self.if_name.clone()
+    }
+
+    async fn hardware_addr(&self) -> String {
+        self.hard_addr.clone()
+    }
+
+    async fn attach(&self, h: &dyn Hypervisor) -> Result<()> {
+        let config = self.get_network_config().context("get network config")?;
+        h.add_device(Device::Network(config))
+            .await
+            .context("Error add device")?;
+        Ok(())
+    }
+
+    async fn detach(&self, h: &dyn Hypervisor) -> Result<()> {
+        // the tap device belongs to the network plugin, leave it in place
+        let config = self.get_network_config().context("get network config")?;
+        h.remove_device(Device::Network(config))
+            .await
+            .context("remove device")?;
+        Ok(())
+    }
+
+    async fn save(&self) -> Option<EndpointState> {
+        Some(EndpointState {
+            tap_endpoint: Some(TapEndpointState {
+                if_name: self.if_name.clone(),
+                hard_addr: self.hard_addr.clone(),
+            }),
+            ..Default::default()
+        })
+    }
+}
@@ -15,7 +15,7 @@ use hypervisor::{device::NetworkConfig, Device, Hypervisor};
 
 #[derive(Debug)]
 pub struct VethEndpoint {
-    net_pair: NetworkPair,
+    pub(crate) net_pair: NetworkPair,
 }
 
 impl VethEndpoint {
@@ -44,8 +44,17 @@ impl VethEndpoint {
             id: self.net_pair.virt_iface.name.clone(),
             host_dev_name: iface.name.clone(),
             guest_mac: Some(guest_mac),
+            queue_files: vec![],
         })
     }
+
+    // The config to attach the endpoint with, along with the queues of the
+    // device which the hypervisor can't open by name.
+    pub(crate) fn attach_network_config(&self) -> Result<NetworkConfig> {
+        let mut config = self.get_network_config().context("get network config")?;
+        config.queue_files = self.net_pair.open_tap_queues().context("open tap queues")?;
+        Ok(config)
+    }
 }
 
 #[async_trait]
@@ -63,7 +72,9 @@ impl Endpoint for VethEndpoint {
             .add_network_model()
             .await
             .context("add network model")?;
-        let config = self.get_network_config().context("get network config")?;
+        let config = self
+            .attach_network_config()
+            .context("attach network config")?;
         h.add_device(Device::Network(config))
             .await
             .context("Error add device")?;
@@ -0,0 +1,145 @@
+// Copyright (c) 2019-2022 Alibaba Cloud
+// Copyright (c) 2019-2022 Ant Group
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use std::{
+    collections::HashMap,
+    io::{self, Error},
+    net::IpAddr,
+    os::unix::fs::FileTypeExt,
+    path::Path,
+};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use hypervisor::{device::VhostUserNetConfig, Device, Hypervisor};
+
+use super::endpoint_persist::{EndpointState, VhostUserEndpointState};
+use super::Endpoint;
+use crate::network::utils;
+
+// The userspace datapath creates the socket of an interface in a directory
+// named after one of the IPv4 addresses the network plugin gave it.
+const HOST_SOCKET_DIR_PREFIX: &str = "tmp/vhostuser_";
+const HOST_SOCKET_NAME: &str = "vhu.sock";
+
+// VhostUserEndpoint attaches an interface of the netns to the VM through a
+// vhost-user-net socket served by a userspace datapath (e.g. OVS-DPDK), the
+// interface itself only carries the addresses and routes for the guest.
+#[derive(Debug)]
+pub struct VhostUserEndpoint {
+    pub(crate) if_name: String,
+    pub(crate) hard_addr: String,
+    pub(crate) socket_path: String,
+}
+
+impl VhostUserEndpoint {
+    pub fn new(name: &str, hard_addr: &[u8], socket_path: &str) -> Result<Self> {
+        let hard_addr = utils::get_mac_addr(hard_addr).context("get mac addr")?;
+        Ok(VhostUserEndpoint {
+            if_name: name.to_string(),
+            hard_addr,
+            socket_path: socket_path.to_string(),
+        })
+    }
+
+    fn get_network_config(&self) -> Result<VhostUserNetConfig> {
+        let guest_mac = utils::parse_mac(&self.hard_addr).ok_or_else(|| {
+            Error::new(
+                io::ErrorKind::InvalidData,
+                format!("hard_addr {}", &self.hard_addr),
+            )
+        })?;
+        Ok(VhostUserNetConfig {
+            id: self.if_name.clone(),
+            socket_path: self.socket_path.clone(),
+            guest_mac: Some(guest_mac),
+        })
+    }
+}
+
+#[async_trait]
+impl Endpoint for VhostUserEndpoint {
+    async fn name(&self) -> String {
+        self.if_name.clone()
+    }
+
+    async fn hardware_addr(&self) -> String {
+        self.hard_addr.clone()
+    }
+
+    async fn attach(&self, h: &dyn Hypervisor) -> Result<()> {
+        let config = self.get_network_config().context("get network config")?;
+        h.add_device(Device::VhostUserNetwork(config))
+            .await
+            .context("Error add device")?;
+        Ok(())
+    }
+
+    async fn detach(&self, h: &dyn Hypervisor) -> Result<()> {
+        let config = self.get_network_config().context("get network config")?;
+        h.remove_device(Device::VhostUserNetwork(config))
+            .await
+            .context("remove device")?;
+        Ok(())
+    }
+
+    async fn save(&self) -> Option<EndpointState> {
+        Some(EndpointState {
+            vhost_user_endpoint: Some(VhostUserEndpointState {
+                if_name: self.if_name.clone(),
+                socket_path: self.socket_path.clone(),
+            }),
+            ..Default::default()
+        })
+    }
+}
+
+/// Parse the `<interface>=<socket path>` entries of the runtime configuration.
+pub(crate) fn parse_vhost_user_sockets(entries: &[String]) -> HashMap<String, String> {
+    entries
+        .iter()
+        .filter_map(|e| e.split_once('='))
+        .map(|(iface, path)| (iface.to_string(), path.to_string()))
+        .collect()
+}
+
+/// Look for the vhost-user socket of an interface, a configured socket takes
+/// precedence over the one the datapath created for the interface addresses.
+pub(crate) fn vhost_user_socket_path(
+    sockets: &HashMap<String, String>,
+    name: &str,
+    addrs: &[IpAddr],
+) -> Option<String> {
+    vhost_user_socket_path_in(Path::new("/"), sockets, name, addrs)
+}
+
+// Same as vhost_user_socket_path(), with the datapath sockets under `root`.
+pub(crate) fn vhost_user_socket_path_in(
+    root: &Path,
+    sockets: &HashMap<String, String>,
+    name: &str,
+    addrs: &[IpAddr],
+) -> Option<String> {
+    if let Some(path) = sockets.get(name) {
+        return Some(path.clone());
+    }
+
+    addrs
+        .iter()
+        .filter(|addr| addr.is_ipv4())
+        .map(|addr| {
+            root.join(format!("{}{}", HOST_SOCKET_DIR_PREFIX, addr))
+                .join(HOST_SOCKET_NAME)
+        })
+        .find(|path| is_socket(path))
+        .map(|path| path.display().to_string())
+}
+
+fn is_socket(path: &Path) -> bool {
+    path.metadata()
+        .map(|m| m.file_type().is_socket())
+        .unwrap_or(false)
+}
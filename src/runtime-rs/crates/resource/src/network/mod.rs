@@ -19,18 +19,35 @@ mod network_pair;
 use network_pair::NetworkPair;
 mod utils;
 pub use utils::netns::{generate_netns_name, NetnsGuard};
+pub(crate) use utils::netns_watcher::NetnsWatcher;
 
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use hypervisor::Hypervisor;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum NetworkConfig {
     NetworkResourceWithNetNs(NetworkWithNetNsConfig),
 }
 
+/// Endpoints hotplugged while syncing a network with its netns, and the ones whose link is
+/// gone, which are only unplugged by `detach_removed`.
+#[derive(Debug, Default)]
+pub struct NetworkChanges {
+    pub added: Vec<agent::Interface>,
+    pub removed: Vec<agent::Interface>,
+    pub neighs: Vec<agent::ARPNeighbor>,
+}
+
+impl NetworkChanges {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.neighs.is_empty()
+    }
+}
+
 #[async_trait]
 pub trait Network: Send + Sync {
     async fn setup(&self, h: &dyn Hypervisor) -> Result<()>;
@@ -38,6 +55,8 @@ pub trait Network: Send + Sync {
     async fn routes(&self) -> Result<Vec<agent::Route>>;
     async fn neighs(&self) -> Result<Vec<agent::ARPNeighbor>>;
     async fn save(&self) -> Option<Vec<EndpointState>>;
+    async fn sync(&self, h: &dyn Hypervisor) -> Result<NetworkChanges>;
+    async fn detach_removed(&self, h: &dyn Hypervisor) -> Result<()>;
     async fn remove(&self, h: &dyn Hypervisor) -> Result<()>;
 }
 
@@ -50,3 +69,11 @@ pub async fn new(config: &NetworkConfig) -> Result<Arc<dyn Network>> {
         )),
     }
 }
+
+// Rebuild a network saved before the shim restarted, the endpoints of the links already
+// attached are not known anymore, only their names.
+pub fn restore(config: &NetworkConfig, links: Vec<String>) -> Arc<dyn Network> {
+    match config {
+        NetworkConfig::NetworkResourceWithNetNs(c) => Arc::new(NetworkWithNetns::restore(c, links)),
+    }
+}
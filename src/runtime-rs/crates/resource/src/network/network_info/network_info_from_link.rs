@@ -4,14 +4,31 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-use std::convert::TryFrom;
+use std::{
+    convert::TryFrom,
+    fs,
+    net::{IpAddr, Ipv6Addr},
+    path::Path,
+};
 
-use agent::{ARPNeighbor, IPAddress, IPFamily, Interface, Route};
-use anyhow::{Context, Result};
+use agent::{ARPNeighbor, IPAddress, IPFamily, IPv6Sysctls, Interface, Route};
+use anyhow::{anyhow, Context, Result};
 use async_trait::async_trait;
 use futures::stream::TryStreamExt;
 use netlink_packet_route::{
-    self, neighbour::NeighbourMessage, nlas::neighbour::Nla, route::RouteMessage,
+    self,
+    constants::{
+        IFA_F_DADFAILED, NTF_PROXY, NTF_ROUTER, NUD_FAILED, NUD_INCOMPLETE, NUD_NOARP, RTN_UNICAST,
+        RTPROT_KERNEL, RT_SCOPE_HOST, RT_SCOPE_LINK, RT_TABLE_MAIN,
+    },
+    neighbour::NeighbourMessage,
+    nlas::{
+        neighbour::Nla,
+        route::{NextHop, NextHopBuffer, Nla as RouteNla},
+    },
+    route::RouteMessage,
+    traits::Parseable,
+    AddressMessage, RouteFlags,
 };
 
 use super::NetworkInfo;
@@ -20,6 +37,8 @@ use crate::network::utils::{
     link::{self, LinkAttrs},
 };
 
+const IPV6_CONF_PATH: &str = "/proc/sys/net/ipv6/conf";
+
 #[derive(Debug)]
 pub(crate) struct NetworkInfoFromLink {
     interface: Interface,
@@ -35,30 +54,63 @@ impl NetworkInfoFromLink {
     ) -> Result<Self> {
         let attrs = link.attrs();
         let name = &attrs.name;
+        let addresses = handle_addresses(handle, attrs)
+            .await
+            .context("handle addresses")?;
+        let routes = handle_routes(handle, attrs, &addresses)
+            .await
+            .context("handle routes")?;
 
         Ok(Self {
             interface: Interface {
                 device: name.clone(),
                 name: name.clone(),
-                ip_addresses: handle_addresses(handle, attrs)
-                    .await
-                    .context("handle addresses")?,
+                ip_addresses: addresses,
                 mtu: attrs.mtu as u64,
                 hw_addr: hw_addr.to_string(),
                 pci_addr: Default::default(),
                 field_type: link.r#type().to_string(),
                 raw_flags: attrs.flags & libc::IFF_NOARP as u32,
+                ipv6_sysctls: handle_ipv6_sysctls(Path::new(IPV6_CONF_PATH), name)
+                    .context("handle ipv6 sysctls")?,
             },
             neighs: handle_neighbors(handle, attrs)
                 .await
                 .context("handle neighbours")?,
-            routes: handle_routes(handle, attrs)
-                .await
-                .context("handle routes")?,
+            routes,
         })
     }
 }
 
+fn generate_address(addr_msg: AddressMessage) -> Result<Option<IPAddress>> {
+    let family = addr_msg.header.family as i32;
+    if family != libc::AF_INET && family != libc::AF_INET6 {
+        warn!(sl!(), "unsupported ip family {}", family);
+        return Ok(None);
+    }
+    let a = Address::try_from(addr_msg).context("get addr from msg")?;
+    if a.addr.is_loopback() || a.scope == RT_SCOPE_HOST || a.flags & IFA_F_DADFAILED != 0 {
+        return Ok(None);
+    }
+    // the guest kernel configures the IPv6 link-local address itself when
+    // the interface is brought up
+    if let IpAddr::V6(addr) = a.addr {
+        if is_ipv6_link_local(&addr) {
+            return Ok(None);
+        }
+    }
+
+    Ok(Some(IPAddress {
+        family: if a.addr.is_ipv4() {
+            IPFamily::V4
+        } else {
+            IPFamily::V6
+        },
+        address: a.addr.to_string(),
+        mask: a.perfix_len.to_string(),
+    }))
+}
+
 async fn handle_addresses(handle: &rtnetlink::Handle, attrs: &LinkAttrs) -> Result<Vec<IPAddress>> {
     let mut addr_msg_list = handle
         .address()
@@ -72,33 +124,25 @@ async fn handle_addresses(handle: &rtnetlink::Handle, attrs: &LinkAttrs) -> Resu
         .await
         .context("try next address msg")?
     {
-        let family = addr_msg.header.family as i32;
-        if family != libc::AF_INET && family != libc::AF_INET6 {
-            warn!(sl!(), "unsupported ip family {}", family);
-            continue;
-        }
-        let a = Address::try_from(addr_msg).context("get addr from msg")?;
-        if a.addr.is_loopback() {
-            continue;
+        if let Some(addr) = generate_address(addr_msg).context("generate address")? {
+            addresses.push(addr);
         }
-
-        addresses.push(IPAddress {
-            family: if a.addr.is_ipv4() {
-                IPFamily::V4
-            } else {
-                IPFamily::V6
-            },
-            address: a.addr.to_string(),
-            mask: a.perfix_len.to_string(),
-        });
     }
     Ok(addresses)
 }
 
-fn generate_neigh(name: &str, n: &NeighbourMessage) -> Result<ARPNeighbor> {
+fn generate_neigh(name: &str, n: &NeighbourMessage) -> Result<Option<ARPNeighbor>> {
+    // proxy entries (proxy ARP / proxy NDP) carry neither a state nor a
+    // link layer address
+    let proxy = n.header.flags & NTF_PROXY != 0;
+    if !proxy && n.header.state & (NUD_INCOMPLETE | NUD_FAILED | NUD_NOARP) != 0 {
+        return Ok(None);
+    }
+
     let mut neigh = ARPNeighbor {
         device: name.to_string(),
         state: n.header.state as i32,
+        flags: (n.header.flags & (NTF_PROXY | NTF_ROUTER)) as i32,
         ..Default::default()
     };
     for nla in &n.nlas {
@@ -132,16 +176,19 @@ fn generate_neigh(name: &str, n: &NeighbourMessage) -> Result<ARPNeighbor> {
         }
     }
 
-    Ok(neigh)
+    if neigh.to_ip_address.is_none() || (!proxy && neigh.ll_addr.is_empty()) {
+        return Ok(None);
+    }
+    Ok(Some(neigh))
 }
 
-async fn handle_neighbors(
-    handle: &rtnetlink::Handle,
+async fn get_neigh_from_msg(
+    neighs: &mut Vec<ARPNeighbor>,
     attrs: &LinkAttrs,
-) -> Result<Vec<ARPNeighbor>> {
+    request: rtnetlink::NeighbourGetRequest,
+) -> Result<()> {
     let name = &attrs.name;
-    let mut neighs = vec![];
-    let mut neigh_msg_list = handle.neighbours().get().execute();
+    let mut neigh_msg_list = request.execute();
     while let Some(neigh) = neigh_msg_list
         .try_next()
         .await
@@ -149,66 +196,229 @@ async fn handle_neighbors(
     {
         // get neigh filter with index
         if neigh.header.ifindex == attrs.index {
-            neighs.push(generate_neigh(name, &neigh).context("generate neigh")?)
+            if let Some(neigh) = generate_neigh(name, &neigh).context("generate neigh")? {
+                neighs.push(neigh);
+            }
         }
     }
+    Ok(())
+}
+
+async fn handle_neighbors(
+    handle: &rtnetlink::Handle,
+    attrs: &LinkAttrs,
+) -> Result<Vec<ARPNeighbor>> {
+    let mut neighs = vec![];
+    get_neigh_from_msg(&mut neighs, attrs, handle.neighbours().get())
+        .await
+        .context("get neighs")?;
+    get_neigh_from_msg(&mut neighs, attrs, handle.neighbours().get().proxies())
+        .await
+        .context("get proxy neighs")?;
     Ok(neighs)
 }
 
-fn generate_route(name: &str, route: &RouteMessage) -> Result<Option<Route>> {
-    if route.header.protocol == libc::RTPROT_KERNEL {
+fn generate_routes(
+    attrs: &LinkAttrs,
+    route: &RouteMessage,
+    addresses: &[IPAddress],
+) -> Result<Vec<Route>> {
+    let header = &route.header;
+    if header.protocol == RTPROT_KERNEL
+        || header.kind != RTN_UNICAST
+        || header.flags.contains(RouteFlags::RTM_F_CLONED)
+        || route_table(route) != RT_TABLE_MAIN as u32
+    {
+        return Ok(vec![]);
+    }
+
+    // a multipath route (e.g. IPv6 default routes learnt from several
+    // routers) has no output interface, only the next hops through this
+    // link are kept.
+    let gateways = if route.output_interface() == Some(attrs.index) {
+        vec![route.gateway()]
+    } else {
+        next_hops(route)
+            .context("next hops")?
+            .iter()
+            .filter(|nh| nh.interface_id == attrs.index)
+            .map(|nh| nh.gateway())
+            .collect()
+    };
+
+    let family = if header.address_family == libc::AF_INET as u8 {
+        IPFamily::V4
+    } else {
+        IPFamily::V6
+    };
+    let dest = route
+        .destination_prefix()
+        .map(|(addr, prefix)| format!("{}/{}", addr, prefix))
+        .unwrap_or_default();
+    let source = route
+        .source_prefix()
+        .map(|(addr, _)| addr.to_string())
+        .unwrap_or_default();
+
+    let mut routes = vec![];
+    for gateway in gateways {
+        if let Some(gateway) = gateway {
+            // an on-link gateway is outside of the subnets of the interface,
+            // the guest needs a route to it before the gateway can be used.
+            if !is_on_subnet(&gateway, addresses) {
+                routes.push(Route {
+                    dest: format!("{}/{}", gateway, if gateway.is_ipv4() { 32 } else { 128 }),
+                    device: attrs.name.clone(),
+                    scope: RT_SCOPE_LINK as u32,
+                    family: family.clone(),
+                    ..Default::default()
+                });
+            }
+        }
+
+        routes.push(Route {
+            dest: dest.clone(),
+            gateway: gateway.map(|v| v.to_string()).unwrap_or_default(),
+            device: attrs.name.clone(),
+            source: source.clone(),
+            scope: header.scope as u32,
+            family: family.clone(),
+        });
+    }
+    Ok(routes)
+}
+
+fn route_table(route: &RouteMessage) -> u32 {
+    route
+        .nlas
+        .iter()
+        .find_map(|nla| match nla {
+            RouteNla::Table(table) => Some(*table),
+            _ => None,
+        })
+        .unwrap_or(route.header.table as u32)
+}
+
+fn next_hops(route: &RouteMessage) -> Result<Vec<NextHop>> {
+    let mut next_hops = vec![];
+    for nla in &route.nlas {
+        if let RouteNla::MultiPath(buf) = nla {
+            let mut offset = 0;
+            while offset < buf.len() {
+                let data = &buf[offset..];
+                let nh_buf = NextHopBuffer::new_checked(&data)
+                    .map_err(|e| anyhow!("invalid next hop: {}", e))?;
+                let len = nh_buf.length() as usize;
+                if len == 0 {
+                    break;
+                }
+                next_hops
+                    .push(NextHop::parse(&nh_buf).map_err(|e| anyhow!("parse next hop: {}", e))?);
+                // next hops are aligned to 4 bytes
+                offset += (len + 3) & !3;
+            }
+        }
+    }
+    Ok(next_hops)
+}
+
+// The IPv6 sysctls of the link, there are none when IPv6 is disabled on the host.
+fn handle_ipv6_sysctls(root: &Path, name: &str) -> Result<Option<IPv6Sysctls>> {
+    let dir = root.join(name);
+    if !dir.exists() {
         return Ok(None);
     }
+    let read = |sysctl: &str| -> Result<u32> {
+        let path = dir.join(sysctl);
+        let value = fs::read_to_string(&path).with_context(|| format!("read {:?}", path))?;
+        value
+            .trim()
+            .parse()
+            .with_context(|| format!("parse {:?} of {:?}", value, path))
+    };
 
-    Ok(Some(Route {
-        dest: route
-            .destination_prefix()
-            .map(|(addr, prefix)| format!("{}/{}", addr, prefix))
-            .unwrap_or_default(),
-        gateway: route.gateway().map(|v| v.to_string()).unwrap_or_default(),
-        device: name.to_string(),
-        source: route
-            .source_prefix()
-            .map(|(addr, _)| addr.to_string())
-            .unwrap_or_default(),
-        scope: route.header.scope as u32,
-        family: if route.header.address_family == libc::AF_INET as u8 {
-            IPFamily::V4
-        } else {
-            IPFamily::V6
-        },
+    Ok(Some(IPv6Sysctls {
+        disable_ipv6: read("disable_ipv6")?,
+        accept_ra: read("accept_ra")?,
+        forwarding: read("forwarding")?,
     }))
 }
 
+fn is_ipv6_link_local(addr: &Ipv6Addr) -> bool {
+    addr.segments()[0] & 0xffc0 == 0xfe80
+}
+
+// Check if the gateway can be reached through the subnets of the interface,
+// IPv6 link-local gateways are always reachable through their interface.
+fn is_on_subnet(gateway: &IpAddr, addresses: &[IPAddress]) -> bool {
+    if let IpAddr::V6(gateway) = gateway {
+        if is_ipv6_link_local(gateway) {
+            return true;
+        }
+    }
+
+    addresses.iter().any(|a| {
+        let (addr, prefix) = match (a.address.parse::<IpAddr>(), a.mask.parse::<u32>()) {
+            (Ok(addr), Ok(prefix)) => (addr, prefix),
+            _ => return false,
+        };
+        match (gateway, addr) {
+            (IpAddr::V4(gateway), IpAddr::V4(addr)) => {
+                let mask = u32::MAX.checked_shl(32 - prefix.min(32)).unwrap_or(0);
+                u32::from(*gateway) & mask == u32::from(addr) & mask
+            }
+            (IpAddr::V6(gateway), IpAddr::V6(addr)) => {
+                let mask = u128::MAX.checked_shl(128 - prefix.min(128)).unwrap_or(0);
+                u128::from(*gateway) & mask == u128::from(addr) & mask
+            }
+            _ => false,
+        }
+    })
+}
+
 async fn get_route_from_msg(
     routes: &mut Vec<Route>,
     handle: &rtnetlink::Handle,
     attrs: &LinkAttrs,
+    addresses: &[IPAddress],
     ip_version: rtnetlink::IpVersion,
 ) -> Result<()> {
-    let name = &attrs.name;
     let mut route_msg_list = handle.route().get(ip_version).execute();
     while let Some(route) = route_msg_list.try_next().await? {
-        // get route filter with index
-        if let Some(index) = route.output_interface() {
-            if index == attrs.index {
-                if let Some(route) = generate_route(name, &route).context("generate route")? {
-                    routes.push(route);
-                }
+        for route in generate_routes(attrs, &route, addresses).context("generate route")? {
+            // several routes may go through the same on-link gateway
+            if !routes.contains(&route) {
+                routes.push(route);
             }
         }
     }
     Ok(())
 }
 
-async fn handle_routes(handle: &rtnetlink::Handle, attrs: &LinkAttrs) -> Result<Vec<Route>> {
+async fn handle_routes(
+    handle: &rtnetlink::Handle,
+    attrs: &LinkAttrs,
+    addresses: &[IPAddress],
+) -> Result<Vec<Route>> {
     let mut routes = vec![];
-    get_route_from_msg(&mut routes, handle, attrs, rtnetlink::IpVersion::V4)
-        .await
-        .context("get ip v4 route")?;
-    get_route_from_msg(&mut routes, handle, attrs, rtnetlink::IpVersion::V6)
-        .await
-        .context("get ip v6 route")?;
+    get_route_from_msg(
+        &mut routes,
+        handle,
+        attrs,
+        addresses,
+        rtnetlink::IpVersion::V4,
+    )
+    .await
+    .context("get ip v4 route")?;
+    get_route_from_msg(
+        &mut routes,
+        handle,
+        attrs,
+        addresses,
+        rtnetlink::IpVersion::V6,
+    )
+    .await
+    .context("get ip v6 route")?;
     Ok(routes)
 }
 
@@ -226,3 +436,543 @@ impl NetworkInfo for NetworkInfoFromLink {
         Ok(self.neighs.clone())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use netlink_packet_route::{
+        constants::{
+            AF_INET, AF_INET6, IFA_F_PERMANENT, IFA_F_TENTATIVE, NUD_PERMANENT, NUD_REACHABLE,
+            NUD_STALE, RTN_LOCAL, RTPROT_BOOT, RTPROT_RA, RT_SCOPE_UNIVERSE, RT_TABLE_LOCAL,
+        },
+        nlas::{address::Nla as AddressNla, route::NextHopFlags},
+        traits::Emitable,
+        AddressHeader, NeighbourHeader, RouteHeader,
+    };
+
+    use super::*;
+
+    const LINK_INDEX: u32 = 2;
+    const OTHER_LINK_INDEX: u32 = 3;
+
+    fn link_attrs() -> LinkAttrs {
+        LinkAttrs {
+            name: "eth0".to_string(),
+            index: LINK_INDEX,
+            ..Default::default()
+        }
+    }
+
+    fn ip_octets(ip: &str) -> Vec<u8> {
+        match ip.parse::<IpAddr>().unwrap() {
+            IpAddr::V4(ip) => ip.octets().to_vec(),
+            IpAddr::V6(ip) => ip.octets().to_vec(),
+        }
+    }
+
+    fn family_of(ip: &str) -> u8 {
+        if ip.contains(':') {
+            AF_INET6 as u8
+        } else {
+            AF_INET as u8
+        }
+    }
+
+    fn address_msg(ip: &str, prefix_len: u8, scope: u8, flags: u32) -> AddressMessage {
+        AddressMessage {
+            header: AddressHeader {
+                family: family_of(ip),
+                prefix_len,
+                flags: 0,
+                scope,
+                index: LINK_INDEX,
+            },
+            nlas: vec![AddressNla::Address(ip_octets(ip)), AddressNla::Flags(flags)],
+        }
+    }
+
+    fn ip_address(ip: &str, mask: &str) -> IPAddress {
+        IPAddress {
+            family: if ip.contains(':') {
+                IPFamily::V6
+            } else {
+                IPFamily::V4
+            },
+            address: ip.to_string(),
+            mask: mask.to_string(),
+        }
+    }
+
+    #[derive(Debug)]
+    struct AddressTestData {
+        msg: AddressMessage,
+        expected: Option<IPAddress>,
+    }
+
+    #[test]
+    fn test_generate_address() {
+        let tests = &[
+            AddressTestData {
+                msg: address_msg("10.0.0.2", 24, RT_SCOPE_UNIVERSE, 0),
+                expected: Some(ip_address("10.0.0.2", "24")),
+            },
+            AddressTestData {
+                msg: address_msg("fd00::2", 64, RT_SCOPE_UNIVERSE, IFA_F_PERMANENT),
+                expected: Some(ip_address("fd00::2", "64")),
+            },
+            // tentative addresses are still being checked by the DAD of the
+            // netns, they are expected to become valid
+            AddressTestData {
+                msg: address_msg("2001:db8::2", 64, RT_SCOPE_UNIVERSE, IFA_F_TENTATIVE),
+                expected: Some(ip_address("2001:db8::2", "64")),
+            },
+            AddressTestData {
+                msg: address_msg("2001:db8::3", 64, RT_SCOPE_UNIVERSE, IFA_F_DADFAILED),
+                expected: None,
+            },
+            AddressTestData {
+                msg: address_msg("fe80::1c2b:3cff:fe4d:5e6f", 64, RT_SCOPE_LINK, 0),
+                expected: None,
+            },
+            AddressTestData {
+                msg: address_msg("127.0.0.1", 8, RT_SCOPE_HOST, 0),
+                expected: None,
+            },
+            AddressTestData {
+                msg: address_msg("::1", 128, RT_SCOPE_HOST, 0),
+                expected: None,
+            },
+            AddressTestData {
+                msg: address_msg("192.168.0.2", 32, RT_SCOPE_HOST, 0),
+                expected: None,
+            },
+        ];
+
+        for (i, d) in tests.iter().enumerate() {
+            let msg = format!("test[{}]: {:?}", i, d);
+            let result = generate_address(d.msg.clone()).unwrap();
+            assert_eq!(result, d.expected, "{}", msg);
+        }
+    }
+
+    #[derive(Debug)]
+    struct NeighTestData {
+        family: u8,
+        state: u16,
+        flags: u8,
+        dest: &'static str,
+        lladdr: Option<[u8; 6]>,
+        expected: Option<ARPNeighbor>,
+    }
+
+    fn arp_neighbor(ip: &str, lladdr: &str, state: u16, flags: u8) -> ARPNeighbor {
+        ARPNeighbor {
+            to_ip_address: Some(ip_address(ip, "")),
+            device: "eth0".to_string(),
+            ll_addr: lladdr.to_string(),
+            state: state as i32,
+            flags: flags as i32,
+        }
+    }
+
+    #[test]
+    fn test_generate_neigh() {
+        let mac = [0x6a, 0x92, 0x3a, 0x59, 0x70, 0xaa];
+        let tests = &[
+            NeighTestData {
+                family: AF_INET as u8,
+                state: NUD_PERMANENT,
+                flags: 0,
+                dest: "169.254.1.1",
+                lladdr: Some(mac),
+                expected: Some(arp_neighbor(
+                    "169.254.1.1",
+                    "6a:92:3a:59:70:aa",
+                    NUD_PERMANENT,
+                    0,
+                )),
+            },
+            NeighTestData {
+                family: AF_INET6 as u8,
+                state: NUD_REACHABLE,
+                flags: NTF_ROUTER,
+                dest: "fe80::1",
+                lladdr: Some(mac),
+                expected: Some(arp_neighbor(
+                    "fe80::1",
+                    "6a:92:3a:59:70:aa",
+                    NUD_REACHABLE,
+                    NTF_ROUTER,
+                )),
+            },
+            NeighTestData {
+                family: AF_INET6 as u8,
+                state: NUD_STALE,
+                flags: 0,
+                dest: "2001:db8::1",
+                lladdr: Some(mac),
+                expected: Some(arp_neighbor(
+                    "2001:db8::1",
+                    "6a:92:3a:59:70:aa",
+                    NUD_STALE,
+                    0,
+                )),
+            },
+            // proxy NDP
+            NeighTestData {
+                family: AF_INET6 as u8,
+                state: 0,
+                flags: NTF_PROXY,
+                dest: "2001:db8::10",
+                lladdr: None,
+                expected: Some(arp_neighbor("2001:db8::10", "", 0, NTF_PROXY)),
+            },
+            // multicast
+            NeighTestData {
+                family: AF_INET6 as u8,
+                state: NUD_NOARP,
+                flags: 0,
+                dest: "ff02::2",
+                lladdr: Some([0x33, 0x33, 0, 0, 0, 0x02]),
+                expected: None,
+            },
+            NeighTestData {
+                family: AF_INET6 as u8,
+                state: NUD_FAILED,
+                flags: 0,
+                dest: "2001:db8::2",
+                lladdr: None,
+                expected: None,
+            },
+            NeighTestData {
+                family: AF_INET as u8,
+                state: NUD_INCOMPLETE,
+                flags: 0,
+                dest: "10.0.0.3",
+                lladdr: None,
+                expected: None,
+            },
+        ];
+
+        for (i, d) in tests.iter().enumerate() {
+            let msg = format!("test[{}]: {:?}", i, d);
+            let mut nlas = vec![Nla::Destination(ip_octets(d.dest))];
+            if let Some(lladdr) = d.lladdr {
+                nlas.push(Nla::LinkLocalAddress(lladdr.to_vec()));
+            }
+            let neigh = NeighbourMessage {
+                header: NeighbourHeader {
+                    family: d.family,
+                    ifindex: LINK_INDEX,
+                    state: d.state,
+                    flags: d.flags,
+                    ntype: RTN_UNICAST,
+                },
+                nlas,
+            };
+
+            let result = generate_neigh("eth0", &neigh).unwrap();
+            assert_eq!(result, d.expected, "{}", msg);
+        }
+    }
+
+    fn next_hops_nla(hops: &[(u32, &str, NextHopFlags)]) -> RouteNla {
+        let mut buf = vec![];
+        for (index, gateway, flags) in hops {
+            let nh = NextHop {
+                flags: *flags,
+                hops: 0,
+                interface_id: *index,
+                nlas: vec![RouteNla::Gateway(ip_octets(gateway))],
+            };
+            let mut nh_buf = vec![0u8; nh.buffer_len()];
+            nh.emit(&mut nh_buf);
+            buf.extend(nh_buf);
+        }
+        RouteNla::MultiPath(buf)
+    }
+
+    #[derive(Debug)]
+    struct RouteTestData {
+        family: u8,
+        table: u8,
+        protocol: u8,
+        kind: u8,
+        scope: u8,
+        dest: Option<(&'static str, u8)>,
+        nlas: Vec<RouteNla>,
+        expected: Vec<Route>,
+    }
+
+    fn route(dest: &str, gateway: &str, scope: u8) -> Route {
+        let family = if dest.contains(':') || gateway.contains(':') {
+            IPFamily::V6
+        } else {
+            IPFamily::V4
+        };
+        Route {
+            dest: dest.to_string(),
+            gateway: gateway.to_string(),
+            device: "eth0".to_string(),
+            source: "".to_string(),
+            scope: scope as u32,
+            family,
+        }
+    }
+
+    #[test]
+    fn test_generate_routes() {
+        let addresses = vec![
+            ip_address("10.0.0.2", "24"),
+            ip_address("2001:db8::2", "64"),
+        ];
+        let tests = vec![
+            // IPv4 default route
+            RouteTestData {
+                family: AF_INET as u8,
+                table: RT_TABLE_MAIN,
+                protocol: RTPROT_BOOT,
+                kind: RTN_UNICAST,
+                scope: RT_SCOPE_UNIVERSE,
+                dest: None,
+                nlas: vec![
+                    RouteNla::Gateway(ip_octets("10.0.0.1")),
+                    RouteNla::Oif(LINK_INDEX),
+                ],
+                expected: vec![route("", "10.0.0.1", RT_SCOPE_UNIVERSE)],
+            },
+            // IPv6 default route through a link-local router
+            RouteTestData {
+                family: AF_INET6 as u8,
+                table: RT_TABLE_MAIN,
+                protocol: RTPROT_RA,
+                kind: RTN_UNICAST,
+                scope: RT_SCOPE_UNIVERSE,
+                dest: None,
+                nlas: vec![
+                    RouteNla::Gateway(ip_octets("fe80::1")),
+                    RouteNla::Oif(LINK_INDEX),
+                ],
+                expected: vec![route("", "fe80::1", RT_SCOPE_UNIVERSE)],
+            },
+            // IPv6 route with a global gateway
+            RouteTestData {
+                family: AF_INET6 as u8,
+                table: RT_TABLE_MAIN,
+                protocol: RTPROT_BOOT,
+                kind: RTN_UNICAST,
+                scope: RT_SCOPE_UNIVERSE,
+                dest: Some(("2001:db8:1::", 48)),
+                nlas: vec![
+                    RouteNla::Gateway(ip_octets("2001:db8::1")),
+                    RouteNla::Oif(LINK_INDEX),
+                ],
+                expected: vec![route("2001:db8:1::/48", "2001:db8::1", RT_SCOPE_UNIVERSE)],
+            },
+            // on-link gateways get a host route first
+            RouteTestData {
+                family: AF_INET as u8,
+                table: RT_TABLE_MAIN,
+                protocol: RTPROT_BOOT,
+                kind: RTN_UNICAST,
+                scope: RT_SCOPE_UNIVERSE,
+                dest: None,
+                nlas: vec![
+                    RouteNla::Gateway(ip_octets("169.254.1.1")),
+                    RouteNla::Oif(LINK_INDEX),
+                ],
+                expected: vec![
+                    route("169.254.1.1/32", "", RT_SCOPE_LINK),
+                    route("", "169.254.1.1", RT_SCOPE_UNIVERSE),
+                ],
+            },
+            RouteTestData {
+                family: AF_INET6 as u8,
+                table: RT_TABLE_MAIN,
+                protocol: RTPROT_BOOT,
+                kind: RTN_UNICAST,
+                scope: RT_SCOPE_UNIVERSE,
+                dest: None,
+                nlas: vec![
+                    RouteNla::Gateway(ip_octets("2001:db8:ffff::1")),
+                    RouteNla::Oif(LINK_INDEX),
+                ],
+                expected: vec![
+                    route("2001:db8:ffff::1/128", "", RT_SCOPE_LINK),
+                    route("", "2001:db8:ffff::1", RT_SCOPE_UNIVERSE),
+                ],
+            },
+            // link scope route without gateway
+            RouteTestData {
+                family: AF_INET as u8,
+                table: RT_TABLE_MAIN,
+                protocol: RTPROT_BOOT,
+                kind: RTN_UNICAST,
+                scope: RT_SCOPE_LINK,
+                dest: Some(("10.1.0.0", 16)),
+                nlas: vec![RouteNla::Oif(LINK_INDEX)],
+                expected: vec![route("10.1.0.0/16", "", RT_SCOPE_LINK)],
+            },
+            // multipath IPv6 default route, only the next hops through
+            // this link are kept
+            RouteTestData {
+                family: AF_INET6 as u8,
+                table: RT_TABLE_MAIN,
+                protocol: RTPROT_RA,
+                kind: RTN_UNICAST,
+                scope: RT_SCOPE_UNIVERSE,
+                dest: None,
+                nlas: vec![next_hops_nla(&[
+                    (LINK_INDEX, "fe80::1", NextHopFlags::RTNH_F_EMPTY),
+                    (OTHER_LINK_INDEX, "fe80::2", NextHopFlags::RTNH_F_EMPTY),
+                    (LINK_INDEX, "fe80::3", NextHopFlags::RTNH_F_EMPTY),
+                ])],
+                expected: vec![
+                    route("", "fe80::1", RT_SCOPE_UNIVERSE),
+                    route("", "fe80::3", RT_SCOPE_UNIVERSE),
+                ],
+            },
+            RouteTestData {
+                family: AF_INET as u8,
+                table: RT_TABLE_MAIN,
+                protocol: RTPROT_BOOT,
+                kind: RTN_UNICAST,
+                scope: RT_SCOPE_UNIVERSE,
+                dest: Some(("10.2.0.0", 16)),
+                nlas: vec![next_hops_nla(&[
+                    (OTHER_LINK_INDEX, "10.3.0.1", NextHopFlags::RTNH_F_EMPTY),
+                    (LINK_INDEX, "10.9.0.1", NextHopFlags::RTNH_F_ONLINK),
+                ])],
+                expected: vec![
+                    route("10.9.0.1/32", "", RT_SCOPE_LINK),
+                    route("10.2.0.0/16", "10.9.0.1", RT_SCOPE_UNIVERSE),
+                ],
+            },
+            // routes of another link
+            RouteTestData {
+                family: AF_INET6 as u8,
+                table: RT_TABLE_MAIN,
+                protocol: RTPROT_BOOT,
+                kind: RTN_UNICAST,
+                scope: RT_SCOPE_UNIVERSE,
+                dest: None,
+                nlas: vec![
+                    RouteNla::Gateway(ip_octets("fe80::1")),
+                    RouteNla::Oif(OTHER_LINK_INDEX),
+                ],
+                expected: vec![],
+            },
+            // the guest kernel creates the prefix routes of its addresses
+            RouteTestData {
+                family: AF_INET6 as u8,
+                table: RT_TABLE_MAIN,
+                protocol: RTPROT_KERNEL,
+                kind: RTN_UNICAST,
+                scope: RT_SCOPE_UNIVERSE,
+                dest: Some(("2001:db8::", 64)),
+                nlas: vec![RouteNla::Oif(LINK_INDEX)],
+                expected: vec![],
+            },
+            RouteTestData {
+                family: AF_INET6 as u8,
+                table: RT_TABLE_LOCAL,
+                protocol: RTPROT_BOOT,
+                kind: RTN_LOCAL,
+                scope: RT_SCOPE_HOST,
+                dest: Some(("2001:db8::2", 128)),
+                nlas: vec![RouteNla::Oif(LINK_INDEX)],
+                expected: vec![],
+            },
+            // tables above 255 only come with RTA_TABLE
+            RouteTestData {
+                family: AF_INET as u8,
+                table: RT_TABLE_MAIN,
+                protocol: RTPROT_BOOT,
+                kind: RTN_UNICAST,
+                scope: RT_SCOPE_UNIVERSE,
+                dest: Some(("10.4.0.0", 16)),
+                nlas: vec![
+                    RouteNla::Table(1000),
+                    RouteNla::Gateway(ip_octets("10.0.0.1")),
+                    RouteNla::Oif(LINK_INDEX),
+                ],
+                expected: vec![],
+            },
+        ];
+
+        let attrs = link_attrs();
+        for (i, d) in tests.iter().enumerate() {
+            let msg = format!("test[{}]: {:?}", i, d);
+            let mut nlas = d.nlas.clone();
+            let mut header = RouteHeader {
+                address_family: d.family,
+                table: d.table,
+                protocol: d.protocol,
+                scope: d.scope,
+                kind: d.kind,
+                ..Default::default()
+            };
+            if let Some((dest, prefix)) = d.dest {
+                header.destination_prefix_length = prefix;
+                nlas.push(RouteNla::Destination(ip_octets(dest)));
+            }
+
+            let result =
+                generate_routes(&attrs, &RouteMessage { header, nlas }, &addresses).unwrap();
+            assert_eq!(result, d.expected, "{}", msg);
+        }
+    }
+
+    #[test]
+    fn test_is_on_subnet() {
+        let addresses = vec![
+            ip_address("10.0.0.2", "24"),
+            ip_address("2001:db8::2", "64"),
+        ];
+        let tests = &[
+            ("10.0.0.1", true),
+            ("10.0.1.1", false),
+            ("2001:db8::1", true),
+            ("2001:db8:1::1", false),
+            ("fe80::1", true),
+        ];
+
+        for (i, (gateway, expected)) in tests.iter().enumerate() {
+            let gateway = gateway.parse::<IpAddr>().unwrap();
+            assert_eq!(
+                is_on_subnet(&gateway, &addresses),
+                *expected,
+                "test[{}]: {}",
+                i,
+                gateway
+            );
+        }
+    }
+
+    #[test]
+    fn test_handle_ipv6_sysctls() {
+        let root = tempfile::tempdir().unwrap();
+        let conf = root.path().join("eth0");
+        std::fs::create_dir_all(&conf).unwrap();
+        for (name, value) in [
+            ("disable_ipv6", "0\n"),
+            ("accept_ra", "2\n"),
+            ("forwarding", "1\n"),
+        ] {
+            std::fs::write(conf.join(name), value).unwrap();
+        }
+
+        assert_eq!(
+            handle_ipv6_sysctls(root.path(), "eth0").unwrap(),
+            Some(IPv6Sysctls {
+                disable_ipv6: 0,
+                accept_ra: 2,
+                forwarding: 1,
+            })
+        );
+        // IPv6 is disabled on the host
+        assert_eq!(handle_ipv6_sysctls(root.path(), "eth1").unwrap(), None);
+
+        std::fs::write(conf.join("forwarding"), "on\n").unwrap();
+        assert!(handle_ipv6_sysctls(root.path(), "eth0").is_err());
+    }
+}
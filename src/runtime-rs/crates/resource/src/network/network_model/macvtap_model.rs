@@ -0,0 +1,151 @@
+// Copyright (c) 2019-2022 Alibaba Cloud
+// Copyright (c) 2019-2022 Ant Group
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use anyhow::{anyhow, Context, Result};
+use async_trait::async_trait;
+use netlink_packet_route::{
+    link::nlas::{Info, InfoData, InfoKind, InfoMacVtap, Nla},
+    MACVLAN_MODE_BRIDGE,
+};
+use scopeguard::defer;
+
+use super::{tc_filter_model::fetch_index, NetworkModel, NetworkModelType};
+use crate::network::{network_pair::get_link_by_name, utils, NetworkPair};
+
+#[derive(Debug)]
+pub(crate) struct MacVtapModel {}
+
+impl MacVtapModel {
+    pub fn new() -> Result<Self> {
+        Ok(Self {})
+    }
+}
+
+#[async_trait]
+impl NetworkModel for MacVtapModel {
+    fn model_type(&self) -> NetworkModelType {
+        NetworkModelType::MacVtap
+    }
+
+    async fn add(&self, pair: &NetworkPair) -> Result<()> {
+        let (connection, handle, _) = rtnetlink::new_connection().context("new connection")?;
+        let thread_handler = tokio::spawn(connection);
+        defer!({
+            thread_handler.abort();
+        });
+
+        let virt_link = get_link_by_name(&handle, &pair.virt_iface.name)
+            .await
+            .context("get virt link")?;
+        let virt_index = virt_link.attrs().index;
+
+        // The macvtap takes over the MAC address of the virt interface, which
+        // is the one the network plugin expects to see traffic from. The virt
+        // interface keeps a random one so that both can live on the same
+        // segment.
+        let tap_hard_addr = utils::parse_mac(&pair.tap.tap_iface.hard_addr)
+            .ok_or_else(|| anyhow!("invalid hard_addr {}", &pair.tap.tap_iface.hard_addr))?;
+        handle
+            .link()
+            .set(virt_index)
+            .address(random_mac_addr().to_vec())
+            .execute()
+            .await
+            .context("set virt random mac")?;
+
+        let mut req = handle.link().add();
+        req.message_mut().nlas.extend(vec![
+            Nla::IfName(pair.tap.tap_iface.name.clone()),
+            Nla::Link(virt_index),
+            Nla::Address(tap_hard_addr.0.to_vec()),
+            Nla::Mtu(virt_link.attrs().mtu),
+            Nla::TxQueueLen(virt_link.attrs().txq_len),
+            Nla::Info(vec![
+                Info::Kind(InfoKind::MacVtap),
+                Info::Data(InfoData::MacVtap(vec![InfoMacVtap::Mode(
+                    MACVLAN_MODE_BRIDGE,
+                )])),
+            ]),
+        ]);
+        req.execute().await.context("add macvtap")?;
+
+        let tap_index = fetch_index(&handle, &pair.tap.tap_iface.name)
+            .await
+            .context("fetch macvtap index")?;
+        handle
+            .link()
+            .set(tap_index)
+            .up()
+            .execute()
+            .await
+            .context("set macvtap up")?;
+
+        Ok(())
+    }
+
+    async fn del(&self, pair: &NetworkPair) -> Result<()> {
+        let (connection, handle, _) = rtnetlink::new_connection().context("new connection")?;
+        let thread_handler = tokio::spawn(connection);
+        defer!({
+            thread_handler.abort();
+        });
+
+        // the macvtap is gone along with the virt link, e.g. when the network
+        // plugin removes the interface
+        let virt_index = match fetch_index(&handle, &pair.virt_iface.name).await {
+            Ok(index) => index,
+            Err(_) => return Ok(()),
+        };
+
+        let tap_index = fetch_index(&handle, &pair.tap.tap_iface.name)
+            .await
+            .context("fetch macvtap index")?;
+        handle
+            .link()
+            .del(tap_index)
+            .execute()
+            .await
+            .context("del macvtap")?;
+
+        // give the original MAC address back to the virt interface
+        if let Some(addr) = utils::parse_mac(&pair.tap.tap_iface.hard_addr) {
+            handle
+                .link()
+                .set(virt_index)
+                .address(addr.0.to_vec())
+                .execute()
+                .await
+                .context("restore virt mac")?;
+        }
+
+        Ok(())
+    }
+}
+
+// Generate a unicast, locally administered MAC address.
+fn random_mac_addr() -> [u8; 6] {
+    let bytes = kata_sys_util::rand::RandomBytes::new(6).bytes;
+    let mut addr = [0u8; 6];
+    addr.copy_from_slice(&bytes);
+    addr[0] = (addr[0] & 0xfe) | 0x02;
+    addr
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_random_mac_addr() {
+        for _ in 0..16 {
+            let addr = random_mac_addr();
+            // unicast
+            assert_eq!(addr[0] & 0x01, 0);
+            // locally administered
+            assert_eq!(addr[0] & 0x02, 0x02);
+        }
+    }
+}
@@ -89,7 +89,12 @@ impl NetworkModel for TcFilterModel {
         defer!({
             thread_handler.abort();
         });
-        let virt_index = fetch_index(&handle, &pair.virt_iface.name).await?;
+        // the qdisc is gone along with the virt link, e.g. when the network
+        // plugin removes the interface
+        let virt_index = match fetch_index(&handle, &pair.virt_iface.name).await {
+            Ok(index) => index,
+            Err(_) => return Ok(()),
+        };
         handle.qdisc().del(virt_index as i32).execute().await?;
         Ok(())
     }
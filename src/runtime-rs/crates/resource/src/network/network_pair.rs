@@ -4,7 +4,12 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-use std::{convert::TryFrom, sync::Arc, usize};
+use std::{
+    convert::TryFrom,
+    fs::{File, OpenOptions},
+    sync::Arc,
+    usize,
+};
 
 use anyhow::{anyhow, Context, Result};
 use futures::stream::TryStreamExt;
@@ -38,6 +43,7 @@ pub struct NetworkPair {
     pub virt_iface: NetworkInterface,
     pub model: Arc<dyn network_model::NetworkModel>,
     pub network_qos: bool,
+    pub queues: usize,
 }
 impl NetworkPair {
     pub(crate) async fn new(
@@ -51,10 +57,6 @@ impl NetworkPair {
         let model = network_model::new(model).context("new network model")?;
         let tap_iface_name = format!("tap{}{}", idx, TAP_SUFFIX);
         let virt_iface_name = format!("eth{}", idx);
-        let tap_link = create_link(handle, &tap_iface_name, queues)
-            .await
-            .context("create link")?;
-
         let virt_link = get_link_by_name(handle, virt_iface_name.clone().as_str())
             .await
             .context("get link by name")?;
@@ -79,27 +81,38 @@ impl NetworkPair {
         let tap_hard_addr =
             utils::get_mac_addr(&virt_link.attrs().hardware_addr).context("get mac addr")?;
 
-        // Save the TAP Mac address to the virt_iface so that it can later updated
-        // the guest's gateway IP's mac as this TAP device. This MAC address has
-        // to be inside the VM in order to the network reach to the gateway.
-        let virt_hard_addr =
-            utils::get_mac_addr(&tap_link.attrs().hardware_addr).context("get mac addr")?;
+        // The macvtap device sits directly on top of the virt interface and is
+        // only created when the network model is added, so there is no TAP
+        // device to set up here.
+        let virt_hard_addr = match model.model_type() {
+            network_model::NetworkModelType::MacVtap => tap_hard_addr.clone(),
+            _ => {
+                let tap_link = create_link(handle, &tap_iface_name, queues)
+                    .await
+                    .context("create link")?;
 
-        handle
-            .link()
-            .set(tap_link.attrs().index)
-            .mtu(virt_link.attrs().mtu)
-            .execute()
-            .await
-            .context("set link mtu")?;
+                handle
+                    .link()
+                    .set(tap_link.attrs().index)
+                    .mtu(virt_link.attrs().mtu)
+                    .execute()
+                    .await
+                    .context("set link mtu")?;
 
-        handle
-            .link()
-            .set(tap_link.attrs().index)
-            .up()
-            .execute()
-            .await
-            .context("set link up")?;
+                handle
+                    .link()
+                    .set(tap_link.attrs().index)
+                    .up()
+                    .execute()
+                    .await
+                    .context("set link up")?;
+
+                // Save the TAP Mac address to the virt_iface so that it can later updated
+                // the guest's gateway IP's mac as this TAP device. This MAC address has
+                // to be inside the VM in order to the network reach to the gateway.
+                utils::get_mac_addr(&tap_link.attrs().hardware_addr).context("get mac addr")?
+            }
+        };
 
         let mut net_pair = NetworkPair {
             tap: TapInterface {
@@ -118,6 +131,7 @@ impl NetworkPair {
             },
             model,
             network_qos: false,
+            queues,
         };
 
         if !name.is_empty() {
@@ -138,6 +152,31 @@ impl NetworkPair {
         model.del(self).await.context("del")?;
         Ok(())
     }
+
+    // Open the queues of the macvtap device, which the hypervisor can't open
+    // by name like a TAP device. Must be called in the netns, once the network
+    // model is added.
+    pub(crate) fn open_tap_queues(&self) -> Result<Vec<File>> {
+        if !matches!(
+            self.model.model_type(),
+            network_model::NetworkModelType::MacVtap
+        ) {
+            return Ok(vec![]);
+        }
+
+        let index = nix::net::if_::if_nametoindex(self.tap.tap_iface.name.as_str())
+            .with_context(|| format!("get index of {}", &self.tap.tap_iface.name))?;
+        let path = format!("/dev/tap{}", index);
+        (0..self.queues.max(1))
+            .map(|_| {
+                OpenOptions::new()
+                    .read(true)
+                    .write(true)
+                    .open(&path)
+                    .with_context(|| format!("open {}", &path))
+            })
+            .collect()
+    }
 }
 
 pub async fn create_link(
@@ -164,6 +203,11 @@ pub async fn create_link(
     Ok(link)
 }
 
+// The links created for the network pairs, which are not part of the pod network.
+pub(crate) fn is_kata_link(name: &str) -> bool {
+    name.ends_with(TAP_SUFFIX)
+}
+
 pub async fn get_link_by_name(
     handle: &rtnetlink::Handle,
     name: &str,
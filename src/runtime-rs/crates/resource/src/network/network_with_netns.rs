@@ -5,7 +5,9 @@
 //
 
 use std::{
+    convert::TryFrom,
     fs,
+    net::IpAddr,
     sync::{
         atomic::{AtomicU32, Ordering},
         Arc,
@@ -19,31 +21,45 @@ use futures::stream::TryStreamExt;
 use hypervisor::Hypervisor;
 use netns_rs::get_from_path;
 use scopeguard::defer;
+use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 
 use super::{
     endpoint::{
-        Endpoint, IPVlanEndpoint, MacVlanEndpoint, PhysicalEndpoint, VethEndpoint, VlanEndpoint,
+        vhost_user_endpoint::{parse_vhost_user_sockets, vhost_user_socket_path},
+        Endpoint, IPVlanEndpoint, MacVlanEndpoint, PhysicalEndpoint, TapEndpoint, VethEndpoint,
+        VhostUserEndpoint, VlanEndpoint,
     },
     network_entity::NetworkEntity,
     network_info::network_info_from_link::NetworkInfoFromLink,
-    utils::{link, netns},
-    Network,
+    network_pair::is_kata_link,
+    utils::{address::Address, link, netns},
+    Network, NetworkChanges,
 };
 use crate::network::NetworkInfo;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NetworkWithNetNsConfig {
     pub network_model: String,
     pub netns_path: String,
     pub queues: usize,
     pub network_created: bool,
+    pub vhost_user_net_sockets: Vec<String>,
+    // the vhost-user sockets are only looked for if the hypervisor supports them
+    pub vhost_user_net_supported: bool,
 }
 
 struct NetworkWithNetnsInner {
     netns_path: String,
     entity_list: Vec<NetworkEntity>,
     network_created: bool,
+    // used to create the endpoints of the links added later
+    config: NetworkWithNetNsConfig,
+    next_idx: u32,
+    // the links attached before the network was restored, there is no endpoint to detach them
+    restored_links: Vec<String>,
+    // the entities whose link is gone, waiting for the agent to release their interface
+    removed: Vec<NetworkEntity>,
 }
 
 impl NetworkWithNetnsInner {
@@ -59,10 +75,26 @@ impl NetworkWithNetnsInner {
         };
         Ok(Self {
             netns_path: config.netns_path.to_string(),
+            next_idx: entity_list.len() as u32,
             entity_list,
             network_created: config.network_created,
+            config: config.clone(),
+            restored_links: vec![],
+            removed: vec![],
         })
     }
+
+    fn restore(config: &NetworkWithNetNsConfig, links: Vec<String>) -> Self {
+        Self {
+            netns_path: config.netns_path.to_string(),
+            entity_list: vec![],
+            network_created: config.network_created,
+            config: config.clone(),
+            next_idx: links.len() as u32,
+            restored_links: links,
+            removed: vec![],
+        }
+    }
 }
 
 pub(crate) struct NetworkWithNetns {
@@ -75,6 +107,12 @@ impl NetworkWithNetns {
             inner: Arc::new(RwLock::new(NetworkWithNetnsInner::new(config).await?)),
         })
     }
+
+    pub(crate) fn restore(config: &NetworkWithNetNsConfig, links: Vec<String>) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(NetworkWithNetnsInner::restore(config, links))),
+        }
+    }
 }
 
 #[async_trait]
@@ -128,6 +166,120 @@ impl Network for NetworkWithNetns {
         Some(endpoint)
     }
 
+    async fn sync(&self, h: &dyn Hypervisor) -> Result<NetworkChanges> {
+        // the lock isn't held while the new endpoints are attached, which can take a while
+        let (netns_path, config, mut known) = {
+            let inner = self.inner.read().await;
+            let mut known = inner.restored_links.clone();
+            for e in &inner.entity_list {
+                known.push(e.endpoint.name().await);
+            }
+            (inner.netns_path.clone(), inner.config.clone(), known)
+        };
+
+        let _netns_guard = netns::NetnsGuard::new(&netns_path).context("net netns guard")?;
+        let (connection, handle, _) = rtnetlink::new_connection().context("new connection")?;
+        let thread_handler = tokio::spawn(connection);
+        defer!({
+            thread_handler.abort();
+        });
+
+        let links = get_links(&handle).await.context("get links")?;
+        let has_link = |name: &str| links.iter().any(|l| l.attrs().name == name);
+        let mut changes = NetworkChanges::default();
+
+        // the endpoints whose link is gone are only detached by detach_removed, once the
+        // agent released their interface
+        {
+            let mut inner = self.inner.write().await;
+            inner.restored_links.retain(|name| {
+                if has_link(name) {
+                    return true;
+                }
+                warn!(
+                    sl!(),
+                    "network interface {} removed, it can't be unplugged after a restore", name
+                );
+                false
+            });
+            for e in std::mem::take(&mut inner.entity_list) {
+                let name = e.endpoint.name().await;
+                if has_link(&name) {
+                    inner.entity_list.push(e);
+                    continue;
+                }
+
+                info!(sl!(), "network interface {} removed", &name);
+                match e.network_info.interface().await {
+                    Ok(interface) => {
+                        changes.removed.push(interface);
+                        inner.removed.push(e);
+                    }
+                    // the agent can't be told about it, unplug it right away
+                    Err(err) => {
+                        warn!(sl!(), "failed to get interface {}: {:?}", &name, err);
+                        if let Err(err) = e.endpoint.detach(h).await {
+                            warn!(sl!(), "failed to detach endpoint {}: {:?}", &name, err);
+                        }
+                    }
+                }
+            }
+        }
+
+        // hotplug the endpoints of the new links, a link failing doesn't stop the others
+        for link in links.iter() {
+            let attrs = link.attrs();
+            // the network plugin brings the link up once it's configured
+            if known.contains(&attrs.name) || (attrs.flags & libc::IFF_UP as u32) == 0 {
+                continue;
+            }
+            known.push(attrs.name.clone());
+
+            info!(sl!(), "network interface {} added", &attrs.name);
+            let idx = {
+                let mut inner = self.inner.write().await;
+                inner.next_idx += 1;
+                inner.next_idx - 1
+            };
+            match attach_link(&handle, link.as_ref(), idx, &config, h).await {
+                Ok((entity, interface, mut neighs)) => {
+                    changes.added.push(interface);
+                    changes.neighs.append(&mut neighs);
+                    self.inner.write().await.entity_list.push(entity);
+                }
+                Err(err) => warn!(
+                    sl!(),
+                    "failed to hotplug network interface {}: {:?}", &attrs.name, err
+                ),
+            }
+        }
+
+        Ok(changes)
+    }
+
+    async fn detach_removed(&self, h: &dyn Hypervisor) -> Result<()> {
+        let (netns_path, removed) = {
+            let mut inner = self.inner.write().await;
+            (inner.netns_path.clone(), std::mem::take(&mut inner.removed))
+        };
+        if removed.is_empty() {
+            return Ok(());
+        }
+
+        let _netns_guard = netns::NetnsGuard::new(&netns_path).context("net netns guard")?;
+        for e in removed {
+            if let Err(err) = e.endpoint.detach(h).await {
+                warn!(
+                    sl!(),
+                    "failed to detach endpoint {}: {:?}",
+                    e.endpoint.name().await,
+                    err
+                );
+            }
+        }
+        Ok(())
+    }
+
     async fn remove(&self, h: &dyn Hypervisor) -> Result<()> {
         let inner = self.inner.read().await;
         // The network namespace would have been deleted at this point
@@ -138,7 +290,7 @@ impl Network for NetworkWithNetns {
         {
             let _netns_guard =
                 netns::NetnsGuard::new(&inner.netns_path).context("net netns guard")?;
-            for e in &inner.entity_list {
+            for e in inner.entity_list.iter().chain(inner.removed.iter()) {
                 e.endpoint.detach(h).await.context("detach")?;
             }
         }
@@ -166,17 +318,10 @@ async fn get_entity_from_netns(config: &NetworkWithNetNsConfig) -> Result<Vec<Ne
         thread_handler.abort();
     });
 
-    let mut links = handle.link().get().execute();
+    let links = get_links(&handle).await.context("get links")?;
 
     let idx = AtomicU32::new(0);
-    while let Some(link) = links.try_next().await? {
-        let link = link::get_link_from_message(link);
-        let attrs = link.attrs();
-
-        if (attrs.flags & libc::IFF_LOOPBACK as u32) != 0 {
-            continue;
-        }
-
+    for link in links {
         let idx = idx.fetch_add(1, Ordering::Relaxed);
         let (endpoint, network_info) = create_endpoint(&handle, link.as_ref(), idx, config)
             .await
@@ -188,6 +333,46 @@ async fn get_entity_from_netns(config: &NetworkWithNetNsConfig) -> Result<Vec<Ne
     Ok(entity_list)
 }
 
+// Create and attach the endpoint of a link added after the network was set up.
+async fn attach_link(
+    handle: &rtnetlink::Handle,
+    link: &dyn link::Link,
+    idx: u32,
+    config: &NetworkWithNetNsConfig,
+    h: &dyn Hypervisor,
+) -> Result<(NetworkEntity, agent::Interface, Vec<agent::ARPNeighbor>)> {
+    let (endpoint, network_info) = create_endpoint(handle, link, idx, config)
+        .await
+        .context("create endpoint")?;
+    endpoint.attach(h).await.context("attach")?;
+
+    let interface = network_info.interface().await.context("interface")?;
+    let neighs = network_info.neighs().await.context("neighs")?;
+    Ok((
+        NetworkEntity::new(endpoint, network_info),
+        interface,
+        neighs,
+    ))
+}
+
+// Get the links of the netns which are connected to the VM, leaving out the
+// loopback and the links created by kata.
+async fn get_links(handle: &rtnetlink::Handle) -> Result<Vec<Box<dyn link::Link>>> {
+    let mut link_msg_list = handle.link().get().execute();
+
+    let mut links = vec![];
+    while let Some(link) = link_msg_list.try_next().await? {
+        let link = link::get_link_from_message(link);
+        let attrs = link.attrs();
+
+        if (attrs.flags & libc::IFF_LOOPBACK as u32) != 0 || is_kata_link(&attrs.name) {
+            continue;
+        }
+        links.push(link);
+    }
+    Ok(links)
+}
+
 async fn create_endpoint(
     handle: &rtnetlink::Handle,
     link: &dyn link::Link,
@@ -199,7 +384,24 @@ async fn create_endpoint(
         .unwrap();
     let attrs = link.attrs();
     let link_type = link.r#type();
-    let endpoint: Arc<dyn Endpoint> = if is_physical_iface(&attrs.name)? {
+    let vhost_user_socket = if config.vhost_user_net_supported {
+        let vhost_user_sockets = parse_vhost_user_sockets(&config.vhost_user_net_sockets);
+        let addrs = get_link_addresses(handle, attrs.index)
+            .await
+            .context("get link addresses")?;
+        vhost_user_socket_path(&vhost_user_sockets, &attrs.name, &addrs)
+    } else {
+        None
+    };
+    let endpoint: Arc<dyn Endpoint> = if let Some(socket_path) = vhost_user_socket {
+        info!(
+            sl!(),
+            "vhost-user socket {} found for network interface {}", &socket_path, &attrs.name
+        );
+        let t = VhostUserEndpoint::new(&attrs.name, &attrs.hardware_addr, &socket_path)
+            .context("new vhost-user endpoint")?;
+        Arc::new(t)
+    } else if is_physical_iface(&attrs.name)? {
         info!(
             sl!(),
             "physical network interface found: {} {:?}",
@@ -251,6 +453,12 @@ async fn create_endpoint(
                 .context("macvlan endpoint")?;
                 Arc::new(ret)
             }
+            "tuntap" => {
+                let ret = TapEndpoint::new(handle, &attrs.name)
+                    .await
+                    .context("tap endpoint")?;
+                Arc::new(ret)
+            }
             _ => return Err(anyhow!("unsupported link type: {}", link_type)),
         }
     };
@@ -266,6 +474,21 @@ async fn create_endpoint(
     Ok((endpoint, network_info))
 }
 
+async fn get_link_addresses(handle: &rtnetlink::Handle, index: u32) -> Result<Vec<IpAddr>> {
+    let mut addr_msg_list = handle
+        .address()
+        .get()
+        .set_link_index_filter(index)
+        .execute();
+
+    let mut addrs = vec![];
+    while let Some(addr_msg) = addr_msg_list.try_next().await? {
+        let addr = Address::try_from(addr_msg).context("get address from msg")?;
+        addrs.push(addr.addr);
+    }
+    Ok(addrs)
+}
+
 fn is_physical_iface(name: &str) -> Result<bool> {
     if name == "lo" {
         return Ok(false);
@@ -34,7 +34,8 @@ impl TryFrom<AddressMessage> for Address {
             peer: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
             broadcast: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
             label: String::default(),
-            flags: 0,
+            // IFA_FLAGS supersedes the 8 bits flags of the header when present
+            flags: header.flags as u32,
             scope: header.scope,
             perfix_len: header.prefix_len,
             prefered_lft: 0,
@@ -115,6 +115,11 @@ fn link_info(mut infos: Vec<Info>) -> Box<dyn Link> {
                         link = Some(Box::new(Vlan::default()));
                     }
                 }
+                InfoKind::MacVtap => {
+                    if link.is_none() {
+                        link = Some(Box::new(MacVtap::default()));
+                    }
+                }
                 InfoKind::Bridge => {
                     if link.is_none() {
                         link = Some(Box::new(Bridge::default()));
@@ -142,6 +147,9 @@ fn link_info(mut infos: Vec<Info>) -> Box<dyn Link> {
                 InfoData::Vlan(_) => {
                     link = Some(Box::new(Vlan::default()));
                 }
+                InfoData::MacVtap(_) => {
+                    link = Some(Box::new(MacVtap::default()));
+                }
                 InfoData::Bridge(ibs) => {
                     link = Some(Box::new(parse_bridge(ibs)));
                 }
@@ -217,6 +225,7 @@ define_and_impl_network_dev!("veth", Veth);
 define_and_impl_network_dev!("ipvlan", IpVlan);
 define_and_impl_network_dev!("macvlan", MacVlan);
 define_and_impl_network_dev!("vlan", Vlan);
+define_and_impl_network_dev!("macvtap", MacVtap);
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
 pub struct Bridge {
@@ -7,6 +7,7 @@
 pub(crate) mod address;
 pub(crate) mod link;
 pub(crate) mod netns;
+pub(crate) mod netns_watcher;
 
 use anyhow::{anyhow, Result};
 
@@ -0,0 +1,111 @@
+// Copyright (c) 2019-2022 Alibaba Cloud
+// Copyright (c) 2019-2022 Ant Group
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use futures::{channel::mpsc::UnboundedReceiver, StreamExt};
+use netlink_packet_route::{NetlinkMessage, NetlinkPayload, RtnlMessage};
+use netlink_sys::{AsyncSocket, SocketAddr};
+use rtnetlink::constants::{
+    RTMGRP_IPV4_IFADDR, RTMGRP_IPV4_ROUTE, RTMGRP_IPV6_IFADDR, RTMGRP_IPV6_ROUTE, RTMGRP_LINK,
+};
+use tokio::task::JoinHandle;
+
+use super::netns::NetnsGuard;
+
+// Network plugins configure a link with several netlink requests, wait for
+// them to settle before reporting the change.
+const SETTLE_TIME: Duration = Duration::from_millis(500);
+
+/// NetnsWatcher reports the link, address and route changes of a netns.
+pub struct NetnsWatcher {
+    connection: JoinHandle<()>,
+    messages: UnboundedReceiver<(NetlinkMessage<RtnlMessage>, SocketAddr)>,
+}
+
+impl NetnsWatcher {
+    pub fn new(netns_path: &str) -> Result<Self> {
+        // the netlink socket belongs to the netns it's created in, whichever
+        // thread it's used from later.
+        let (mut connection, _, messages) = {
+            let _netns_guard = NetnsGuard::new(netns_path).context("net netns guard")?;
+            rtnetlink::new_connection().context("new connection")?
+        };
+
+        let groups = RTMGRP_LINK
+            | RTMGRP_IPV4_IFADDR
+            | RTMGRP_IPV6_IFADDR
+            | RTMGRP_IPV4_ROUTE
+            | RTMGRP_IPV6_ROUTE;
+        connection
+            .socket_mut()
+            .socket_mut()
+            .bind(&SocketAddr::new(0, groups))
+            .context("bind netlink groups")?;
+
+        Ok(Self {
+            connection: tokio::spawn(connection),
+            messages,
+        })
+    }
+
+    /// Wait for the next batch of changes, returns None once the netlink
+    /// connection is closed.
+    pub async fn changed(&mut self) -> Option<()> {
+        loop {
+            let (msg, _) = self.messages.next().await?;
+            if is_change(&msg) {
+                break;
+            }
+        }
+
+        // drain the changes which follow closely
+        while let Ok(msg) = tokio::time::timeout(SETTLE_TIME, self.messages.next()).await {
+            msg?;
+        }
+
+        Some(())
+    }
+}
+
+impl Drop for NetnsWatcher {
+    fn drop(&mut self) {
+        self.connection.abort();
+    }
+}
+
+fn is_change(msg: &NetlinkMessage<RtnlMessage>) -> bool {
+    matches!(
+        &msg.payload,
+        NetlinkPayload::InnerMessage(
+            RtnlMessage::NewLink(_)
+                | RtnlMessage::DelLink(_)
+                | RtnlMessage::NewAddress(_)
+                | RtnlMessage::DelAddress(_)
+                | RtnlMessage::NewRoute(_)
+                | RtnlMessage::DelRoute(_)
+        )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use netlink_packet_route::{LinkMessage, NeighbourMessage};
+
+    use super::*;
+
+    #[test]
+    fn test_is_change() {
+        let link = NetlinkMessage::from(RtnlMessage::NewLink(LinkMessage::default()));
+        assert!(is_change(&link));
+        let link = NetlinkMessage::from(RtnlMessage::DelLink(LinkMessage::default()));
+        assert!(is_change(&link));
+
+        let neigh = NetlinkMessage::from(RtnlMessage::NewNeighbour(NeighbourMessage::default()));
+        assert!(!is_change(&neigh));
+    }
+}
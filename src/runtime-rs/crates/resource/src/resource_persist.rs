@@ -4,12 +4,36 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-use crate::network::EndpointState;
+use crate::network::{EndpointState, NetworkConfig};
 use serde::{Deserialize, Serialize};
 
 use crate::cgroups::cgroup_persist::CgroupState;
 #[derive(Serialize, Deserialize, Default)]
 pub struct ResourceState {
     pub endpoint: Vec<EndpointState>,
+    pub network_config: Option<NetworkConfig>,
     pub cgroup_state: Option<CgroupState>,
+    pub sandbox_bind_mounts: Option<Vec<String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restore_without_network_config() {
+        // the state saved before the network config was persisted
+        let state: ResourceState = serde_json::from_str(
+            r#"{"endpoint":[{"veth_endpoint":{"if_name":"eth0","network_qos":false}}]}"#,
+        )
+        .unwrap();
+        assert!(state.network_config.is_none());
+        assert_eq!(state.endpoint[0].if_name(), Some("eth0"));
+
+        let physical = EndpointState {
+            physical_endpoint: Some(Default::default()),
+            ..Default::default()
+        };
+        assert_eq!(physical.if_name(), None);
+    }
 }
@@ -4,6 +4,8 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
+mod sandbox_bind_mounts;
+pub use sandbox_bind_mounts::SandboxBindMounts;
 mod share_virtio_fs;
 pub use share_virtio_fs::rafs_mount;
 mod share_virtio_fs_inline;
@@ -0,0 +1,228 @@
+// Copyright (c) 2023 Kata Contributors
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Context, Result};
+use kata_sys_util::mount::{bind_mount_unchecked, bind_remount, umount_timeout};
+use kata_types::config::split_sandbox_bind_mount;
+
+use super::utils::{ensure_dir_exist, get_host_ro_shared_path, get_host_rw_shared_path};
+use super::{KATA_GUEST_SHARE_DIR, PASSTHROUGH_FS_DIR};
+
+/// Directory of the sandbox bind mounts, under the passthrough share dir.
+pub const SANDBOX_BIND_MOUNTS_DIR: &str = "sandbox-mounts";
+
+/// Host paths listed in `sandbox_bind_mounts`, shared with the guest for the whole sandbox life.
+///
+/// Each path is bind mounted to `<rw shared dir>/passthrough/sandbox-mounts/<file name>`, and
+/// shows up in the guest at
+/// `/run/kata-containers/shared/containers/passthrough/sandbox-mounts/<file name>`.
+#[derive(Debug, Clone)]
+pub struct SandboxBindMounts {
+    sid: String,
+    bind_mounts: Vec<String>,
+}
+
+impl SandboxBindMounts {
+    pub fn new(sid: &str, bind_mounts: Vec<String>) -> Self {
+        Self {
+            sid: sid.to_string(),
+            bind_mounts,
+        }
+    }
+
+    /// The configured entries, with their optional `:ro`/`:rw` suffix.
+    pub fn bind_mounts(&self) -> &[String] {
+        &self.bind_mounts
+    }
+
+    /// Guest path of a sandbox bind mount.
+    pub fn guest_path(host_path: &str) -> Result<PathBuf> {
+        Ok(Path::new(KATA_GUEST_SHARE_DIR)
+            .join(PASSTHROUGH_FS_DIR)
+            .join(SANDBOX_BIND_MOUNTS_DIR)
+            .join(file_name(host_path)?))
+    }
+
+    fn host_dir(&self, read_only: bool) -> PathBuf {
+        let shared_path = if read_only {
+            get_host_ro_shared_path(&self.sid)
+        } else {
+            get_host_rw_shared_path(&self.sid)
+        };
+        shared_path
+            .join(PASSTHROUGH_FS_DIR)
+            .join(SANDBOX_BIND_MOUNTS_DIR)
+    }
+
+    /// Bind mount all the entries into the shared directory, the shared directory has to be
+    /// created already.
+    pub fn setup(&self) -> Result<()> {
+        if self.bind_mounts.is_empty() {
+            return Ok(());
+        }
+
+        let rw_dir = self.host_dir(false);
+        ensure_dir_exist(&rw_dir)?;
+
+        if let Err(e) = self.do_setup() {
+            if let Err(err) = self.cleanup() {
+                warn!(sl!(), "failed to cleanup sandbox bind mounts: {:?}", err);
+            }
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    fn do_setup(&self) -> Result<()> {
+        self.check_file_names()?;
+
+        for bind in self.bind_mounts.iter() {
+            let (source, read_only) = split_sandbox_bind_mount(bind);
+            let name = file_name(source)?;
+
+            let host_dest = self.host_dir(false).join(&name);
+            bind_mount_unchecked(source, &host_dest, read_only)
+                .with_context(|| format!("bind mount {} to {:?}", source, &host_dest))?;
+
+            // bind mount remount event is not propagated to mount subtrees, so we have
+            // to remount the read only dir mount point directly.
+            if read_only {
+                let ro_dest = self.host_dir(true).join(&name);
+                bind_remount(&ro_dest, read_only)
+                    .with_context(|| format!("remount {:?} read only", &ro_dest))?;
+            }
+
+            info!(
+                sl!(),
+                "sandbox bind mount {} shared at {:?}",
+                bind,
+                Self::guest_path(source)?
+            );
+        }
+
+        Ok(())
+    }
+
+    // All entries share the same directory, two paths with the same file name would be mounted
+    // on top of each other.
+    fn check_file_names(&self) -> Result<()> {
+        let mut names = HashSet::new();
+        for bind in self.bind_mounts.iter() {
+            let (source, _) = split_sandbox_bind_mount(bind);
+            let name = file_name(source)?;
+            if !names.insert(name) {
+                return Err(anyhow!(
+                    "sandbox bind mount {} has the same file name as another one",
+                    source
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Unmount all the entries and remove their directory, going on after errors so that as
+    /// much as possible is cleaned up.
+    pub fn cleanup(&self) -> Result<()> {
+        if self.bind_mounts.is_empty() {
+            return Ok(());
+        }
+
+        let rw_dir = self.host_dir(false);
+        let mut result = Ok(());
+        for bind in self.bind_mounts.iter() {
+            let (source, _) = split_sandbox_bind_mount(bind);
+            let host_dest = rw_dir.join(file_name(source)?);
+            if !host_dest.exists() {
+                continue;
+            }
+
+            if let Err(e) = umount_timeout(&host_dest, 0) {
+                error!(
+                    sl!(),
+                    "failed to umount sandbox bind mount {:?}: {:?}", host_dest, e
+                );
+                result = Err(anyhow!("umount {:?}: {:?}", host_dest, e));
+                continue;
+            }
+            let removed = if host_dest.is_dir() {
+                fs::remove_dir(&host_dest)
+            } else {
+                fs::remove_file(&host_dest)
+            };
+            if let Err(e) = removed {
+                warn!(sl!(), "failed to remove {:?}: {:?}", host_dest, e);
+            }
+        }
+        result?;
+
+        // only empty mount points are left, never remove the content of a bind mount
+        if rw_dir.exists() {
+            fs::remove_dir(&rw_dir).with_context(|| format!("remove {:?}", &rw_dir))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn file_name(path: &str) -> Result<String> {
+    Path::new(path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .map(|n| n.to_string())
+        .ok_or_else(|| anyhow!("failed to get file name of {}", path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sandbox_bind_mounts_path() {
+        let mounts = SandboxBindMounts::new(
+            "sid",
+            vec!["/etc/shared:ro".to_string(), "/var/lib/conf:rw".to_string()],
+        );
+        assert_eq!(mounts.bind_mounts().len(), 2);
+        assert_eq!(
+            mounts.host_dir(false),
+            PathBuf::from(
+                "/run/kata-containers/shared/sandboxes/sid/rw/passthrough/sandbox-mounts"
+            )
+        );
+        assert_eq!(
+            SandboxBindMounts::guest_path("/etc/shared").unwrap(),
+            PathBuf::from(
+                "/run/kata-containers/shared/containers/passthrough/sandbox-mounts/shared"
+            )
+        );
+        assert!(SandboxBindMounts::guest_path("/").is_err());
+    }
+
+    #[test]
+    fn test_sandbox_bind_mounts_file_names() {
+        let mounts = SandboxBindMounts::new(
+            "sid",
+            vec!["/etc/shared:ro".to_string(), "/var/lib/conf:rw".to_string()],
+        );
+        assert!(mounts.check_file_names().is_ok());
+
+        let mounts = SandboxBindMounts::new(
+            "sid",
+            vec!["/etc/conf:ro".to_string(), "/var/lib/conf:rw".to_string()],
+        );
+        assert!(mounts.check_file_names().is_err());
+
+        let mounts = SandboxBindMounts::new("sid", vec!["/".to_string()]);
+        assert!(mounts.check_file_names().is_err());
+    }
+}
@@ -4,39 +4,448 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-use anyhow::Result;
+use std::{
+    collections::BTreeSet,
+    fs,
+    os::unix::fs::{FileTypeExt, MetadataExt},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{anyhow, Context, Result};
 use async_trait::async_trait;
+use hypervisor::{BlockConfig, BlockIoLimits, Device, Hypervisor, VM_ROOTFS_DRIVER_BLK};
+use nix::sys::stat::{major, minor};
+
+use super::{Volume, BIND};
+use crate::share_fs::DEFAULT_KATA_GUEST_SANDBOX_DIR;
+
+// Agent storage drivers of the block devices.
+const KATA_BLK_DEV_TYPE: &str = "blk";
+const KATA_MMIO_BLK_DEV_TYPE: &str = "mmioblk";
+
+// Directory of the guest sandbox the block devices are mounted at.
+const STORAGE_DIR: &str = "storage";
 
-use super::Volume;
+/// Indexes of the block devices attached to the VM. The guest names the
+/// virtio-mmio block devices after the lowest free index, so the indexes of
+/// the detached devices are reused.
+#[derive(Debug, Default)]
+pub(crate) struct BlockIndexes {
+    used: Mutex<BTreeSet<u64>>,
+}
 
-#[derive(Debug)]
-pub(crate) struct BlockVolume {}
+impl BlockIndexes {
+    fn allocate(&self, first: u64) -> Result<u64> {
+        let mut used = self
+            .used
+            .lock()
+            .map_err(|e| anyhow!("lock block indexes: {}", e))?;
+        let index = (first..)
+            .find(|i| !used.contains(i))
+            .ok_or_else(|| anyhow!("no free block index"))?;
+        used.insert(index);
+        Ok(index)
+    }
 
-/// BlockVolume: block device volume
+    fn release(&self, index: u64) {
+        if let Ok(mut used) = self.used.lock() {
+            used.remove(&index);
+        }
+    }
+}
+
+pub(crate) struct BlockVolume {
+    storage: agent::Storage,
+    mount: oci::Mount,
+    config: BlockConfig,
+    major: i64,
+    minor: i64,
+    hypervisor: Arc<dyn Hypervisor>,
+    indexes: Arc<BlockIndexes>,
+}
+
+/// BlockVolume: block device volume, attached to the VM by the hypervisor
 impl BlockVolume {
-    pub(crate) fn new(_m: &oci::Mount) -> Result<Self> {
-        Ok(Self {})
+    pub(crate) async fn new(
+        hypervisor: &Arc<dyn Hypervisor>,
+        indexes: &Arc<BlockIndexes>,
+        m: &oci::Mount,
+        block_io: Option<&oci::LinuxBlockIo>,
+    ) -> Result<Self> {
+        let metadata = fs::metadata(&m.source).context("get block device metadata")?;
+        let (major, minor) = (major(metadata.rdev()) as i64, minor(metadata.rdev()) as i64);
+        let io_limits = block_io
+            .map(|block_io| get_block_io_limits(block_io, major, minor))
+            .unwrap_or_default();
+
+        // the VM rootfs is the first block device when it's attached as a
+        // virtio-blk device
+        let hypervisor_config = hypervisor.hypervisor_config().await;
+        let first_index =
+            if hypervisor_config.blockdev_info.block_device_driver == VM_ROOTFS_DRIVER_BLK {
+                1
+            } else {
+                0
+            };
+        let index = indexes.allocate(first_index)?;
+
+        let config = BlockConfig {
+            id: format!("drive_{}", index),
+            path_on_host: m.source.clone(),
+            is_readonly: m.options.iter().any(|o| o == "ro"),
+            no_drop: false,
+            index,
+            io_limits,
+        };
+        let id = config.id.clone();
+        if let Err(err) = hypervisor.add_device(Device::Block(config.clone())).await {
+            indexes.release(index);
+            return Err(err).context("add block device");
+        }
+
+        // the agent finds the PCI devices by their PCI path, and the
+        // virtio-mmio devices by their name
+        let (driver, source) = match hypervisor.get_device_pci_path(&id).await {
+            Ok(Some(pci_path)) => (KATA_BLK_DEV_TYPE, pci_path),
+            Ok(None) => (
+                KATA_MMIO_BLK_DEV_TYPE,
+                format!("/dev/{}", get_virt_drive_name(index)),
+            ),
+            Err(err) => {
+                hypervisor.remove_device(Device::Block(config)).await.ok();
+                indexes.release(index);
+                return Err(err).context("get block device pci path");
+            }
+        };
+
+        // the device is mounted once in the guest sandbox, and bind mounted
+        // into the container from there
+        let mount_point = Path::new(DEFAULT_KATA_GUEST_SANDBOX_DIR)
+            .join(STORAGE_DIR)
+            .join(&id)
+            .display()
+            .to_string();
+        let storage = agent::Storage {
+            driver: driver.to_string(),
+            source,
+            fs_type: BIND.to_string(),
+            options: vec![BIND.to_string()],
+            mount_point: mount_point.clone(),
+            ..Default::default()
+        };
+        let mut mount = m.clone();
+        mount.source = mount_point;
+
+        Ok(Self {
+            storage,
+            mount,
+            config,
+            major,
+            minor,
+            hypervisor: hypervisor.clone(),
+            indexes: indexes.clone(),
+        })
     }
 }
 
 #[async_trait]
 impl Volume for BlockVolume {
     fn get_volume_mount(&self) -> anyhow::Result<Vec<oci::Mount>> {
-        todo!()
+        Ok(vec![self.mount.clone()])
     }
 
     fn get_storage(&self) -> Result<Vec<agent::Storage>> {
-        todo!()
+        Ok(vec![self.storage.clone()])
+    }
+
+    async fn update_block_io(&self, block_io: &oci::LinuxBlockIo) -> Result<()> {
+        let config = BlockConfig {
+            io_limits: get_block_io_limits(block_io, self.major, self.minor),
+            ..self.config.clone()
+        };
+
+        self.hypervisor
+            .update_device(Device::Block(config))
+            .await
+            .context("update block device")
     }
 
     async fn cleanup(&self) -> Result<()> {
-        // TODO: Clean up BlockVolume
-        warn!(sl!(), "Cleaning up BlockVolume is still unimplemented.");
+        self.hypervisor
+            .remove_device(Device::Block(self.config.clone()))
+            .await
+            .context("remove block device")?;
+        self.indexes.release(self.config.index);
         Ok(())
     }
 }
 
-pub(crate) fn is_block_volume(_m: &oci::Mount) -> bool {
-    // attach block device
-    false
+pub(crate) fn is_block_volume(m: &oci::Mount) -> bool {
+    if m.r#type != BIND {
+        return false;
+    }
+
+    fs::metadata(&m.source)
+        .map(|metadata| metadata.file_type().is_block_device())
+        .unwrap_or(false)
+}
+
+// Get the name the guest gives to the virtio block device of the index:
+// vda, ..., vdz, vdaa, ...
+fn get_virt_drive_name(index: u64) -> String {
+    let mut name = String::new();
+    let mut index = index as i64;
+    while index >= 0 {
+        name.insert(0, (b'a' + (index % 26) as u8) as char);
+        index = index / 26 - 1;
+    }
+
+    format!("vd{}", name)
+}
+
+/// Get the I/O limits of the block device major:minor from the throttle
+/// settings of the OCI blockIO resources, to be enforced by the hypervisor.
+///
+/// The hypervisor rate limits reads and writes together, so the lowest of
+/// the read and write limits applies to both.
+pub fn get_block_io_limits(block_io: &oci::LinuxBlockIo, major: i64, minor: i64) -> BlockIoLimits {
+    let rate = |devices: &[oci::LinuxThrottleDevice]| {
+        devices
+            .iter()
+            .rfind(|d| d.blk.major == major && d.blk.minor == minor)
+            .map(|d| d.rate)
+            .unwrap_or(0)
+    };
+
+    BlockIoLimits {
+        bytes_per_sec: lowest_limit(
+            rate(&block_io.throttle_read_bps_device),
+            rate(&block_io.throttle_write_bps_device),
+        ),
+        ops_per_sec: lowest_limit(
+            rate(&block_io.throttle_read_iops_device),
+            rate(&block_io.throttle_write_iops_device),
+        ),
+    }
+}
+
+// 0 means unlimited
+fn lowest_limit(a: u64, b: u64) -> u64 {
+    match (a, b) {
+        (0, b) => b,
+        (a, 0) => a,
+        (a, b) => a.min(b),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use hypervisor::{hypervisor_persist::HypervisorState, VcpuThreadIds};
+    use kata_types::{
+        capabilities::Capabilities, config::hypervisor::Hypervisor as HypervisorConfig,
+    };
+    use nix::sys::stat::{makedev, mknod, Mode, SFlag};
+    use tempfile::tempdir;
+    use test_utils::skip_if_not_root;
+
+    // Records the devices instead of attaching them to a VM.
+    #[derive(Default)]
+    pub(crate) struct MockHypervisor {
+        pub(crate) added: Mutex<Vec<Device>>,
+        pub(crate) updated: Mutex<Vec<Device>>,
+    }
+
+    #[async_trait]
+    impl Hypervisor for MockHypervisor {
+        async fn prepare_vm(&self, _id: &str, _netns: Option<String>) -> Result<()> {
+            Ok(())
+        }
+        async fn start_vm(&self, _timeout: i32) -> Result<()> {
+            Ok(())
+        }
+        async fn stop_vm(&self) -> Result<()> {
+            Ok(())
+        }
+        async fn pause_vm(&self) -> Result<()> {
+            Ok(())
+        }
+        async fn save_vm(&self) -> Result<()> {
+            Ok(())
+        }
+        async fn resume_vm(&self) -> Result<()> {
+            Ok(())
+        }
+        async fn add_device(&self, device: Device) -> Result<()> {
+            self.added.lock().unwrap().push(device);
+            Ok(())
+        }
+        async fn remove_device(&self, _device: Device) -> Result<()> {
+            Ok(())
+        }
+        async fn update_device(&self, device: Device) -> Result<()> {
+            self.updated.lock().unwrap().push(device);
+            Ok(())
+        }
+        async fn get_device_pci_path(&self, _id: &str) -> Result<Option<String>> {
+            Ok(None)
+        }
+        async fn get_agent_socket(&self) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn disconnect(&self) {}
+        async fn hypervisor_config(&self) -> HypervisorConfig {
+            HypervisorConfig::default()
+        }
+        async fn get_thread_ids(&self) -> Result<VcpuThreadIds> {
+            Ok(VcpuThreadIds::default())
+        }
+        async fn get_pids(&self) -> Result<Vec<u32>> {
+            Ok(vec![])
+        }
+        async fn get_vmm_master_tid(&self) -> Result<u32> {
+            Ok(0)
+        }
+        async fn get_ns_path(&self) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn cleanup(&self) -> Result<()> {
+            Ok(())
+        }
+        async fn check(&self) -> Result<()> {
+            Ok(())
+        }
+        async fn get_jailer_root(&self) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn save_state(&self) -> Result<HypervisorState> {
+            Ok(HypervisorState::default())
+        }
+        async fn capabilities(&self) -> Result<Capabilities> {
+            Ok(Capabilities::default())
+        }
+    }
+
+    // Creates the loop0 block device node at path.
+    pub(crate) fn mknod_block_device(path: &Path) {
+        mknod(
+            path,
+            SFlag::S_IFBLK,
+            Mode::from_bits_truncate(0o600),
+            makedev(7, 0),
+        )
+        .unwrap();
+    }
+
+    fn throttle(major: i64, minor: i64, rate: u64) -> oci::LinuxThrottleDevice {
+        oci::LinuxThrottleDevice {
+            blk: oci::LinuxBlockIoDevice { major, minor },
+            rate,
+        }
+    }
+
+    #[test]
+    fn test_get_block_io_limits() {
+        let block_io = oci::LinuxBlockIo {
+            throttle_read_bps_device: vec![throttle(8, 0, 2_097_152), throttle(8, 16, 4096)],
+            throttle_write_bps_device: vec![throttle(8, 0, 1_048_576)],
+            throttle_write_iops_device: vec![throttle(8, 0, 300)],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            get_block_io_limits(&block_io, 8, 0),
+            BlockIoLimits {
+                bytes_per_sec: 1_048_576,
+                ops_per_sec: 300,
+            }
+        );
+        assert_eq!(
+            get_block_io_limits(&block_io, 8, 16),
+            BlockIoLimits {
+                bytes_per_sec: 4096,
+                ops_per_sec: 0,
+            }
+        );
+        assert!(get_block_io_limits(&block_io, 253, 0).is_unlimited());
+        assert!(get_block_io_limits(&oci::LinuxBlockIo::default(), 8, 0).is_unlimited());
+    }
+
+    #[test]
+    fn test_get_virt_drive_name() {
+        assert_eq!(get_virt_drive_name(0), "vda");
+        assert_eq!(get_virt_drive_name(25), "vdz");
+        assert_eq!(get_virt_drive_name(26), "vdaa");
+        assert_eq!(get_virt_drive_name(701), "vdzz");
+        assert_eq!(get_virt_drive_name(702), "vdaaa");
+    }
+
+    #[test]
+    fn test_block_indexes() {
+        let indexes = BlockIndexes::default();
+
+        assert_eq!(indexes.allocate(1).unwrap(), 1);
+        assert_eq!(indexes.allocate(1).unwrap(), 2);
+        assert_eq!(indexes.allocate(1).unwrap(), 3);
+
+        // the guest reuses the name of a detached device
+        indexes.release(2);
+        assert_eq!(indexes.allocate(1).unwrap(), 2);
+        assert_eq!(indexes.allocate(0).unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_block_volume_update_block_io() {
+        skip_if_not_root!();
+
+        let dir = tempdir().unwrap();
+        let source = dir.path().join("loop0");
+        mknod_block_device(&source);
+
+        let mock = Arc::new(MockHypervisor::default());
+        let hypervisor: Arc<dyn Hypervisor> = mock.clone();
+        let m = oci::Mount {
+            destination: "/data".to_string(),
+            r#type: BIND.to_string(),
+            source: source.display().to_string(),
+            options: vec!["rbind".to_string()],
+        };
+        let block_io = oci::LinuxBlockIo {
+            throttle_read_bps_device: vec![throttle(7, 0, 4096)],
+            ..Default::default()
+        };
+        let volume = BlockVolume::new(
+            &hypervisor,
+            &Arc::new(BlockIndexes::default()),
+            &m,
+            Some(&block_io),
+        )
+        .await
+        .unwrap();
+
+        let block_io = oci::LinuxBlockIo {
+            throttle_read_bps_device: vec![throttle(7, 0, 1_048_576)],
+            throttle_write_iops_device: vec![throttle(7, 0, 100), throttle(8, 0, 10)],
+            ..Default::default()
+        };
+        volume.update_block_io(&block_io).await.unwrap();
+
+        let updated = mock.updated.lock().unwrap();
+        assert_eq!(updated.len(), 1);
+        match &updated[0] {
+            Device::Block(config) => {
+                assert_eq!(config.id, "drive_0");
+                assert_eq!(config.path_on_host, m.source);
+                assert_eq!(
+                    config.io_limits,
+                    BlockIoLimits {
+                        bytes_per_sec: 1_048_576,
+                        ops_per_sec: 100,
+                    }
+                );
+            }
+            d => panic!("unexpected device {}", d),
+        }
+    }
 }
@@ -12,11 +12,14 @@ mod shm_volume;
 use async_trait::async_trait;
 
 use anyhow::{Context, Result};
+use hypervisor::Hypervisor;
 use std::{sync::Arc, vec::Vec};
 use tokio::sync::RwLock;
 
 use crate::share_fs::ShareFs;
 
+pub use self::block_volume::get_block_io_limits;
+use self::block_volume::BlockIndexes;
 use self::hugepage::{get_huge_page_limits_map, get_huge_page_option};
 
 const BIND: &str = "bind";
@@ -26,11 +29,17 @@ pub trait Volume: Send + Sync {
     fn get_volume_mount(&self) -> Result<Vec<oci::Mount>>;
     fn get_storage(&self) -> Result<Vec<agent::Storage>>;
     async fn cleanup(&self) -> Result<()>;
+
+    // Update the I/O limits of the block devices of the volume.
+    async fn update_block_io(&self, _block_io: &oci::LinuxBlockIo) -> Result<()> {
+        Ok(())
+    }
 }
 
 #[derive(Default)]
 pub struct VolumeResourceInner {
     volumes: Vec<Arc<dyn Volume>>,
+    block_indexes: Arc<BlockIndexes>,
 }
 
 #[derive(Default)]
@@ -46,11 +55,23 @@ impl VolumeResource {
     pub async fn handler_volumes(
         &self,
         share_fs: &Option<Arc<dyn ShareFs>>,
+        hypervisor: &Arc<dyn Hypervisor>,
         cid: &str,
         spec: &oci::Spec,
     ) -> Result<Vec<Arc<dyn Volume>>> {
         let mut volumes: Vec<Arc<dyn Volume>> = vec![];
         let oci_mounts = &spec.mounts;
+        let block_io = spec
+            .linux
+            .as_ref()
+            .and_then(|linux| linux.resources.as_ref())
+            .and_then(|resources| resources.block_io.as_ref());
+        let block_indexes = self.inner.read().await.block_indexes.clone();
+        let disable_block_device_use = hypervisor
+            .hypervisor_config()
+            .await
+            .blockdev_info
+            .disable_block_device_use;
         // handle mounts
         for m in oci_mounts {
             let volume: Arc<dyn Volume> = if shm_volume::is_shim_volume(m) {
@@ -59,6 +80,14 @@ impl VolumeResource {
                     shm_volume::ShmVolume::new(m, shm_size)
                         .with_context(|| format!("new shm volume {:?}", m))?,
                 )
+            } else if !disable_block_device_use && block_volume::is_block_volume(m) {
+                // the host block devices are hotplugged to the VM rather
+                // than shared through the shared filesystem
+                Arc::new(
+                    block_volume::BlockVolume::new(hypervisor, &block_indexes, m, block_io)
+                        .await
+                        .with_context(|| format!("new block volume {:?}", m))?,
+                )
             } else if share_fs_volume::is_share_fs_volume(m) {
                 Arc::new(
                     share_fs_volume::ShareFsVolume::new(share_fs, m, cid)
@@ -76,11 +105,6 @@ impl VolumeResource {
                     hugepage::Hugepage::new(m, hugepage_limits, options)
                         .with_context(|| format!("handle hugepages {:?}", m))?,
                 )
-            } else if block_volume::is_block_volume(m) {
-                Arc::new(
-                    block_volume::BlockVolume::new(m)
-                        .with_context(|| format!("new block volume {:?}", m))?,
-                )
             } else if is_skip_volume(m) {
                 info!(sl!(), "skip volume {:?}", m);
                 continue;
@@ -116,3 +140,50 @@ fn is_skip_volume(_m: &oci::Mount) -> bool {
     // TODO: support volume check
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::block_volume::tests::{mknod_block_device, MockHypervisor};
+    use super::*;
+    use hypervisor::Device;
+    use tempfile::tempdir;
+    use test_utils::skip_if_not_root;
+
+    #[tokio::test]
+    async fn test_handler_volumes_block_device() {
+        skip_if_not_root!();
+
+        let dir = tempdir().unwrap();
+        let source = dir.path().join("loop0");
+        mknod_block_device(&source);
+
+        let mock = Arc::new(MockHypervisor::default());
+        let hypervisor: Arc<dyn Hypervisor> = mock.clone();
+        let spec = oci::Spec {
+            mounts: vec![oci::Mount {
+                destination: "/data".to_string(),
+                r#type: BIND.to_string(),
+                source: source.display().to_string(),
+                options: vec!["rbind".to_string()],
+            }],
+            ..Default::default()
+        };
+
+        let volumes = VolumeResource::new()
+            .handler_volumes(&None, &hypervisor, "cid", &spec)
+            .await
+            .unwrap();
+
+        // The block device is attached to the VM instead of being shared
+        // through the shared filesystem.
+        assert_eq!(volumes.len(), 1);
+        let storages = volumes[0].get_storage().unwrap();
+        assert_eq!(storages.len(), 1);
+        assert_eq!(storages[0].driver, "mmioblk");
+        assert_eq!(storages[0].source, "/dev/vda");
+
+        let added = mock.added.lock().unwrap();
+        assert_eq!(added.len(), 1);
+        assert!(matches!(&added[0], Device::Block(c) if c.path_on_host == spec.mounts[0].source));
+    }
+}
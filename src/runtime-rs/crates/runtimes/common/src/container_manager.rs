@@ -37,4 +37,6 @@ pub trait ContainerManager: Send + Sync {
     async fn pid(&self) -> Result<PID>;
     async fn need_shutdown_sandbox(&self, req: &ShutdownRequest) -> bool;
     async fn is_sandbox_container(&self, process_id: &ContainerProcess) -> bool;
+    /// Log the state of the containers, their processes and the resources, for debugging.
+    async fn dump(&self);
 }
@@ -11,5 +11,5 @@ pub mod message;
 mod runtime_handler;
 pub use runtime_handler::{RuntimeHandler, RuntimeInstance};
 mod sandbox;
-pub use sandbox::{Sandbox, SandboxNetworkEnv};
+pub use sandbox::{Sandbox, SandboxDnsFiles, SandboxNetworkEnv};
 pub mod types;
@@ -13,11 +13,19 @@ pub struct SandboxNetworkEnv {
     pub network_created: bool,
 }
 
+/// The host files of the name resolution of the sandbox, usually prepared by
+/// the container manager and mounted into the sandbox spec.
+#[derive(Clone, Debug, Default)]
+pub struct SandboxDnsFiles {
+    pub resolv_conf: Option<String>,
+    pub hosts: Option<String>,
+}
+
 #[async_trait]
 pub trait Sandbox: Send + Sync {
     async fn start(
         &self,
-        dns: Vec<String>,
+        dns: SandboxDnsFiles,
         spec: &oci::Spec,
         state: &oci::State,
         network_env: SandboxNetworkEnv,
@@ -7,14 +7,14 @@
 use std::convert::From;
 
 use containerd_shim_protos::cgroups::metrics;
-use protobuf::Message;
+use protobuf::{Message, MessageField};
+use protocols::cgroups_v2;
 
 use super::{StatsInfo, StatsInfoValue};
 
 // TODO: trans from agent proto?
 impl From<Option<agent::StatsContainerResponse>> for StatsInfo {
     fn from(c_stats: Option<agent::StatsContainerResponse>) -> Self {
-        let mut metric = metrics::Metrics::new();
         let stats = match c_stats {
             None => {
                 return StatsInfo { value: None };
@@ -22,176 +22,319 @@ impl From<Option<agent::StatsContainerResponse>> for StatsInfo {
             Some(stats) => stats,
         };
 
-        if let Some(cg_stats) = stats.cgroup_stats {
-            if let Some(cpu) = cg_stats.cpu_stats {
-                // set protobuf cpu stat
-                let mut p_cpu = metrics::CPUStat::new();
-                if let Some(usage) = cpu.cpu_usage {
-                    let mut p_usage = metrics::CPUUsage::new();
-                    p_usage.set_total(usage.total_usage);
-                    p_usage.set_per_cpu(usage.percpu_usage);
-                    p_usage.set_kernel(usage.usage_in_kernelmode);
-                    p_usage.set_user(usage.usage_in_usermode);
-
-                    // set protobuf cpu usage
-                    p_cpu.set_usage(p_usage);
-                }
+        // the agent collects the stats in the layout of the guest cgroup
+        // version, which containerd decodes by type url. Older agents don't
+        // report it, assume the guest runs the same version as the host then.
+        let cgroup_version = stats
+            .cgroup_stats
+            .as_ref()
+            .map(|s| s.cgroup_version)
+            .unwrap_or_default();
+        let v2 = match cgroup_version {
+            1 => false,
+            2 => true,
+            _ => cgroups_rs::hierarchies::is_cgroup2_unified_mode(),
+        };
+        if v2 {
+            stats_to_metrics_v2(stats)
+        } else {
+            stats_to_metrics_v1(stats)
+        }
+    }
+}
 
-                if let Some(throttle) = cpu.throttling_data {
-                    let mut p_throttle = metrics::Throttle::new();
-                    p_throttle.set_periods(throttle.periods);
-                    p_throttle.set_throttled_time(throttle.throttled_time);
-                    p_throttle.set_throttled_periods(throttle.throttled_periods);
+fn stats_to_metrics_v1(stats: agent::StatsContainerResponse) -> StatsInfo {
+    let mut metric = metrics::Metrics::new();
 
-                    // set protobuf cpu usage
-                    p_cpu.set_throttling(p_throttle);
-                }
+    if let Some(cg_stats) = stats.cgroup_stats {
+        if let Some(cpu) = cg_stats.cpu_stats {
+            // set protobuf cpu stat
+            let mut p_cpu = metrics::CPUStat::new();
+            if let Some(usage) = cpu.cpu_usage {
+                let mut p_usage = metrics::CPUUsage::new();
+                p_usage.set_total(usage.total_usage);
+                p_usage.set_per_cpu(usage.percpu_usage);
+                p_usage.set_kernel(usage.usage_in_kernelmode);
+                p_usage.set_user(usage.usage_in_usermode);
 
-                metric.set_cpu(p_cpu);
+                // set protobuf cpu usage
+                p_cpu.set_usage(p_usage);
             }
 
-            if let Some(m_stats) = cg_stats.memory_stats {
-                let mut p_m = metrics::MemoryStat::new();
-                p_m.set_cache(m_stats.cache);
-                // memory usage
-                if let Some(m_data) = m_stats.usage {
-                    let mut p_m_entry = metrics::MemoryEntry::new();
-                    p_m_entry.set_usage(m_data.usage);
-                    p_m_entry.set_limit(m_data.limit);
-                    p_m_entry.set_failcnt(m_data.failcnt);
-                    p_m_entry.set_max(m_data.max_usage);
-
-                    p_m.set_usage(p_m_entry);
-                }
-                // memory swap_usage
-                if let Some(m_data) = m_stats.swap_usage {
-                    let mut p_m_entry = metrics::MemoryEntry::new();
-                    p_m_entry.set_usage(m_data.usage);
-                    p_m_entry.set_limit(m_data.limit);
-                    p_m_entry.set_failcnt(m_data.failcnt);
-                    p_m_entry.set_max(m_data.max_usage);
-
-                    p_m.set_swap(p_m_entry);
-                }
-                // memory kernel_usage
-                if let Some(m_data) = m_stats.kernel_usage {
-                    let mut p_m_entry = metrics::MemoryEntry::new();
-                    p_m_entry.set_usage(m_data.usage);
-                    p_m_entry.set_limit(m_data.limit);
-                    p_m_entry.set_failcnt(m_data.failcnt);
-                    p_m_entry.set_max(m_data.max_usage);
-
-                    p_m.set_kernel(p_m_entry);
-                }
+            if let Some(throttle) = cpu.throttling_data {
+                let mut p_throttle = metrics::Throttle::new();
+                p_throttle.set_periods(throttle.periods);
+                p_throttle.set_throttled_time(throttle.throttled_time);
+                p_throttle.set_throttled_periods(throttle.throttled_periods);
 
-                for (k, v) in m_stats.stats {
-                    match k.as_str() {
-                        "dirty" => p_m.set_dirty(v),
-                        "rss" => p_m.set_rss(v),
-                        "rss_huge" => p_m.set_rss_huge(v),
-                        "mapped_file" => p_m.set_mapped_file(v),
-                        "writeback" => p_m.set_writeback(v),
-                        "pg_pg_in" => p_m.set_pg_pg_in(v),
-                        "pg_pg_out" => p_m.set_pg_pg_out(v),
-                        "pg_fault" => p_m.set_pg_fault(v),
-                        "pg_maj_fault" => p_m.set_pg_maj_fault(v),
-                        "inactive_file" => p_m.set_inactive_file(v),
-                        "inactive_anon" => p_m.set_inactive_anon(v),
-                        "active_file" => p_m.set_active_file(v),
-                        "unevictable" => p_m.set_unevictable(v),
-                        "hierarchical_memory_limit" => p_m.set_hierarchical_memory_limit(v),
-                        "hierarchical_swap_limit" => p_m.set_hierarchical_swap_limit(v),
-                        "total_cache" => p_m.set_total_cache(v),
-                        "total_rss" => p_m.set_total_rss(v),
-                        "total_mapped_file" => p_m.set_total_mapped_file(v),
-                        "total_dirty" => p_m.set_total_dirty(v),
-
-                        "total_pg_pg_in" => p_m.set_total_pg_pg_in(v),
-                        "total_pg_pg_out" => p_m.set_total_pg_pg_out(v),
-                        "total_pg_fault" => p_m.set_total_pg_fault(v),
-                        "total_pg_maj_fault" => p_m.set_total_pg_maj_fault(v),
-                        "total_inactive_file" => p_m.set_total_inactive_file(v),
-                        "total_inactive_anon" => p_m.set_total_inactive_anon(v),
-                        "total_active_file" => p_m.set_total_active_file(v),
-                        "total_unevictable" => p_m.set_total_unevictable(v),
-                        _ => (),
-                    }
-                }
-                metric.set_memory(p_m);
+                // set protobuf cpu usage
+                p_cpu.set_throttling(p_throttle);
             }
 
-            if let Some(pid_stats) = cg_stats.pids_stats {
-                let mut p_pid = metrics::PidsStat::new();
-                p_pid.set_limit(pid_stats.limit);
-                p_pid.set_current(pid_stats.current);
-                metric.set_pids(p_pid);
+            metric.set_cpu(p_cpu);
+        }
+
+        if let Some(m_stats) = cg_stats.memory_stats {
+            let mut p_m = metrics::MemoryStat::new();
+            p_m.set_cache(m_stats.cache);
+            // memory usage
+            if let Some(m_data) = m_stats.usage {
+                let mut p_m_entry = metrics::MemoryEntry::new();
+                p_m_entry.set_usage(m_data.usage);
+                p_m_entry.set_limit(m_data.limit);
+                p_m_entry.set_failcnt(m_data.failcnt);
+                p_m_entry.set_max(m_data.max_usage);
+
+                p_m.set_usage(p_m_entry);
+            }
+            // memory swap_usage
+            if let Some(m_data) = m_stats.swap_usage {
+                let mut p_m_entry = metrics::MemoryEntry::new();
+                p_m_entry.set_usage(m_data.usage);
+                p_m_entry.set_limit(m_data.limit);
+                p_m_entry.set_failcnt(m_data.failcnt);
+                p_m_entry.set_max(m_data.max_usage);
+
+                p_m.set_swap(p_m_entry);
             }
+            // memory kernel_usage
+            if let Some(m_data) = m_stats.kernel_usage {
+                let mut p_m_entry = metrics::MemoryEntry::new();
+                p_m_entry.set_usage(m_data.usage);
+                p_m_entry.set_limit(m_data.limit);
+                p_m_entry.set_failcnt(m_data.failcnt);
+                p_m_entry.set_max(m_data.max_usage);
 
-            if let Some(blk_stats) = cg_stats.blkio_stats {
-                let mut p_blk_stats = metrics::BlkIOStat::new();
-                p_blk_stats
-                    .set_io_serviced_recursive(copy_blkio_entry(&blk_stats.io_serviced_recursive));
-                p_blk_stats.set_io_service_bytes_recursive(copy_blkio_entry(
-                    &blk_stats.io_service_bytes_recursive,
-                ));
-                p_blk_stats
-                    .set_io_queued_recursive(copy_blkio_entry(&blk_stats.io_queued_recursive));
-                p_blk_stats.set_io_service_time_recursive(copy_blkio_entry(
-                    &blk_stats.io_service_time_recursive,
-                ));
-                p_blk_stats.set_io_wait_time_recursive(copy_blkio_entry(
-                    &blk_stats.io_wait_time_recursive,
-                ));
-                p_blk_stats
-                    .set_io_merged_recursive(copy_blkio_entry(&blk_stats.io_merged_recursive));
-                p_blk_stats.set_io_time_recursive(copy_blkio_entry(&blk_stats.io_time_recursive));
-                p_blk_stats.set_sectors_recursive(copy_blkio_entry(&blk_stats.sectors_recursive));
-
-                metric.set_blkio(p_blk_stats);
+                p_m.set_kernel(p_m_entry);
             }
 
-            if !cg_stats.hugetlb_stats.is_empty() {
-                let mut p_huge = Vec::new();
-                for (k, v) in cg_stats.hugetlb_stats {
-                    let mut h = metrics::HugetlbStat::new();
-                    h.set_pagesize(k);
-                    h.set_max(v.max_usage);
-                    h.set_usage(v.usage);
-                    h.set_failcnt(v.failcnt);
-                    p_huge.push(h);
+            for (k, v) in m_stats.stats {
+                match k.as_str() {
+                    "dirty" => p_m.set_dirty(v),
+                    "rss" => p_m.set_rss(v),
+                    "rss_huge" => p_m.set_rss_huge(v),
+                    "mapped_file" => p_m.set_mapped_file(v),
+                    "writeback" => p_m.set_writeback(v),
+                    "pg_pg_in" => p_m.set_pg_pg_in(v),
+                    "pg_pg_out" => p_m.set_pg_pg_out(v),
+                    "pg_fault" => p_m.set_pg_fault(v),
+                    "pg_maj_fault" => p_m.set_pg_maj_fault(v),
+                    "inactive_file" => p_m.set_inactive_file(v),
+                    "inactive_anon" => p_m.set_inactive_anon(v),
+                    "active_file" => p_m.set_active_file(v),
+                    "unevictable" => p_m.set_unevictable(v),
+                    "hierarchical_memory_limit" => p_m.set_hierarchical_memory_limit(v),
+                    "hierarchical_swap_limit" => p_m.set_hierarchical_swap_limit(v),
+                    "total_cache" => p_m.set_total_cache(v),
+                    "total_rss" => p_m.set_total_rss(v),
+                    "total_mapped_file" => p_m.set_total_mapped_file(v),
+                    "total_dirty" => p_m.set_total_dirty(v),
+
+                    "total_pg_pg_in" => p_m.set_total_pg_pg_in(v),
+                    "total_pg_pg_out" => p_m.set_total_pg_pg_out(v),
+                    "total_pg_fault" => p_m.set_total_pg_fault(v),
+                    "total_pg_maj_fault" => p_m.set_total_pg_maj_fault(v),
+                    "total_inactive_file" => p_m.set_total_inactive_file(v),
+                    "total_inactive_anon" => p_m.set_total_inactive_anon(v),
+                    "total_active_file" => p_m.set_total_active_file(v),
+                    "total_unevictable" => p_m.set_total_unevictable(v),
+                    _ => (),
                 }
-                metric.set_hugetlb(p_huge);
             }
+            metric.set_memory(p_m);
         }
 
-        let net_stats = stats.network_stats;
-        if !net_stats.is_empty() {
-            let mut p_net = Vec::new();
-            for v in net_stats.iter() {
-                let mut h = metrics::NetworkStat::new();
-                h.set_name(v.name.clone());
+        if let Some(pid_stats) = cg_stats.pids_stats {
+            let mut p_pid = metrics::PidsStat::new();
+            p_pid.set_limit(pid_stats.limit);
+            p_pid.set_current(pid_stats.current);
+            metric.set_pids(p_pid);
+        }
 
-                h.set_tx_bytes(v.tx_bytes);
-                h.set_tx_packets(v.tx_packets);
-                h.set_tx_errors(v.tx_errors);
-                h.set_tx_dropped(v.tx_dropped);
+        if let Some(blk_stats) = cg_stats.blkio_stats {
+            let mut p_blk_stats = metrics::BlkIOStat::new();
+            p_blk_stats
+                .set_io_serviced_recursive(copy_blkio_entry(&blk_stats.io_serviced_recursive));
+            p_blk_stats.set_io_service_bytes_recursive(copy_blkio_entry(
+                &blk_stats.io_service_bytes_recursive,
+            ));
+            p_blk_stats.set_io_queued_recursive(copy_blkio_entry(&blk_stats.io_queued_recursive));
+            p_blk_stats.set_io_service_time_recursive(copy_blkio_entry(
+                &blk_stats.io_service_time_recursive,
+            ));
+            p_blk_stats
+                .set_io_wait_time_recursive(copy_blkio_entry(&blk_stats.io_wait_time_recursive));
+            p_blk_stats.set_io_merged_recursive(copy_blkio_entry(&blk_stats.io_merged_recursive));
+            p_blk_stats.set_io_time_recursive(copy_blkio_entry(&blk_stats.io_time_recursive));
+            p_blk_stats.set_sectors_recursive(copy_blkio_entry(&blk_stats.sectors_recursive));
 
-                h.set_rx_bytes(v.rx_bytes);
-                h.set_rx_packets(v.rx_packets);
-                h.set_rx_errors(v.rx_errors);
-                h.set_rx_dropped(v.rx_dropped);
+            metric.set_blkio(p_blk_stats);
+        }
 
-                p_net.push(h);
+        if !cg_stats.hugetlb_stats.is_empty() {
+            let mut p_huge = Vec::new();
+            for (k, v) in cg_stats.hugetlb_stats {
+                let mut h = metrics::HugetlbStat::new();
+                h.set_pagesize(k);
+                h.set_max(v.max_usage);
+                h.set_usage(v.usage);
+                h.set_failcnt(v.failcnt);
+                p_huge.push(h);
             }
-            metric.set_network(p_net);
+            metric.set_hugetlb(p_huge);
         }
+    }
 
-        StatsInfo {
-            value: Some(StatsInfoValue {
-                type_url: "io.containerd.cgroups.v1.Metrics".to_string(),
-                value: metric.write_to_bytes().unwrap(),
-            }),
+    let net_stats = stats.network_stats;
+    if !net_stats.is_empty() {
+        let mut p_net = Vec::new();
+        for v in net_stats.iter() {
+            let mut h = metrics::NetworkStat::new();
+            h.set_name(v.name.clone());
+
+            h.set_tx_bytes(v.tx_bytes);
+            h.set_tx_packets(v.tx_packets);
+            h.set_tx_errors(v.tx_errors);
+            h.set_tx_dropped(v.tx_dropped);
+
+            h.set_rx_bytes(v.rx_bytes);
+            h.set_rx_packets(v.rx_packets);
+            h.set_rx_errors(v.rx_errors);
+            h.set_rx_dropped(v.rx_dropped);
+
+            p_net.push(h);
         }
+        metric.set_network(p_net);
+    }
+
+    StatsInfo {
+        value: Some(StatsInfoValue {
+            type_url: "io.containerd.cgroups.v1.Metrics".to_string(),
+            value: metric.write_to_bytes().unwrap(),
+        }),
+    }
+}
+
+// The agent reports cpu times in nanoseconds for both cgroup versions, and
+// the cgroup v2 memory.stat and io.stat breakdowns through the v1 shaped
+// memory stats map and blkio service bytes entries.
+fn stats_to_metrics_v2(stats: agent::StatsContainerResponse) -> StatsInfo {
+    let mut metric = cgroups_v2::Metrics::new();
+
+    if let Some(cg_stats) = stats.cgroup_stats {
+        if let Some(cpu) = cg_stats.cpu_stats {
+            let mut p_cpu = cgroups_v2::CPUStat::new();
+            if let Some(usage) = cpu.cpu_usage {
+                p_cpu.usage_usec = usage.total_usage / 1000;
+                p_cpu.user_usec = usage.usage_in_usermode / 1000;
+                p_cpu.system_usec = usage.usage_in_kernelmode / 1000;
+            }
+            if let Some(throttle) = cpu.throttling_data {
+                p_cpu.nr_periods = throttle.periods;
+                p_cpu.nr_throttled = throttle.throttled_periods;
+                p_cpu.throttled_usec = throttle.throttled_time / 1000;
+            }
+            p_cpu.psi = copy_psi_stats(cpu.psi);
+            metric.cpu = MessageField::some(p_cpu);
+        }
+
+        if let Some(m_stats) = cg_stats.memory_stats {
+            let mut p_m = cgroups_v2::MemoryStat::new();
+            if let Some(m_data) = m_stats.usage {
+                p_m.usage = m_data.usage;
+                p_m.usage_limit = m_data.limit;
+                p_m.max_usage = m_data.max_usage;
+            }
+            if let Some(m_data) = m_stats.swap_usage {
+                p_m.swap_usage = m_data.usage;
+                p_m.swap_limit = m_data.limit;
+                p_m.swap_max_usage = m_data.max_usage;
+            }
+            for (k, v) in m_stats.stats {
+                match k.as_str() {
+                    "anon" => p_m.anon = v,
+                    "file" => p_m.file = v,
+                    "kernel_stack" => p_m.kernel_stack = v,
+                    "slab" => p_m.slab = v,
+                    "sock" => p_m.sock = v,
+                    "shmem" => p_m.shmem = v,
+                    "file_mapped" => p_m.file_mapped = v,
+                    "file_dirty" => p_m.file_dirty = v,
+                    "file_writeback" => p_m.file_writeback = v,
+                    "anon_thp" => p_m.anon_thp = v,
+                    "inactive_anon" => p_m.inactive_anon = v,
+                    "active_anon" => p_m.active_anon = v,
+                    "inactive_file" => p_m.inactive_file = v,
+                    "active_file" => p_m.active_file = v,
+                    "unevictable" => p_m.unevictable = v,
+                    "slab_reclaimable" => p_m.slab_reclaimable = v,
+                    "slab_unreclaimable" => p_m.slab_unreclaimable = v,
+                    "pgfault" => p_m.pgfault = v,
+                    "pgmajfault" => p_m.pgmajfault = v,
+                    "workingset_refault" => p_m.workingset_refault = v,
+                    "workingset_activate" => p_m.workingset_activate = v,
+                    "workingset_nodereclaim" => p_m.workingset_nodereclaim = v,
+                    "pgrefill" => p_m.pgrefill = v,
+                    "pgscan" => p_m.pgscan = v,
+                    "pgsteal" => p_m.pgsteal = v,
+                    "pgactivate" => p_m.pgactivate = v,
+                    "pgdeactivate" => p_m.pgdeactivate = v,
+                    "pglazyfree" => p_m.pglazyfree = v,
+                    "pglazyfreed" => p_m.pglazyfreed = v,
+                    "thp_fault_alloc" => p_m.thp_fault_alloc = v,
+                    "thp_collapse_alloc" => p_m.thp_collapse_alloc = v,
+                    _ => (),
+                }
+            }
+            p_m.psi = copy_psi_stats(m_stats.psi);
+            metric.memory = MessageField::some(p_m);
+        }
+
+        if let Some(pid_stats) = cg_stats.pids_stats {
+            let mut p_pid = cgroups_v2::PidsStat::new();
+            p_pid.limit = pid_stats.limit;
+            p_pid.current = pid_stats.current;
+            metric.pids = MessageField::some(p_pid);
+        }
+
+        if let Some(blk_stats) = cg_stats.blkio_stats {
+            let mut p_io = cgroups_v2::IOStat::new();
+            p_io.usage = copy_io_entry(&blk_stats.io_service_bytes_recursive);
+            p_io.psi = copy_psi_stats(blk_stats.psi);
+            metric.io = MessageField::some(p_io);
+        }
+
+        for (k, v) in cg_stats.hugetlb_stats {
+            let mut h = cgroups_v2::HugeTlbStat::new();
+            h.pagesize = k;
+            h.current = v.usage;
+            h.max = v.limit;
+            metric.hugetlb.push(h);
+        }
+    }
+
+    for v in stats.network_stats.iter() {
+        let mut h = cgroups_v2::NetworkStat::new();
+        h.name = v.name.clone();
+
+        h.tx_bytes = v.tx_bytes;
+        h.tx_packets = v.tx_packets;
+        h.tx_errors = v.tx_errors;
+        h.tx_dropped = v.tx_dropped;
+
+        h.rx_bytes = v.rx_bytes;
+        h.rx_packets = v.rx_packets;
+        h.rx_errors = v.rx_errors;
+        h.rx_dropped = v.rx_dropped;
+
+        metric.network.push(h);
+    }
+
+    StatsInfo {
+        value: Some(StatsInfoValue {
+            type_url: "io.containerd.cgroups.v2.Metrics".to_string(),
+            value: metric.write_to_bytes().unwrap(),
+        }),
     }
 }
 
@@ -210,3 +353,106 @@ fn copy_blkio_entry(entry: &[agent::BlkioStatsEntry]) -> Vec<metrics::BlkIOEntry
 
     p_entry
 }
+
+// The agent flattens each io.stat device line into one entry per op, fold
+// them back into a single entry per device.
+fn copy_io_entry(entry: &[agent::BlkioStatsEntry]) -> Vec<cgroups_v2::IOEntry> {
+    let mut p_entry: Vec<cgroups_v2::IOEntry> = Vec::new();
+    for e in entry.iter() {
+        let pos = match p_entry
+            .iter()
+            .position(|io| io.major == e.major && io.minor == e.minor)
+        {
+            Some(pos) => pos,
+            None => {
+                let mut io = cgroups_v2::IOEntry::new();
+                io.major = e.major;
+                io.minor = e.minor;
+                p_entry.push(io);
+                p_entry.len() - 1
+            }
+        };
+
+        let io = &mut p_entry[pos];
+        match e.op.as_str() {
+            "read" => io.rbytes = e.value,
+            "write" => io.wbytes = e.value,
+            "rios" => io.rios = e.value,
+            "wios" => io.wios = e.value,
+            _ => (),
+        }
+    }
+    p_entry
+}
+
+fn copy_psi_stats(psi: Option<agent::PsiStats>) -> MessageField<cgroups_v2::PSIStats> {
+    let copy_data = |data: Option<agent::PsiData>| {
+        MessageField::from_option(data.map(|d| {
+            let mut p_data = cgroups_v2::PSIData::new();
+            p_data.avg10 = d.avg10;
+            p_data.avg60 = d.avg60;
+            p_data.avg300 = d.avg300;
+            p_data.total = d.total;
+            p_data
+        }))
+    };
+
+    MessageField::from_option(psi.map(|p| {
+        let mut p_psi = cgroups_v2::PSIStats::new();
+        p_psi.some = copy_data(p.some);
+        p_psi.full = copy_data(p.full);
+        p_psi
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn stats(cgroup_version: u32) -> agent::StatsContainerResponse {
+        let mut hugetlb_stats = HashMap::new();
+        hugetlb_stats.insert(
+            "2MB".to_string(),
+            agent::types::HugetlbStats {
+                usage: 4096,
+                max_usage: 8192,
+                failcnt: 1,
+                limit: 1 << 21,
+            },
+        );
+        agent::StatsContainerResponse {
+            cgroup_stats: Some(agent::types::CgroupStats {
+                hugetlb_stats,
+                cgroup_version,
+                ..Default::default()
+            }),
+            network_stats: vec![agent::types::NetworkStats {
+                name: "eth0".to_string(),
+                rx_bytes: 100,
+                tx_bytes: 200,
+                ..Default::default()
+            }],
+        }
+    }
+
+    #[test]
+    fn test_stats_to_metrics_by_guest_cgroup_version() {
+        let info = StatsInfo::from(Some(stats(2))).value.unwrap();
+        assert_eq!(info.type_url, "io.containerd.cgroups.v2.Metrics");
+        let metric = cgroups_v2::Metrics::parse_from_bytes(&info.value).unwrap();
+        assert_eq!(metric.hugetlb.len(), 1);
+        assert_eq!(metric.hugetlb[0].current, 4096);
+        assert_eq!(metric.hugetlb[0].max, 1 << 21);
+        assert_eq!(metric.network.len(), 1);
+        assert_eq!(metric.network[0].name, "eth0");
+        assert_eq!(metric.network[0].rx_bytes, 100);
+        assert_eq!(metric.network[0].tx_bytes, 200);
+
+        let info = StatsInfo::from(Some(stats(1))).value.unwrap();
+        assert_eq!(info.type_url, "io.containerd.cgroups.v1.Metrics");
+        let metric = metrics::Metrics::parse_from_bytes(&info.value).unwrap();
+        assert_eq!(metric.hugetlb[0].max, 8192);
+        assert_eq!(metric.network[0].name, "eth0");
+    }
+}
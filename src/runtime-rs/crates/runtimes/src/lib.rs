@@ -14,3 +14,4 @@ pub use manager::RuntimeHandlerManager;
 pub use shim_interface;
 mod shim_mgmt;
 mod static_resource;
+pub mod tracer;
@@ -6,17 +6,19 @@
 
 use std::{path::PathBuf, str::from_utf8, sync::Arc};
 
-use crate::{shim_mgmt::server::MgmtServer, static_resource::StaticResourceManager};
+use crate::{shim_mgmt::server::MgmtServer, static_resource::StaticResourceManager, tracer};
 use anyhow::{anyhow, Context, Result};
 use common::{
     message::Message,
     types::{Request, Response},
-    RuntimeHandler, RuntimeInstance, Sandbox, SandboxNetworkEnv,
+    RuntimeHandler, RuntimeInstance, Sandbox, SandboxDnsFiles, SandboxNetworkEnv,
 };
 use hypervisor::Param;
 use kata_sys_util::spec::load_oci_spec;
 use kata_types::{
-    annotations::Annotation, config::default::DEFAULT_GUEST_DNS_FILE, config::TomlConfig,
+    annotations::Annotation,
+    config::default::{DEFAULT_GUEST_DNS_FILE, DEFAULT_GUEST_HOSTS_FILE},
+    config::TomlConfig,
 };
 use netns_rs::NetNs;
 use resource::network::generate_netns_name;
@@ -25,7 +27,6 @@ use resource::network::generate_netns_name;
 use linux_container::LinuxContainer;
 use persist::sandbox_persist::Persist;
 use shim_interface::shim_mgmt::ERR_NO_SHIM_SERVER;
-use tokio::fs;
 use tokio::sync::{mpsc::Sender, RwLock};
 #[cfg(feature = "virt")]
 use virt_container::{
@@ -36,6 +37,9 @@ use virt_container::{
 #[cfg(feature = "wasm")]
 use wasm_container::WasmContainer;
 
+/// service name of the spans exported by the shim
+const TRACER_NAME: &str = "kata-shim";
+
 struct RuntimeHandlerManagerInner {
     id: String,
     msg_sender: Sender<Message>,
@@ -56,7 +60,7 @@ impl RuntimeHandlerManagerInner {
         spec: &oci::Spec,
         state: &oci::State,
         network_env: SandboxNetworkEnv,
-        dns: Vec<String>,
+        dns: SandboxDnsFiles,
         config: Arc<TomlConfig>,
     ) -> Result<()> {
         info!(sl!(), "new runtime handler {}", &config.runtime.name);
@@ -97,7 +101,7 @@ impl RuntimeHandlerManagerInner {
             return Ok(());
         }
 
-        let mut dns: Vec<String> = vec![];
+        let mut dns = SandboxDnsFiles::default();
 
         #[cfg(feature = "linux")]
         LinuxContainer::init().context("init linux container")?;
@@ -108,12 +112,19 @@ impl RuntimeHandlerManagerInner {
 
         for m in &spec.mounts {
             if m.destination == DEFAULT_GUEST_DNS_FILE {
-                let contents = fs::read_to_string(&m.source).await?;
-                dns = contents.split('\n').map(|e| e.to_string()).collect();
+                dns.resolv_conf = Some(m.source.clone());
+            } else if m.destination == DEFAULT_GUEST_HOSTS_FILE {
+                dns.hosts = Some(m.source.clone());
             }
         }
 
         let config = load_config(spec, options).context("load config")?;
+        if config.runtime.enable_tracing {
+            // tracing is best effort, the sandbox can still be started
+            if let Err(err) = tracer::setup_tracing(TRACER_NAME, &config) {
+                warn!(sl!(), "failed to setup tracing {:?}", err);
+            }
+        }
 
         let mut network_created = false;
         // set netns to None if we want no network for the VM
@@ -149,6 +160,7 @@ impl RuntimeHandlerManagerInner {
             netns,
             network_created,
         };
+        let enable_pprof = config.runtime.enable_pprof;
         self.init_runtime_handler(spec, state, network_env, dns, Arc::new(config))
             .await
             .context("init runtime handler")?;
@@ -159,6 +171,7 @@ impl RuntimeHandlerManagerInner {
         let shim_mgmt_svr = MgmtServer::new(
             &self.id,
             self.runtime_instance.as_ref().unwrap().sandbox.clone(),
+            enable_pprof,
         )
         .context(ERR_NO_SHIM_SERVER)?;
 
@@ -236,6 +249,14 @@ impl RuntimeHandlerManager {
         Ok(())
     }
 
+    /// Log the state of the containers, processes and resources, to debug a hung shim.
+    pub async fn dump(&self) {
+        match self.get_runtime_instance().await {
+            Ok(instance) => instance.container_manager.dump().await,
+            Err(_) => info!(sl!(), "no runtime instance to dump"),
+        }
+    }
+
     async fn get_runtime_instance(&self) -> Result<Arc<RuntimeInstance>> {
         let inner = self.inner.read().await;
         inner
@@ -16,13 +16,16 @@ use url::Url;
 
 use shim_interface::shim_mgmt::{
     AGENT_URL, DIRECT_VOLUME_PATH_KEY, DIRECT_VOLUME_RESIZE_URL, DIRECT_VOLUME_STATS_URL,
-    IP6_TABLE_URL, IP_TABLE_URL,
+    IP6_TABLE_URL, IP_TABLE_URL, PPROF_HEAP_URL, PPROF_PROFILE_URL, PPROF_RUNTIME_URL,
 };
 
+use super::pprof::{heap_handler, profile_handler, runtime_handler};
+
 // main router for response, this works as a multiplexer on
 // http arrival which invokes the corresponding handler function
 pub(crate) async fn handler_mux(
     sandbox: Arc<dyn Sandbox>,
+    enable_pprof: bool,
     req: Request<Body>,
 ) -> Result<Response<Body>> {
     info!(
@@ -43,6 +46,9 @@ pub(crate) async fn handler_mux(
         (&Method::POST, DIRECT_VOLUME_RESIZE_URL) => {
             direct_volume_resize_handler(sandbox, req).await
         }
+        (&Method::GET, PPROF_PROFILE_URL) if enable_pprof => profile_handler(req).await,
+        (&Method::GET, PPROF_HEAP_URL) if enable_pprof => heap_handler(req).await,
+        (&Method::GET, PPROF_RUNTIME_URL) if enable_pprof => runtime_handler(req).await,
         _ => Ok(not_found(req).await),
     }
 }
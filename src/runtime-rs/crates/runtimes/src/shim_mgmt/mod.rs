@@ -11,4 +11,5 @@
 //! from libs/shim-interface library
 
 mod handlers;
+mod pprof;
 pub mod server;
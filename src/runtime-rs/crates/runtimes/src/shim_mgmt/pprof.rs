@@ -0,0 +1,318 @@
+// Copyright (c) 2023 Kata Contributors
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Profiling handlers of the shim management server, only routed when `enable_pprof` is set.
+
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    fs,
+    sync::atomic::{AtomicBool, Ordering},
+    thread,
+    time::Duration,
+};
+
+use anyhow::{anyhow, Context, Result};
+use hyper::{header, Body, Request, Response, StatusCode};
+use pprof::protos::Message;
+use url::Url;
+
+use shim_interface::shim_mgmt::{PPROF_FORMAT_KEY, PPROF_SECONDS_KEY};
+
+// sampling frequency of the cpu profiler, in Hz
+const PROFILE_FREQUENCY: i32 = 99;
+// same default duration as the go runtime
+const DEFAULT_PROFILE_SECONDS: u64 = 30;
+const MAX_PROFILE_SECONDS: u64 = 300;
+
+const FORMAT_PPROF: &str = "pprof";
+const FORMAT_FLAMEGRAPH: &str = "flamegraph";
+
+// the profiler samples the whole process, so only one cpu profile runs at a time
+static PROFILING: AtomicBool = AtomicBool::new(false);
+
+// memory fields of /proc/self/status reported by the heap handler
+const STATUS_MEMORY_FIELDS: &[&str] = &[
+    "VmPeak", "VmSize", "VmHWM", "VmRSS", "RssAnon", "RssFile", "VmData", "VmStk", "Threads",
+];
+
+fn query_params(req: &Request<Body>) -> HashMap<String, String> {
+    // the uri of the request has no scheme nor host
+    Url::parse("unix://localhost")
+        .and_then(|base| base.join(&req.uri().to_string()))
+        .map(|url| url.query_pairs().into_owned().collect())
+        .unwrap_or_default()
+}
+
+fn error_response(status: StatusCode, msg: String) -> Result<Response<Body>> {
+    Response::builder()
+        .status(status)
+        .body(Body::from(msg))
+        .map_err(|e| anyhow!(e))
+}
+
+fn bad_request(msg: String) -> Result<Response<Body>> {
+    error_response(StatusCode::BAD_REQUEST, msg)
+}
+
+// Marks a cpu profile as running until it is dropped.
+struct ProfilingGuard;
+
+impl ProfilingGuard {
+    fn acquire() -> Option<Self> {
+        PROFILING
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .ok()
+            .map(|_| ProfilingGuard)
+    }
+}
+
+impl Drop for ProfilingGuard {
+    fn drop(&mut self) {
+        PROFILING.store(false, Ordering::Release);
+    }
+}
+
+/// Sample the shim cpu usage for `seconds` (30 by default), and return the profile in the pprof
+/// protobuf format, or as a flamegraph svg with `format=flamegraph`. A request made while another
+/// profile is running gets 409 Conflict.
+pub(crate) async fn profile_handler(req: Request<Body>) -> Result<Response<Body>> {
+    let params = query_params(&req);
+    let seconds = match params.get(PPROF_SECONDS_KEY) {
+        None => DEFAULT_PROFILE_SECONDS,
+        Some(s) => match s.parse::<u64>() {
+            Ok(n) if n > 0 && n <= MAX_PROFILE_SECONDS => n,
+            _ => {
+                return bad_request(format!(
+                    "invalid {} {}, it must be between 1 and {}",
+                    PPROF_SECONDS_KEY, s, MAX_PROFILE_SECONDS
+                ))
+            }
+        },
+    };
+    let format = params
+        .get(PPROF_FORMAT_KEY)
+        .map(|f| f.as_str())
+        .unwrap_or(FORMAT_PPROF)
+        .to_string();
+    let content_type = match format.as_str() {
+        FORMAT_PPROF => "application/octet-stream",
+        FORMAT_FLAMEGRAPH => "image/svg+xml",
+        f => return bad_request(format!("unsupported profile format {}", f)),
+    };
+
+    let running = match ProfilingGuard::acquire() {
+        Some(running) => running,
+        None => {
+            return error_response(
+                StatusCode::CONFLICT,
+                String::from("a cpu profile is already running"),
+            )
+        }
+    };
+
+    info!(
+        sl!(),
+        "mgmt-svr: cpu profile for {}s as {}", seconds, format
+    );
+    // the profiler guard has to live on a single thread
+    let body = tokio::task::spawn_blocking(move || -> Result<Vec<u8>> {
+        // released once the profile is done, even if the request is dropped
+        let _running = running;
+        let guard = pprof::ProfilerGuardBuilder::default()
+            .frequency(PROFILE_FREQUENCY)
+            .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+            .build()
+            .context("start profiler")?;
+        thread::sleep(Duration::from_secs(seconds));
+        let report = guard.report().build().context("build report")?;
+
+        let mut body = Vec::new();
+        if format == FORMAT_FLAMEGRAPH {
+            report.flamegraph(&mut body).context("write flamegraph")?;
+        } else {
+            report
+                .pprof()
+                .context("build pprof")?
+                .encode(&mut body)
+                .context("encode pprof")?;
+        }
+        Ok(body)
+    })
+    .await
+    .context("join profiler")??;
+
+    Response::builder()
+        .header(header::CONTENT_TYPE, content_type)
+        .body(Body::from(body))
+        .map_err(|e| anyhow!(e))
+}
+
+fn memory_status(status: &str) -> String {
+    let mut out = String::new();
+    for line in status.lines() {
+        if let Some((key, _)) = line.split_once(':') {
+            if STATUS_MEMORY_FIELDS.contains(&key) {
+                let _ = writeln!(
+                    out,
+                    "{}",
+                    line.split_whitespace().collect::<Vec<_>>().join(" ")
+                );
+            }
+        }
+    }
+    out
+}
+
+// The glibc malloc statistics as (arena, mmap, in use, free, releasable) bytes. mallinfo2()
+// only exists since glibc 2.33, older glibc only has mallinfo(), whose int fields wrap past
+// 2GiB, so look it up at runtime rather than failing to start on an older host.
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+fn mallinfo() -> (usize, usize, usize, usize, usize) {
+    use nix::libc;
+
+    let sym = unsafe { libc::dlsym(libc::RTLD_DEFAULT, b"mallinfo2\0".as_ptr() as *const _) };
+    if sym.is_null() {
+        #[allow(deprecated)]
+        let info = unsafe { libc::mallinfo() };
+        // the fields are the low 32 bits of the real values
+        let bytes = |v: libc::c_int| v as u32 as usize;
+        return (
+            bytes(info.arena),
+            bytes(info.hblkhd),
+            bytes(info.uordblks),
+            bytes(info.fordblks),
+            bytes(info.keepcost),
+        );
+    }
+
+    let mallinfo2: extern "C" fn() -> libc::mallinfo2 = unsafe { std::mem::transmute(sym) };
+    let info = mallinfo2();
+    (
+        info.arena,
+        info.hblkhd,
+        info.uordblks,
+        info.fordblks,
+        info.keepcost,
+    )
+}
+
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+fn malloc_stats(out: &mut String) {
+    let (arena, mmap, in_use, free, releasable) = mallinfo();
+    let _ = writeln!(out, "\n# glibc malloc (bytes)");
+    let _ = writeln!(out, "arena: {}", arena);
+    let _ = writeln!(out, "mmap: {}", mmap);
+    let _ = writeln!(out, "in_use: {}", in_use);
+    let _ = writeln!(out, "free: {}", free);
+    let _ = writeln!(out, "releasable: {}", releasable);
+}
+
+#[cfg(not(all(target_os = "linux", target_env = "gnu")))]
+fn malloc_stats(_out: &mut String) {}
+
+/// Heap statistics of the shim process, from procfs and the allocator.
+pub(crate) async fn heap_handler(_req: Request<Body>) -> Result<Response<Body>> {
+    let status = fs::read_to_string("/proc/self/status").context("read process status")?;
+    let mut out = String::from("# process memory\n");
+    out.push_str(&memory_status(&status));
+    malloc_stats(&mut out);
+
+    Ok(Response::new(Body::from(out)))
+}
+
+/// Metrics of the tokio runtime the shim is running on.
+pub(crate) async fn runtime_handler(_req: Request<Body>) -> Result<Response<Body>> {
+    Ok(Response::new(Body::from(tokio_metrics())))
+}
+
+#[cfg(tokio_unstable)]
+fn tokio_metrics() -> String {
+    let metrics = tokio::runtime::Handle::current().metrics();
+    let mut out = String::new();
+    let _ = writeln!(out, "workers: {}", metrics.num_workers());
+    let _ = writeln!(out, "blocking_threads: {}", metrics.num_blocking_threads());
+    let _ = writeln!(
+        out,
+        "idle_blocking_threads: {}",
+        metrics.num_idle_blocking_threads()
+    );
+    let _ = writeln!(
+        out,
+        "injection_queue_depth: {}",
+        metrics.injection_queue_depth()
+    );
+    let _ = writeln!(
+        out,
+        "blocking_queue_depth: {}",
+        metrics.blocking_queue_depth()
+    );
+    for worker in 0..metrics.num_workers() {
+        let _ = writeln!(
+            out,
+            "worker {}: polls {} steals {} parks {} busy {:?} local_queue_depth {}",
+            worker,
+            metrics.worker_poll_count(worker),
+            metrics.worker_steal_count(worker),
+            metrics.worker_park_count(worker),
+            metrics.worker_total_busy_duration(worker),
+            metrics.worker_local_queue_depth(worker)
+        );
+    }
+    out
+}
+
+#[cfg(not(tokio_unstable))]
+fn tokio_metrics() -> String {
+    String::from("tokio runtime metrics need a build with RUSTFLAGS=\"--cfg tokio_unstable\"\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_status() {
+        let status = "Name:\tcontainerd-shim\nVmPeak:\t  10 kB\nVmRSS:\t   5 kB\nSigQ:\t0/1\n";
+        assert_eq!(memory_status(status), "VmPeak: 10 kB\nVmRSS: 5 kB\n");
+    }
+
+    #[test]
+    fn test_query_params() {
+        let req = Request::builder()
+            .uri("/debug/pprof/profile?seconds=5&format=flamegraph")
+            .body(Body::empty())
+            .unwrap();
+        let params = query_params(&req);
+        assert_eq!(params.get(PPROF_SECONDS_KEY).unwrap(), "5");
+        assert_eq!(params.get(PPROF_FORMAT_KEY).unwrap(), FORMAT_FLAMEGRAPH);
+    }
+
+    #[test]
+    fn test_profile_conflict() {
+        let running = ProfilingGuard::acquire().unwrap();
+        assert!(ProfilingGuard::acquire().is_none());
+
+        let req = Request::builder()
+            .uri("/debug/pprof/profile?seconds=1")
+            .body(Body::empty())
+            .unwrap();
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let resp = rt.block_on(profile_handler(req)).unwrap();
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        drop(running);
+        assert!(ProfilingGuard::acquire().is_some());
+    }
+
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    #[test]
+    fn test_mallinfo() {
+        let data = vec![0u8; 4096];
+        let (arena, _, in_use, _, _) = mallinfo();
+        assert!(arena > 0);
+        assert!(in_use >= data.len());
+    }
+}
@@ -28,14 +28,18 @@ pub struct MgmtServer {
 
     /// The sandbox instance
     pub sandbox: Arc<dyn Sandbox>,
+
+    /// Serve the profiling urls
+    pub enable_pprof: bool,
 }
 
 impl MgmtServer {
     /// construct a new management server
-    pub fn new(sid: &str, sandbox: Arc<dyn Sandbox>) -> Result<Self> {
+    pub fn new(sid: &str, sandbox: Arc<dyn Sandbox>, enable_pprof: bool) -> Result<Self> {
         Ok(Self {
             s_addr: mgmt_socket_addr(sid).context(ERR_NO_SHIM_SERVER)?,
             sandbox,
+            enable_pprof,
         })
     }
 
@@ -54,7 +58,9 @@ impl MgmtServer {
                 if let Err(err) = Http::new()
                     .serve_connection(
                         stream,
-                        service_fn(|request| handler_mux(me.sandbox.clone(), request)),
+                        service_fn(|request| {
+                            handler_mux(me.sandbox.clone(), me.enable_pprof, request)
+                        }),
                     )
                     .await
                 {
@@ -0,0 +1,101 @@
+// Copyright (c) 2019-2022 Alibaba Cloud
+// Copyright (c) 2019-2022 Ant Group
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use anyhow::{Context, Result};
+use kata_types::config::TomlConfig;
+use opentelemetry::global;
+use opentelemetry::sdk::propagation::TraceContextPropagator;
+use opentelemetry::sdk::{trace, Resource};
+use opentelemetry::KeyValue;
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::Registry;
+
+/// default jaeger collector endpoint, the http thrift api of jaeger
+const DEFAULT_JAEGER_ENDPOINT: &str = "http://localhost:14268/api/traces";
+
+static TRACING_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Set up the jaeger exporter, or the OTLP one if an OTLP endpoint is
+/// configured, and install it as the global subscriber, so that the
+/// `tracing` spans emitted by runtime-rs crates are exported.
+/// Only the first call takes effect, the subscriber can't be replaced.
+pub fn setup_tracing(name: &'static str, config: &TomlConfig) -> Result<()> {
+    if TRACING_ENABLED.load(Ordering::SeqCst) {
+        return Ok(());
+    }
+
+    let (tracer, endpoint) = if config.runtime.otlp_endpoint.is_empty() {
+        jaeger_tracer(name, config)?
+    } else {
+        otlp_tracer(name, config)?
+    };
+
+    let subscriber = Registry::default().with(OpenTelemetryLayer::new(tracer));
+    tracing::subscriber::set_global_default(subscriber).context("set global subscriber")?;
+
+    // propagate the trace context to the agent, see the agent crate
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    TRACING_ENABLED.store(true, Ordering::SeqCst);
+    info!(sl!(), "tracing setup, endpoint {}", endpoint);
+
+    Ok(())
+}
+
+fn jaeger_tracer(name: &'static str, config: &TomlConfig) -> Result<(trace::Tracer, String)> {
+    let endpoint = if config.runtime.jaeger_endpoint.is_empty() {
+        DEFAULT_JAEGER_ENDPOINT
+    } else {
+        config.runtime.jaeger_endpoint.as_str()
+    };
+
+    let mut pipeline = opentelemetry_jaeger::new_pipeline()
+        .with_service_name(name)
+        .with_collector_endpoint(endpoint);
+    if !config.runtime.jaeger_user.is_empty() {
+        pipeline = pipeline
+            .with_collector_username(&config.runtime.jaeger_user)
+            .with_collector_password(&config.runtime.jaeger_password);
+    }
+
+    let tracer = pipeline
+        .install_batch(opentelemetry::runtime::Tokio)
+        .context("install jaeger pipeline")?;
+
+    Ok((tracer, endpoint.to_string()))
+}
+
+// Export the spans to an OpenTelemetry collector with OTLP over gRPC.
+fn otlp_tracer(name: &'static str, config: &TomlConfig) -> Result<(trace::Tracer, String)> {
+    let endpoint = config.runtime.otlp_endpoint.clone();
+
+    let tracer = opentelemetry_otlp::new_pipeline()
+        .with_endpoint(endpoint.as_str())
+        .with_trace_config(
+            trace::config().with_resource(Resource::new(vec![KeyValue::new("service.name", name)])),
+        )
+        .with_tonic()
+        .install_batch(opentelemetry::runtime::Tokio)
+        .context("install otlp pipeline")?;
+
+    Ok((tracer, endpoint))
+}
+
+/// Flush the pending spans and shut the exporter down.
+pub async fn end_tracing() {
+    if !TRACING_ENABLED.swap(false, Ordering::SeqCst) {
+        return;
+    }
+
+    // shutting down the provider blocks until the batch exporter finished
+    // its last export, which runs on the tokio runtime too
+    if let Err(err) = tokio::task::spawn_blocking(global::shutdown_tracer_provider).await {
+        warn!(sl!(), "failed to shutdown tracer provider {:?}", err);
+    }
+}
@@ -179,10 +179,15 @@ impl Container {
                 }
 
                 let container_io = inner.new_container_io(process).await?;
-                inner
+                if let Err(err) = inner
                     .init_process
                     .start_io_and_wait(self.agent.clone(), container_io)
-                    .await?;
+                    .await
+                {
+                    // the container output would be lost
+                    let _ = inner.stop_process(process, true).await;
+                    return Err(err).context("start io and wait");
+                }
             }
             ProcessType::Exec => {
                 if let Err(e) = inner.start_exec_process(process).await {
@@ -206,7 +211,7 @@ impl Container {
                 }
 
                 // start io and wait
-                {
+                let result = {
                     let exec = inner
                         .exec_processes
                         .get_mut(&process.exec_id)
@@ -215,7 +220,10 @@ impl Container {
                     exec.process
                         .start_io_and_wait(self.agent.clone(), container_io)
                         .await
-                        .context("start io and wait")?;
+                };
+                if let Err(err) = result {
+                    let _ = inner.stop_process(process, true).await;
+                    return Err(err).context("start io and wait");
                 }
             }
         }
@@ -387,6 +395,16 @@ impl Container {
             .update_cgroups(&self.config.container_id, Some(resources))
             .await?;
 
+        // the hypervisor enforces the I/O limits of the block devices
+        if let Some(block_io) = &resources.block_io {
+            let inner = self.inner.read().await;
+            for v in inner.volumes.iter() {
+                v.update_block_io(block_io)
+                    .await
+                    .context("update volume block io")?;
+            }
+        }
+
         let req = agent::UpdateContainerRequest {
             container_id: self.container_id.container_id.clone(),
             resources: resources.clone(),
@@ -399,6 +417,27 @@ impl Container {
         Ok(())
     }
 
+    pub async fn dump(&self) {
+        let inner = self.inner.read().await;
+        info!(
+            self.logger,
+            "container {} has {} rootfs, {} volumes and {} exec processes",
+            self.config.container_id,
+            inner.rootfs.len(),
+            inner.volumes.len(),
+            inner.exec_processes.len()
+        );
+
+        let processes = std::iter::once(&inner.init_process)
+            .chain(inner.exec_processes.values().map(|exec| &exec.process));
+        for process in processes {
+            match process.state().await {
+                Ok(state) => info!(self.logger, "process state {:?}", state),
+                Err(err) => warn!(self.logger, "failed to get process state {:?}", err),
+            }
+        }
+    }
+
     pub async fn config(&self) -> ContainerConfig {
         self.config.clone()
     }
@@ -5,13 +5,17 @@
 //
 
 use std::{
-    io,
+    fs::File as StdFile,
+    io::{self, Read},
     os::unix::{
-        io::{FromRawFd, RawFd},
+        io::{FromRawFd, IntoRawFd, RawFd},
         net::UnixStream as StdUnixStream,
         prelude::AsRawFd,
+        process::CommandExt,
     },
+    path::Path,
     pin::Pin,
+    process::{Command, Stdio},
     task::Context as TaskContext,
     task::Poll,
 };
@@ -20,14 +24,23 @@ use anyhow::{anyhow, Context, Result};
 use nix::{
     fcntl::{self, OFlag},
     sys::stat::Mode,
+    unistd,
 };
 use tokio::{
-    fs::OpenOptions,
+    fs::{self, File, OpenOptions},
     io::{AsyncRead, AsyncWrite},
     net::UnixStream as AsyncUnixStream,
 };
 use url::Url;
 
+// set by containerd in the environment of the shim
+const CONTAINERD_NAMESPACE_ENV: &str = "NAMESPACE";
+const DEFAULT_CONTAINERD_NAMESPACE: &str = "default";
+// the logging binary gets the stdout, stderr and wait fds as 3, 4 and 5
+const LOG_BINARY_FIRST_FD: RawFd = 3;
+
+type ShimIoWriter = Box<dyn AsyncWrite + Send + Unpin>;
+
 fn open_fifo(path: &str) -> Result<AsyncUnixStream> {
     let fd = fcntl::open(path, OFlag::O_RDWR, Mode::from_bits(0).unwrap())?;
 
@@ -41,12 +54,13 @@ fn open_fifo(path: &str) -> Result<AsyncUnixStream> {
 
 pub struct ShimIo {
     pub stdin: Option<Box<dyn AsyncRead + Send + Unpin>>,
-    pub stdout: Option<Box<dyn AsyncWrite + Send + Unpin>>,
-    pub stderr: Option<Box<dyn AsyncWrite + Send + Unpin>>,
+    pub stdout: Option<ShimIoWriter>,
+    pub stderr: Option<ShimIoWriter>,
 }
 
 impl ShimIo {
     pub async fn new(
+        container_id: &str,
         stdin: &Option<String>,
         stdout: &Option<String>,
         stderr: &Option<String>,
@@ -113,37 +127,166 @@ impl ShimIo {
         };
 
         let stdout_url = get_url(stdout);
-        let get_fd = |url: &Option<Url>| -> Option<Box<dyn AsyncWrite + Send + Unpin>> {
-            info!(sl!(), "get fd for {:?}", &url);
-            if let Some(url) = url {
-                if url.scheme() == "fifo" {
-                    let path = url.path();
-                    match open_fifo(path) {
-                        Ok(s) => {
-                            return Some(Box::new(ShimIoWrite::Stream(s)));
-                        }
-                        Err(err) => {
-                            error!(sl!(), "failed to open file {} error {:?}", url.path(), err);
-                        }
-                    }
-                }
-            }
-            None
-        };
-
         let stderr_url = get_url(stderr);
+
+        // the logging binary receives both stdout and stderr, containerd gives the same uri
+        if let Some(url) = stdout_url.as_ref().filter(|u| u.scheme() == "binary") {
+            let (stdout, stderr) = spawn_log_binary(url, container_id)
+                .await
+                .with_context(|| format!("spawn logging binary {}", url))?;
+            return Ok(Self {
+                stdin: stdin_fd,
+                stdout: Some(Box::new(stdout)),
+                stderr: stderr_url.map(|_| Box::new(stderr) as ShimIoWriter),
+            });
+        }
+
         Ok(Self {
             stdin: stdin_fd,
-            stdout: get_fd(&stdout_url),
-            stderr: get_fd(&stderr_url),
+            stdout: get_fd(&stdout_url).await?,
+            stderr: get_fd(&stderr_url).await?,
         })
     }
 }
 
+async fn get_fd(url: &Option<Url>) -> Result<Option<ShimIoWriter>> {
+    info!(sl!(), "get fd for {:?}", &url);
+    let url = match url {
+        Some(url) => url,
+        None => return Ok(None),
+    };
+
+    match url.scheme() {
+        "fifo" => {
+            let stream =
+                open_fifo(url.path()).with_context(|| format!("open fifo {}", url.path()))?;
+            return Ok(Some(Box::new(ShimIoWrite::Stream(stream))));
+        }
+        "file" => {
+            let file = open_log_file(url.path())
+                .await
+                .with_context(|| format!("open log file {}", url.path()))?;
+            return Ok(Some(Box::new(ShimIoWrite::File(file))));
+        }
+        scheme => {
+            warn!(sl!(), "unsupported io scheme {} of {}", scheme, url);
+        }
+    }
+
+    Ok(None)
+}
+
+async fn open_log_file(path: &str) -> Result<File> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)
+            .await
+            .with_context(|| format!("create dir {:?}", parent))?;
+    }
+
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .mode(0o644)
+        .open(path)
+        .await
+        .map_err(|e| anyhow!(e))
+}
+
+// The arguments of the logging binary are the key value pairs of the uri query.
+fn log_binary_args(url: &Url) -> Vec<String> {
+    let mut args = vec![];
+    for (key, value) in url.query_pairs() {
+        args.push(key.to_string());
+        if !value.is_empty() {
+            args.push(value.to_string());
+        }
+    }
+    args
+}
+
+fn pipe_stream(file: StdFile) -> Result<AsyncUnixStream> {
+    let std_stream = unsafe { StdUnixStream::from_raw_fd(file.into_raw_fd()) };
+    std_stream
+        .set_nonblocking(true)
+        .context("set nonblocking")?;
+
+    AsyncUnixStream::from_std(std_stream).map_err(|e| anyhow!(e))
+}
+
+/// Start the logging binary of a `binary://` uri with the containerd protocol: the binary reads
+/// the container stdout and stderr from fd 3 and 4, and closes fd 5 once it is ready.
+async fn spawn_log_binary(url: &Url, container_id: &str) -> Result<(ShimIoWrite, ShimIoWrite)> {
+    let new_pipe = || -> Result<(StdFile, StdFile)> {
+        let (r, w) = unistd::pipe2(OFlag::O_CLOEXEC).context("create pipe")?;
+        Ok(unsafe { (StdFile::from_raw_fd(r), StdFile::from_raw_fd(w)) })
+    };
+    let (stdout_r, stdout_w) = new_pipe()?;
+    let (stderr_r, stderr_w) = new_pipe()?;
+    let (mut wait_r, wait_w) = new_pipe()?;
+
+    let namespace = std::env::var(CONTAINERD_NAMESPACE_ENV)
+        .unwrap_or_else(|_| DEFAULT_CONTAINERD_NAMESPACE.to_string());
+    let mut cmd = Command::new(url.path());
+    // the binary gets the same environment as with containerd
+    cmd.args(log_binary_args(url))
+        .env_clear()
+        .env("CONTAINER_ID", container_id)
+        .env("CONTAINER_NAMESPACE", namespace)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+
+    let fds = [
+        stdout_r.as_raw_fd(),
+        stderr_r.as_raw_fd(),
+        wait_w.as_raw_fd(),
+    ];
+    unsafe {
+        cmd.pre_exec(move || {
+            // move the fds out of the way first, as they may already be numbered 3 to 5
+            let mut moved = [0; 3];
+            for (i, fd) in fds.iter().enumerate() {
+                moved[i] = libc::fcntl(*fd, libc::F_DUPFD_CLOEXEC, LOG_BINARY_FIRST_FD + 3);
+                if moved[i] < 0 {
+                    return Err(io::Error::last_os_error());
+                }
+            }
+            for (i, fd) in moved.iter().enumerate() {
+                if libc::dup2(*fd, LOG_BINARY_FIRST_FD + i as RawFd) < 0 {
+                    return Err(io::Error::last_os_error());
+                }
+            }
+            Ok(())
+        });
+    }
+
+    let mut child = cmd.spawn().context("spawn")?;
+    // only the binary keeps the read ends and the wait fd open
+    drop(stdout_r);
+    drop(stderr_r);
+    drop(wait_w);
+
+    tokio::task::spawn_blocking(move || wait_r.read(&mut [0u8; 1]))
+        .await
+        .context("join wait")?
+        .context("wait for logging binary")?;
+
+    let path = url.path().to_string();
+    let _ = tokio::task::spawn_blocking(move || match child.wait() {
+        Ok(status) => info!(sl!(), "logging binary {} exited with {}", path, status),
+        Err(err) => warn!(sl!(), "failed to wait logging binary {} {:?}", path, err),
+    });
+
+    Ok((
+        ShimIoWrite::Stream(pipe_stream(stdout_w).context("stdout pipe")?),
+        ShimIoWrite::Stream(pipe_stream(stderr_w).context("stderr pipe")?),
+    ))
+}
+
 #[derive(Debug)]
 enum ShimIoWrite {
     Stream(AsyncUnixStream),
-    // TODO: support other type
+    File(File),
 }
 
 impl AsyncWrite for ShimIoWrite {
@@ -154,18 +297,55 @@ impl AsyncWrite for ShimIoWrite {
     ) -> Poll<io::Result<usize>> {
         match *self {
             ShimIoWrite::Stream(ref mut s) => Pin::new(s).poll_write(cx, buf),
+            ShimIoWrite::File(ref mut f) => Pin::new(f).poll_write(cx, buf),
         }
     }
 
     fn poll_flush(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<io::Result<()>> {
         match *self {
             ShimIoWrite::Stream(ref mut s) => Pin::new(s).poll_flush(cx),
+            ShimIoWrite::File(ref mut f) => Pin::new(f).poll_flush(cx),
         }
     }
 
     fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<io::Result<()>> {
         match *self {
             ShimIoWrite::Stream(ref mut s) => Pin::new(s).poll_shutdown(cx),
+            ShimIoWrite::File(ref mut f) => Pin::new(f).poll_shutdown(cx),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_binary_args() {
+        let url = Url::parse("binary:///usr/bin/logger?--id=abc&--json&-v=2").unwrap();
+        assert_eq!(url.path(), "/usr/bin/logger");
+        assert_eq!(
+            log_binary_args(&url),
+            vec!["--id", "abc", "--json", "-v", "2"]
+        );
+
+        let url = Url::parse("binary:///usr/bin/logger").unwrap();
+        assert!(log_binary_args(&url).is_empty());
+    }
+
+    #[test]
+    fn test_get_fd_open_error() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        // the container must not start without its output
+        let url = Url::parse("fifo:///nonexistent/kata/stdout").unwrap();
+        assert!(rt.block_on(get_fd(&Some(url))).is_err());
+
+        let url = Url::parse("unknown:///nonexistent/kata/stdout").unwrap();
+        assert!(rt.block_on(get_fd(&Some(url))).unwrap().is_none());
+        assert!(rt.block_on(get_fd(&None)).unwrap().is_none());
+    }
+}
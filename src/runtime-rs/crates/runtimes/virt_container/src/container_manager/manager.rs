@@ -342,4 +342,19 @@ impl ContainerManager for VirtContainerManager {
         process.process_type == ProcessType::Container
             && process.container_id.container_id == self.sid
     }
+
+    async fn dump(&self) {
+        let containers = self.containers.read().await;
+        info!(
+            sl!(),
+            "sandbox {} shim pid {} has {} containers",
+            self.sid,
+            self.pid,
+            containers.len()
+        );
+        for container in containers.values() {
+            container.dump().await;
+        }
+        self.resource_manager.dump().await;
+    }
 }
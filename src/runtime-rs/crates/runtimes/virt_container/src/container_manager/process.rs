@@ -89,9 +89,14 @@ impl Process {
         info!(self.logger, "start io and wait");
 
         // new shim io
-        let shim_io = ShimIo::new(&self.stdin, &self.stdout, &self.stderr)
-            .await
-            .context("new shim io")?;
+        let shim_io = ShimIo::new(
+            &self.process.container_id.container_id,
+            &self.stdin,
+            &self.stdout,
+            &self.stderr,
+        )
+        .await
+        .context("new shim io")?;
 
         // start io copy for stdin
         let wgw_stdin = self.wg_stdin.worker();
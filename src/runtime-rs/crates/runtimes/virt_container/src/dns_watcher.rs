@@ -0,0 +1,329 @@
+// Copyright (c) 2019-2022 Alibaba Cloud
+// Copyright (c) 2019-2022 Ant Group
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use std::{net::IpAddr, sync::Arc};
+
+use agent::{Agent, DnsConfig, HostEntry, UpdateSandboxDnsRequest};
+use anyhow::Context;
+use common::SandboxDnsFiles;
+use tokio::sync::{mpsc, Mutex};
+
+/// dns files check interval 5s
+const DNS_CHECK_TIMER_INTERVAL: u64 = 5;
+
+/// dns watcher stop channel buffer size
+const DNS_WATCHER_STOP_CHANNEL_BUFFER_SIZE: usize = 1;
+
+/// The content of the name resolution files of the sandbox, a file which
+/// can't be read is None.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DnsFilesContent {
+    resolv_conf: Option<String>,
+    hosts: Option<String>,
+}
+
+impl DnsFilesContent {
+    pub fn read(files: &SandboxDnsFiles) -> Self {
+        Self {
+            resolv_conf: files.resolv_conf.as_deref().and_then(read_file),
+            hosts: files.hosts.as_deref().and_then(read_file),
+        }
+    }
+
+    /// The raw resolv.conf lines, for the agents which don't know about
+    /// the DNS config.
+    pub fn dns_lines(&self) -> Vec<String> {
+        self.resolv_conf
+            .as_deref()
+            .map(|c| c.lines().map(|l| l.to_string()).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn dns_config(&self) -> Option<DnsConfig> {
+        self.resolv_conf.as_deref().map(parse_resolv_conf)
+    }
+
+    pub fn hosts(&self) -> Vec<HostEntry> {
+        self.hosts.as_deref().map(parse_hosts).unwrap_or_default()
+    }
+}
+
+fn read_file(path: &str) -> Option<String> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Some(content),
+        Err(e) => {
+            warn!(sl!(), "failed to read {}: {:?}", path, e);
+            None
+        }
+    }
+}
+
+/// Parse a resolv.conf, see resolv.conf(5).
+fn parse_resolv_conf(content: &str) -> DnsConfig {
+    let mut config = DnsConfig::default();
+    for line in content.lines() {
+        let mut fields = line.split_whitespace();
+        let key = match fields.next() {
+            Some(key) if !key.starts_with('#') && !key.starts_with(';') => key,
+            _ => continue,
+        };
+        let values: Vec<String> = fields
+            .take_while(|f| !f.starts_with('#') && !f.starts_with(';'))
+            .map(|f| f.to_string())
+            .collect();
+        match key {
+            "nameserver" => config.nameservers.extend(values.into_iter().take(1)),
+            // the last of the domain and search keywords wins
+            "domain" => config.searches = values.into_iter().take(1).collect(),
+            "search" => config.searches = values,
+            "options" => config.options.extend(values),
+            _ => {}
+        }
+    }
+    config
+}
+
+/// Parse a hosts file, see hosts(5).
+fn parse_hosts(content: &str) -> Vec<HostEntry> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let line = line.split('#').next().unwrap_or_default();
+            let mut fields = line.split_whitespace();
+            let ip = fields.next()?;
+            // a link-local IPv6 address may come with its zone
+            let addr = ip.split('%').next().unwrap_or_default();
+            if addr.parse::<IpAddr>().is_err() {
+                warn!(sl!(), "invalid address {} in hosts file", ip);
+                return None;
+            }
+            let names: Vec<String> = fields.map(|f| f.to_string()).collect();
+            if names.is_empty() {
+                return None;
+            }
+            Some(HostEntry {
+                ip: ip.to_string(),
+                names,
+            })
+        })
+        .collect()
+}
+
+/// DnsWatcher forwards the changes of the host name resolution files of the
+/// sandbox to the agent.
+pub struct DnsWatcher {
+    stop_tx: mpsc::Sender<()>,
+    stop_rx: Arc<Mutex<mpsc::Receiver<()>>>,
+    // the watched files, saved to restart the watcher on restore
+    files: std::sync::Mutex<SandboxDnsFiles>,
+}
+
+impl DnsWatcher {
+    pub fn new() -> DnsWatcher {
+        let (tx, rx) = mpsc::channel(DNS_WATCHER_STOP_CHANNEL_BUFFER_SIZE);
+        DnsWatcher {
+            stop_tx: tx,
+            stop_rx: Arc::new(Mutex::new(rx)),
+            files: Default::default(),
+        }
+    }
+
+    pub fn files(&self) -> SandboxDnsFiles {
+        self.files.lock().unwrap().clone()
+    }
+
+    pub fn start(
+        &self,
+        id: &str,
+        files: SandboxDnsFiles,
+        content: DnsFilesContent,
+        agent: Arc<dyn Agent>,
+    ) {
+        if files.resolv_conf.is_none() && files.hosts.is_none() {
+            return;
+        }
+        *self.files.lock().unwrap() = files.clone();
+        let id = id.to_string();
+
+        info!(sl!(), "start dns watcher");
+
+        let stop_rx = self.stop_rx.clone();
+        let _ = tokio::spawn(async move {
+            let mut content = content;
+
+            loop {
+                tokio::time::sleep(std::time::Duration::from_secs(DNS_CHECK_TIMER_INTERVAL)).await;
+                let mut stop_rx = stop_rx.lock().await;
+                match stop_rx.try_recv() {
+                    Ok(_) => {
+                        info!(sl!(), "receive stop {} dns watcher signal", id);
+                        break;
+                    }
+
+                    Err(mpsc::error::TryRecvError::Empty) => {
+                        let new_content = DnsFilesContent::read(&files);
+                        if new_content == content {
+                            continue;
+                        }
+
+                        info!(sl!(), "dns files of {} changed", id);
+                        let req = UpdateSandboxDnsRequest {
+                            dns_config: new_content.dns_config(),
+                            hosts: new_content.hosts(),
+                        };
+                        match agent
+                            .update_sandbox_dns(req)
+                            .await
+                            .context("update sandbox dns")
+                        {
+                            // retry at the next check otherwise
+                            Ok(_) => content = new_content,
+                            Err(e) if agent::is_unimplemented(&e) => {
+                                warn!(sl!(), "agent can't update the dns, stop {} dns watcher", id);
+                                break;
+                            }
+                            Err(e) => warn!(sl!(), "failed to update {} dns: {:?}", id, e),
+                        }
+                    }
+
+                    Err(mpsc::error::TryRecvError::Disconnected) => {
+                        warn!(sl!(), "{} dns watcher channel has broken", id);
+                        break;
+                    }
+                }
+            }
+        });
+    }
+
+    pub async fn stop(&self) {
+        info!(sl!(), "stop dns watcher");
+        self.stop_tx
+            .send(())
+            .await
+            .map_err(|e| {
+                warn!(sl!(), "failed send dns watcher channel. {:?}", e);
+            })
+            .ok();
+    }
+}
+
+impl Default for DnsWatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(v: &[&str]) -> Vec<String> {
+        v.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_resolv_conf() {
+        let tests = &[
+            (
+                "nameserver 10.96.0.10\n\
+                 search default.svc.cluster.local svc.cluster.local cluster.local\n\
+                 options ndots:5\n",
+                DnsConfig {
+                    nameservers: strings(&["10.96.0.10"]),
+                    searches: strings(&[
+                        "default.svc.cluster.local",
+                        "svc.cluster.local",
+                        "cluster.local",
+                    ]),
+                    options: strings(&["ndots:5"]),
+                },
+            ),
+            (
+                "# generated\n\
+                 ; legacy comment\n\
+                 nameserver 10.0.0.2 # primary\n\
+                 nameserver fd00::53\n\
+                 domain example.com\n\
+                 options timeout:1\n\
+                 options attempts:2 rotate\n\
+                 sortlist 130.155.160.0/255.255.240.0\n",
+                DnsConfig {
+                    nameservers: strings(&["10.0.0.2", "fd00::53"]),
+                    searches: strings(&["example.com"]),
+                    options: strings(&["timeout:1", "attempts:2", "rotate"]),
+                },
+            ),
+            (
+                "search a.example.com\nsearch b.example.com c.example.com\n",
+                DnsConfig {
+                    searches: strings(&["b.example.com", "c.example.com"]),
+                    ..Default::default()
+                },
+            ),
+            ("", DnsConfig::default()),
+        ];
+
+        for (i, (content, expected)) in tests.iter().enumerate() {
+            assert_eq!(&parse_resolv_conf(content), expected, "test[{}]", i);
+        }
+    }
+
+    #[test]
+    fn test_parse_hosts() {
+        let content = "# Kubernetes-managed hosts file.\n\
+                       127.0.0.1\tlocalhost\n\
+                       ::1\tlocalhost ip6-localhost ip6-loopback\n\
+                       fe80::1%eth0\tgateway\n\
+                       10.244.0.5\tpod-0.svc pod-0 # comment\n\
+                       10.244.0.6\n\
+                       not-an-ip\tfoo\n\
+                       \n";
+        let expected = vec![
+            HostEntry {
+                ip: "127.0.0.1".to_string(),
+                names: strings(&["localhost"]),
+            },
+            HostEntry {
+                ip: "::1".to_string(),
+                names: strings(&["localhost", "ip6-localhost", "ip6-loopback"]),
+            },
+            HostEntry {
+                ip: "fe80::1%eth0".to_string(),
+                names: strings(&["gateway"]),
+            },
+            HostEntry {
+                ip: "10.244.0.5".to_string(),
+                names: strings(&["pod-0.svc", "pod-0"]),
+            },
+        ];
+        assert_eq!(parse_hosts(content), expected);
+    }
+
+    #[test]
+    fn test_dns_files_content() {
+        let content = DnsFilesContent {
+            resolv_conf: Some("nameserver 10.96.0.10\noptions ndots:5".to_string()),
+            hosts: None,
+        };
+        assert_eq!(
+            content.dns_lines(),
+            strings(&["nameserver 10.96.0.10", "options ndots:5"])
+        );
+        assert_eq!(
+            content.dns_config(),
+            Some(DnsConfig {
+                nameservers: strings(&["10.96.0.10"]),
+                options: strings(&["ndots:5"]),
+                ..Default::default()
+            })
+        );
+        assert!(content.hosts().is_empty());
+
+        let content = DnsFilesContent::default();
+        assert!(content.dns_lines().is_empty());
+        assert_eq!(content.dns_config(), None);
+    }
+}
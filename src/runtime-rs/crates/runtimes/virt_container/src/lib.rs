@@ -10,6 +10,7 @@ extern crate slog;
 logging::logger_with_subsystem!(sl, "virt-container");
 
 mod container_manager;
+pub mod dns_watcher;
 pub mod health_check;
 pub mod sandbox;
 pub mod sandbox_persist;
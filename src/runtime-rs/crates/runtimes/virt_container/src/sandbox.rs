@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-use std::sync::Arc;
+use std::{fs::File, io::Read, sync::Arc};
 
 use agent::{
     self, kata::KataAgent, types::KernelModule, Agent, GetIPTablesRequest, SetIPTablesRequest,
@@ -14,7 +14,7 @@ use anyhow::{anyhow, Context, Result};
 use async_trait::async_trait;
 use common::{
     message::{Action, Message},
-    Sandbox, SandboxNetworkEnv,
+    Sandbox, SandboxDnsFiles, SandboxNetworkEnv,
 };
 use containerd_shim_protos::events::task::TaskOOM;
 use hypervisor::{dragonball::Dragonball, Hypervisor, HYPERVISOR_DRAGONBALL};
@@ -26,11 +26,16 @@ use resource::{
     ResourceConfig, ResourceManager,
 };
 use tokio::sync::{mpsc::Sender, Mutex, RwLock};
+use tracing::instrument;
 
+use crate::dns_watcher::{DnsFilesContent, DnsWatcher};
 use crate::health_check::HealthCheck;
 use persist::{self, sandbox_persist::Persist};
 
 pub(crate) const VIRTCONTAINER: &str = "virt_container";
+// source and size of the seed given to the guest RNG, matching the go runtime
+const RNG_SEED_SOURCE: &str = "/dev/urandom";
+const RNG_SEED_SIZE: usize = 512;
 pub struct SandboxRestoreArgs {
     pub sid: String,
     pub toml_config: TomlConfig,
@@ -65,6 +70,7 @@ pub struct VirtSandbox {
     agent: Arc<dyn Agent>,
     hypervisor: Arc<dyn Hypervisor>,
     monitor: Arc<HealthCheck>,
+    dns_watcher: Arc<DnsWatcher>,
 }
 
 impl VirtSandbox {
@@ -85,6 +91,7 @@ impl VirtSandbox {
             hypervisor,
             resource_manager,
             monitor: Arc::new(HealthCheck::new(true, keep_abnormal)),
+            dns_watcher: Arc::new(DnsWatcher::new()),
         })
     }
 
@@ -110,6 +117,19 @@ impl VirtSandbox {
         Ok(resource_configs)
     }
 
+    async fn reseed_guest_rng(&self) -> Result<()> {
+        let mut data = vec![0u8; RNG_SEED_SIZE];
+        File::open(RNG_SEED_SOURCE)
+            .and_then(|mut f| f.read_exact(&mut data))
+            .with_context(|| format!("read seed from {}", RNG_SEED_SOURCE))?;
+
+        self.agent
+            .reseed_random_dev(agent::ReseedRandomDevRequest { data })
+            .await
+            .context("reseed random dev")?;
+        Ok(())
+    }
+
     async fn execute_oci_hook_functions(
         &self,
         prestart_hooks: &[oci::Hook],
@@ -159,6 +179,13 @@ impl VirtSandbox {
                 .network_info
                 .network_queues as usize,
             network_created,
+            vhost_user_net_sockets: config.runtime.vhost_user_net_sockets.clone(),
+            vhost_user_net_supported: self
+                .hypervisor
+                .capabilities()
+                .await
+                .map(|caps| caps.is_vhost_user_net_supported())
+                .unwrap_or_default(),
         })
     }
 
@@ -173,9 +200,14 @@ impl VirtSandbox {
 
 #[async_trait]
 impl Sandbox for VirtSandbox {
+    #[instrument(
+        name = "start_sandbox",
+        skip(self, dns, spec, state, network_env),
+        fields(sid = %self.sid)
+    )]
     async fn start(
         &self,
-        dns: Vec<String>,
+        dns: SandboxDnsFiles,
         spec: &oci::Spec,
         state: &oci::State,
         network_env: SandboxNetworkEnv,
@@ -220,7 +252,8 @@ impl Sandbox for VirtSandbox {
         // 1. if there are pre-start hook functions, network config might have been changed.
         //    We need to rescan the netns to handle the change.
         // 2. Do not scan the netns if we want no network for the VM.
-        // TODO In case of vm factory, scan the netns to hotplug interfaces after the VM is started.
+        // 3. Interfaces added to the netns once the VM is started (e.g. in case of vm factory)
+        //    are hotplugged by the netns watcher of the resource manager.
         if self.has_prestart_hooks(prestart_hooks, create_runtime_hooks)
             && !self
                 .resource_manager
@@ -257,9 +290,10 @@ impl Sandbox for VirtSandbox {
         // create sandbox in vm
         let agent_config = self.agent.agent_config().await;
         let kernel_modules = KernelModule::set_kernel_modules(agent_config.kernel_modules)?;
+        let dns_content = DnsFilesContent::read(&dns);
         let req = agent::CreateSandboxRequest {
             hostname: spec.hostname.clone(),
-            dns,
+            dns: dns_content.dns_lines(),
             storages: self
                 .resource_manager
                 .get_storage_for_sandbox()
@@ -274,6 +308,8 @@ impl Sandbox for VirtSandbox {
                 .security_info
                 .guest_hook_path,
             kernel_modules,
+            dns_config: dns_content.dns_config(),
+            hosts: dns_content.hosts(),
         };
 
         self.agent
@@ -281,6 +317,12 @@ impl Sandbox for VirtSandbox {
             .await
             .context("create sandbox")?;
 
+        // make sure the guest RNG doesn't start from a state shared with other VMs, the
+        // sandbox still works without it, e.g. with an older agent
+        if let Err(err) = self.reseed_guest_rng().await {
+            warn!(sl!(), "failed to reseed guest rng: {:?}", err);
+        }
+
         inner.state = SandboxState::Running;
         let agent = self.agent.clone();
         let sender = self.msg_sender.clone();
@@ -316,6 +358,8 @@ impl Sandbox for VirtSandbox {
             }
         });
         self.monitor.start(id, self.agent.clone());
+        self.dns_watcher
+            .start(id, dns, dns_content, self.agent.clone());
         self.save().await.context("save state")?;
         Ok(())
     }
@@ -336,6 +380,8 @@ impl Sandbox for VirtSandbox {
         info!(sl!(), "stop monitor");
         self.monitor.stop().await;
 
+        self.dns_watcher.stop().await;
+
         info!(sl!(), "stop agent");
         self.agent.stop().await;
 
@@ -419,10 +465,13 @@ impl Persist for VirtSandbox {
 
     /// Save a state of Sandbox
     async fn save(&self) -> Result<Self::State> {
+        let dns = self.dns_watcher.files();
         let sandbox_state = crate::sandbox_persist::SandboxState {
             sandbox_type: VIRTCONTAINER.to_string(),
             resource: Some(self.resource_manager.save().await?),
             hypervisor: Some(self.hypervisor.save_state().await?),
+            resolv_conf: dns.resolv_conf,
+            hosts: dns.hosts,
         };
         persist::to_disk(&sandbox_state, &self.sid)?;
         Ok(sandbox_state)
@@ -450,6 +499,13 @@ impl Persist for VirtSandbox {
             config,
         };
         let resource_manager = Arc::new(ResourceManager::restore(args, r).await?);
+        // the files may have changed while the shim was gone, so push them at the first check
+        let dns_watcher = Arc::new(DnsWatcher::new());
+        let dns = SandboxDnsFiles {
+            resolv_conf: sandbox_state.resolv_conf,
+            hosts: sandbox_state.hosts,
+        };
+        dns_watcher.start(&sid, dns, DnsFilesContent::default(), agent.clone());
         Ok(Self {
             sid: sid.to_string(),
             msg_sender: Arc::new(Mutex::new(sandbox_args.sender)),
@@ -458,6 +514,7 @@ impl Persist for VirtSandbox {
             hypervisor,
             resource_manager,
             monitor: Arc::new(HealthCheck::new(true, keep_abnormal)),
+            dns_watcher,
         })
     }
 }
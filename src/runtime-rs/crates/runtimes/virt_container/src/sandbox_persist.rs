@@ -13,4 +13,7 @@ pub struct SandboxState {
     pub sandbox_type: String,
     pub resource: Option<ResourceState>,
     pub hypervisor: Option<HypervisorState>,
+    // the host name resolution files of the sandbox
+    pub resolv_conf: Option<String>,
+    pub hosts: Option<String>,
 }
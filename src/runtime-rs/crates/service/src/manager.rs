@@ -17,7 +17,7 @@ use containerd_shim_protos::{
     protobuf::{well_known_types::any::Any, Message as ProtobufMessage},
     shim_async,
 };
-use runtimes::RuntimeHandlerManager;
+use runtimes::{tracer, RuntimeHandlerManager};
 use tokio::{
     io::AsyncWriteExt,
     process::Command,
@@ -106,6 +106,10 @@ impl ServiceManager {
         })
     }
 
+    pub fn runtime_handler(&self) -> Arc<RuntimeHandlerManager> {
+        self.handler.clone()
+    }
+
     pub async fn run(&mut self) -> Result<()> {
         info!(sl!(), "begin to run service");
         self.start().await.context("start")?;
@@ -120,6 +124,8 @@ impl ServiceManager {
                     Action::Stop => self.stop_listen().await.context("stop listen"),
                     Action::Shutdown => {
                         self.stop_listen().await.context("stop listen")?;
+                        // flush the spans before the shim exits
+                        tracer::end_tracing().await;
                         break;
                     }
                     Action::Event(event) => {
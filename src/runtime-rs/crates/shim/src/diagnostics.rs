@@ -0,0 +1,291 @@
+// Copyright (c) 2023 Kata Contributors
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use std::{
+    ffi::c_void,
+    fmt::Write,
+    fs,
+    future::Future,
+    sync::atomic::{AtomicBool, AtomicI32, AtomicUsize, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use backtrace::Backtrace;
+use nix::sys::signal::{self, SaFlags, SigAction, SigHandler, SigSet, Signal};
+use tokio::{
+    signal::unix::{signal, SignalKind},
+    time::timeout,
+};
+
+// signal sent to every thread to capture its backtrace, SIGUSR1 triggers the whole dump
+const CAPTURE_SIGNAL: Signal = Signal::SIGUSR2;
+const MAX_FRAMES: usize = 64;
+// time given to a thread to capture its backtrace
+const CAPTURE_TIMEOUT: Duration = Duration::from_millis(200);
+// the runtime state is dumped behind its locks, don't wait forever on a hung task
+const STATE_DUMP_TIMEOUT: Duration = Duration::from_secs(10);
+
+// Frames captured by the signalled thread. Threads are captured one at a time, by the task
+// handling SIGUSR1 only.
+#[allow(clippy::declare_interior_mutable_const)]
+const NO_FRAME: AtomicUsize = AtomicUsize::new(0);
+static FRAMES: [AtomicUsize; MAX_FRAMES] = [NO_FRAME; MAX_FRAMES];
+static FRAME_COUNT: AtomicUsize = AtomicUsize::new(0);
+// thread expected to capture its frames, a late signal for another thread is ignored
+static TARGET_TID: AtomicI32 = AtomicI32::new(0);
+static CAPTURED: AtomicBool = AtomicBool::new(false);
+
+fn gettid() -> i32 {
+    unsafe { libc::syscall(libc::SYS_gettid) as i32 }
+}
+
+// Read a word of the interrupted thread's stack. process_vm_readv() fails with EFAULT instead
+// of crashing on a bad address, and is a plain syscall safe to call in a signal handler.
+fn read_word(addr: usize) -> Option<usize> {
+    let mut word: usize = 0;
+    let local = libc::iovec {
+        iov_base: &mut word as *mut usize as *mut c_void,
+        iov_len: std::mem::size_of::<usize>(),
+    };
+    let remote = libc::iovec {
+        iov_base: addr as *mut c_void,
+        iov_len: std::mem::size_of::<usize>(),
+    };
+    let n = unsafe { libc::process_vm_readv(libc::getpid(), &local, 1, &remote, 1, 0) };
+    if n as usize != std::mem::size_of::<usize>() {
+        return None;
+    }
+    Some(word)
+}
+
+// The instruction pointer and frame pointer of the interrupted code.
+fn interrupted_registers(ucontext: *mut c_void) -> (usize, usize) {
+    let uc = unsafe { &*(ucontext as *const libc::ucontext_t) };
+    #[cfg(target_arch = "x86_64")]
+    {
+        (
+            uc.uc_mcontext.gregs[libc::REG_RIP as usize] as usize,
+            uc.uc_mcontext.gregs[libc::REG_RBP as usize] as usize,
+        )
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        (uc.uc_mcontext.pc as usize, uc.uc_mcontext.regs[29] as usize)
+    }
+}
+
+// Walk the frame pointer chain of the interrupted thread into the preallocated FRAMES. This
+// runs in the signal handler so it neither allocates nor takes locks, unlike a DWARF unwinder,
+// which is why it only sees the frames built with frame pointers, see
+// `-C force-frame-pointers=yes`.
+extern "C" fn capture_frames(_: libc::c_int, _: *mut libc::siginfo_t, ucontext: *mut c_void) {
+    if gettid() != TARGET_TID.load(Ordering::Acquire) {
+        return;
+    }
+
+    let (ip, mut fp) = interrupted_registers(ucontext);
+    FRAMES[0].store(ip, Ordering::Relaxed);
+    let mut count = 1;
+    while count < MAX_FRAMES && fp != 0 && fp % std::mem::align_of::<usize>() == 0 {
+        // the caller frame pointer is saved at fp, and the return address right above it
+        let (next_fp, ret) = match (read_word(fp), read_word(fp + std::mem::size_of::<usize>())) {
+            (Some(next_fp), Some(ret)) => (next_fp, ret),
+            _ => break,
+        };
+        if ret == 0 {
+            break;
+        }
+        FRAMES[count].store(ret, Ordering::Relaxed);
+        count += 1;
+        // the stack grows down, a caller frame is always above
+        if next_fp <= fp {
+            break;
+        }
+        fp = next_fp;
+    }
+    FRAME_COUNT.store(count, Ordering::Relaxed);
+    CAPTURED.store(true, Ordering::Release);
+}
+
+fn install_capture_handler() -> Result<()> {
+    let action = SigAction::new(
+        SigHandler::SigAction(capture_frames),
+        SaFlags::SA_RESTART | SaFlags::SA_SIGINFO,
+        SigSet::empty(),
+    );
+    unsafe { signal::sigaction(CAPTURE_SIGNAL, &action) }
+        .map(|_| ())
+        .context("sigaction")
+}
+
+/// Signal the thread `tid` and collect the instruction pointers of its frames.
+fn capture_thread(tid: i32) -> Option<Vec<usize>> {
+    CAPTURED.store(false, Ordering::Release);
+    TARGET_TID.store(tid, Ordering::Release);
+
+    let ret = unsafe {
+        libc::syscall(
+            libc::SYS_tgkill,
+            libc::getpid(),
+            tid,
+            CAPTURE_SIGNAL as libc::c_int,
+        )
+    };
+    if ret < 0 {
+        return None;
+    }
+
+    let start = Instant::now();
+    while !CAPTURED.load(Ordering::Acquire) {
+        if start.elapsed() > CAPTURE_TIMEOUT {
+            TARGET_TID.store(0, Ordering::Release);
+            return None;
+        }
+        thread::sleep(Duration::from_millis(1));
+    }
+    TARGET_TID.store(0, Ordering::Release);
+
+    let count = FRAME_COUNT.load(Ordering::Relaxed);
+    Some(
+        FRAMES[..count]
+            .iter()
+            .map(|ip| ip.load(Ordering::Relaxed))
+            .collect(),
+    )
+}
+
+fn format_frames(ips: &[usize]) -> String {
+    let mut out = String::new();
+    for (i, ip) in ips.iter().enumerate() {
+        let mut resolved = false;
+        backtrace::resolve(*ip as *mut c_void, |symbol| {
+            resolved = true;
+            let name = symbol
+                .name()
+                .map(|n| n.to_string())
+                .unwrap_or_else(|| "<unknown>".to_string());
+            let _ = write!(out, "\n{:4}: {}", i, name);
+            if let (Some(file), Some(line)) = (symbol.filename(), symbol.lineno()) {
+                let _ = write!(out, "\n        at {}:{}", file.display(), line);
+            }
+        });
+        if !resolved {
+            let _ = write!(out, "\n{:4}: {:#x}", i, ip);
+        }
+    }
+    out
+}
+
+/// Log the backtrace of every thread of the shim.
+fn dump_thread_backtraces() {
+    let tids: Vec<i32> = match fs::read_dir("/proc/self/task") {
+        Ok(entries) => entries
+            .filter_map(|e| e.ok()?.file_name().to_str()?.parse().ok())
+            .collect(),
+        Err(err) => {
+            error!(sl!(), "failed to list threads: {:?}", err);
+            return;
+        }
+    };
+
+    let self_tid = gettid();
+    for tid in tids {
+        let name = fs::read_to_string(format!("/proc/self/task/{}/comm", tid))
+            .map(|n| n.trim_end().to_string())
+            .unwrap_or_default();
+
+        let frames = if tid == self_tid {
+            format!("\n{:?}", Backtrace::new())
+        } else {
+            match capture_thread(tid) {
+                Some(ips) => format_frames(&ips),
+                None => {
+                    warn!(sl!(), "thread {} ({}) backtrace not captured", tid, name);
+                    continue;
+                }
+            }
+        };
+        info!(sl!(), "thread {} ({}) backtrace:{}", tid, name, frames);
+    }
+}
+
+#[cfg(tokio_unstable)]
+fn dump_tokio_runtime() {
+    let metrics = tokio::runtime::Handle::current().metrics();
+    info!(
+        sl!(),
+        "tokio runtime: {} workers, {} blocking threads, {} tasks in the injection queue",
+        metrics.num_workers(),
+        metrics.num_blocking_threads(),
+        metrics.injection_queue_depth()
+    );
+}
+
+#[cfg(not(tokio_unstable))]
+fn dump_tokio_runtime() {
+    info!(sl!(), "tokio runtime metrics need a build with tokio_unstable");
+}
+
+/// On SIGUSR1, log the backtraces of all the threads, the tokio runtime and the runtime state
+/// dumped by `dump_state`. This is meant to debug a hung shim, like the SIGUSR1 handler of the
+/// Kata 1.x runtime.
+pub(crate) fn watch_dump_signal<F, Fut>(dump_state: F) -> Result<()>
+where
+    F: Fn() -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    install_capture_handler().context("install backtrace capture handler")?;
+    let mut signals = signal(SignalKind::user_defined1()).context("listen SIGUSR1")?;
+
+    tokio::spawn(async move {
+        while signals.recv().await.is_some() {
+            info!(sl!(), "received SIGUSR1, dump backtraces and runtime state");
+            if let Err(err) = tokio::task::spawn_blocking(dump_thread_backtraces).await {
+                error!(sl!(), "failed to dump thread backtraces: {:?}", err);
+            }
+            dump_tokio_runtime();
+            if timeout(STATE_DUMP_TIMEOUT, dump_state()).await.is_err() {
+                warn!(
+                    sl!(),
+                    "timeout dumping the runtime state, a lock may be held by a hung task"
+                );
+            }
+            info!(sl!(), "end of SIGUSR1 dump");
+        }
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::channel;
+
+    #[test]
+    fn test_capture_thread() {
+        install_capture_handler().unwrap();
+
+        let (tid_tx, tid_rx) = channel();
+        let (done_tx, done_rx) = channel::<()>();
+        let handle = thread::spawn(move || {
+            tid_tx.send(gettid()).unwrap();
+            done_rx.recv().unwrap();
+        });
+
+        let tid = tid_rx.recv().unwrap();
+        let ips = capture_thread(tid).unwrap();
+        assert!(!ips.is_empty());
+        assert!(!format_frames(&ips).is_empty());
+
+        done_tx.send(()).unwrap();
+        handle.join().unwrap();
+
+        // the thread is gone
+        assert!(capture_thread(tid).is_none());
+    }
+}
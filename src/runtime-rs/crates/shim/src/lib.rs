@@ -11,6 +11,7 @@ logging::logger_with_subsystem!(sl, "shim");
 
 mod args;
 pub use args::Args;
+mod diagnostics;
 mod error;
 pub use error::Error;
 mod logger;
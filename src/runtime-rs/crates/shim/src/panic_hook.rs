@@ -10,8 +10,6 @@ use backtrace::Backtrace;
 
 const KMESG_DEVICE: &str = "/dev/kmsg";
 
-// TODO: the Kata 1.x runtime had a SIGUSR1 handler that would log a formatted backtrace on
-// receiving that signal. It could be useful to re-add that feature.
 pub(crate) fn set_panic_hook() {
     std::panic::set_hook(Box::new(move |panic_info| {
         let (filename, line) = panic_info
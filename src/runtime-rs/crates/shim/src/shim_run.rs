@@ -55,6 +55,14 @@ impl ShimExecutor {
         )
         .await
         .context("new shim server")?;
+
+        let handler = service_manager.runtime_handler();
+        crate::diagnostics::watch_dump_signal(move || {
+            let handler = handler.clone();
+            async move { handler.dump().await }
+        })
+        .context("watch dump signal")?;
+
         service_manager.run().await.context("run")?;
 
         Ok(())
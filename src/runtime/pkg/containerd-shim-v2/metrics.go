@@ -150,8 +150,8 @@ func setCPUStatsV1(vcCPU vc.CPUStats) *cgroupsv1.CPUStat {
 func setCPUStatsV2(vcCPU vc.CPUStats) *cgroupsv2.CPUStat {
 	cpuStats := &cgroupsv2.CPUStat{
 		UsageUsec:     vcCPU.CPUUsage.TotalUsage / 1000,
-		UserUsec:      vcCPU.CPUUsage.UsageInKernelmode / 1000,
-		SystemUsec:    vcCPU.CPUUsage.UsageInUsermode / 1000,
+		UserUsec:      vcCPU.CPUUsage.UsageInUsermode / 1000,
+		SystemUsec:    vcCPU.CPUUsage.UsageInKernelmode / 1000,
 		NrPeriods:     vcCPU.ThrottlingData.Periods,
 		NrThrottled:   vcCPU.ThrottlingData.ThrottledPeriods,
 		ThrottledUsec: vcCPU.ThrottlingData.ThrottledTime / 1000,
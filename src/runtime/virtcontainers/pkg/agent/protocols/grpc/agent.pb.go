@@ -5,6 +5,7 @@ package grpc
 
 import (
 	context "context"
+	encoding_binary "encoding/binary"
 	fmt "fmt"
 	github_com_containerd_ttrpc "github.com/containerd/ttrpc"
 	proto "github.com/gogo/protobuf/proto"
@@ -568,9 +569,94 @@ func (m *ThrottlingData) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_ThrottlingData proto.InternalMessageInfo
 
+// Pressure stall information, as reported by the cgroup v2
+// cpu.pressure, memory.pressure and io.pressure files.
+type PSIData struct {
+	Avg10                float64  `protobuf:"fixed64,1,opt,name=avg10,proto3" json:"avg10,omitempty"`
+	Avg60                float64  `protobuf:"fixed64,2,opt,name=avg60,proto3" json:"avg60,omitempty"`
+	Avg300               float64  `protobuf:"fixed64,3,opt,name=avg300,proto3" json:"avg300,omitempty"`
+	Total                uint64   `protobuf:"varint,4,opt,name=total,proto3" json:"total,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *PSIData) Reset()      { *m = PSIData{} }
+func (*PSIData) ProtoMessage() {}
+func (*PSIData) Descriptor() ([]byte, []int) {
+	return fileDescriptor_712ce9a559fda969, []int{13}
+}
+func (m *PSIData) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *PSIData) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	if deterministic {
+		return xxx_messageInfo_PSIData.Marshal(b, m, deterministic)
+	} else {
+		b = b[:cap(b)]
+		n, err := m.MarshalToSizedBuffer(b)
+		if err != nil {
+			return nil, err
+		}
+		return b[:n], nil
+	}
+}
+func (m *PSIData) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_PSIData.Merge(m, src)
+}
+func (m *PSIData) XXX_Size() int {
+	return m.Size()
+}
+func (m *PSIData) XXX_DiscardUnknown() {
+	xxx_messageInfo_PSIData.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_PSIData proto.InternalMessageInfo
+
+type PSIStats struct {
+	Some                 *PSIData `protobuf:"bytes,1,opt,name=some,proto3" json:"some,omitempty"`
+	Full                 *PSIData `protobuf:"bytes,2,opt,name=full,proto3" json:"full,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *PSIStats) Reset()      { *m = PSIStats{} }
+func (*PSIStats) ProtoMessage() {}
+func (*PSIStats) Descriptor() ([]byte, []int) {
+	return fileDescriptor_712ce9a559fda969, []int{14}
+}
+func (m *PSIStats) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *PSIStats) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	if deterministic {
+		return xxx_messageInfo_PSIStats.Marshal(b, m, deterministic)
+	} else {
+		b = b[:cap(b)]
+		n, err := m.MarshalToSizedBuffer(b)
+		if err != nil {
+			return nil, err
+		}
+		return b[:n], nil
+	}
+}
+func (m *PSIStats) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_PSIStats.Merge(m, src)
+}
+func (m *PSIStats) XXX_Size() int {
+	return m.Size()
+}
+func (m *PSIStats) XXX_DiscardUnknown() {
+	xxx_messageInfo_PSIStats.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_PSIStats proto.InternalMessageInfo
+
 type CpuStats struct {
 	CpuUsage             *CpuUsage       `protobuf:"bytes,1,opt,name=cpu_usage,json=cpuUsage,proto3" json:"cpu_usage,omitempty"`
 	ThrottlingData       *ThrottlingData `protobuf:"bytes,2,opt,name=throttling_data,json=throttlingData,proto3" json:"throttling_data,omitempty"`
+	Psi                  *PSIStats       `protobuf:"bytes,3,opt,name=psi,proto3" json:"psi,omitempty"`
 	XXX_NoUnkeyedLiteral struct{}        `json:"-"`
 	XXX_unrecognized     []byte          `json:"-"`
 	XXX_sizecache        int32           `json:"-"`
@@ -579,7 +665,7 @@ type CpuStats struct {
 func (m *CpuStats) Reset()      { *m = CpuStats{} }
 func (*CpuStats) ProtoMessage() {}
 func (*CpuStats) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{13}
+	return fileDescriptor_712ce9a559fda969, []int{15}
 }
 func (m *CpuStats) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -619,7 +705,7 @@ type PidsStats struct {
 func (m *PidsStats) Reset()      { *m = PidsStats{} }
 func (*PidsStats) ProtoMessage() {}
 func (*PidsStats) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{14}
+	return fileDescriptor_712ce9a559fda969, []int{16}
 }
 func (m *PidsStats) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -661,7 +747,7 @@ type MemoryData struct {
 func (m *MemoryData) Reset()      { *m = MemoryData{} }
 func (*MemoryData) ProtoMessage() {}
 func (*MemoryData) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{15}
+	return fileDescriptor_712ce9a559fda969, []int{17}
 }
 func (m *MemoryData) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -697,6 +783,7 @@ type MemoryStats struct {
 	KernelUsage          *MemoryData       `protobuf:"bytes,4,opt,name=kernel_usage,json=kernelUsage,proto3" json:"kernel_usage,omitempty"`
 	UseHierarchy         bool              `protobuf:"varint,5,opt,name=use_hierarchy,json=useHierarchy,proto3" json:"use_hierarchy,omitempty"`
 	Stats                map[string]uint64 `protobuf:"bytes,6,rep,name=stats,proto3" json:"stats,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"varint,2,opt,name=value,proto3"`
+	Psi                  *PSIStats         `protobuf:"bytes,7,opt,name=psi,proto3" json:"psi,omitempty"`
 	XXX_NoUnkeyedLiteral struct{}          `json:"-"`
 	XXX_unrecognized     []byte            `json:"-"`
 	XXX_sizecache        int32             `json:"-"`
@@ -705,7 +792,7 @@ type MemoryStats struct {
 func (m *MemoryStats) Reset()      { *m = MemoryStats{} }
 func (*MemoryStats) ProtoMessage() {}
 func (*MemoryStats) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{16}
+	return fileDescriptor_712ce9a559fda969, []int{18}
 }
 func (m *MemoryStats) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -747,7 +834,7 @@ type BlkioStatsEntry struct {
 func (m *BlkioStatsEntry) Reset()      { *m = BlkioStatsEntry{} }
 func (*BlkioStatsEntry) ProtoMessage() {}
 func (*BlkioStatsEntry) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{17}
+	return fileDescriptor_712ce9a559fda969, []int{19}
 }
 func (m *BlkioStatsEntry) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -785,6 +872,7 @@ type BlkioStats struct {
 	IoMergedRecursive       []*BlkioStatsEntry `protobuf:"bytes,6,rep,name=io_merged_recursive,json=ioMergedRecursive,proto3" json:"io_merged_recursive,omitempty"`
 	IoTimeRecursive         []*BlkioStatsEntry `protobuf:"bytes,7,rep,name=io_time_recursive,json=ioTimeRecursive,proto3" json:"io_time_recursive,omitempty"`
 	SectorsRecursive        []*BlkioStatsEntry `protobuf:"bytes,8,rep,name=sectors_recursive,json=sectorsRecursive,proto3" json:"sectors_recursive,omitempty"`
+	Psi                     *PSIStats          `protobuf:"bytes,9,opt,name=psi,proto3" json:"psi,omitempty"`
 	XXX_NoUnkeyedLiteral    struct{}           `json:"-"`
 	XXX_unrecognized        []byte             `json:"-"`
 	XXX_sizecache           int32              `json:"-"`
@@ -793,7 +881,7 @@ type BlkioStats struct {
 func (m *BlkioStats) Reset()      { *m = BlkioStats{} }
 func (*BlkioStats) ProtoMessage() {}
 func (*BlkioStats) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{18}
+	return fileDescriptor_712ce9a559fda969, []int{20}
 }
 func (m *BlkioStats) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -826,6 +914,7 @@ type HugetlbStats struct {
 	Usage                uint64   `protobuf:"varint,1,opt,name=usage,proto3" json:"usage,omitempty"`
 	MaxUsage             uint64   `protobuf:"varint,2,opt,name=max_usage,json=maxUsage,proto3" json:"max_usage,omitempty"`
 	Failcnt              uint64   `protobuf:"varint,3,opt,name=failcnt,proto3" json:"failcnt,omitempty"`
+	Limit                uint64   `protobuf:"varint,4,opt,name=limit,proto3" json:"limit,omitempty"`
 	XXX_NoUnkeyedLiteral struct{} `json:"-"`
 	XXX_unrecognized     []byte   `json:"-"`
 	XXX_sizecache        int32    `json:"-"`
@@ -834,7 +923,7 @@ type HugetlbStats struct {
 func (m *HugetlbStats) Reset()      { *m = HugetlbStats{} }
 func (*HugetlbStats) ProtoMessage() {}
 func (*HugetlbStats) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{19}
+	return fileDescriptor_712ce9a559fda969, []int{21}
 }
 func (m *HugetlbStats) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -869,6 +958,7 @@ type CgroupStats struct {
 	PidsStats            *PidsStats               `protobuf:"bytes,3,opt,name=pids_stats,json=pidsStats,proto3" json:"pids_stats,omitempty"`
 	BlkioStats           *BlkioStats              `protobuf:"bytes,4,opt,name=blkio_stats,json=blkioStats,proto3" json:"blkio_stats,omitempty"`
 	HugetlbStats         map[string]*HugetlbStats `protobuf:"bytes,5,rep,name=hugetlb_stats,json=hugetlbStats,proto3" json:"hugetlb_stats,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
+	CgroupVersion        uint32                   `protobuf:"varint,6,opt,name=cgroup_version,json=cgroupVersion,proto3" json:"cgroup_version,omitempty"`
 	XXX_NoUnkeyedLiteral struct{}                 `json:"-"`
 	XXX_unrecognized     []byte                   `json:"-"`
 	XXX_sizecache        int32                    `json:"-"`
@@ -877,7 +967,7 @@ type CgroupStats struct {
 func (m *CgroupStats) Reset()      { *m = CgroupStats{} }
 func (*CgroupStats) ProtoMessage() {}
 func (*CgroupStats) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{20}
+	return fileDescriptor_712ce9a559fda969, []int{22}
 }
 func (m *CgroupStats) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -924,7 +1014,7 @@ type NetworkStats struct {
 func (m *NetworkStats) Reset()      { *m = NetworkStats{} }
 func (*NetworkStats) ProtoMessage() {}
 func (*NetworkStats) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{21}
+	return fileDescriptor_712ce9a559fda969, []int{23}
 }
 func (m *NetworkStats) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -964,7 +1054,7 @@ type StatsContainerResponse struct {
 func (m *StatsContainerResponse) Reset()      { *m = StatsContainerResponse{} }
 func (*StatsContainerResponse) ProtoMessage() {}
 func (*StatsContainerResponse) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{22}
+	return fileDescriptor_712ce9a559fda969, []int{24}
 }
 func (m *StatsContainerResponse) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1005,7 +1095,7 @@ type WriteStreamRequest struct {
 func (m *WriteStreamRequest) Reset()      { *m = WriteStreamRequest{} }
 func (*WriteStreamRequest) ProtoMessage() {}
 func (*WriteStreamRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{23}
+	return fileDescriptor_712ce9a559fda969, []int{25}
 }
 func (m *WriteStreamRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1044,7 +1134,7 @@ type WriteStreamResponse struct {
 func (m *WriteStreamResponse) Reset()      { *m = WriteStreamResponse{} }
 func (*WriteStreamResponse) ProtoMessage() {}
 func (*WriteStreamResponse) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{24}
+	return fileDescriptor_712ce9a559fda969, []int{26}
 }
 func (m *WriteStreamResponse) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1085,7 +1175,7 @@ type ReadStreamRequest struct {
 func (m *ReadStreamRequest) Reset()      { *m = ReadStreamRequest{} }
 func (*ReadStreamRequest) ProtoMessage() {}
 func (*ReadStreamRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{25}
+	return fileDescriptor_712ce9a559fda969, []int{27}
 }
 func (m *ReadStreamRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1124,7 +1214,7 @@ type ReadStreamResponse struct {
 func (m *ReadStreamResponse) Reset()      { *m = ReadStreamResponse{} }
 func (*ReadStreamResponse) ProtoMessage() {}
 func (*ReadStreamResponse) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{26}
+	return fileDescriptor_712ce9a559fda969, []int{28}
 }
 func (m *ReadStreamResponse) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1164,7 +1254,7 @@ type CloseStdinRequest struct {
 func (m *CloseStdinRequest) Reset()      { *m = CloseStdinRequest{} }
 func (*CloseStdinRequest) ProtoMessage() {}
 func (*CloseStdinRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{27}
+	return fileDescriptor_712ce9a559fda969, []int{29}
 }
 func (m *CloseStdinRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1206,7 +1296,7 @@ type TtyWinResizeRequest struct {
 func (m *TtyWinResizeRequest) Reset()      { *m = TtyWinResizeRequest{} }
 func (*TtyWinResizeRequest) ProtoMessage() {}
 func (*TtyWinResizeRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{28}
+	return fileDescriptor_712ce9a559fda969, []int{30}
 }
 func (m *TtyWinResizeRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1249,7 +1339,7 @@ type KernelModule struct {
 func (m *KernelModule) Reset()      { *m = KernelModule{} }
 func (*KernelModule) ProtoMessage() {}
 func (*KernelModule) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{29}
+	return fileDescriptor_712ce9a559fda969, []int{31}
 }
 func (m *KernelModule) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1295,16 +1385,21 @@ type CreateSandboxRequest struct {
 	// that the agent will search for OCI hooks to run within the guest.
 	GuestHookPath string `protobuf:"bytes,6,opt,name=guest_hook_path,json=guestHookPath,proto3" json:"guest_hook_path,omitempty"`
 	// This field is the list of kernel modules to be loaded in the guest kernel.
-	KernelModules        []*KernelModule `protobuf:"bytes,7,rep,name=kernel_modules,json=kernelModules,proto3" json:"kernel_modules,omitempty"`
-	XXX_NoUnkeyedLiteral struct{}        `json:"-"`
-	XXX_unrecognized     []byte          `json:"-"`
-	XXX_sizecache        int32           `json:"-"`
+	KernelModules []*KernelModule `protobuf:"bytes,7,rep,name=kernel_modules,json=kernelModules,proto3" json:"kernel_modules,omitempty"`
+	// This field, if set, is used to generate the resolv.conf of the sandbox
+	// instead of the dns lines.
+	DnsConfig *DNSConfig `protobuf:"bytes,8,opt,name=dns_config,json=dnsConfig,proto3" json:"dns_config,omitempty"`
+	// This field is the list of entries of the hosts file of the sandbox.
+	Hosts                []*HostEntry `protobuf:"bytes,9,rep,name=hosts,proto3" json:"hosts,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}     `json:"-"`
+	XXX_unrecognized     []byte       `json:"-"`
+	XXX_sizecache        int32        `json:"-"`
 }
 
 func (m *CreateSandboxRequest) Reset()      { *m = CreateSandboxRequest{} }
 func (*CreateSandboxRequest) ProtoMessage() {}
 func (*CreateSandboxRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{30}
+	return fileDescriptor_712ce9a559fda969, []int{32}
 }
 func (m *CreateSandboxRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1333,6 +1428,128 @@ func (m *CreateSandboxRequest) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_CreateSandboxRequest proto.InternalMessageInfo
 
+type DNSConfig struct {
+	Nameservers          []string `protobuf:"bytes,1,rep,name=nameservers,proto3" json:"nameservers,omitempty"`
+	Searches             []string `protobuf:"bytes,2,rep,name=searches,proto3" json:"searches,omitempty"`
+	Options              []string `protobuf:"bytes,3,rep,name=options,proto3" json:"options,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *DNSConfig) Reset()      { *m = DNSConfig{} }
+func (*DNSConfig) ProtoMessage() {}
+func (*DNSConfig) Descriptor() ([]byte, []int) {
+	return fileDescriptor_712ce9a559fda969, []int{33}
+}
+func (m *DNSConfig) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *DNSConfig) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	if deterministic {
+		return xxx_messageInfo_DNSConfig.Marshal(b, m, deterministic)
+	} else {
+		b = b[:cap(b)]
+		n, err := m.MarshalToSizedBuffer(b)
+		if err != nil {
+			return nil, err
+		}
+		return b[:n], nil
+	}
+}
+func (m *DNSConfig) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_DNSConfig.Merge(m, src)
+}
+func (m *DNSConfig) XXX_Size() int {
+	return m.Size()
+}
+func (m *DNSConfig) XXX_DiscardUnknown() {
+	xxx_messageInfo_DNSConfig.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_DNSConfig proto.InternalMessageInfo
+
+type HostEntry struct {
+	Ip string `protobuf:"bytes,1,opt,name=ip,proto3" json:"ip,omitempty"`
+	// The canonical hostname followed by its aliases.
+	Names                []string `protobuf:"bytes,2,rep,name=names,proto3" json:"names,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *HostEntry) Reset()      { *m = HostEntry{} }
+func (*HostEntry) ProtoMessage() {}
+func (*HostEntry) Descriptor() ([]byte, []int) {
+	return fileDescriptor_712ce9a559fda969, []int{34}
+}
+func (m *HostEntry) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *HostEntry) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	if deterministic {
+		return xxx_messageInfo_HostEntry.Marshal(b, m, deterministic)
+	} else {
+		b = b[:cap(b)]
+		n, err := m.MarshalToSizedBuffer(b)
+		if err != nil {
+			return nil, err
+		}
+		return b[:n], nil
+	}
+}
+func (m *HostEntry) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_HostEntry.Merge(m, src)
+}
+func (m *HostEntry) XXX_Size() int {
+	return m.Size()
+}
+func (m *HostEntry) XXX_DiscardUnknown() {
+	xxx_messageInfo_HostEntry.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_HostEntry proto.InternalMessageInfo
+
+type UpdateSandboxDNSRequest struct {
+	DnsConfig            *DNSConfig   `protobuf:"bytes,1,opt,name=dns_config,json=dnsConfig,proto3" json:"dns_config,omitempty"`
+	Hosts                []*HostEntry `protobuf:"bytes,2,rep,name=hosts,proto3" json:"hosts,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}     `json:"-"`
+	XXX_unrecognized     []byte       `json:"-"`
+	XXX_sizecache        int32        `json:"-"`
+}
+
+func (m *UpdateSandboxDNSRequest) Reset()      { *m = UpdateSandboxDNSRequest{} }
+func (*UpdateSandboxDNSRequest) ProtoMessage() {}
+func (*UpdateSandboxDNSRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_712ce9a559fda969, []int{35}
+}
+func (m *UpdateSandboxDNSRequest) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *UpdateSandboxDNSRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	if deterministic {
+		return xxx_messageInfo_UpdateSandboxDNSRequest.Marshal(b, m, deterministic)
+	} else {
+		b = b[:cap(b)]
+		n, err := m.MarshalToSizedBuffer(b)
+		if err != nil {
+			return nil, err
+		}
+		return b[:n], nil
+	}
+}
+func (m *UpdateSandboxDNSRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_UpdateSandboxDNSRequest.Merge(m, src)
+}
+func (m *UpdateSandboxDNSRequest) XXX_Size() int {
+	return m.Size()
+}
+func (m *UpdateSandboxDNSRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_UpdateSandboxDNSRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_UpdateSandboxDNSRequest proto.InternalMessageInfo
+
 type DestroySandboxRequest struct {
 	XXX_NoUnkeyedLiteral struct{} `json:"-"`
 	XXX_unrecognized     []byte   `json:"-"`
@@ -1342,7 +1559,7 @@ type DestroySandboxRequest struct {
 func (m *DestroySandboxRequest) Reset()      { *m = DestroySandboxRequest{} }
 func (*DestroySandboxRequest) ProtoMessage() {}
 func (*DestroySandboxRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{31}
+	return fileDescriptor_712ce9a559fda969, []int{36}
 }
 func (m *DestroySandboxRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1381,7 +1598,7 @@ type Interfaces struct {
 func (m *Interfaces) Reset()      { *m = Interfaces{} }
 func (*Interfaces) ProtoMessage() {}
 func (*Interfaces) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{32}
+	return fileDescriptor_712ce9a559fda969, []int{37}
 }
 func (m *Interfaces) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1420,7 +1637,7 @@ type Routes struct {
 func (m *Routes) Reset()      { *m = Routes{} }
 func (*Routes) ProtoMessage() {}
 func (*Routes) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{33}
+	return fileDescriptor_712ce9a559fda969, []int{38}
 }
 func (m *Routes) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1459,7 +1676,7 @@ type UpdateInterfaceRequest struct {
 func (m *UpdateInterfaceRequest) Reset()      { *m = UpdateInterfaceRequest{} }
 func (*UpdateInterfaceRequest) ProtoMessage() {}
 func (*UpdateInterfaceRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{34}
+	return fileDescriptor_712ce9a559fda969, []int{39}
 }
 func (m *UpdateInterfaceRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1498,7 +1715,7 @@ type UpdateRoutesRequest struct {
 func (m *UpdateRoutesRequest) Reset()      { *m = UpdateRoutesRequest{} }
 func (*UpdateRoutesRequest) ProtoMessage() {}
 func (*UpdateRoutesRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{35}
+	return fileDescriptor_712ce9a559fda969, []int{40}
 }
 func (m *UpdateRoutesRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1537,7 +1754,7 @@ type UpdateEphemeralMountsRequest struct {
 func (m *UpdateEphemeralMountsRequest) Reset()      { *m = UpdateEphemeralMountsRequest{} }
 func (*UpdateEphemeralMountsRequest) ProtoMessage() {}
 func (*UpdateEphemeralMountsRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{36}
+	return fileDescriptor_712ce9a559fda969, []int{41}
 }
 func (m *UpdateEphemeralMountsRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1575,7 +1792,7 @@ type ListInterfacesRequest struct {
 func (m *ListInterfacesRequest) Reset()      { *m = ListInterfacesRequest{} }
 func (*ListInterfacesRequest) ProtoMessage() {}
 func (*ListInterfacesRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{37}
+	return fileDescriptor_712ce9a559fda969, []int{42}
 }
 func (m *ListInterfacesRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1613,7 +1830,7 @@ type ListRoutesRequest struct {
 func (m *ListRoutesRequest) Reset()      { *m = ListRoutesRequest{} }
 func (*ListRoutesRequest) ProtoMessage() {}
 func (*ListRoutesRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{38}
+	return fileDescriptor_712ce9a559fda969, []int{43}
 }
 func (m *ListRoutesRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1652,7 +1869,7 @@ type ARPNeighbors struct {
 func (m *ARPNeighbors) Reset()      { *m = ARPNeighbors{} }
 func (*ARPNeighbors) ProtoMessage() {}
 func (*ARPNeighbors) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{39}
+	return fileDescriptor_712ce9a559fda969, []int{44}
 }
 func (m *ARPNeighbors) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1691,7 +1908,7 @@ type AddARPNeighborsRequest struct {
 func (m *AddARPNeighborsRequest) Reset()      { *m = AddARPNeighborsRequest{} }
 func (*AddARPNeighborsRequest) ProtoMessage() {}
 func (*AddARPNeighborsRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{40}
+	return fileDescriptor_712ce9a559fda969, []int{45}
 }
 func (m *AddARPNeighborsRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1730,7 +1947,7 @@ type GetIPTablesRequest struct {
 func (m *GetIPTablesRequest) Reset()      { *m = GetIPTablesRequest{} }
 func (*GetIPTablesRequest) ProtoMessage() {}
 func (*GetIPTablesRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{41}
+	return fileDescriptor_712ce9a559fda969, []int{46}
 }
 func (m *GetIPTablesRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1770,7 +1987,7 @@ type GetIPTablesResponse struct {
 func (m *GetIPTablesResponse) Reset()      { *m = GetIPTablesResponse{} }
 func (*GetIPTablesResponse) ProtoMessage() {}
 func (*GetIPTablesResponse) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{42}
+	return fileDescriptor_712ce9a559fda969, []int{47}
 }
 func (m *GetIPTablesResponse) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1812,7 +2029,7 @@ type SetIPTablesRequest struct {
 func (m *SetIPTablesRequest) Reset()      { *m = SetIPTablesRequest{} }
 func (*SetIPTablesRequest) ProtoMessage() {}
 func (*SetIPTablesRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{43}
+	return fileDescriptor_712ce9a559fda969, []int{48}
 }
 func (m *SetIPTablesRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1852,7 +2069,7 @@ type SetIPTablesResponse struct {
 func (m *SetIPTablesResponse) Reset()      { *m = SetIPTablesResponse{} }
 func (*SetIPTablesResponse) ProtoMessage() {}
 func (*SetIPTablesResponse) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{44}
+	return fileDescriptor_712ce9a559fda969, []int{49}
 }
 func (m *SetIPTablesResponse) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1898,7 +2115,7 @@ type OnlineCPUMemRequest struct {
 func (m *OnlineCPUMemRequest) Reset()      { *m = OnlineCPUMemRequest{} }
 func (*OnlineCPUMemRequest) ProtoMessage() {}
 func (*OnlineCPUMemRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{45}
+	return fileDescriptor_712ce9a559fda969, []int{50}
 }
 func (m *OnlineCPUMemRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1938,7 +2155,7 @@ type ReseedRandomDevRequest struct {
 func (m *ReseedRandomDevRequest) Reset()      { *m = ReseedRandomDevRequest{} }
 func (*ReseedRandomDevRequest) ProtoMessage() {}
 func (*ReseedRandomDevRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{46}
+	return fileDescriptor_712ce9a559fda969, []int{51}
 }
 func (m *ReseedRandomDevRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -1988,7 +2205,7 @@ type AgentDetails struct {
 func (m *AgentDetails) Reset()      { *m = AgentDetails{} }
 func (*AgentDetails) ProtoMessage() {}
 func (*AgentDetails) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{47}
+	return fileDescriptor_712ce9a559fda969, []int{52}
 }
 func (m *AgentDetails) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2034,7 +2251,7 @@ type GuestDetailsRequest struct {
 func (m *GuestDetailsRequest) Reset()      { *m = GuestDetailsRequest{} }
 func (*GuestDetailsRequest) ProtoMessage() {}
 func (*GuestDetailsRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{48}
+	return fileDescriptor_712ce9a559fda969, []int{53}
 }
 func (m *GuestDetailsRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2076,7 +2293,7 @@ type GuestDetailsResponse struct {
 func (m *GuestDetailsResponse) Reset()      { *m = GuestDetailsResponse{} }
 func (*GuestDetailsResponse) ProtoMessage() {}
 func (*GuestDetailsResponse) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{49}
+	return fileDescriptor_712ce9a559fda969, []int{54}
 }
 func (m *GuestDetailsResponse) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2117,7 +2334,7 @@ type MemHotplugByProbeRequest struct {
 func (m *MemHotplugByProbeRequest) Reset()      { *m = MemHotplugByProbeRequest{} }
 func (*MemHotplugByProbeRequest) ProtoMessage() {}
 func (*MemHotplugByProbeRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{50}
+	return fileDescriptor_712ce9a559fda969, []int{55}
 }
 func (m *MemHotplugByProbeRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2159,7 +2376,7 @@ type SetGuestDateTimeRequest struct {
 func (m *SetGuestDateTimeRequest) Reset()      { *m = SetGuestDateTimeRequest{} }
 func (*SetGuestDateTimeRequest) ProtoMessage() {}
 func (*SetGuestDateTimeRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{51}
+	return fileDescriptor_712ce9a559fda969, []int{56}
 }
 func (m *SetGuestDateTimeRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2205,7 +2422,7 @@ type FSGroup struct {
 func (m *FSGroup) Reset()      { *m = FSGroup{} }
 func (*FSGroup) ProtoMessage() {}
 func (*FSGroup) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{52}
+	return fileDescriptor_712ce9a559fda969, []int{57}
 }
 func (m *FSGroup) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2273,7 +2490,7 @@ type Storage struct {
 func (m *Storage) Reset()      { *m = Storage{} }
 func (*Storage) ProtoMessage() {}
 func (*Storage) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{53}
+	return fileDescriptor_712ce9a559fda969, []int{58}
 }
 func (m *Storage) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2344,7 +2561,7 @@ type Device struct {
 func (m *Device) Reset()      { *m = Device{} }
 func (*Device) ProtoMessage() {}
 func (*Device) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{54}
+	return fileDescriptor_712ce9a559fda969, []int{59}
 }
 func (m *Device) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2385,7 +2602,7 @@ type StringUser struct {
 func (m *StringUser) Reset()      { *m = StringUser{} }
 func (*StringUser) ProtoMessage() {}
 func (*StringUser) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{55}
+	return fileDescriptor_712ce9a559fda969, []int{60}
 }
 func (m *StringUser) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2442,7 +2659,7 @@ type CopyFileRequest struct {
 func (m *CopyFileRequest) Reset()      { *m = CopyFileRequest{} }
 func (*CopyFileRequest) ProtoMessage() {}
 func (*CopyFileRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{56}
+	return fileDescriptor_712ce9a559fda969, []int{61}
 }
 func (m *CopyFileRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2480,7 +2697,7 @@ type GetOOMEventRequest struct {
 func (m *GetOOMEventRequest) Reset()      { *m = GetOOMEventRequest{} }
 func (*GetOOMEventRequest) ProtoMessage() {}
 func (*GetOOMEventRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{57}
+	return fileDescriptor_712ce9a559fda969, []int{62}
 }
 func (m *GetOOMEventRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2519,7 +2736,7 @@ type OOMEvent struct {
 func (m *OOMEvent) Reset()      { *m = OOMEvent{} }
 func (*OOMEvent) ProtoMessage() {}
 func (*OOMEvent) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{58}
+	return fileDescriptor_712ce9a559fda969, []int{63}
 }
 func (m *OOMEvent) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2558,7 +2775,7 @@ type AddSwapRequest struct {
 func (m *AddSwapRequest) Reset()      { *m = AddSwapRequest{} }
 func (*AddSwapRequest) ProtoMessage() {}
 func (*AddSwapRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{59}
+	return fileDescriptor_712ce9a559fda969, []int{64}
 }
 func (m *AddSwapRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2596,7 +2813,7 @@ type GetMetricsRequest struct {
 func (m *GetMetricsRequest) Reset()      { *m = GetMetricsRequest{} }
 func (*GetMetricsRequest) ProtoMessage() {}
 func (*GetMetricsRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{60}
+	return fileDescriptor_712ce9a559fda969, []int{65}
 }
 func (m *GetMetricsRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2635,7 +2852,7 @@ type Metrics struct {
 func (m *Metrics) Reset()      { *m = Metrics{} }
 func (*Metrics) ProtoMessage() {}
 func (*Metrics) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{61}
+	return fileDescriptor_712ce9a559fda969, []int{66}
 }
 func (m *Metrics) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2675,7 +2892,7 @@ type VolumeStatsRequest struct {
 func (m *VolumeStatsRequest) Reset()      { *m = VolumeStatsRequest{} }
 func (*VolumeStatsRequest) ProtoMessage() {}
 func (*VolumeStatsRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{62}
+	return fileDescriptor_712ce9a559fda969, []int{67}
 }
 func (m *VolumeStatsRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2716,7 +2933,7 @@ type ResizeVolumeRequest struct {
 func (m *ResizeVolumeRequest) Reset()      { *m = ResizeVolumeRequest{} }
 func (*ResizeVolumeRequest) ProtoMessage() {}
 func (*ResizeVolumeRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_712ce9a559fda969, []int{63}
+	return fileDescriptor_712ce9a559fda969, []int{68}
 }
 func (m *ResizeVolumeRequest) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -2759,6 +2976,8 @@ func init() {
 	proto.RegisterType((*ResumeContainerRequest)(nil), "grpc.ResumeContainerRequest")
 	proto.RegisterType((*CpuUsage)(nil), "grpc.CpuUsage")
 	proto.RegisterType((*ThrottlingData)(nil), "grpc.ThrottlingData")
+	proto.RegisterType((*PSIData)(nil), "grpc.PSIData")
+	proto.RegisterType((*PSIStats)(nil), "grpc.PSIStats")
 	proto.RegisterType((*CpuStats)(nil), "grpc.CpuStats")
 	proto.RegisterType((*PidsStats)(nil), "grpc.PidsStats")
 	proto.RegisterType((*MemoryData)(nil), "grpc.MemoryData")
@@ -2779,6 +2998,9 @@ func init() {
 	proto.RegisterType((*TtyWinResizeRequest)(nil), "grpc.TtyWinResizeRequest")
 	proto.RegisterType((*KernelModule)(nil), "grpc.KernelModule")
 	proto.RegisterType((*CreateSandboxRequest)(nil), "grpc.CreateSandboxRequest")
+	proto.RegisterType((*DNSConfig)(nil), "grpc.DNSConfig")
+	proto.RegisterType((*HostEntry)(nil), "grpc.HostEntry")
+	proto.RegisterType((*UpdateSandboxDNSRequest)(nil), "grpc.UpdateSandboxDNSRequest")
 	proto.RegisterType((*DestroySandboxRequest)(nil), "grpc.DestroySandboxRequest")
 	proto.RegisterType((*Interfaces)(nil), "grpc.Interfaces")
 	proto.RegisterType((*Routes)(nil), "grpc.Routes")
@@ -2819,210 +3041,223 @@ func init() {
 }
 
 var fileDescriptor_712ce9a559fda969 = []byte{
-	// 3249 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xc4, 0x3a, 0xcb, 0x72, 0x1c, 0x47,
-	0x72, 0x9a, 0x07, 0x30, 0x33, 0x39, 0x2f, 0x4c, 0x0f, 0x08, 0x0e, 0x47, 0x14, 0x44, 0x35, 0x25,
-	0x0a, 0x94, 0x4c, 0x40, 0xa2, 0x14, 0xa2, 0x1e, 0x21, 0xd3, 0x00, 0x08, 0x01, 0x90, 0x04, 0x71,
-	0xdc, 0x43, 0x58, 0x0e, 0x3b, 0xec, 0x8e, 0x46, 0x77, 0x61, 0xa6, 0x84, 0xe9, 0xae, 0x56, 0x75,
-	0x35, 0x08, 0xc8, 0x11, 0x0e, 0x9f, 0xec, 0x9b, 0x8f, 0xbe, 0xe9, 0x07, 0x1c, 0xfe, 0x03, 0x5f,
-	0x7d, 0x50, 0xf8, 0xb4, 0xc7, 0xbd, 0xec, 0xc6, 0x8a, 0x9f, 0xb0, 0x5f, 0xb0, 0x51, 0xaf, 0x7e,
-	0xcc, 0x03, 0xd2, 0x22, 0x18, 0xb1, 0x97, 0x89, 0xce, 0xac, 0xac, 0x7c, 0x55, 0x56, 0x56, 0x66,
-	0xd5, 0xc0, 0x60, 0x84, 0xd9, 0x38, 0x3e, 0xd9, 0x74, 0x89, 0xbf, 0x75, 0xe6, 0x30, 0xe7, 0x81,
-	0x4b, 0x02, 0xe6, 0xe0, 0x00, 0xd1, 0x68, 0x06, 0x8e, 0xa8, 0xbb, 0x35, 0xc1, 0x27, 0xd1, 0x56,
-	0x48, 0x09, 0x23, 0x2e, 0x99, 0xa8, 0xaf, 0x68, 0xcb, 0x19, 0xa1, 0x80, 0x6d, 0x0a, 0xc0, 0x28,
-	0x8f, 0x68, 0xe8, 0xf6, 0x6b, 0xc4, 0xc5, 0x12, 0xd1, 0xaf, 0xb9, 0x91, 0xfe, 0xac, 0xb3, 0xcb,
-	0x10, 0x45, 0x0a, 0x78, 0x75, 0x44, 0xc8, 0x68, 0x82, 0x24, 0x8f, 0x93, 0xf8, 0x74, 0x0b, 0xf9,
-	0x21, 0xbb, 0x94, 0x83, 0xe6, 0x8f, 0x45, 0x58, 0xdb, 0xa5, 0xc8, 0x61, 0x68, 0x57, 0x2b, 0x60,
-	0xa1, 0xef, 0x63, 0x14, 0x31, 0xe3, 0x0d, 0x68, 0x24, 0x4a, 0xd9, 0xd8, 0xeb, 0x15, 0xee, 0x14,
-	0x36, 0x6a, 0x56, 0x3d, 0xc1, 0x1d, 0x7a, 0xc6, 0x4d, 0xa8, 0xa0, 0x0b, 0xe4, 0xf2, 0xd1, 0xa2,
-	0x18, 0x5d, 0xe6, 0xe0, 0xa1, 0x67, 0xbc, 0x0f, 0xf5, 0x88, 0x51, 0x1c, 0x8c, 0xec, 0x38, 0x42,
-	0xb4, 0x57, 0xba, 0x53, 0xd8, 0xa8, 0x3f, 0x5c, 0xd9, 0xe4, 0x2a, 0x6f, 0x0e, 0xc5, 0xc0, 0x71,
-	0x84, 0xa8, 0x05, 0x51, 0xf2, 0x6d, 0xdc, 0x83, 0x8a, 0x87, 0xce, 0xb1, 0x8b, 0xa2, 0x5e, 0xf9,
-	0x4e, 0x69, 0xa3, 0xfe, 0xb0, 0x21, 0xc9, 0x9f, 0x08, 0xa4, 0xa5, 0x07, 0x8d, 0xfb, 0x50, 0x8d,
-	0x18, 0xa1, 0xce, 0x08, 0x45, 0xbd, 0x25, 0x41, 0xd8, 0xd4, 0x7c, 0x05, 0xd6, 0x4a, 0x86, 0x8d,
-	0xdb, 0x50, 0x7a, 0xba, 0x7b, 0xd8, 0x5b, 0x16, 0xd2, 0x41, 0x51, 0x85, 0xc8, 0xb5, 0x38, 0xda,
-	0xb8, 0x0b, 0xcd, 0xc8, 0x09, 0xbc, 0x13, 0x72, 0x61, 0x87, 0xd8, 0x0b, 0xa2, 0x5e, 0xe5, 0x4e,
-	0x61, 0xa3, 0x6a, 0x35, 0x14, 0x72, 0xc0, 0x71, 0xe6, 0xa7, 0x70, 0x63, 0xc8, 0x1c, 0xca, 0xae,
-	0xe1, 0x1d, 0xf3, 0x18, 0xd6, 0x2c, 0xe4, 0x93, 0xf3, 0x6b, 0xb9, 0xb6, 0x07, 0x15, 0x86, 0x7d,
-	0x44, 0x62, 0x26, 0x5c, 0xdb, 0xb4, 0x34, 0x68, 0xfe, 0x4f, 0x01, 0x8c, 0xbd, 0x0b, 0xe4, 0x0e,
-	0x28, 0x71, 0x51, 0x14, 0xfd, 0x85, 0x96, 0xeb, 0x6d, 0xa8, 0x84, 0x52, 0x81, 0x5e, 0x59, 0x90,
-	0xab, 0x55, 0xd0, 0x5a, 0xe9, 0x51, 0xf3, 0x3b, 0x58, 0x1d, 0xe2, 0x51, 0xe0, 0x4c, 0x5e, 0xa2,
-	0xbe, 0x6b, 0xb0, 0x1c, 0x09, 0x9e, 0x42, 0xd5, 0xa6, 0xa5, 0x20, 0x73, 0x00, 0xc6, 0xb7, 0x0e,
-	0x66, 0x2f, 0x4f, 0x92, 0xf9, 0x00, 0xba, 0x39, 0x8e, 0x51, 0x48, 0x82, 0x08, 0x09, 0x05, 0x98,
-	0xc3, 0xe2, 0x48, 0x30, 0x5b, 0xb2, 0x14, 0x64, 0x12, 0x58, 0x3b, 0x0e, 0xbd, 0x6b, 0xee, 0xa6,
-	0x87, 0x50, 0xa3, 0x28, 0x22, 0x31, 0xe5, 0x7b, 0xa0, 0x28, 0x9c, 0xba, 0x2a, 0x9d, 0xfa, 0x35,
-	0x0e, 0xe2, 0x0b, 0x4b, 0x8f, 0x59, 0x29, 0x99, 0x8a, 0x4f, 0x16, 0x5d, 0x27, 0x3e, 0x3f, 0x85,
-	0x1b, 0x03, 0x27, 0x8e, 0xae, 0xa3, 0xab, 0xf9, 0x19, 0x8f, 0xed, 0x28, 0xf6, 0xaf, 0x35, 0xf9,
-	0xbf, 0x0b, 0x50, 0xdd, 0x0d, 0xe3, 0xe3, 0xc8, 0x19, 0x21, 0xe3, 0x75, 0xa8, 0x33, 0xc2, 0x9c,
-	0x89, 0x1d, 0x73, 0x50, 0x90, 0x97, 0x2d, 0x10, 0x28, 0x49, 0xf0, 0x06, 0x34, 0x42, 0x44, 0xdd,
-	0x30, 0x56, 0x14, 0xc5, 0x3b, 0xa5, 0x8d, 0xb2, 0x55, 0x97, 0x38, 0x49, 0xb2, 0x09, 0x5d, 0x31,
-	0x66, 0xe3, 0xc0, 0x3e, 0x43, 0x34, 0x40, 0x13, 0x9f, 0x78, 0x48, 0x04, 0x47, 0xd9, 0xea, 0x88,
-	0xa1, 0xc3, 0xe0, 0xab, 0x64, 0xc0, 0x78, 0x07, 0x3a, 0x09, 0x3d, 0x8f, 0x78, 0x41, 0x5d, 0x16,
-	0xd4, 0x6d, 0x45, 0x7d, 0xac, 0xd0, 0xe6, 0xbf, 0x42, 0xeb, 0xd9, 0x98, 0x12, 0xc6, 0x26, 0x38,
-	0x18, 0x3d, 0x71, 0x98, 0xc3, 0xb7, 0x66, 0x88, 0x28, 0x26, 0x5e, 0xa4, 0xb4, 0xd5, 0xa0, 0xf1,
-	0x2e, 0x74, 0x98, 0xa4, 0x45, 0x9e, 0xad, 0x69, 0x8a, 0x82, 0x66, 0x25, 0x19, 0x18, 0x28, 0xe2,
-	0xb7, 0xa0, 0x95, 0x12, 0xf3, 0xcd, 0xad, 0xf4, 0x6d, 0x26, 0xd8, 0x67, 0xd8, 0x47, 0xe6, 0xb9,
-	0xf0, 0x95, 0x58, 0x64, 0xe3, 0x5d, 0xa8, 0xa5, 0x7e, 0x28, 0x88, 0x08, 0x69, 0xc9, 0x08, 0xd1,
-	0xee, 0xb4, 0xaa, 0x89, 0x53, 0x3e, 0x87, 0x36, 0x4b, 0x14, 0xb7, 0x3d, 0x87, 0x39, 0xf9, 0xa0,
-	0xca, 0x5b, 0x65, 0xb5, 0x58, 0x0e, 0x36, 0x3f, 0x83, 0xda, 0x00, 0x7b, 0x91, 0x14, 0xdc, 0x83,
-	0x8a, 0x1b, 0x53, 0x8a, 0x02, 0xa6, 0x4d, 0x56, 0xa0, 0xb1, 0x0a, 0x4b, 0x13, 0xec, 0x63, 0xa6,
-	0xcc, 0x94, 0x80, 0x49, 0x00, 0x8e, 0x90, 0x4f, 0xe8, 0xa5, 0x70, 0xd8, 0x2a, 0x2c, 0x65, 0x17,
-	0x57, 0x02, 0xc6, 0xab, 0x50, 0xf3, 0x9d, 0x8b, 0x64, 0x51, 0xf9, 0x48, 0xd5, 0x77, 0x2e, 0xa4,
-	0xf2, 0x3d, 0xa8, 0x9c, 0x3a, 0x78, 0xe2, 0x06, 0x4c, 0x79, 0x45, 0x83, 0xa9, 0xc0, 0x72, 0x56,
-	0xe0, 0xff, 0x15, 0xa1, 0x2e, 0x25, 0x4a, 0x85, 0x57, 0x61, 0xc9, 0x75, 0xdc, 0x71, 0x22, 0x52,
-	0x00, 0xc6, 0x3d, 0xad, 0x48, 0x31, 0x9b, 0xe1, 0x52, 0x4d, 0xb5, 0x6a, 0x5b, 0x00, 0xd1, 0x73,
-	0x27, 0x54, 0xba, 0x95, 0x16, 0x10, 0xd7, 0x38, 0x8d, 0x54, 0xf7, 0x03, 0x68, 0xc8, 0xb8, 0x53,
-	0x53, 0xca, 0x0b, 0xa6, 0xd4, 0x25, 0x95, 0x9c, 0x74, 0x17, 0x9a, 0x71, 0x84, 0xec, 0x31, 0x46,
-	0xd4, 0xa1, 0xee, 0xf8, 0xb2, 0xb7, 0x24, 0x0f, 0xa0, 0x38, 0x42, 0x07, 0x1a, 0x67, 0x3c, 0x84,
-	0x25, 0x9e, 0x5b, 0xa2, 0xde, 0xb2, 0x38, 0xeb, 0x6e, 0x67, 0x59, 0x0a, 0x53, 0x37, 0xc5, 0xef,
-	0x5e, 0xc0, 0xe8, 0xa5, 0x25, 0x49, 0xfb, 0x1f, 0x03, 0xa4, 0x48, 0x63, 0x05, 0x4a, 0x67, 0xe8,
-	0x52, 0xed, 0x43, 0xfe, 0xc9, 0x9d, 0x73, 0xee, 0x4c, 0x62, 0xed, 0x75, 0x09, 0x7c, 0x5a, 0xfc,
-	0xb8, 0x60, 0xba, 0xd0, 0xde, 0x99, 0x9c, 0x61, 0x92, 0x99, 0xbe, 0x0a, 0x4b, 0xbe, 0xf3, 0x1d,
-	0xa1, 0xda, 0x93, 0x02, 0x10, 0x58, 0x1c, 0x10, 0xaa, 0x59, 0x08, 0xc0, 0x68, 0x41, 0x91, 0x84,
-	0xc2, 0x5f, 0x35, 0xab, 0x48, 0xc2, 0x54, 0x50, 0x39, 0x23, 0xc8, 0xfc, 0x7d, 0x19, 0x20, 0x95,
-	0x62, 0x58, 0xd0, 0xc7, 0xc4, 0x8e, 0x10, 0xe5, 0xe7, 0xbb, 0x7d, 0x72, 0xc9, 0x50, 0x64, 0x53,
-	0xe4, 0xc6, 0x34, 0xc2, 0xe7, 0x7c, 0xfd, 0xb8, 0xd9, 0x37, 0xa4, 0xd9, 0x53, 0xba, 0x59, 0x37,
-	0x31, 0x19, 0xca, 0x79, 0x3b, 0x7c, 0x9a, 0xa5, 0x67, 0x19, 0x87, 0x70, 0x23, 0xe5, 0xe9, 0x65,
-	0xd8, 0x15, 0xaf, 0x62, 0xd7, 0x4d, 0xd8, 0x79, 0x29, 0xab, 0x3d, 0xe8, 0x62, 0x62, 0x7f, 0x1f,
-	0xa3, 0x38, 0xc7, 0xa8, 0x74, 0x15, 0xa3, 0x0e, 0x26, 0x7f, 0x2b, 0x26, 0xa4, 0x6c, 0x06, 0x70,
-	0x2b, 0x63, 0x25, 0xdf, 0xee, 0x19, 0x66, 0xe5, 0xab, 0x98, 0xad, 0x25, 0x5a, 0xf1, 0x7c, 0x90,
-	0x72, 0xfc, 0x12, 0xd6, 0x30, 0xb1, 0x9f, 0x3b, 0x98, 0x4d, 0xb3, 0x5b, 0xfa, 0x05, 0x23, 0xf9,
-	0x89, 0x96, 0xe7, 0x25, 0x8d, 0xf4, 0x11, 0x1d, 0xe5, 0x8c, 0x5c, 0xfe, 0x05, 0x23, 0x8f, 0xc4,
-	0x84, 0x94, 0xcd, 0x36, 0x74, 0x30, 0x99, 0xd6, 0xa6, 0x72, 0x15, 0x93, 0x36, 0x26, 0x79, 0x4d,
-	0x76, 0xa0, 0x13, 0x21, 0x97, 0x11, 0x9a, 0x0d, 0x82, 0xea, 0x55, 0x2c, 0x56, 0x14, 0x7d, 0xc2,
-	0xc3, 0xfc, 0x47, 0x68, 0x1c, 0xc4, 0x23, 0xc4, 0x26, 0x27, 0x49, 0x32, 0x78, 0x69, 0xf9, 0xc7,
-	0xfc, 0x63, 0x11, 0xea, 0xbb, 0x23, 0x4a, 0xe2, 0x30, 0x97, 0x93, 0xe5, 0x26, 0x9d, 0xce, 0xc9,
-	0x82, 0x44, 0xe4, 0x64, 0x49, 0xfc, 0x21, 0x34, 0x7c, 0xb1, 0x75, 0x15, 0xbd, 0xcc, 0x43, 0x9d,
-	0x99, 0x4d, 0x6d, 0xd5, 0xfd, 0x4c, 0x32, 0xdb, 0x04, 0x08, 0xb1, 0x17, 0xa9, 0x39, 0x32, 0x1d,
-	0xb5, 0x55, 0xb9, 0xa5, 0x53, 0xb4, 0x55, 0x0b, 0x93, 0x6c, 0xfd, 0x3e, 0xd4, 0x4f, 0xb8, 0x93,
-	0xd4, 0x84, 0x5c, 0x32, 0x4a, 0xbd, 0x67, 0xc1, 0x49, 0xba, 0x09, 0x0f, 0xa0, 0x39, 0x96, 0x2e,
-	0x53, 0x93, 0x64, 0x0c, 0xdd, 0x55, 0x96, 0xa4, 0xf6, 0x6e, 0x66, 0x3d, 0x2b, 0x17, 0xa0, 0x31,
-	0xce, 0xa0, 0xfa, 0x43, 0xe8, 0xcc, 0x90, 0xcc, 0xc9, 0x41, 0x1b, 0xd9, 0x1c, 0x54, 0x7f, 0x68,
-	0x48, 0x41, 0xd9, 0x99, 0xd9, 0xbc, 0xf4, 0x9f, 0x45, 0x68, 0x7c, 0x83, 0xd8, 0x73, 0x42, 0xcf,
-	0xa4, 0xbe, 0x06, 0x94, 0x03, 0xc7, 0x47, 0x8a, 0xa3, 0xf8, 0x36, 0x6e, 0x41, 0x95, 0x5e, 0xc8,
-	0x04, 0xa2, 0xd6, 0xb3, 0x42, 0x2f, 0x44, 0x62, 0x30, 0x5e, 0x03, 0xa0, 0x17, 0x76, 0xe8, 0xb8,
-	0x67, 0x48, 0x79, 0xb0, 0x6c, 0xd5, 0xe8, 0xc5, 0x40, 0x22, 0x78, 0x28, 0xd0, 0x0b, 0x1b, 0x51,
-	0x4a, 0x68, 0xa4, 0x72, 0x55, 0x95, 0x5e, 0xec, 0x09, 0x58, 0xcd, 0xf5, 0x28, 0x09, 0x43, 0xe4,
-	0x89, 0x1c, 0x2d, 0xe6, 0x3e, 0x91, 0x08, 0x2e, 0x95, 0x69, 0xa9, 0xcb, 0x52, 0x2a, 0x4b, 0xa5,
-	0xb2, 0x54, 0x6a, 0x45, 0xce, 0x64, 0x59, 0xa9, 0x2c, 0x91, 0x5a, 0x95, 0x52, 0x59, 0x46, 0x2a,
-	0x4b, 0xa5, 0xd6, 0xf4, 0x5c, 0x25, 0xd5, 0xfc, 0x8f, 0x02, 0xac, 0x4d, 0x17, 0x7e, 0xaa, 0x36,
-	0xfd, 0x10, 0x1a, 0xae, 0x58, 0xaf, 0x5c, 0x4c, 0x76, 0x66, 0x56, 0xd2, 0xaa, 0xbb, 0x99, 0x30,
-	0x7e, 0x04, 0xcd, 0x40, 0x3a, 0x38, 0x09, 0xcd, 0x52, 0xba, 0x2e, 0x59, 0xdf, 0x5b, 0x8d, 0x20,
-	0x03, 0x99, 0x1e, 0x18, 0xdf, 0x52, 0xcc, 0xd0, 0x90, 0x51, 0xe4, 0xf8, 0x2f, 0xa3, 0xba, 0x37,
-	0xa0, 0x2c, 0xaa, 0x15, 0xbe, 0x4c, 0x0d, 0x4b, 0x7c, 0x9b, 0x6f, 0x43, 0x37, 0x27, 0x45, 0xd9,
-	0xba, 0x02, 0xa5, 0x09, 0x0a, 0x04, 0xf7, 0xa6, 0xc5, 0x3f, 0x4d, 0x07, 0x3a, 0x16, 0x72, 0xbc,
-	0x97, 0xa7, 0x8d, 0x12, 0x51, 0x4a, 0x45, 0x6c, 0x80, 0x91, 0x15, 0xa1, 0x54, 0xd1, 0x5a, 0x17,
-	0x32, 0x5a, 0x3f, 0x85, 0xce, 0xee, 0x84, 0x44, 0x68, 0xc8, 0x3c, 0x1c, 0xbc, 0x8c, 0x76, 0xe4,
-	0x5f, 0xa0, 0xfb, 0x8c, 0x5d, 0x7e, 0xcb, 0x99, 0x45, 0xf8, 0x07, 0xf4, 0x92, 0xec, 0xa3, 0xe4,
-	0xb9, 0xb6, 0x8f, 0x92, 0xe7, 0xbc, 0xb9, 0x71, 0xc9, 0x24, 0xf6, 0x03, 0xb1, 0x15, 0x9a, 0x96,
-	0x82, 0xcc, 0x1d, 0x68, 0xc8, 0x1a, 0xfa, 0x88, 0x78, 0xf1, 0x04, 0xcd, 0xdd, 0x83, 0xeb, 0x00,
-	0xa1, 0x43, 0x1d, 0x1f, 0x31, 0x44, 0x65, 0x0c, 0xd5, 0xac, 0x0c, 0xc6, 0xfc, 0xaf, 0x22, 0xac,
-	0xca, 0xfb, 0x86, 0xa1, 0x6c, 0xb3, 0xb5, 0x09, 0x7d, 0xa8, 0x8e, 0x49, 0xc4, 0x32, 0x0c, 0x13,
-	0x98, 0xab, 0xc8, 0xfb, 0x73, 0xc9, 0x8d, 0x7f, 0xe6, 0x2e, 0x01, 0x4a, 0x57, 0x5f, 0x02, 0xcc,
-	0xb4, 0xf9, 0xe5, 0xd9, 0x36, 0x9f, 0xef, 0x36, 0x4d, 0x84, 0xe5, 0x1e, 0xaf, 0x59, 0x35, 0x85,
-	0x39, 0xf4, 0x8c, 0x7b, 0xd0, 0x1e, 0x71, 0x2d, 0xed, 0x31, 0x21, 0x67, 0x76, 0xe8, 0xb0, 0xb1,
-	0xd8, 0xea, 0x35, 0xab, 0x29, 0xd0, 0x07, 0x84, 0x9c, 0x0d, 0x1c, 0x36, 0x36, 0x3e, 0x81, 0x96,
-	0x2a, 0x03, 0x7d, 0xe1, 0xa2, 0x48, 0x1d, 0x7e, 0x6a, 0x17, 0x65, 0xbd, 0x67, 0x35, 0xcf, 0x32,
-	0x50, 0x64, 0xde, 0x84, 0x1b, 0x4f, 0x50, 0xc4, 0x28, 0xb9, 0xcc, 0x3b, 0xc6, 0xfc, 0x6b, 0x80,
-	0xc3, 0x80, 0x21, 0x7a, 0xea, 0xb8, 0x28, 0x32, 0xde, 0xcb, 0x42, 0xaa, 0x38, 0x5a, 0xd9, 0x94,
-	0xd7, 0x3d, 0xc9, 0x80, 0x95, 0xa1, 0x31, 0x37, 0x61, 0xd9, 0x22, 0x31, 0x4f, 0x47, 0x6f, 0xea,
-	0x2f, 0x35, 0xaf, 0xa1, 0xe6, 0x09, 0xa4, 0xa5, 0xc6, 0xcc, 0x03, 0xdd, 0xc2, 0xa6, 0xec, 0xd4,
-	0x12, 0x6d, 0x42, 0x0d, 0x6b, 0x9c, 0xca, 0x2a, 0xb3, 0xa2, 0x53, 0x12, 0xf3, 0x33, 0xe8, 0x4a,
-	0x4e, 0x92, 0xb3, 0x66, 0xf3, 0x26, 0x2c, 0x53, 0xad, 0x46, 0x21, 0xbd, 0xe7, 0x51, 0x44, 0x6a,
-	0xcc, 0x3c, 0x84, 0xdb, 0x72, 0xf2, 0x5e, 0x38, 0x46, 0x3e, 0xa2, 0xce, 0xe4, 0x88, 0xc4, 0x01,
-	0x4b, 0xb8, 0x64, 0x23, 0xa0, 0x70, 0x65, 0x04, 0x70, 0xd7, 0x7e, 0x8d, 0x23, 0x96, 0xfa, 0x44,
-	0xbb, 0xb6, 0x0b, 0x1d, 0x3e, 0x90, 0x53, 0xcf, 0xfc, 0x02, 0x1a, 0xdb, 0xd6, 0xe0, 0x1b, 0x84,
-	0x47, 0xe3, 0x13, 0x9e, 0x88, 0x3f, 0xca, 0xc3, 0x4a, 0x98, 0xa1, 0x0c, 0xcf, 0x0c, 0x59, 0x39,
-	0x3a, 0xf3, 0x4b, 0x58, 0xdb, 0xf6, 0xbc, 0x2c, 0x4a, 0xab, 0xfe, 0x1e, 0xd4, 0x82, 0x0c, 0xbb,
-	0xcc, 0xf1, 0x97, 0xa3, 0x4e, 0x89, 0xcc, 0x07, 0x60, 0xec, 0x23, 0x76, 0x38, 0x78, 0xe6, 0x9c,
-	0x4c, 0x52, 0x47, 0xde, 0x84, 0x0a, 0x8e, 0x6c, 0x1c, 0x9e, 0x7f, 0x24, 0xb8, 0x54, 0xad, 0x65,
-	0x1c, 0x1d, 0x86, 0xe7, 0x1f, 0x99, 0xf7, 0xa1, 0x9b, 0x23, 0xbf, 0x22, 0x43, 0x6d, 0x83, 0x31,
-	0xfc, 0xf5, 0x9c, 0x13, 0x16, 0xc5, 0x0c, 0x8b, 0xfb, 0xd0, 0x1d, 0xfe, 0x4a, 0x69, 0xff, 0x04,
-	0xdd, 0xa7, 0xc1, 0x04, 0x07, 0x68, 0x77, 0x70, 0x7c, 0x84, 0x92, 0xf4, 0x6c, 0x40, 0x99, 0x97,
-	0xb1, 0x4a, 0x96, 0xf8, 0xe6, 0x2a, 0x04, 0x27, 0xb6, 0x1b, 0xc6, 0x91, 0xba, 0xff, 0x5a, 0x0e,
-	0x4e, 0x76, 0xc3, 0x38, 0xe2, 0xe7, 0x2d, 0xaf, 0xb7, 0x48, 0x30, 0xb9, 0x14, 0x49, 0xab, 0x6a,
-	0x55, 0xdc, 0x30, 0x7e, 0x1a, 0x4c, 0x2e, 0xcd, 0xbf, 0x12, 0x97, 0x12, 0x08, 0x79, 0x96, 0x13,
-	0x78, 0xc4, 0x7f, 0x82, 0xce, 0x33, 0x12, 0x66, 0xf4, 0xfe, 0xa9, 0x00, 0x8d, 0xed, 0x11, 0x0a,
-	0xd8, 0x13, 0xc4, 0x1c, 0x3c, 0x11, 0x4d, 0xee, 0x39, 0xa2, 0x11, 0x26, 0x81, 0xca, 0x40, 0x1a,
-	0x34, 0x5e, 0x87, 0x3a, 0x0e, 0x30, 0xb3, 0x3d, 0x07, 0xf9, 0x24, 0x10, 0x5c, 0xaa, 0x16, 0x70,
-	0xd4, 0x13, 0x81, 0x31, 0xde, 0x86, 0xb6, 0xbc, 0x9f, 0xb4, 0xc7, 0x4e, 0xe0, 0x4d, 0x78, 0xee,
-	0x2b, 0x89, 0x6c, 0xd5, 0x92, 0xe8, 0x03, 0x85, 0x35, 0xee, 0xc3, 0x8a, 0x8a, 0xcb, 0x94, 0xb2,
-	0x2c, 0x28, 0xdb, 0x0a, 0x9f, 0x23, 0x8d, 0xc3, 0x90, 0x50, 0x16, 0xd9, 0x11, 0x72, 0x5d, 0xe2,
-	0x87, 0xaa, 0x43, 0x6c, 0x6b, 0xfc, 0x50, 0xa2, 0xcd, 0x11, 0x74, 0xf7, 0xb9, 0x9d, 0xca, 0x92,
-	0x74, 0xa7, 0xb5, 0x7c, 0xe4, 0xdb, 0x27, 0x13, 0xe2, 0x9e, 0xd9, 0xfc, 0xbc, 0x50, 0x1e, 0xe6,
-	0x35, 0xe8, 0x0e, 0x47, 0x0e, 0xf1, 0x0f, 0xe2, 0x32, 0x84, 0x53, 0x8d, 0x09, 0x0b, 0x27, 0xf1,
-	0xc8, 0x0e, 0x29, 0x39, 0x41, 0xca, 0xc4, 0xb6, 0x8f, 0xfc, 0x03, 0x89, 0x1f, 0x70, 0xb4, 0xf9,
-	0xbf, 0x05, 0x58, 0xcd, 0x4b, 0x52, 0xab, 0xbd, 0x05, 0xab, 0x79, 0x51, 0xaa, 0x22, 0x92, 0x15,
-	0x77, 0x27, 0x2b, 0x50, 0xd6, 0x46, 0x8f, 0xa0, 0x29, 0x6e, 0xb3, 0x6d, 0x4f, 0x72, 0xca, 0xd7,
-	0x81, 0xd9, 0x75, 0xb1, 0x1a, 0x4e, 0x76, 0x95, 0x3e, 0x81, 0x5b, 0xca, 0x7c, 0x7b, 0x56, 0x6d,
-	0x19, 0x10, 0x6b, 0x8a, 0xe0, 0x68, 0x4a, 0xfb, 0xaf, 0xa1, 0x97, 0xa2, 0x76, 0x2e, 0x05, 0x32,
-	0xdd, 0x94, 0xdd, 0x29, 0x63, 0xb7, 0x3d, 0x8f, 0x8a, 0xdd, 0x5e, 0xb6, 0xe6, 0x0d, 0x99, 0x8f,
-	0xe1, 0xe6, 0x10, 0x31, 0xe9, 0x0d, 0x87, 0xa9, 0xe6, 0x4c, 0x32, 0x5b, 0x81, 0xd2, 0x10, 0xb9,
-	0xc2, 0xf8, 0x92, 0xc5, 0x3f, 0x79, 0x00, 0x1e, 0x47, 0xc8, 0x15, 0x56, 0x96, 0x2c, 0xf1, 0x6d,
-	0x86, 0x50, 0xf9, 0x62, 0xb8, 0xcf, 0x4b, 0x30, 0x1e, 0xd4, 0xb2, 0x64, 0x53, 0xc7, 0x73, 0xd3,
-	0xaa, 0x08, 0xf8, 0xd0, 0x33, 0xbe, 0x84, 0xae, 0x1c, 0x72, 0xc7, 0x4e, 0x30, 0x42, 0x76, 0x48,
-	0x26, 0xd8, 0x95, 0xa1, 0xdf, 0x7a, 0xd8, 0x57, 0x69, 0x48, 0xf1, 0xd9, 0x15, 0x24, 0x03, 0x41,
-	0x61, 0x75, 0x46, 0xd3, 0x28, 0xf3, 0x77, 0x05, 0xa8, 0xa8, 0xfc, 0xc8, 0x4f, 0x79, 0x8f, 0xe2,
-	0x73, 0x44, 0x55, 0xb0, 0x2b, 0xc8, 0x78, 0x0b, 0x5a, 0xf2, 0xcb, 0x26, 0x21, 0xc3, 0x24, 0x39,
-	0x77, 0x9b, 0x12, 0xfb, 0x54, 0x22, 0xc5, 0x0d, 0xa8, 0xb8, 0x83, 0x54, 0xed, 0xbe, 0x82, 0x38,
-	0xfe, 0x34, 0xe2, 0x4a, 0x89, 0x73, 0xb6, 0x66, 0x29, 0x88, 0x6f, 0x2e, 0xcd, 0x6f, 0x49, 0xf0,
-	0xd3, 0x20, 0xdf, 0x5c, 0x3e, 0x4f, 0xed, 0x76, 0x48, 0x70, 0xc0, 0xd4, 0xc1, 0x0a, 0x02, 0x35,
-	0xe0, 0x18, 0x63, 0x03, 0xaa, 0xa7, 0x91, 0x2d, 0xac, 0x11, 0x45, 0x74, 0x92, 0xea, 0x95, 0xd5,
-	0x56, 0xe5, 0x34, 0x12, 0x1f, 0xe6, 0xbf, 0x17, 0x60, 0x59, 0xbe, 0x17, 0x18, 0x2d, 0x28, 0x26,
-	0x85, 0x50, 0x11, 0x8b, 0xa2, 0x52, 0x68, 0x25, 0x8b, 0x1f, 0xf1, 0xcd, 0x73, 0xcc, 0xb9, 0x2f,
-	0x8f, 0x73, 0x65, 0xc4, 0xb9, 0x2f, 0xce, 0xf1, 0xb7, 0xa0, 0x95, 0xd6, 0x53, 0x62, 0x5c, 0x1a,
-	0xd3, 0x4c, 0xb0, 0x82, 0x6c, 0xa1, 0x4d, 0xe6, 0xdf, 0x03, 0xa4, 0xf7, 0xe6, 0x3c, 0x1c, 0xe2,
-	0x44, 0x19, 0xfe, 0xc9, 0x31, 0xa3, 0xa4, 0x12, 0xe3, 0x9f, 0xc6, 0x3d, 0x68, 0x39, 0x9e, 0x87,
-	0xf9, 0x74, 0x67, 0xb2, 0x8f, 0xbd, 0x24, 0x81, 0xe4, 0xb1, 0xe6, 0xff, 0x17, 0xa0, 0xbd, 0x4b,
-	0xc2, 0xcb, 0x2f, 0xf0, 0x04, 0x65, 0xb2, 0x9b, 0x50, 0x52, 0x15, 0x62, 0xfc, 0x9b, 0x37, 0x17,
-	0xa7, 0x78, 0x82, 0xe4, 0xb6, 0x97, 0x51, 0x57, 0xe5, 0x08, 0xb1, 0xe5, 0xf5, 0x60, 0x72, 0x4b,
-	0xda, 0x94, 0x83, 0x47, 0xc4, 0x13, 0x6d, 0x94, 0x87, 0xa9, 0x9d, 0xdc, 0x89, 0x36, 0xad, 0x8a,
-	0x87, 0xa9, 0x18, 0x52, 0x86, 0x2c, 0x89, 0x3b, 0xef, 0xac, 0x21, 0xcb, 0x12, 0xc3, 0x0d, 0x59,
-	0x83, 0x65, 0x72, 0x7a, 0x1a, 0x21, 0x26, 0xd6, 0xaa, 0x64, 0x29, 0x28, 0x49, 0xc1, 0xd5, 0x4c,
-	0x0a, 0x5e, 0x15, 0xe7, 0xda, 0xd3, 0xa7, 0x47, 0x7b, 0xe7, 0x28, 0x60, 0xfa, 0x04, 0x7e, 0x00,
-	0x55, 0x8d, 0xfa, 0x35, 0xb7, 0xc9, 0xef, 0x40, 0x6b, 0xdb, 0xf3, 0x86, 0xcf, 0x9d, 0x50, 0xfb,
-	0xa3, 0x07, 0x95, 0xc1, 0xee, 0xe1, 0x40, 0xba, 0xa4, 0xc4, 0x0d, 0x50, 0x20, 0x3f, 0xf1, 0xf7,
-	0x11, 0x3b, 0x42, 0x8c, 0x62, 0x37, 0x39, 0xf1, 0xef, 0x42, 0x45, 0x61, 0xf8, 0x4c, 0x5f, 0x7e,
-	0xea, 0x23, 0x40, 0x81, 0xe6, 0xdf, 0x80, 0xf1, 0x77, 0xbc, 0x0c, 0x46, 0xb2, 0x07, 0x52, 0x92,
-	0xde, 0x81, 0xce, 0xb9, 0xc0, 0xda, 0xb2, 0x3e, 0xcc, 0x2c, 0x43, 0x5b, 0x0e, 0x88, 0xfc, 0x20,
-	0x64, 0x1f, 0x43, 0x57, 0x56, 0xed, 0x92, 0xcf, 0x35, 0x58, 0x70, 0x1f, 0x26, 0xeb, 0x59, 0xb6,
-	0xc4, 0xf7, 0xc3, 0x1f, 0xbb, 0xea, 0x18, 0x53, 0x97, 0x44, 0xc6, 0x3e, 0xb4, 0xa7, 0x5e, 0xf4,
-	0x0c, 0x75, 0x6b, 0x38, 0xff, 0xa1, 0xaf, 0xbf, 0xb6, 0x29, 0x5f, 0x08, 0x37, 0xf5, 0x0b, 0xe1,
-	0xe6, 0x9e, 0x1f, 0xb2, 0x4b, 0x63, 0x0f, 0x5a, 0xf9, 0xb7, 0x2f, 0xe3, 0x55, 0x5d, 0x62, 0xcd,
-	0x79, 0x11, 0x5b, 0xc8, 0x66, 0x1f, 0xda, 0x53, 0xcf, 0x60, 0x5a, 0x9f, 0xf9, 0xaf, 0x63, 0x0b,
-	0x19, 0x3d, 0x86, 0x7a, 0xe6, 0xdd, 0xcb, 0xe8, 0x49, 0x26, 0xb3, 0x4f, 0x61, 0x0b, 0x19, 0xec,
-	0x42, 0x33, 0xf7, 0x14, 0x65, 0xf4, 0x95, 0x3d, 0x73, 0xde, 0xa7, 0x16, 0x32, 0xd9, 0x81, 0x7a,
-	0xe6, 0x45, 0x48, 0x6b, 0x31, 0xfb, 0xec, 0xd4, 0xbf, 0x35, 0x67, 0x44, 0x9d, 0x96, 0xfb, 0xd0,
-	0x9e, 0x7a, 0x26, 0xd2, 0x2e, 0x99, 0xff, 0x7a, 0xb4, 0x50, 0x99, 0x21, 0xdc, 0x98, 0x5b, 0x25,
-	0x1b, 0x66, 0x96, 0xdd, 0xfc, 0x12, 0x7a, 0x21, 0xd3, 0xaf, 0xc4, 0xba, 0x67, 0xae, 0x16, 0x32,
-	0xeb, 0x3e, 0xfb, 0xd2, 0xd4, 0xbf, 0x3d, 0x7f, 0x50, 0x99, 0xba, 0x07, 0xad, 0xfc, 0x23, 0x93,
-	0x66, 0x36, 0xf7, 0xe9, 0xe9, 0xea, 0x20, 0xca, 0xbd, 0x37, 0xa5, 0x41, 0x34, 0xef, 0x19, 0x6a,
-	0x21, 0xa3, 0x6d, 0x00, 0x75, 0x91, 0xe0, 0xe1, 0x20, 0x59, 0xbd, 0x99, 0x0b, 0x8c, 0x64, 0xf5,
-	0xe6, 0x5c, 0x3a, 0x3c, 0x06, 0x90, 0xfd, 0xbf, 0x47, 0x62, 0x66, 0xdc, 0xd4, 0x6a, 0x4c, 0x5d,
-	0x3a, 0xf4, 0x7b, 0xb3, 0x03, 0x33, 0x0c, 0x10, 0xa5, 0xd7, 0x61, 0xf0, 0x39, 0x40, 0x7a, 0xaf,
-	0xa0, 0x19, 0xcc, 0xdc, 0x34, 0x5c, 0xe1, 0x83, 0x46, 0xf6, 0x16, 0xc1, 0x50, 0xb6, 0xce, 0xb9,
-	0x59, 0xb8, 0x82, 0x45, 0x7b, 0xaa, 0x4b, 0xcc, 0x47, 0xf0, 0x74, 0xf3, 0xd8, 0x9f, 0xe9, 0x14,
-	0x8d, 0x47, 0xd0, 0xc8, 0xb6, 0x87, 0x5a, 0x8b, 0x39, 0x2d, 0x63, 0x3f, 0xd7, 0x22, 0x1a, 0x8f,
-	0xa1, 0x95, 0xef, 0xe7, 0x74, 0x48, 0xcd, 0xed, 0xf2, 0xfa, 0xea, 0xe2, 0x33, 0x43, 0xfe, 0x01,
-	0x40, 0xda, 0xf7, 0x69, 0xf7, 0xcd, 0x74, 0x82, 0x53, 0x52, 0xf7, 0xa1, 0x3d, 0xd5, 0xcf, 0x69,
-	0x8b, 0xe7, 0xb7, 0x79, 0x57, 0x25, 0x90, 0x4c, 0x77, 0xa6, 0x43, 0x70, 0xb6, 0xbf, 0xd3, 0x21,
-	0x38, 0xaf, 0x95, 0xdb, 0x81, 0xfa, 0x70, 0x96, 0xc7, 0x70, 0x21, 0x8f, 0x79, 0x0d, 0xda, 0x87,
-	0x00, 0xe9, 0x59, 0xa8, 0xbd, 0x30, 0x73, 0x3a, 0xf6, 0x9b, 0xfa, 0x72, 0x5a, 0xd2, 0xed, 0x42,
-	0x33, 0x77, 0x7f, 0xa3, 0x73, 0xe8, 0xbc, 0x4b, 0x9d, 0xab, 0x4e, 0x96, 0xfc, 0x65, 0x87, 0x5e,
-	0xc1, 0xb9, 0x57, 0x20, 0x57, 0xc5, 0x71, 0xb6, 0x9d, 0xd4, 0x11, 0x34, 0xa7, 0xc5, 0xfc, 0x85,
-	0xbc, 0x92, 0x6d, 0x19, 0x33, 0x79, 0x65, 0x4e, 0x27, 0xb9, 0x90, 0xd1, 0x01, 0xb4, 0xf7, 0x75,
-	0x37, 0xa0, 0x3a, 0x15, 0xbd, 0x7e, 0xb3, 0x9d, 0x59, 0xbf, 0x3f, 0x6f, 0x48, 0xad, 0xcb, 0x57,
-	0xd0, 0x99, 0xe9, 0x52, 0x8c, 0xf5, 0xe4, 0x89, 0x60, 0x6e, 0xfb, 0xb2, 0x50, 0xad, 0x43, 0x58,
-	0x99, 0x6e, 0x52, 0x8c, 0xd7, 0x92, 0x98, 0x98, 0xd7, 0xbc, 0x2c, 0x64, 0xf5, 0x09, 0x54, 0x75,
-	0xe1, 0x69, 0xa8, 0xa7, 0x98, 0xa9, 0x42, 0x74, 0xe1, 0xd4, 0x47, 0x22, 0xe4, 0x93, 0xa2, 0x2e,
-	0x0d, 0xf9, 0xa9, 0xd2, 0xaf, 0xaf, 0x5e, 0x4e, 0x12, 0xca, 0x47, 0x50, 0x51, 0xb5, 0x9d, 0xb1,
-	0x9a, 0x6c, 0xb6, 0x4c, 0xa9, 0x77, 0x55, 0x84, 0xed, 0x23, 0x96, 0xa9, 0xd8, 0xb4, 0xd0, 0xd9,
-	0x22, 0x4e, 0xef, 0x91, 0xdc, 0x88, 0x5a, 0x8b, 0x6d, 0x68, 0x64, 0x6b, 0x36, 0xbd, 0xa4, 0x73,
-	0xea, 0xb8, 0x45, 0x9a, 0xec, 0x5c, 0xfc, 0xf4, 0xf3, 0xfa, 0x2b, 0xbf, 0xfd, 0x79, 0xfd, 0x95,
-	0x7f, 0x7b, 0xb1, 0x5e, 0xf8, 0xe9, 0xc5, 0x7a, 0xe1, 0x37, 0x2f, 0xd6, 0x0b, 0x7f, 0x78, 0xb1,
-	0x5e, 0xf8, 0x87, 0x7f, 0xfe, 0x33, 0xff, 0x13, 0x46, 0xe3, 0x80, 0x61, 0x1f, 0x6d, 0x9d, 0x63,
-	0xca, 0x32, 0x43, 0xe1, 0xd9, 0x48, 0xfe, 0x31, 0x2c, 0xf3, 0x7f, 0x31, 0xae, 0xe5, 0xc9, 0xb2,
-	0x80, 0x3f, 0xf8, 0x53, 0x00, 0x00, 0x00, 0xff, 0xff, 0xeb, 0xb0, 0x39, 0x1d, 0x7c, 0x26, 0x00,
+	// 3457 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xc5, 0x1a, 0x5d, 0x73, 0x23, 0x47,
+	0x11, 0x59, 0xb2, 0x2d, 0xb5, 0xbe, 0x4e, 0x6b, 0x9f, 0xcf, 0xa7, 0x5c, 0x8e, 0xb0, 0x81, 0x7c,
+	0x12, 0xdb, 0xb9, 0x4b, 0x5d, 0x3e, 0xa8, 0x10, 0xce, 0x1f, 0x39, 0x3b, 0x89, 0x73, 0x62, 0x75,
+	0x4e, 0x28, 0xaa, 0x40, 0xb5, 0x92, 0xc6, 0xd2, 0xc6, 0xd2, 0xee, 0x66, 0x77, 0xe5, 0xb3, 0xa1,
+	0x8a, 0xca, 0x13, 0xbc, 0xf1, 0x0c, 0x0f, 0xfc, 0x01, 0x8a, 0x7f, 0xc0, 0x1f, 0x48, 0xf1, 0xc4,
+	0x23, 0x2f, 0x54, 0x01, 0x0f, 0x14, 0x2f, 0xfc, 0x07, 0x7a, 0xa6, 0x67, 0x76, 0x67, 0xa5, 0x95,
+	0xee, 0x70, 0x1d, 0xc5, 0x83, 0x5c, 0x3b, 0x3d, 0x3d, 0xdd, 0x3d, 0xdd, 0x3d, 0x3d, 0xdd, 0x3d,
+	0x86, 0xd6, 0xc0, 0x89, 0x86, 0x93, 0xee, 0x56, 0xcf, 0x1b, 0x6f, 0x9f, 0xd9, 0x91, 0xfd, 0x46,
+	0xcf, 0x73, 0x23, 0xdb, 0x71, 0x59, 0x10, 0xce, 0x8c, 0xc3, 0xa0, 0xb7, 0x3d, 0x72, 0xba, 0xe1,
+	0xb6, 0x1f, 0x78, 0x91, 0xd7, 0xf3, 0x46, 0xf2, 0x2b, 0xdc, 0xb6, 0x07, 0xcc, 0x8d, 0xb6, 0xc4,
+	0xc0, 0x28, 0x0c, 0x02, 0xbf, 0xd7, 0x2c, 0x79, 0x3d, 0x87, 0x00, 0xcd, 0x52, 0x2f, 0x54, 0x9f,
+	0xe5, 0xe8, 0xd2, 0x67, 0xa1, 0x1c, 0x3c, 0x37, 0xf0, 0xbc, 0xc1, 0x88, 0x11, 0x8d, 0xee, 0xe4,
+	0x74, 0x9b, 0x8d, 0xfd, 0xe8, 0x92, 0x26, 0xcd, 0xdf, 0x2d, 0xc1, 0xc6, 0x5e, 0xc0, 0xec, 0x88,
+	0xed, 0x29, 0x01, 0x2c, 0xf6, 0xe5, 0x84, 0x85, 0x91, 0xf1, 0x2d, 0xa8, 0xc4, 0x42, 0x75, 0x9c,
+	0xfe, 0x66, 0xee, 0x85, 0xdc, 0x2b, 0x25, 0xab, 0x1c, 0xc3, 0x8e, 0xfa, 0xc6, 0x0d, 0x58, 0x65,
+	0x17, 0xac, 0xc7, 0x67, 0x97, 0xc4, 0xec, 0x0a, 0x1f, 0xe2, 0xc4, 0x9b, 0x50, 0x0e, 0xa3, 0xc0,
+	0x71, 0x07, 0x9d, 0x49, 0xc8, 0x82, 0xcd, 0x3c, 0x4e, 0x96, 0xef, 0x5c, 0xdb, 0xe2, 0x22, 0x6f,
+	0xb5, 0xc5, 0xc4, 0x09, 0xc2, 0x2d, 0x08, 0xe3, 0x6f, 0xe3, 0x25, 0x58, 0xed, 0xb3, 0x73, 0xa7,
+	0xc7, 0xc2, 0xcd, 0xc2, 0x0b, 0x79, 0x44, 0xaf, 0x10, 0xfa, 0xbe, 0x00, 0x5a, 0x6a, 0xd2, 0x78,
+	0x15, 0x8a, 0x61, 0xe4, 0x05, 0xa8, 0x8a, 0x70, 0x73, 0x59, 0x20, 0x56, 0x15, 0x5d, 0x01, 0xb5,
+	0xe2, 0x69, 0xe3, 0x16, 0xe4, 0x1f, 0xee, 0x1d, 0x6d, 0xae, 0x08, 0xee, 0x20, 0xb1, 0x7c, 0xd6,
+	0xb3, 0x38, 0xd8, 0x78, 0x11, 0xaa, 0xa1, 0xed, 0xf6, 0xbb, 0xde, 0x45, 0xc7, 0x77, 0xfa, 0x6e,
+	0xb8, 0xb9, 0x8a, 0x78, 0x45, 0xab, 0x22, 0x81, 0x2d, 0x0e, 0x33, 0xdf, 0x83, 0xeb, 0xed, 0xc8,
+	0x0e, 0xa2, 0x2b, 0x68, 0xc7, 0x3c, 0x81, 0x0d, 0x8b, 0x8d, 0xbd, 0xf3, 0x2b, 0xa9, 0x76, 0x13,
+	0x56, 0x23, 0x67, 0xcc, 0xbc, 0x49, 0x24, 0x54, 0x5b, 0xb5, 0xd4, 0xd0, 0xfc, 0x43, 0x0e, 0x8c,
+	0x03, 0x54, 0x73, 0x2b, 0xf0, 0x50, 0x1f, 0xe1, 0xff, 0xc9, 0x5c, 0x2f, 0xc3, 0xaa, 0x4f, 0x02,
+	0xa0, 0xb9, 0x72, 0x89, 0x15, 0x94, 0x54, 0x6a, 0xd6, 0xfc, 0x02, 0xd6, 0xdb, 0xce, 0xc0, 0xb5,
+	0x47, 0xcf, 0x50, 0xde, 0x0d, 0x58, 0x09, 0x05, 0x4d, 0x21, 0x6a, 0xd5, 0x92, 0x23, 0xb3, 0x05,
+	0xc6, 0xe7, 0xb6, 0x13, 0x3d, 0x3b, 0x4e, 0xe6, 0x1b, 0xb0, 0x96, 0xa2, 0x18, 0xfa, 0x9e, 0x1b,
+	0x32, 0x21, 0x40, 0x64, 0x47, 0x93, 0x50, 0x10, 0x5b, 0xb6, 0xe4, 0xc8, 0xf4, 0x60, 0xe3, 0xc4,
+	0xef, 0x5f, 0xf1, 0x34, 0xdd, 0x81, 0x52, 0xc0, 0x42, 0x6f, 0x12, 0xf0, 0x33, 0xb0, 0x24, 0x94,
+	0xba, 0x4e, 0x4a, 0xfd, 0xc4, 0x71, 0x27, 0x17, 0x96, 0x9a, 0xb3, 0x12, 0x34, 0xe9, 0x9f, 0x51,
+	0x78, 0x15, 0xff, 0xc4, 0xb5, 0x2d, 0x1b, 0x0d, 0x7e, 0x95, 0xb5, 0xdf, 0xe3, 0xbe, 0x1d, 0x4e,
+	0xc6, 0x57, 0x5a, 0xfc, 0xfb, 0x1c, 0x14, 0xf7, 0xfc, 0xc9, 0x49, 0x88, 0xa7, 0xd4, 0xf8, 0x26,
+	0x94, 0x23, 0x2f, 0xb2, 0x47, 0xe8, 0x7a, 0x38, 0x14, 0xe8, 0x05, 0x0b, 0x04, 0x88, 0x10, 0x90,
+	0xa0, 0xcf, 0x82, 0x9e, 0x3f, 0x91, 0x18, 0x4b, 0x78, 0xe8, 0x0b, 0x56, 0x99, 0x60, 0x84, 0xb2,
+	0x05, 0x6b, 0x62, 0xae, 0xe3, 0xb8, 0x9d, 0x33, 0x16, 0xb8, 0x6c, 0x34, 0xf6, 0xfa, 0x4c, 0x38,
+	0x47, 0xc1, 0x6a, 0x88, 0xa9, 0x23, 0xf7, 0xe3, 0x78, 0xc2, 0x78, 0x0d, 0x1a, 0x31, 0x3e, 0xf7,
+	0x78, 0x81, 0x5d, 0x10, 0xd8, 0x75, 0x89, 0x7d, 0x22, 0xc1, 0xe6, 0x2f, 0xa0, 0xf6, 0x68, 0x88,
+	0xb1, 0x32, 0x1a, 0xa1, 0xeb, 0xef, 0x63, 0xa8, 0xe6, 0x47, 0x13, 0x99, 0x3b, 0x5e, 0x3f, 0x94,
+	0xd2, 0xaa, 0xa1, 0xf1, 0x3a, 0x34, 0x22, 0xc2, 0x65, 0xfd, 0x8e, 0xc2, 0x59, 0x12, 0x38, 0xd7,
+	0xe2, 0x89, 0x96, 0x44, 0xfe, 0x0e, 0xd4, 0x12, 0x64, 0x7e, 0xb8, 0xa5, 0xbc, 0xd5, 0x18, 0xfa,
+	0x08, 0x81, 0x66, 0x0f, 0x56, 0x5b, 0xed, 0x23, 0xc1, 0x78, 0x1d, 0x96, 0xed, 0xf3, 0xc1, 0x9b,
+	0x3b, 0x82, 0x6d, 0xce, 0xa2, 0x81, 0x84, 0xde, 0xdb, 0x11, 0x8c, 0x08, 0x7a, 0x6f, 0x87, 0x7b,
+	0x28, 0x7e, 0xdc, 0xdd, 0xd9, 0x11, 0x54, 0x73, 0x96, 0x1c, 0x71, 0x6c, 0xa1, 0x5b, 0xb9, 0x5d,
+	0x1a, 0xe0, 0xc1, 0x29, 0x22, 0x13, 0xe1, 0x49, 0xa8, 0xef, 0x42, 0xe8, 0x8d, 0xc9, 0x12, 0xc9,
+	0xb1, 0x26, 0x11, 0x2c, 0x31, 0xc5, 0x51, 0x4e, 0x27, 0xa3, 0x91, 0x74, 0xd2, 0x69, 0x14, 0x3e,
+	0x65, 0xfe, 0x86, 0x6c, 0x4c, 0x24, 0x5f, 0x87, 0x52, 0x62, 0x3f, 0xa2, 0x5b, 0xa3, 0x45, 0xca,
+	0x0d, 0xac, 0x62, 0x6c, 0xcc, 0xf7, 0xa1, 0x1e, 0xc5, 0x0a, 0xef, 0xe0, 0x61, 0xb2, 0xd3, 0x87,
+	0x21, 0x6d, 0x0d, 0xab, 0x16, 0xa5, 0xad, 0xf3, 0x02, 0xe4, 0xfd, 0xd0, 0x91, 0x31, 0xac, 0x16,
+	0x8b, 0x26, 0x04, 0xb1, 0xf8, 0x14, 0xfa, 0x6e, 0x09, 0x83, 0x7b, 0x48, 0xa2, 0xa1, 0x31, 0x7b,
+	0x93, 0x20, 0xc0, 0x7b, 0x55, 0x19, 0x53, 0x0e, 0xb9, 0xa6, 0x46, 0xce, 0xd8, 0x89, 0xa4, 0x01,
+	0x69, 0x80, 0x27, 0x1c, 0x8e, 0x31, 0xa8, 0x07, 0x97, 0xca, 0x22, 0xba, 0xdb, 0xd2, 0xc0, 0x78,
+	0x0e, 0x4a, 0x63, 0xfb, 0x22, 0x76, 0x57, 0x3e, 0x53, 0x44, 0x00, 0x6d, 0x0f, 0x19, 0x9e, 0xda,
+	0xce, 0xa8, 0x87, 0x0c, 0xc9, 0xde, 0x6a, 0x98, 0x30, 0x2c, 0xe8, 0x0c, 0xff, 0xb9, 0x04, 0x65,
+	0xe2, 0x48, 0x02, 0x23, 0x56, 0xcf, 0xee, 0x0d, 0x63, 0x96, 0x62, 0x80, 0xb7, 0xe7, 0x72, 0xc2,
+	0x2e, 0x8e, 0xdd, 0x89, 0xa4, 0x4a, 0xb4, 0x6d, 0x80, 0xf0, 0xb1, 0xed, 0x4b, 0xd9, 0xf2, 0x73,
+	0x90, 0x4b, 0x1c, 0x87, 0xc4, 0xbd, 0x0b, 0x15, 0x3a, 0x51, 0x72, 0x49, 0x61, 0xce, 0x92, 0x32,
+	0x61, 0xd1, 0x22, 0xbc, 0x5a, 0xf1, 0x58, 0x75, 0x86, 0x0e, 0x0b, 0xec, 0xa0, 0x37, 0xbc, 0xc4,
+	0x8b, 0x5a, 0x5c, 0xad, 0x08, 0x3c, 0x54, 0x30, 0x0c, 0x77, 0xcb, 0x3c, 0x6a, 0x86, 0x78, 0x3f,
+	0xf3, 0x5b, 0xfc, 0x96, 0x4e, 0x52, 0x6c, 0x75, 0x4b, 0xfc, 0x3d, 0x70, 0xa3, 0xe0, 0xd2, 0x22,
+	0x54, 0x65, 0xdc, 0xd5, 0xb9, 0xc6, 0x6d, 0xbe, 0x03, 0x90, 0x2c, 0x33, 0xae, 0x41, 0xfe, 0x8c,
+	0x5d, 0xca, 0x18, 0xc4, 0x3f, 0xb9, 0xfa, 0xce, 0xed, 0xd1, 0x44, 0xd9, 0x85, 0x06, 0xef, 0x2d,
+	0xbd, 0x93, 0xc3, 0x83, 0x56, 0xdf, 0x1d, 0x9d, 0x39, 0x9e, 0xb6, 0x1c, 0x91, 0xc7, 0xf6, 0x17,
+	0x5e, 0xa0, 0x74, 0x2d, 0x06, 0x02, 0xea, 0xb8, 0x08, 0x95, 0x24, 0xc4, 0xc0, 0xa8, 0xc1, 0x92,
+	0xe7, 0x0b, 0x8d, 0x96, 0x2c, 0xfc, 0x4a, 0x18, 0x15, 0x34, 0x46, 0xe6, 0x57, 0xcb, 0x00, 0x09,
+	0x17, 0xc3, 0x82, 0xa6, 0xe3, 0x75, 0x30, 0xd4, 0xf0, 0xdc, 0xa6, 0xd3, 0xbd, 0x8c, 0x58, 0xd8,
+	0x09, 0x18, 0x7a, 0x60, 0xe8, 0x9c, 0x73, 0x0b, 0x73, 0xc5, 0x5c, 0xa7, 0x6d, 0x4e, 0xc9, 0x66,
+	0xdd, 0xc0, 0x11, 0xad, 0xdb, 0xe5, 0xcb, 0x2c, 0xb5, 0xca, 0x38, 0x82, 0xeb, 0x09, 0xcd, 0xbe,
+	0x46, 0x6e, 0x69, 0x11, 0xb9, 0xb5, 0x98, 0x5c, 0x3f, 0x21, 0x75, 0x00, 0x08, 0xee, 0x60, 0x5c,
+	0x9f, 0xa4, 0x08, 0xe5, 0x17, 0x11, 0x6a, 0x38, 0xde, 0x0f, 0xc5, 0x82, 0x84, 0x4c, 0x0b, 0x6e,
+	0x6a, 0xbb, 0xe4, 0xa1, 0x4e, 0x23, 0x56, 0x58, 0x44, 0x6c, 0x23, 0x96, 0x8a, 0xc7, 0xc2, 0x84,
+	0xe2, 0x47, 0x80, 0x33, 0x9d, 0xc7, 0x78, 0x33, 0x4f, 0x93, 0x5b, 0x7e, 0xc2, 0x26, 0xf9, 0x6d,
+	0x9e, 0xa6, 0x45, 0x9b, 0x1c, 0xb3, 0x60, 0x90, 0xda, 0xe4, 0xca, 0x13, 0x36, 0x79, 0x2c, 0x16,
+	0x24, 0x64, 0xee, 0x03, 0x02, 0xa7, 0xa5, 0x59, 0x5d, 0x44, 0xa4, 0xee, 0x78, 0x69, 0x49, 0x76,
+	0xa1, 0x11, 0xb2, 0x1e, 0x66, 0xaf, 0xba, 0x13, 0x14, 0x17, 0x91, 0xb8, 0x26, 0xf1, 0x13, 0x1a,
+	0xf2, 0x84, 0x94, 0xe6, 0x87, 0xbf, 0x2f, 0xa1, 0x72, 0x38, 0x19, 0xb0, 0x68, 0xd4, 0x8d, 0x03,
+	0xca, 0xff, 0x3a, 0x86, 0xfd, 0x36, 0x0f, 0xe5, 0xbd, 0x41, 0xe0, 0x4d, 0xfc, 0xd4, 0x7d, 0x40,
+	0xc7, 0x7f, 0xfa, 0x3e, 0x20, 0x51, 0xf9, 0x7d, 0x40, 0xc8, 0x6f, 0x41, 0x65, 0x2c, 0x82, 0x82,
+	0xc4, 0xa7, 0x08, 0xd7, 0x98, 0x09, 0x17, 0x56, 0x79, 0xac, 0x85, 0xc9, 0x2d, 0x00, 0xcc, 0xea,
+	0x43, 0xb9, 0x86, 0x02, 0x5d, 0x5d, 0xaa, 0x43, 0x05, 0x7f, 0xab, 0xe4, 0xc7, 0xf7, 0x00, 0xa6,
+	0xc0, 0x5d, 0xae, 0x5c, 0xb9, 0x20, 0x15, 0xe6, 0x12, 0xad, 0x5b, 0xd0, 0x4d, 0x0e, 0xef, 0x21,
+	0x54, 0x87, 0xa4, 0x48, 0xb9, 0x88, 0x7c, 0xef, 0x45, 0xb9, 0x93, 0x64, 0xbf, 0x5b, 0xba, 0xbe,
+	0xc9, 0x70, 0x95, 0xa1, 0x6e, 0x02, 0x4c, 0x05, 0x7a, 0x02, 0xbd, 0x73, 0x8e, 0x05, 0xa0, 0xe3,
+	0xb9, 0xa2, 0x66, 0xa9, 0x5a, 0x55, 0x82, 0x7e, 0x46, 0xc0, 0x66, 0x1b, 0x1a, 0x33, 0x94, 0x32,
+	0x42, 0xdc, 0x2b, 0x7a, 0x88, 0x2b, 0xdf, 0x31, 0x48, 0x1e, 0x7d, 0xa5, 0x1e, 0xf6, 0x7e, 0xbd,
+	0x04, 0x95, 0x4f, 0x59, 0xf4, 0xd8, 0x0b, 0xce, 0x48, 0x18, 0x03, 0x0a, 0xae, 0x2d, 0xef, 0xff,
+	0x92, 0x25, 0xbe, 0x8d, 0x9b, 0x50, 0x0c, 0x2e, 0x28, 0x3e, 0x49, 0x67, 0x58, 0x0d, 0x2e, 0x44,
+	0xdc, 0x31, 0x9e, 0x07, 0xc0, 0x29, 0xdf, 0xee, 0x9d, 0x31, 0xa9, 0xe8, 0x02, 0x26, 0xa8, 0x17,
+	0x2d, 0x02, 0x70, 0x3f, 0xc2, 0x69, 0x16, 0x04, 0xe8, 0xa5, 0xd2, 0x29, 0x90, 0xd4, 0x81, 0x18,
+	0xcb, 0xb5, 0xfd, 0xc0, 0xf3, 0x7d, 0xd6, 0x17, 0x97, 0x84, 0x58, 0xbb, 0x4f, 0x00, 0xce, 0x35,
+	0x52, 0x5c, 0x57, 0x88, 0x6b, 0x94, 0x70, 0x8d, 0x12, 0xae, 0xab, 0xb4, 0x32, 0xd2, 0xb9, 0x46,
+	0x31, 0xd7, 0x22, 0x71, 0x8d, 0x34, 0xae, 0x51, 0xc2, 0xb5, 0xa4, 0xd6, 0x4a, 0xae, 0xe6, 0xaf,
+	0x72, 0xb0, 0x31, 0x9d, 0x53, 0xcb, 0xb4, 0x1f, 0x5d, 0x51, 0xda, 0x49, 0x77, 0xdd, 0xc6, 0x8c,
+	0xc1, 0x31, 0xdd, 0xd5, 0xbc, 0xfd, 0x6d, 0xa8, 0xba, 0xa4, 0xe0, 0xd8, 0x83, 0xf3, 0x89, 0x5d,
+	0x74, 0xdd, 0x5b, 0x15, 0x57, 0x1b, 0x99, 0x7d, 0x2c, 0x67, 0x02, 0x27, 0x62, 0x58, 0x82, 0x31,
+	0x7b, 0xfc, 0x2c, 0x0a, 0x27, 0xb4, 0xad, 0x48, 0xa8, 0xb8, 0x99, 0x2a, 0x96, 0xf8, 0x36, 0x5f,
+	0xc6, 0x12, 0x47, 0xe7, 0x22, 0xf7, 0x8a, 0x7e, 0x35, 0x62, 0xae, 0xa0, 0x5e, 0xb5, 0xf8, 0xa7,
+	0x69, 0x43, 0xc3, 0x62, 0x76, 0xff, 0xd9, 0x49, 0x23, 0x59, 0xe4, 0x13, 0x16, 0xaf, 0x80, 0xa1,
+	0xb3, 0x90, 0xa2, 0x28, 0xa9, 0x73, 0x9a, 0xd4, 0x0f, 0xa1, 0xb1, 0x37, 0xf2, 0x42, 0x94, 0xba,
+	0xef, 0xb8, 0xcf, 0xa2, 0xd2, 0xfb, 0x39, 0xac, 0x3d, 0x8a, 0x2e, 0x3f, 0xe7, 0xc4, 0x42, 0xe7,
+	0x67, 0xec, 0x19, 0xed, 0x2f, 0xf0, 0x1e, 0xab, 0xfd, 0xe1, 0x27, 0xcf, 0xca, 0xb1, 0x9f, 0x33,
+	0x19, 0xbb, 0xe2, 0x28, 0x60, 0xe1, 0x4a, 0x23, 0x73, 0x17, 0x2a, 0x54, 0x9e, 0x1c, 0x7b, 0xfd,
+	0xc9, 0x88, 0x65, 0x9e, 0xc1, 0xdb, 0x18, 0xd1, 0xec, 0x00, 0xbf, 0x22, 0x0c, 0x07, 0xc2, 0x87,
+	0x4a, 0x96, 0x06, 0x31, 0xff, 0xbd, 0x04, 0xeb, 0xd4, 0xca, 0x69, 0x53, 0x07, 0x43, 0x6d, 0xa1,
+	0x09, 0xc5, 0xa1, 0x17, 0x46, 0x1a, 0xc1, 0x78, 0xcc, 0x45, 0xe4, 0xad, 0x0f, 0xa2, 0xc6, 0x3f,
+	0x53, 0xfd, 0x95, 0xfc, 0xe2, 0xfe, 0xca, 0x4c, 0x07, 0xa5, 0x30, 0xdb, 0x41, 0xe1, 0xa7, 0x4d,
+	0x21, 0x39, 0x74, 0xc6, 0x4b, 0x98, 0x5f, 0x12, 0x04, 0x75, 0xf4, 0x12, 0xd4, 0x07, 0x5c, 0xca,
+	0xce, 0xd0, 0xf3, 0xce, 0xf0, 0x40, 0x47, 0x43, 0x71, 0xd4, 0x4b, 0x56, 0x55, 0x80, 0x0f, 0x11,
+	0xda, 0x42, 0xa0, 0xf1, 0x2e, 0xd4, 0x64, 0x1e, 0x3a, 0x16, 0x2a, 0x0a, 0xe5, 0xdd, 0x2a, 0x4f,
+	0x91, 0xae, 0x3d, 0xab, 0x7a, 0xa6, 0x8d, 0xc4, 0x55, 0x80, 0x82, 0x74, 0xd0, 0x64, 0xa7, 0xce,
+	0x40, 0x44, 0x83, 0xf8, 0x2a, 0xd8, 0xff, 0xb4, 0xbd, 0x27, 0xc0, 0x56, 0x09, 0x51, 0xe8, 0x13,
+	0xa3, 0xf1, 0x32, 0xd7, 0x4f, 0x88, 0xa1, 0x21, 0x9f, 0xa0, 0x1e, 0x22, 0x48, 0xe6, 0xa2, 0x62,
+	0x16, 0xf3, 0xc5, 0x52, 0xbc, 0x1c, 0xaf, 0xdd, 0x32, 0xd7, 0x27, 0xcf, 0x71, 0xb8, 0x75, 0x72,
+	0x42, 0x9f, 0x3a, 0x88, 0x5b, 0x21, 0x64, 0x3c, 0xf3, 0x65, 0x4a, 0xdd, 0xf1, 0x98, 0xdf, 0xa7,
+	0x9e, 0x1f, 0x61, 0x88, 0x27, 0x95, 0x97, 0x2c, 0x35, 0x34, 0xdf, 0x84, 0x52, 0xcc, 0x98, 0xa7,
+	0x98, 0x8e, 0x2f, 0x4d, 0x88, 0x5f, 0xfc, 0xb2, 0x15, 0x1c, 0x24, 0x3d, 0x1a, 0x98, 0x3e, 0xdc,
+	0xa0, 0x1e, 0x84, 0x74, 0x03, 0x14, 0x52, 0x79, 0x42, 0x5a, 0x13, 0xb9, 0xa7, 0xd7, 0xc4, 0xd2,
+	0x42, 0x4d, 0xdc, 0x80, 0xeb, 0xfb, 0x48, 0x3e, 0xf0, 0x2e, 0xd3, 0x9e, 0x67, 0x7e, 0x1f, 0xe0,
+	0xc8, 0x45, 0xe7, 0x3c, 0xb5, 0x79, 0xe7, 0x6e, 0x47, 0x1f, 0xc9, 0xe4, 0xf6, 0xda, 0x16, 0xb5,
+	0x2a, 0xe3, 0x09, 0x4b, 0xc3, 0x31, 0xb7, 0x60, 0xc5, 0xc2, 0x96, 0x17, 0xae, 0xfd, 0xb6, 0xfa,
+	0x92, 0xeb, 0x2a, 0x72, 0x9d, 0x00, 0x5a, 0x72, 0xce, 0x3c, 0x54, 0xed, 0x97, 0x84, 0x5c, 0xbc,
+	0xf3, 0x92, 0xa3, 0x60, 0x72, 0xe3, 0xb3, 0xac, 0x13, 0x14, 0xac, 0x11, 0xd7, 0x88, 0x12, 0x51,
+	0x56, 0x64, 0x50, 0x8c, 0x40, 0x89, 0x91, 0x4b, 0x7a, 0x94, 0x12, 0x49, 0xce, 0x99, 0x47, 0x70,
+	0x8b, 0x16, 0x1f, 0xf8, 0x43, 0x86, 0x89, 0xa5, 0x8d, 0xae, 0x38, 0x71, 0xa3, 0x98, 0x8a, 0x7e,
+	0xc4, 0x72, 0x0b, 0x8f, 0x18, 0x57, 0xed, 0x27, 0x4e, 0x18, 0x25, 0x3a, 0x51, 0xaa, 0x5d, 0x83,
+	0x06, 0x9f, 0x48, 0x89, 0x67, 0x7e, 0x08, 0x95, 0xfb, 0x56, 0xeb, 0x53, 0xe6, 0x0c, 0x86, 0x5d,
+	0x7e, 0xd3, 0xdd, 0x4b, 0x8f, 0x25, 0x33, 0x43, 0x6e, 0x5c, 0x9b, 0xb2, 0x52, 0x78, 0x26, 0xa6,
+	0xd7, 0xf7, 0xfb, 0x7d, 0x1d, 0xa4, 0x44, 0xdf, 0x81, 0x92, 0xab, 0x91, 0xd3, 0xf2, 0x8b, 0x14,
+	0x76, 0x82, 0x84, 0x1d, 0x34, 0xe3, 0x01, 0x8b, 0x8e, 0x5a, 0x8f, 0xec, 0xee, 0x28, 0x51, 0x24,
+	0xc6, 0x4c, 0x27, 0xec, 0x38, 0xfe, 0xf9, 0x3d, 0x41, 0xa5, 0x68, 0xad, 0x38, 0xe1, 0x11, 0x8e,
+	0xcc, 0x57, 0x61, 0x2d, 0x85, 0xbe, 0xe0, 0x0a, 0xb8, 0x0f, 0x46, 0xfb, 0xe9, 0x29, 0xc7, 0x24,
+	0x96, 0x34, 0x12, 0xc8, 0xad, 0xfd, 0x94, 0xdc, 0x7e, 0x02, 0x6b, 0x0f, 0x5d, 0xec, 0x32, 0xb0,
+	0xbd, 0xd6, 0x09, 0x66, 0x9d, 0x8a, 0x1d, 0xa2, 0xf2, 0x32, 0x44, 0xf2, 0x12, 0xdf, 0x5c, 0x04,
+	0xb7, 0xdb, 0xc1, 0x04, 0x36, 0x94, 0xbd, 0xdb, 0x15, 0xb7, 0x8b, 0x89, 0x6d, 0xc8, 0x13, 0x1a,
+	0x9e, 0xf7, 0x7a, 0xee, 0xe8, 0x52, 0xdc, 0x0a, 0x45, 0xec, 0x36, 0xf8, 0x13, 0x24, 0x7b, 0x69,
+	0x7e, 0x57, 0x34, 0xd4, 0x18, 0x56, 0x14, 0x78, 0x84, 0xbc, 0x31, 0x76, 0xbd, 0x35, 0x0e, 0x33,
+	0x72, 0x7f, 0x9d, 0x43, 0xcb, 0xf2, 0xc7, 0x80, 0x7d, 0x86, 0xd7, 0xd0, 0x48, 0x44, 0x10, 0x95,
+	0x3a, 0x52, 0x7c, 0x50, 0x43, 0xde, 0x5f, 0x73, 0x5c, 0xac, 0x93, 0xfa, 0x36, 0x66, 0xc7, 0xae,
+	0xa0, 0x52, 0xb4, 0x80, 0x83, 0xf6, 0x05, 0x04, 0x3b, 0xb9, 0x75, 0xea, 0xad, 0x77, 0x86, 0xc8,
+	0x7a, 0xc4, 0xc3, 0x17, 0x05, 0xa1, 0x1a, 0x81, 0x0f, 0x25, 0x14, 0xdd, 0xf6, 0x9a, 0xf4, 0xcb,
+	0x04, 0xb3, 0x20, 0x30, 0xeb, 0x12, 0x9e, 0x42, 0x9d, 0xf8, 0xbe, 0x17, 0x44, 0x98, 0x81, 0xb3,
+	0x1e, 0xbe, 0x7a, 0xf8, 0xb2, 0x07, 0x50, 0x57, 0xf0, 0x36, 0x81, 0xcd, 0x01, 0x1a, 0x9c, 0xef,
+	0x53, 0xee, 0x24, 0x39, 0x69, 0x35, 0x4c, 0xe7, 0x3b, 0xdd, 0x91, 0xd7, 0xc3, 0xb4, 0x09, 0x2f,
+	0x64, 0xa9, 0x61, 0x5e, 0x0b, 0xec, 0x72, 0x60, 0x1b, 0x61, 0xbc, 0x91, 0xc7, 0xb1, 0x86, 0x5e,
+	0xe4, 0x8f, 0x26, 0x83, 0x0e, 0xf6, 0x9c, 0xbb, 0x4c, 0x6e, 0xb1, 0x8e, 0x13, 0x87, 0x04, 0x6f,
+	0x71, 0xb0, 0xf9, 0xc7, 0x1c, 0xac, 0xa7, 0x39, 0x49, 0x6b, 0x6f, 0xc3, 0x7a, 0x9a, 0x95, 0x4c,
+	0x39, 0xa9, 0x1e, 0x6a, 0xe8, 0x0c, 0x29, 0xf9, 0xc4, 0x84, 0x4e, 0xbc, 0xc4, 0x74, 0xfa, 0x44,
+	0x29, 0x9d, 0x68, 0xeb, 0x76, 0xb1, 0x2a, 0xb6, 0x6e, 0xa5, 0x77, 0xe1, 0xa6, 0xdc, 0x7e, 0x67,
+	0x56, 0x6c, 0x72, 0x88, 0x0d, 0x89, 0x70, 0x3c, 0x25, 0xfd, 0x27, 0xb0, 0x99, 0x80, 0x76, 0x2f,
+	0x05, 0x30, 0x39, 0x94, 0x6b, 0x53, 0x9b, 0xc5, 0xd3, 0x1b, 0x88, 0xd3, 0x5e, 0xb0, 0xb2, 0xa6,
+	0xcc, 0x0f, 0xe0, 0x06, 0xfa, 0x3d, 0x69, 0x03, 0xe3, 0x14, 0x95, 0xa1, 0x44, 0x0c, 0x33, 0x02,
+	0x34, 0x8d, 0xd8, 0x7c, 0xde, 0xe2, 0x9f, 0xdc, 0x01, 0xb1, 0x1b, 0xda, 0x13, 0xbb, 0xcc, 0x5b,
+	0xe2, 0x1b, 0x2f, 0x99, 0xd5, 0x0f, 0xdb, 0x0f, 0x78, 0x8e, 0xcb, 0x9d, 0x9a, 0x72, 0x62, 0x99,
+	0xff, 0xe0, 0x53, 0x85, 0x18, 0xe3, 0xe5, 0xfe, 0x11, 0xac, 0xd1, 0x54, 0x0f, 0x1d, 0x06, 0xdd,
+	0xc6, 0xf7, 0x46, 0x4e, 0x8f, 0x5c, 0xbf, 0x76, 0xa7, 0x29, 0xc3, 0x90, 0xa4, 0xb3, 0x27, 0x50,
+	0x5a, 0x02, 0xc3, 0x6a, 0x0c, 0xa6, 0x41, 0xe6, 0x5f, 0x73, 0xb0, 0x2a, 0xe3, 0x23, 0x4f, 0xa3,
+	0xfa, 0x01, 0x56, 0xbb, 0x81, 0x74, 0x76, 0x39, 0xe2, 0x75, 0x14, 0x7d, 0x75, 0xd4, 0x75, 0x4a,
+	0x37, 0x63, 0x95, 0xa0, 0x0f, 0x09, 0x28, 0xba, 0xf7, 0xa2, 0x7f, 0x2e, 0xdb, 0x35, 0x72, 0xc4,
+	0xe1, 0xa7, 0x21, 0x17, 0x4a, 0x24, 0x32, 0x08, 0xa7, 0x91, 0x7e, 0x3d, 0x2f, 0xa7, 0xae, 0x67,
+	0x7e, 0xb8, 0xc6, 0x3c, 0xb4, 0xe3, 0xce, 0xf0, 0xee, 0x90, 0x99, 0x0b, 0x08, 0x50, 0x8b, 0x43,
+	0xb0, 0x16, 0x2b, 0x9e, 0x86, 0x1d, 0xb1, 0x1b, 0xd9, 0xb5, 0x92, 0xa1, 0x5e, 0xee, 0x1a, 0x2b,
+	0xe7, 0x50, 0x7c, 0x98, 0xbf, 0xcc, 0xc1, 0x0a, 0xbd, 0x75, 0x89, 0x7b, 0xbe, 0x1f, 0xdf, 0xf3,
+	0x22, 0x6b, 0x17, 0x52, 0x51, 0x76, 0x29, 0xbe, 0x79, 0x8c, 0x39, 0x1f, 0x53, 0xbe, 0x24, 0x37,
+	0x71, 0x3e, 0x16, 0x89, 0x12, 0xaf, 0x25, 0xe3, 0x84, 0x55, 0xcc, 0xd3, 0x66, 0xaa, 0x31, 0x54,
+	0xa0, 0xcd, 0xdd, 0x93, 0xf9, 0x23, 0xde, 0x41, 0x8b, 0xdf, 0x79, 0xd0, 0x1d, 0x26, 0xb1, 0x30,
+	0xfc, 0x93, 0x43, 0x06, 0x71, 0xaa, 0xcb, 0x3f, 0x31, 0x87, 0xab, 0xd9, 0xfd, 0xbe, 0xc3, 0x97,
+	0xdb, 0xa3, 0x07, 0x58, 0x52, 0xab, 0x00, 0x92, 0x86, 0x9a, 0x7f, 0xca, 0x41, 0x7d, 0xcf, 0xf3,
+	0x2f, 0x3f, 0x74, 0x46, 0x4c, 0x8b, 0x6e, 0x42, 0x48, 0x99, 0xe9, 0xf2, 0x6f, 0x5e, 0xbd, 0x9d,
+	0x22, 0x0a, 0x1d, 0x7b, 0xf2, 0xba, 0x22, 0x07, 0x88, 0x23, 0xaf, 0x26, 0xe3, 0x0e, 0x7f, 0x95,
+	0x26, 0x8f, 0x79, 0x63, 0x1f, 0x7d, 0xb1, 0xef, 0x04, 0x9d, 0xb8, 0x9f, 0x8f, 0xbe, 0x88, 0x63,
+	0x31, 0x25, 0x37, 0xb2, 0x2c, 0xde, 0x6b, 0xf4, 0x8d, 0xac, 0x10, 0x84, 0x6f, 0x04, 0x1d, 0xc0,
+	0x3b, 0x3d, 0x0d, 0x59, 0x24, 0x6c, 0x95, 0xb7, 0xe4, 0x28, 0x0e, 0xc1, 0x45, 0x2d, 0x04, 0xaf,
+	0x8b, 0x7b, 0xed, 0xe1, 0xc3, 0xe3, 0x83, 0x73, 0x3c, 0xe1, 0xea, 0x06, 0x7e, 0x03, 0x8a, 0x0a,
+	0xf4, 0x34, 0x2f, 0x21, 0xaf, 0x41, 0x0d, 0xcf, 0x63, 0x1b, 0xbb, 0xad, 0x4a, 0x1f, 0x68, 0x97,
+	0xd6, 0xde, 0x51, 0x8b, 0x54, 0x92, 0xe7, 0x1b, 0x90, 0x43, 0x7e, 0xe3, 0x23, 0xc3, 0x63, 0x86,
+	0xb6, 0xe9, 0xc5, 0x37, 0xfe, 0x8b, 0xb0, 0x2a, 0x21, 0x7c, 0xe5, 0x98, 0x3e, 0xd5, 0x15, 0x20,
+	0x87, 0xe6, 0x0f, 0xc0, 0xf8, 0x8c, 0xd7, 0x19, 0x8c, 0x8a, 0x4c, 0xc9, 0x09, 0x63, 0xe7, 0xb9,
+	0x80, 0x76, 0x28, 0x01, 0xd7, 0xcc, 0x50, 0xa7, 0x09, 0x11, 0x1f, 0x04, 0xef, 0x13, 0x58, 0xa3,
+	0xb2, 0x88, 0xe8, 0x5c, 0x81, 0x04, 0xd7, 0x61, 0x6c, 0xcf, 0x82, 0x25, 0xbe, 0xef, 0xfc, 0x6b,
+	0x4d, 0x5e, 0x63, 0xb2, 0xc9, 0x67, 0x3c, 0x40, 0x07, 0x49, 0xbf, 0x46, 0x1b, 0xb2, 0x2f, 0x9c,
+	0xfd, 0x48, 0xdd, 0xdc, 0xd8, 0xa2, 0xd7, 0xed, 0x2d, 0xf5, 0xba, 0xbd, 0x75, 0xc0, 0x5f, 0xb7,
+	0xb1, 0xa9, 0x57, 0x4b, 0xbf, 0xdb, 0x1a, 0xcf, 0xa9, 0x14, 0x2b, 0xe3, 0x35, 0x77, 0x2e, 0x19,
+	0x94, 0x67, 0xea, 0x09, 0x57, 0xc9, 0x93, 0xfd, 0xb2, 0x3b, 0x97, 0xd0, 0x07, 0x50, 0xd6, 0xde,
+	0x6c, 0x8d, 0x4d, 0x22, 0x32, 0xfb, 0x8c, 0x3b, 0x97, 0xc0, 0x1e, 0x54, 0x53, 0xcf, 0xa8, 0x46,
+	0x53, 0xee, 0x27, 0xe3, 0x6d, 0x75, 0x2e, 0x91, 0x5d, 0x28, 0x6b, 0xaf, 0x99, 0x4a, 0x8a, 0xd9,
+	0x27, 0xd3, 0xe6, 0xcd, 0x8c, 0x19, 0x79, 0x5b, 0xa2, 0x4a, 0xa6, 0x9e, 0x38, 0x95, 0x4a, 0xb2,
+	0x5f, 0x3e, 0xe7, 0x0a, 0xd3, 0x86, 0xeb, 0x99, 0x59, 0xb2, 0x61, 0xea, 0xe4, 0xb2, 0x53, 0xe8,
+	0xb9, 0x44, 0x3f, 0x16, 0x76, 0xd7, 0x7a, 0x37, 0x9a, 0xdd, 0x67, 0x5f, 0x49, 0x9b, 0xb7, 0xb2,
+	0x27, 0xe5, 0x56, 0xd1, 0x89, 0xd2, 0x0f, 0xa4, 0x8a, 0x58, 0xe6, 0xb3, 0xe9, 0x62, 0x27, 0x4a,
+	0xbd, 0x95, 0x26, 0x4e, 0x94, 0xf5, 0x84, 0x3a, 0x97, 0xd0, 0x7d, 0x00, 0xd9, 0xa9, 0xc1, 0x9e,
+	0x47, 0x6c, 0xbd, 0x99, 0x0e, 0x51, 0x6c, 0xbd, 0x8c, 0xae, 0xce, 0x07, 0x00, 0xd4, 0x60, 0xe9,
+	0x63, 0xe1, 0x60, 0xdc, 0x50, 0x62, 0x4c, 0x75, 0x75, 0x9a, 0x9b, 0xb3, 0x13, 0x33, 0x04, 0xb0,
+	0xbb, 0x76, 0x15, 0x02, 0xef, 0x03, 0x24, 0x8d, 0x1b, 0x45, 0x60, 0xa6, 0x95, 0xb3, 0x40, 0x07,
+	0x15, 0xbd, 0x4d, 0x63, 0xc8, 0xbd, 0x66, 0xb4, 0x6e, 0x16, 0x90, 0xa8, 0x4f, 0x55, 0x89, 0x69,
+	0x0f, 0x9e, 0x2e, 0x1e, 0x9b, 0x33, 0x95, 0x22, 0x66, 0x80, 0x15, 0xbd, 0x3c, 0x54, 0x52, 0x64,
+	0x94, 0x8c, 0xcd, 0x54, 0x89, 0x88, 0xea, 0xab, 0xa5, 0xeb, 0x39, 0xe5, 0x52, 0x99, 0x55, 0x5e,
+	0x53, 0x36, 0xa0, 0x35, 0xf4, 0xbb, 0x00, 0x49, 0xdd, 0xa7, 0xd4, 0x37, 0x53, 0x09, 0x4e, 0x71,
+	0x45, 0x0f, 0x9c, 0xaa, 0xe7, 0xd4, 0x8e, 0xb3, 0xcb, 0xbc, 0x45, 0x01, 0x44, 0xab, 0xce, 0x94,
+	0x0b, 0xce, 0xd6, 0x77, 0xca, 0x05, 0xb3, 0x4a, 0x39, 0xa4, 0xd1, 0x9e, 0xa5, 0xd1, 0x9e, 0x4b,
+	0x23, 0xab, 0x40, 0x7b, 0x0b, 0x20, 0xb9, 0x0b, 0x95, 0x16, 0x66, 0x6e, 0xc7, 0x66, 0x55, 0x3d,
+	0x12, 0x10, 0x1e, 0xc6, 0xd0, 0x54, 0x83, 0x4c, 0xc5, 0xd0, 0xac, 0xae, 0xd9, 0xa2, 0x9b, 0x25,
+	0xdd, 0xec, 0x50, 0x16, 0xcc, 0x6c, 0x81, 0xcc, 0x25, 0x73, 0x04, 0xd7, 0xa6, 0xbb, 0x34, 0xc6,
+	0xf3, 0xba, 0x17, 0xcd, 0x74, 0x6f, 0x16, 0x1d, 0x09, 0xbd, 0x32, 0x55, 0xce, 0x98, 0x51, 0xad,
+	0x3e, 0x21, 0x44, 0xe9, 0xd5, 0xa7, 0x16, 0xa2, 0x32, 0x8a, 0xd2, 0xb9, 0x84, 0x0e, 0xa1, 0xfe,
+	0x40, 0x15, 0x16, 0xb2, 0xe8, 0x51, 0xae, 0x30, 0x5b, 0xe4, 0x35, 0x9b, 0x59, 0x53, 0xd2, 0xc4,
+	0x1f, 0x43, 0x63, 0xa6, 0xe0, 0x31, 0x6e, 0xc7, 0xaf, 0x3e, 0x99, 0x95, 0xd0, 0x22, 0x6d, 0x4f,
+	0xd7, 0x3b, 0x4a, 0xdb, 0x73, 0xea, 0xa0, 0xb9, 0xa4, 0xde, 0x85, 0xa2, 0xca, 0x61, 0x0d, 0xf9,
+	0x2a, 0x37, 0x95, 0xd3, 0xce, 0x5d, 0xfa, 0xb6, 0x38, 0x3d, 0x71, 0x7e, 0x98, 0x9c, 0x9e, 0xa9,
+	0x2c, 0xb2, 0x29, 0x1f, 0xc3, 0x62, 0xcc, 0xb7, 0x61, 0x55, 0xa6, 0x89, 0xc6, 0x7a, 0x7c, 0x6e,
+	0xb5, 0xac, 0x71, 0x91, 0xb3, 0x22, 0x79, 0x2d, 0xf9, 0x53, 0x4c, 0x67, 0xf3, 0x41, 0x75, 0xdc,
+	0x52, 0x33, 0xd2, 0x16, 0xe8, 0x61, 0x7a, 0xfa, 0xa7, 0x4c, 0x9a, 0x91, 0x12, 0xce, 0x93, 0x64,
+	0xf7, 0xe2, 0xeb, 0xbf, 0xdf, 0xfe, 0xc6, 0x5f, 0xf0, 0xf7, 0xd5, 0x3f, 0x6e, 0xe7, 0xbe, 0xc6,
+	0xdf, 0x9f, 0xf1, 0xf7, 0x37, 0xfc, 0xfd, 0xf8, 0xa7, 0xff, 0xe5, 0xbf, 0x46, 0x06, 0x98, 0x07,
+	0xa0, 0x9d, 0xb6, 0xcf, 0x9d, 0x20, 0xd2, 0xa6, 0xfc, 0xb3, 0x01, 0xfd, 0x7f, 0xa4, 0xf6, 0x6f,
+	0x93, 0x5c, 0xca, 0xee, 0x8a, 0x18, 0xdf, 0xfd, 0x0f, 0x9a, 0xd3, 0xdb, 0x4b, 0x83, 0x29, 0x00,
 	0x00,
 }
 
@@ -3636,7 +3871,7 @@ func (m *ThrottlingData) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
-func (m *CpuStats) Marshal() (dAtA []byte, err error) {
+func (m *PSIData) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
 	n, err := m.MarshalToSizedBuffer(dAtA[:size])
@@ -3646,12 +3881,12 @@ func (m *CpuStats) Marshal() (dAtA []byte, err error) {
 	return dAtA[:n], nil
 }
 
-func (m *CpuStats) MarshalTo(dAtA []byte) (int, error) {
+func (m *PSIData) MarshalTo(dAtA []byte) (int, error) {
 	size := m.Size()
 	return m.MarshalToSizedBuffer(dAtA[:size])
 }
 
-func (m *CpuStats) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+func (m *PSIData) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	i := len(dAtA)
 	_ = i
 	var l int
@@ -3660,34 +3895,33 @@ func (m *CpuStats) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
-	if m.ThrottlingData != nil {
-		{
-			size, err := m.ThrottlingData.MarshalToSizedBuffer(dAtA[:i])
-			if err != nil {
-				return 0, err
-			}
-			i -= size
-			i = encodeVarintAgent(dAtA, i, uint64(size))
-		}
+	if m.Total != 0 {
+		i = encodeVarintAgent(dAtA, i, uint64(m.Total))
 		i--
-		dAtA[i] = 0x12
+		dAtA[i] = 0x20
 	}
-	if m.CpuUsage != nil {
-		{
-			size, err := m.CpuUsage.MarshalToSizedBuffer(dAtA[:i])
-			if err != nil {
-				return 0, err
-			}
-			i -= size
-			i = encodeVarintAgent(dAtA, i, uint64(size))
-		}
+	if m.Avg300 != 0 {
+		i -= 8
+		encoding_binary.LittleEndian.PutUint64(dAtA[i:], uint64(math.Float64bits(float64(m.Avg300))))
 		i--
-		dAtA[i] = 0xa
+		dAtA[i] = 0x19
+	}
+	if m.Avg60 != 0 {
+		i -= 8
+		encoding_binary.LittleEndian.PutUint64(dAtA[i:], uint64(math.Float64bits(float64(m.Avg60))))
+		i--
+		dAtA[i] = 0x11
+	}
+	if m.Avg10 != 0 {
+		i -= 8
+		encoding_binary.LittleEndian.PutUint64(dAtA[i:], uint64(math.Float64bits(float64(m.Avg10))))
+		i--
+		dAtA[i] = 0x9
 	}
 	return len(dAtA) - i, nil
 }
 
-func (m *PidsStats) Marshal() (dAtA []byte, err error) {
+func (m *PSIStats) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
 	n, err := m.MarshalToSizedBuffer(dAtA[:size])
@@ -3697,12 +3931,12 @@ func (m *PidsStats) Marshal() (dAtA []byte, err error) {
 	return dAtA[:n], nil
 }
 
-func (m *PidsStats) MarshalTo(dAtA []byte) (int, error) {
+func (m *PSIStats) MarshalTo(dAtA []byte) (int, error) {
 	size := m.Size()
 	return m.MarshalToSizedBuffer(dAtA[:size])
 }
 
-func (m *PidsStats) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+func (m *PSIStats) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	i := len(dAtA)
 	_ = i
 	var l int
@@ -3711,8 +3945,122 @@ func (m *PidsStats) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
-	if m.Limit != 0 {
-		i = encodeVarintAgent(dAtA, i, uint64(m.Limit))
+	if m.Full != nil {
+		{
+			size, err := m.Full.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintAgent(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x12
+	}
+	if m.Some != nil {
+		{
+			size, err := m.Some.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintAgent(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0xa
+	}
+	return len(dAtA) - i, nil
+}
+
+func (m *CpuStats) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *CpuStats) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *CpuStats) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.XXX_unrecognized != nil {
+		i -= len(m.XXX_unrecognized)
+		copy(dAtA[i:], m.XXX_unrecognized)
+	}
+	if m.Psi != nil {
+		{
+			size, err := m.Psi.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintAgent(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x1a
+	}
+	if m.ThrottlingData != nil {
+		{
+			size, err := m.ThrottlingData.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintAgent(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x12
+	}
+	if m.CpuUsage != nil {
+		{
+			size, err := m.CpuUsage.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintAgent(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0xa
+	}
+	return len(dAtA) - i, nil
+}
+
+func (m *PidsStats) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *PidsStats) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *PidsStats) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.XXX_unrecognized != nil {
+		i -= len(m.XXX_unrecognized)
+		copy(dAtA[i:], m.XXX_unrecognized)
+	}
+	if m.Limit != 0 {
+		i = encodeVarintAgent(dAtA, i, uint64(m.Limit))
 		i--
 		dAtA[i] = 0x10
 	}
@@ -3795,6 +4143,18 @@ func (m *MemoryStats) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if m.Psi != nil {
+		{
+			size, err := m.Psi.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintAgent(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x3a
+	}
 	if len(m.Stats) > 0 {
 		for k := range m.Stats {
 			v := m.Stats[k]
@@ -3939,6 +4299,18 @@ func (m *BlkioStats) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if m.Psi != nil {
+		{
+			size, err := m.Psi.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintAgent(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x4a
+	}
 	if len(m.SectorsRecursive) > 0 {
 		for iNdEx := len(m.SectorsRecursive) - 1; iNdEx >= 0; iNdEx-- {
 			{
@@ -4078,6 +4450,11 @@ func (m *HugetlbStats) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if m.Limit != 0 {
+		i = encodeVarintAgent(dAtA, i, uint64(m.Limit))
+		i--
+		dAtA[i] = 0x20
+	}
 	if m.Failcnt != 0 {
 		i = encodeVarintAgent(dAtA, i, uint64(m.Failcnt))
 		i--
@@ -4120,6 +4497,11 @@ func (m *CgroupStats) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if m.CgroupVersion != 0 {
+		i = encodeVarintAgent(dAtA, i, uint64(m.CgroupVersion))
+		i--
+		dAtA[i] = 0x30
+	}
 	if len(m.HugetlbStats) > 0 {
 		for k := range m.HugetlbStats {
 			v := m.HugetlbStats[k]
@@ -4643,6 +5025,32 @@ func (m *CreateSandboxRequest) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if len(m.Hosts) > 0 {
+		for iNdEx := len(m.Hosts) - 1; iNdEx >= 0; iNdEx-- {
+			{
+				size, err := m.Hosts[iNdEx].MarshalToSizedBuffer(dAtA[:i])
+				if err != nil {
+					return 0, err
+				}
+				i -= size
+				i = encodeVarintAgent(dAtA, i, uint64(size))
+			}
+			i--
+			dAtA[i] = 0x4a
+		}
+	}
+	if m.DnsConfig != nil {
+		{
+			size, err := m.DnsConfig.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintAgent(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x42
+	}
 	if len(m.KernelModules) > 0 {
 		for iNdEx := len(m.KernelModules) - 1; iNdEx >= 0; iNdEx-- {
 			{
@@ -4714,7 +5122,7 @@ func (m *CreateSandboxRequest) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
-func (m *DestroySandboxRequest) Marshal() (dAtA []byte, err error) {
+func (m *DNSConfig) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
 	n, err := m.MarshalToSizedBuffer(dAtA[:size])
@@ -4724,12 +5132,12 @@ func (m *DestroySandboxRequest) Marshal() (dAtA []byte, err error) {
 	return dAtA[:n], nil
 }
 
-func (m *DestroySandboxRequest) MarshalTo(dAtA []byte) (int, error) {
+func (m *DNSConfig) MarshalTo(dAtA []byte) (int, error) {
 	size := m.Size()
 	return m.MarshalToSizedBuffer(dAtA[:size])
 }
 
-func (m *DestroySandboxRequest) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+func (m *DNSConfig) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	i := len(dAtA)
 	_ = i
 	var l int
@@ -4738,10 +5146,37 @@ func (m *DestroySandboxRequest) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if len(m.Options) > 0 {
+		for iNdEx := len(m.Options) - 1; iNdEx >= 0; iNdEx-- {
+			i -= len(m.Options[iNdEx])
+			copy(dAtA[i:], m.Options[iNdEx])
+			i = encodeVarintAgent(dAtA, i, uint64(len(m.Options[iNdEx])))
+			i--
+			dAtA[i] = 0x1a
+		}
+	}
+	if len(m.Searches) > 0 {
+		for iNdEx := len(m.Searches) - 1; iNdEx >= 0; iNdEx-- {
+			i -= len(m.Searches[iNdEx])
+			copy(dAtA[i:], m.Searches[iNdEx])
+			i = encodeVarintAgent(dAtA, i, uint64(len(m.Searches[iNdEx])))
+			i--
+			dAtA[i] = 0x12
+		}
+	}
+	if len(m.Nameservers) > 0 {
+		for iNdEx := len(m.Nameservers) - 1; iNdEx >= 0; iNdEx-- {
+			i -= len(m.Nameservers[iNdEx])
+			copy(dAtA[i:], m.Nameservers[iNdEx])
+			i = encodeVarintAgent(dAtA, i, uint64(len(m.Nameservers[iNdEx])))
+			i--
+			dAtA[i] = 0xa
+		}
+	}
 	return len(dAtA) - i, nil
 }
 
-func (m *Interfaces) Marshal() (dAtA []byte, err error) {
+func (m *HostEntry) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
 	n, err := m.MarshalToSizedBuffer(dAtA[:size])
@@ -4751,12 +5186,12 @@ func (m *Interfaces) Marshal() (dAtA []byte, err error) {
 	return dAtA[:n], nil
 }
 
-func (m *Interfaces) MarshalTo(dAtA []byte) (int, error) {
+func (m *HostEntry) MarshalTo(dAtA []byte) (int, error) {
 	size := m.Size()
 	return m.MarshalToSizedBuffer(dAtA[:size])
 }
 
-func (m *Interfaces) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+func (m *HostEntry) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	i := len(dAtA)
 	_ = i
 	var l int
@@ -4765,24 +5200,26 @@ func (m *Interfaces) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
-	if len(m.Interfaces) > 0 {
-		for iNdEx := len(m.Interfaces) - 1; iNdEx >= 0; iNdEx-- {
-			{
-				size, err := m.Interfaces[iNdEx].MarshalToSizedBuffer(dAtA[:i])
-				if err != nil {
-					return 0, err
-				}
-				i -= size
-				i = encodeVarintAgent(dAtA, i, uint64(size))
-			}
+	if len(m.Names) > 0 {
+		for iNdEx := len(m.Names) - 1; iNdEx >= 0; iNdEx-- {
+			i -= len(m.Names[iNdEx])
+			copy(dAtA[i:], m.Names[iNdEx])
+			i = encodeVarintAgent(dAtA, i, uint64(len(m.Names[iNdEx])))
 			i--
-			dAtA[i] = 0xa
+			dAtA[i] = 0x12
 		}
 	}
+	if len(m.Ip) > 0 {
+		i -= len(m.Ip)
+		copy(dAtA[i:], m.Ip)
+		i = encodeVarintAgent(dAtA, i, uint64(len(m.Ip)))
+		i--
+		dAtA[i] = 0xa
+	}
 	return len(dAtA) - i, nil
 }
 
-func (m *Routes) Marshal() (dAtA []byte, err error) {
+func (m *UpdateSandboxDNSRequest) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
 	n, err := m.MarshalToSizedBuffer(dAtA[:size])
@@ -4792,12 +5229,12 @@ func (m *Routes) Marshal() (dAtA []byte, err error) {
 	return dAtA[:n], nil
 }
 
-func (m *Routes) MarshalTo(dAtA []byte) (int, error) {
+func (m *UpdateSandboxDNSRequest) MarshalTo(dAtA []byte) (int, error) {
 	size := m.Size()
 	return m.MarshalToSizedBuffer(dAtA[:size])
 }
 
-func (m *Routes) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+func (m *UpdateSandboxDNSRequest) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	i := len(dAtA)
 	_ = i
 	var l int
@@ -4806,10 +5243,10 @@ func (m *Routes) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
-	if len(m.Routes) > 0 {
-		for iNdEx := len(m.Routes) - 1; iNdEx >= 0; iNdEx-- {
+	if len(m.Hosts) > 0 {
+		for iNdEx := len(m.Hosts) - 1; iNdEx >= 0; iNdEx-- {
 			{
-				size, err := m.Routes[iNdEx].MarshalToSizedBuffer(dAtA[:i])
+				size, err := m.Hosts[iNdEx].MarshalToSizedBuffer(dAtA[:i])
 				if err != nil {
 					return 0, err
 				}
@@ -4817,39 +5254,12 @@ func (m *Routes) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 				i = encodeVarintAgent(dAtA, i, uint64(size))
 			}
 			i--
-			dAtA[i] = 0xa
+			dAtA[i] = 0x12
 		}
 	}
-	return len(dAtA) - i, nil
-}
-
-func (m *UpdateInterfaceRequest) Marshal() (dAtA []byte, err error) {
-	size := m.Size()
-	dAtA = make([]byte, size)
-	n, err := m.MarshalToSizedBuffer(dAtA[:size])
-	if err != nil {
-		return nil, err
-	}
-	return dAtA[:n], nil
-}
-
-func (m *UpdateInterfaceRequest) MarshalTo(dAtA []byte) (int, error) {
-	size := m.Size()
-	return m.MarshalToSizedBuffer(dAtA[:size])
-}
-
-func (m *UpdateInterfaceRequest) MarshalToSizedBuffer(dAtA []byte) (int, error) {
-	i := len(dAtA)
-	_ = i
-	var l int
-	_ = l
-	if m.XXX_unrecognized != nil {
-		i -= len(m.XXX_unrecognized)
-		copy(dAtA[i:], m.XXX_unrecognized)
-	}
-	if m.Interface != nil {
+	if m.DnsConfig != nil {
 		{
-			size, err := m.Interface.MarshalToSizedBuffer(dAtA[:i])
+			size, err := m.DnsConfig.MarshalToSizedBuffer(dAtA[:i])
 			if err != nil {
 				return 0, err
 			}
@@ -4862,7 +5272,7 @@ func (m *UpdateInterfaceRequest) MarshalToSizedBuffer(dAtA []byte) (int, error)
 	return len(dAtA) - i, nil
 }
 
-func (m *UpdateRoutesRequest) Marshal() (dAtA []byte, err error) {
+func (m *DestroySandboxRequest) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
 	n, err := m.MarshalToSizedBuffer(dAtA[:size])
@@ -4872,12 +5282,12 @@ func (m *UpdateRoutesRequest) Marshal() (dAtA []byte, err error) {
 	return dAtA[:n], nil
 }
 
-func (m *UpdateRoutesRequest) MarshalTo(dAtA []byte) (int, error) {
+func (m *DestroySandboxRequest) MarshalTo(dAtA []byte) (int, error) {
 	size := m.Size()
 	return m.MarshalToSizedBuffer(dAtA[:size])
 }
 
-func (m *UpdateRoutesRequest) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+func (m *DestroySandboxRequest) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	i := len(dAtA)
 	_ = i
 	var l int
@@ -4886,22 +5296,10 @@ func (m *UpdateRoutesRequest) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
-	if m.Routes != nil {
-		{
-			size, err := m.Routes.MarshalToSizedBuffer(dAtA[:i])
-			if err != nil {
-				return 0, err
-			}
-			i -= size
-			i = encodeVarintAgent(dAtA, i, uint64(size))
-		}
-		i--
-		dAtA[i] = 0xa
-	}
 	return len(dAtA) - i, nil
 }
 
-func (m *UpdateEphemeralMountsRequest) Marshal() (dAtA []byte, err error) {
+func (m *Interfaces) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
 	n, err := m.MarshalToSizedBuffer(dAtA[:size])
@@ -4911,12 +5309,172 @@ func (m *UpdateEphemeralMountsRequest) Marshal() (dAtA []byte, err error) {
 	return dAtA[:n], nil
 }
 
-func (m *UpdateEphemeralMountsRequest) MarshalTo(dAtA []byte) (int, error) {
+func (m *Interfaces) MarshalTo(dAtA []byte) (int, error) {
 	size := m.Size()
 	return m.MarshalToSizedBuffer(dAtA[:size])
 }
 
-func (m *UpdateEphemeralMountsRequest) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+func (m *Interfaces) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.XXX_unrecognized != nil {
+		i -= len(m.XXX_unrecognized)
+		copy(dAtA[i:], m.XXX_unrecognized)
+	}
+	if len(m.Interfaces) > 0 {
+		for iNdEx := len(m.Interfaces) - 1; iNdEx >= 0; iNdEx-- {
+			{
+				size, err := m.Interfaces[iNdEx].MarshalToSizedBuffer(dAtA[:i])
+				if err != nil {
+					return 0, err
+				}
+				i -= size
+				i = encodeVarintAgent(dAtA, i, uint64(size))
+			}
+			i--
+			dAtA[i] = 0xa
+		}
+	}
+	return len(dAtA) - i, nil
+}
+
+func (m *Routes) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *Routes) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *Routes) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.XXX_unrecognized != nil {
+		i -= len(m.XXX_unrecognized)
+		copy(dAtA[i:], m.XXX_unrecognized)
+	}
+	if len(m.Routes) > 0 {
+		for iNdEx := len(m.Routes) - 1; iNdEx >= 0; iNdEx-- {
+			{
+				size, err := m.Routes[iNdEx].MarshalToSizedBuffer(dAtA[:i])
+				if err != nil {
+					return 0, err
+				}
+				i -= size
+				i = encodeVarintAgent(dAtA, i, uint64(size))
+			}
+			i--
+			dAtA[i] = 0xa
+		}
+	}
+	return len(dAtA) - i, nil
+}
+
+func (m *UpdateInterfaceRequest) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *UpdateInterfaceRequest) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *UpdateInterfaceRequest) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.XXX_unrecognized != nil {
+		i -= len(m.XXX_unrecognized)
+		copy(dAtA[i:], m.XXX_unrecognized)
+	}
+	if m.Interface != nil {
+		{
+			size, err := m.Interface.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintAgent(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0xa
+	}
+	return len(dAtA) - i, nil
+}
+
+func (m *UpdateRoutesRequest) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *UpdateRoutesRequest) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *UpdateRoutesRequest) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.XXX_unrecognized != nil {
+		i -= len(m.XXX_unrecognized)
+		copy(dAtA[i:], m.XXX_unrecognized)
+	}
+	if m.Routes != nil {
+		{
+			size, err := m.Routes.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintAgent(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0xa
+	}
+	return len(dAtA) - i, nil
+}
+
+func (m *UpdateEphemeralMountsRequest) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *UpdateEphemeralMountsRequest) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *UpdateEphemeralMountsRequest) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	i := len(dAtA)
 	_ = i
 	var l int
@@ -6405,6 +6963,50 @@ func (m *ThrottlingData) Size() (n int) {
 	return n
 }
 
+func (m *PSIData) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	if m.Avg10 != 0 {
+		n += 9
+	}
+	if m.Avg60 != 0 {
+		n += 9
+	}
+	if m.Avg300 != 0 {
+		n += 9
+	}
+	if m.Total != 0 {
+		n += 1 + sovAgent(uint64(m.Total))
+	}
+	if m.XXX_unrecognized != nil {
+		n += len(m.XXX_unrecognized)
+	}
+	return n
+}
+
+func (m *PSIStats) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	if m.Some != nil {
+		l = m.Some.Size()
+		n += 1 + l + sovAgent(uint64(l))
+	}
+	if m.Full != nil {
+		l = m.Full.Size()
+		n += 1 + l + sovAgent(uint64(l))
+	}
+	if m.XXX_unrecognized != nil {
+		n += len(m.XXX_unrecognized)
+	}
+	return n
+}
+
 func (m *CpuStats) Size() (n int) {
 	if m == nil {
 		return 0
@@ -6419,6 +7021,10 @@ func (m *CpuStats) Size() (n int) {
 		l = m.ThrottlingData.Size()
 		n += 1 + l + sovAgent(uint64(l))
 	}
+	if m.Psi != nil {
+		l = m.Psi.Size()
+		n += 1 + l + sovAgent(uint64(l))
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -6499,6 +7105,10 @@ func (m *MemoryStats) Size() (n int) {
 			n += mapEntrySize + 1 + sovAgent(uint64(mapEntrySize))
 		}
 	}
+	if m.Psi != nil {
+		l = m.Psi.Size()
+		n += 1 + l + sovAgent(uint64(l))
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -6584,6 +7194,10 @@ func (m *BlkioStats) Size() (n int) {
 			n += 1 + l + sovAgent(uint64(l))
 		}
 	}
+	if m.Psi != nil {
+		l = m.Psi.Size()
+		n += 1 + l + sovAgent(uint64(l))
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -6605,6 +7219,9 @@ func (m *HugetlbStats) Size() (n int) {
 	if m.Failcnt != 0 {
 		n += 1 + sovAgent(uint64(m.Failcnt))
 	}
+	if m.Limit != 0 {
+		n += 1 + sovAgent(uint64(m.Limit))
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -6646,6 +7263,9 @@ func (m *CgroupStats) Size() (n int) {
 			n += mapEntrySize + 1 + sovAgent(uint64(mapEntrySize))
 		}
 	}
+	if m.CgroupVersion != 0 {
+		n += 1 + sovAgent(uint64(m.CgroupVersion))
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -6899,33 +7519,65 @@ func (m *CreateSandboxRequest) Size() (n int) {
 			n += 1 + l + sovAgent(uint64(l))
 		}
 	}
+	if m.DnsConfig != nil {
+		l = m.DnsConfig.Size()
+		n += 1 + l + sovAgent(uint64(l))
+	}
+	if len(m.Hosts) > 0 {
+		for _, e := range m.Hosts {
+			l = e.Size()
+			n += 1 + l + sovAgent(uint64(l))
+		}
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
 	return n
 }
 
-func (m *DestroySandboxRequest) Size() (n int) {
+func (m *DNSConfig) Size() (n int) {
 	if m == nil {
 		return 0
 	}
 	var l int
 	_ = l
+	if len(m.Nameservers) > 0 {
+		for _, s := range m.Nameservers {
+			l = len(s)
+			n += 1 + l + sovAgent(uint64(l))
+		}
+	}
+	if len(m.Searches) > 0 {
+		for _, s := range m.Searches {
+			l = len(s)
+			n += 1 + l + sovAgent(uint64(l))
+		}
+	}
+	if len(m.Options) > 0 {
+		for _, s := range m.Options {
+			l = len(s)
+			n += 1 + l + sovAgent(uint64(l))
+		}
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
 	return n
 }
 
-func (m *Interfaces) Size() (n int) {
+func (m *HostEntry) Size() (n int) {
 	if m == nil {
 		return 0
 	}
 	var l int
 	_ = l
-	if len(m.Interfaces) > 0 {
-		for _, e := range m.Interfaces {
-			l = e.Size()
+	l = len(m.Ip)
+	if l > 0 {
+		n += 1 + l + sovAgent(uint64(l))
+	}
+	if len(m.Names) > 0 {
+		for _, s := range m.Names {
+			l = len(s)
 			n += 1 + l + sovAgent(uint64(l))
 		}
 	}
@@ -6935,14 +7587,18 @@ func (m *Interfaces) Size() (n int) {
 	return n
 }
 
-func (m *Routes) Size() (n int) {
+func (m *UpdateSandboxDNSRequest) Size() (n int) {
 	if m == nil {
 		return 0
 	}
 	var l int
 	_ = l
-	if len(m.Routes) > 0 {
-		for _, e := range m.Routes {
+	if m.DnsConfig != nil {
+		l = m.DnsConfig.Size()
+		n += 1 + l + sovAgent(uint64(l))
+	}
+	if len(m.Hosts) > 0 {
+		for _, e := range m.Hosts {
 			l = e.Size()
 			n += 1 + l + sovAgent(uint64(l))
 		}
@@ -6953,31 +7609,29 @@ func (m *Routes) Size() (n int) {
 	return n
 }
 
-func (m *UpdateInterfaceRequest) Size() (n int) {
+func (m *DestroySandboxRequest) Size() (n int) {
 	if m == nil {
 		return 0
 	}
 	var l int
 	_ = l
-	if m.Interface != nil {
-		l = m.Interface.Size()
-		n += 1 + l + sovAgent(uint64(l))
-	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
 	return n
 }
 
-func (m *UpdateRoutesRequest) Size() (n int) {
+func (m *Interfaces) Size() (n int) {
 	if m == nil {
 		return 0
 	}
 	var l int
 	_ = l
-	if m.Routes != nil {
-		l = m.Routes.Size()
-		n += 1 + l + sovAgent(uint64(l))
+	if len(m.Interfaces) > 0 {
+		for _, e := range m.Interfaces {
+			l = e.Size()
+			n += 1 + l + sovAgent(uint64(l))
+		}
 	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
@@ -6985,14 +7639,64 @@ func (m *UpdateRoutesRequest) Size() (n int) {
 	return n
 }
 
-func (m *UpdateEphemeralMountsRequest) Size() (n int) {
+func (m *Routes) Size() (n int) {
 	if m == nil {
 		return 0
 	}
 	var l int
 	_ = l
-	if len(m.Storages) > 0 {
-		for _, e := range m.Storages {
+	if len(m.Routes) > 0 {
+		for _, e := range m.Routes {
+			l = e.Size()
+			n += 1 + l + sovAgent(uint64(l))
+		}
+	}
+	if m.XXX_unrecognized != nil {
+		n += len(m.XXX_unrecognized)
+	}
+	return n
+}
+
+func (m *UpdateInterfaceRequest) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	if m.Interface != nil {
+		l = m.Interface.Size()
+		n += 1 + l + sovAgent(uint64(l))
+	}
+	if m.XXX_unrecognized != nil {
+		n += len(m.XXX_unrecognized)
+	}
+	return n
+}
+
+func (m *UpdateRoutesRequest) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	if m.Routes != nil {
+		l = m.Routes.Size()
+		n += 1 + l + sovAgent(uint64(l))
+	}
+	if m.XXX_unrecognized != nil {
+		n += len(m.XXX_unrecognized)
+	}
+	return n
+}
+
+func (m *UpdateEphemeralMountsRequest) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	if len(m.Storages) > 0 {
+		for _, e := range m.Storages {
 			l = e.Size()
 			n += 1 + l + sovAgent(uint64(l))
 		}
@@ -7723,6 +8427,32 @@ func (this *ThrottlingData) String() string {
 	}, "")
 	return s
 }
+func (this *PSIData) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&PSIData{`,
+		`Avg10:` + fmt.Sprintf("%v", this.Avg10) + `,`,
+		`Avg60:` + fmt.Sprintf("%v", this.Avg60) + `,`,
+		`Avg300:` + fmt.Sprintf("%v", this.Avg300) + `,`,
+		`Total:` + fmt.Sprintf("%v", this.Total) + `,`,
+		`XXX_unrecognized:` + fmt.Sprintf("%v", this.XXX_unrecognized) + `,`,
+		`}`,
+	}, "")
+	return s
+}
+func (this *PSIStats) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&PSIStats{`,
+		`Some:` + strings.Replace(this.Some.String(), "PSIData", "PSIData", 1) + `,`,
+		`Full:` + strings.Replace(this.Full.String(), "PSIData", "PSIData", 1) + `,`,
+		`XXX_unrecognized:` + fmt.Sprintf("%v", this.XXX_unrecognized) + `,`,
+		`}`,
+	}, "")
+	return s
+}
 func (this *CpuStats) String() string {
 	if this == nil {
 		return "nil"
@@ -7730,6 +8460,7 @@ func (this *CpuStats) String() string {
 	s := strings.Join([]string{`&CpuStats{`,
 		`CpuUsage:` + strings.Replace(this.CpuUsage.String(), "CpuUsage", "CpuUsage", 1) + `,`,
 		`ThrottlingData:` + strings.Replace(this.ThrottlingData.String(), "ThrottlingData", "ThrottlingData", 1) + `,`,
+		`Psi:` + strings.Replace(this.Psi.String(), "PSIStats", "PSIStats", 1) + `,`,
 		`XXX_unrecognized:` + fmt.Sprintf("%v", this.XXX_unrecognized) + `,`,
 		`}`,
 	}, "")
@@ -7782,6 +8513,7 @@ func (this *MemoryStats) String() string {
 		`KernelUsage:` + strings.Replace(this.KernelUsage.String(), "MemoryData", "MemoryData", 1) + `,`,
 		`UseHierarchy:` + fmt.Sprintf("%v", this.UseHierarchy) + `,`,
 		`Stats:` + mapStringForStats + `,`,
+		`Psi:` + strings.Replace(this.Psi.String(), "PSIStats", "PSIStats", 1) + `,`,
 		`XXX_unrecognized:` + fmt.Sprintf("%v", this.XXX_unrecognized) + `,`,
 		`}`,
 	}, "")
@@ -7854,6 +8586,7 @@ func (this *BlkioStats) String() string {
 		`IoMergedRecursive:` + repeatedStringForIoMergedRecursive + `,`,
 		`IoTimeRecursive:` + repeatedStringForIoTimeRecursive + `,`,
 		`SectorsRecursive:` + repeatedStringForSectorsRecursive + `,`,
+		`Psi:` + strings.Replace(this.Psi.String(), "PSIStats", "PSIStats", 1) + `,`,
 		`XXX_unrecognized:` + fmt.Sprintf("%v", this.XXX_unrecognized) + `,`,
 		`}`,
 	}, "")
@@ -7867,6 +8600,7 @@ func (this *HugetlbStats) String() string {
 		`Usage:` + fmt.Sprintf("%v", this.Usage) + `,`,
 		`MaxUsage:` + fmt.Sprintf("%v", this.MaxUsage) + `,`,
 		`Failcnt:` + fmt.Sprintf("%v", this.Failcnt) + `,`,
+		`Limit:` + fmt.Sprintf("%v", this.Limit) + `,`,
 		`XXX_unrecognized:` + fmt.Sprintf("%v", this.XXX_unrecognized) + `,`,
 		`}`,
 	}, "")
@@ -7892,6 +8626,7 @@ func (this *CgroupStats) String() string {
 		`PidsStats:` + strings.Replace(this.PidsStats.String(), "PidsStats", "PidsStats", 1) + `,`,
 		`BlkioStats:` + strings.Replace(this.BlkioStats.String(), "BlkioStats", "BlkioStats", 1) + `,`,
 		`HugetlbStats:` + mapStringForHugetlbStats + `,`,
+		`CgroupVersion:` + fmt.Sprintf("%v", this.CgroupVersion) + `,`,
 		`XXX_unrecognized:` + fmt.Sprintf("%v", this.XXX_unrecognized) + `,`,
 		`}`,
 	}, "")
@@ -8033,6 +8768,11 @@ func (this *CreateSandboxRequest) String() string {
 		repeatedStringForKernelModules += strings.Replace(f.String(), "KernelModule", "KernelModule", 1) + ","
 	}
 	repeatedStringForKernelModules += "}"
+	repeatedStringForHosts := "[]*HostEntry{"
+	for _, f := range this.Hosts {
+		repeatedStringForHosts += strings.Replace(f.String(), "HostEntry", "HostEntry", 1) + ","
+	}
+	repeatedStringForHosts += "}"
 	s := strings.Join([]string{`&CreateSandboxRequest{`,
 		`Hostname:` + fmt.Sprintf("%v", this.Hostname) + `,`,
 		`Dns:` + fmt.Sprintf("%v", this.Dns) + `,`,
@@ -8041,6 +8781,50 @@ func (this *CreateSandboxRequest) String() string {
 		`SandboxId:` + fmt.Sprintf("%v", this.SandboxId) + `,`,
 		`GuestHookPath:` + fmt.Sprintf("%v", this.GuestHookPath) + `,`,
 		`KernelModules:` + repeatedStringForKernelModules + `,`,
+		`DnsConfig:` + strings.Replace(this.DnsConfig.String(), "DNSConfig", "DNSConfig", 1) + `,`,
+		`Hosts:` + repeatedStringForHosts + `,`,
+		`XXX_unrecognized:` + fmt.Sprintf("%v", this.XXX_unrecognized) + `,`,
+		`}`,
+	}, "")
+	return s
+}
+func (this *DNSConfig) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&DNSConfig{`,
+		`Nameservers:` + fmt.Sprintf("%v", this.Nameservers) + `,`,
+		`Searches:` + fmt.Sprintf("%v", this.Searches) + `,`,
+		`Options:` + fmt.Sprintf("%v", this.Options) + `,`,
+		`XXX_unrecognized:` + fmt.Sprintf("%v", this.XXX_unrecognized) + `,`,
+		`}`,
+	}, "")
+	return s
+}
+func (this *HostEntry) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&HostEntry{`,
+		`Ip:` + fmt.Sprintf("%v", this.Ip) + `,`,
+		`Names:` + fmt.Sprintf("%v", this.Names) + `,`,
+		`XXX_unrecognized:` + fmt.Sprintf("%v", this.XXX_unrecognized) + `,`,
+		`}`,
+	}, "")
+	return s
+}
+func (this *UpdateSandboxDNSRequest) String() string {
+	if this == nil {
+		return "nil"
+	}
+	repeatedStringForHosts := "[]*HostEntry{"
+	for _, f := range this.Hosts {
+		repeatedStringForHosts += strings.Replace(f.String(), "HostEntry", "HostEntry", 1) + ","
+	}
+	repeatedStringForHosts += "}"
+	s := strings.Join([]string{`&UpdateSandboxDNSRequest{`,
+		`DnsConfig:` + strings.Replace(this.DnsConfig.String(), "DNSConfig", "DNSConfig", 1) + `,`,
+		`Hosts:` + repeatedStringForHosts + `,`,
 		`XXX_unrecognized:` + fmt.Sprintf("%v", this.XXX_unrecognized) + `,`,
 		`}`,
 	}, "")
@@ -8492,6 +9276,7 @@ type AgentServiceService interface {
 	GetMetrics(ctx context.Context, req *GetMetricsRequest) (*Metrics, error)
 	CreateSandbox(ctx context.Context, req *CreateSandboxRequest) (*types.Empty, error)
 	DestroySandbox(ctx context.Context, req *DestroySandboxRequest) (*types.Empty, error)
+	UpdateSandboxDNS(ctx context.Context, req *UpdateSandboxDNSRequest) (*types.Empty, error)
 	OnlineCPUMem(ctx context.Context, req *OnlineCPUMemRequest) (*types.Empty, error)
 	ReseedRandomDev(ctx context.Context, req *ReseedRandomDevRequest) (*types.Empty, error)
 	GetGuestDetails(ctx context.Context, req *GuestDetailsRequest) (*GuestDetailsResponse, error)
@@ -8688,6 +9473,13 @@ func RegisterAgentServiceService(srv *github_com_containerd_ttrpc.Server, svc Ag
 			}
 			return svc.DestroySandbox(ctx, &req)
 		},
+		"UpdateSandboxDNS": func(ctx context.Context, unmarshal func(interface{}) error) (interface{}, error) {
+			var req UpdateSandboxDNSRequest
+			if err := unmarshal(&req); err != nil {
+				return nil, err
+			}
+			return svc.UpdateSandboxDNS(ctx, &req)
+		},
 		"OnlineCPUMem": func(ctx context.Context, unmarshal func(interface{}) error) (interface{}, error) {
 			var req OnlineCPUMemRequest
 			if err := unmarshal(&req); err != nil {
@@ -8979,6 +9771,14 @@ func (c *agentServiceClient) DestroySandbox(ctx context.Context, req *DestroySan
 	return &resp, nil
 }
 
+func (c *agentServiceClient) UpdateSandboxDNS(ctx context.Context, req *UpdateSandboxDNSRequest) (*types.Empty, error) {
+	var resp types.Empty
+	if err := c.client.Call(ctx, "grpc.AgentService", "UpdateSandboxDNS", req, &resp); err != nil {
+		return nil, err
+	}
+	return &resp, nil
+}
+
 func (c *agentServiceClient) OnlineCPUMem(ctx context.Context, req *OnlineCPUMemRequest) (*types.Empty, error) {
 	var resp types.Empty
 	if err := c.client.Call(ctx, "grpc.AgentService", "OnlineCPUMem", req, &resp); err != nil {
@@ -10684,7 +11484,7 @@ func (m *ThrottlingData) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *CpuStats) Unmarshal(dAtA []byte) error {
+func (m *PSIData) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -10707,53 +11507,50 @@ func (m *CpuStats) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: CpuStats: wiretype end group for non-group")
+			return fmt.Errorf("proto: PSIData: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: CpuStats: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: PSIData: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field CpuUsage", wireType)
-			}
-			var msglen int
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowAgent
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				msglen |= int(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
+			if wireType != 1 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Avg10", wireType)
 			}
-			if msglen < 0 {
-				return ErrInvalidLengthAgent
+			var v uint64
+			if (iNdEx + 8) > l {
+				return io.ErrUnexpectedEOF
 			}
-			postIndex := iNdEx + msglen
-			if postIndex < 0 {
-				return ErrInvalidLengthAgent
+			v = uint64(encoding_binary.LittleEndian.Uint64(dAtA[iNdEx:]))
+			iNdEx += 8
+			m.Avg10 = float64(math.Float64frombits(v))
+		case 2:
+			if wireType != 1 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Avg60", wireType)
 			}
-			if postIndex > l {
+			var v uint64
+			if (iNdEx + 8) > l {
 				return io.ErrUnexpectedEOF
 			}
-			if m.CpuUsage == nil {
-				m.CpuUsage = &CpuUsage{}
+			v = uint64(encoding_binary.LittleEndian.Uint64(dAtA[iNdEx:]))
+			iNdEx += 8
+			m.Avg60 = float64(math.Float64frombits(v))
+		case 3:
+			if wireType != 1 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Avg300", wireType)
 			}
-			if err := m.CpuUsage.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
+			var v uint64
+			if (iNdEx + 8) > l {
+				return io.ErrUnexpectedEOF
 			}
-			iNdEx = postIndex
-		case 2:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ThrottlingData", wireType)
+			v = uint64(encoding_binary.LittleEndian.Uint64(dAtA[iNdEx:]))
+			iNdEx += 8
+			m.Avg300 = float64(math.Float64frombits(v))
+		case 4:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Total", wireType)
 			}
-			var msglen int
+			m.Total = 0
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowAgent
@@ -10763,28 +11560,11 @@ func (m *CpuStats) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				msglen |= int(b&0x7F) << shift
+				m.Total |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			if msglen < 0 {
-				return ErrInvalidLengthAgent
-			}
-			postIndex := iNdEx + msglen
-			if postIndex < 0 {
-				return ErrInvalidLengthAgent
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			if m.ThrottlingData == nil {
-				m.ThrottlingData = &ThrottlingData{}
-			}
-			if err := m.ThrottlingData.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
-			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipAgent(dAtA[iNdEx:])
@@ -10807,7 +11587,7 @@ func (m *CpuStats) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *PidsStats) Unmarshal(dAtA []byte) error {
+func (m *PSIStats) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -10830,17 +11610,17 @@ func (m *PidsStats) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: PidsStats: wiretype end group for non-group")
+			return fmt.Errorf("proto: PSIStats: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: PidsStats: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: PSIStats: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Current", wireType)
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Some", wireType)
 			}
-			m.Current = 0
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowAgent
@@ -10850,16 +11630,33 @@ func (m *PidsStats) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				m.Current |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
+			if msglen < 0 {
+				return ErrInvalidLengthAgent
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Some == nil {
+				m.Some = &PSIData{}
+			}
+			if err := m.Some.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		case 2:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Limit", wireType)
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Full", wireType)
 			}
-			m.Limit = 0
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowAgent
@@ -10869,11 +11666,28 @@ func (m *PidsStats) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				m.Limit |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
+			if msglen < 0 {
+				return ErrInvalidLengthAgent
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Full == nil {
+				m.Full = &PSIData{}
+			}
+			if err := m.Full.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipAgent(dAtA[iNdEx:])
@@ -10896,7 +11710,7 @@ func (m *PidsStats) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *MemoryData) Unmarshal(dAtA []byte) error {
+func (m *CpuStats) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -10919,17 +11733,17 @@ func (m *MemoryData) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: MemoryData: wiretype end group for non-group")
+			return fmt.Errorf("proto: CpuStats: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: MemoryData: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: CpuStats: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Usage", wireType)
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field CpuUsage", wireType)
 			}
-			m.Usage = 0
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowAgent
@@ -10939,16 +11753,33 @@ func (m *MemoryData) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				m.Usage |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
+			if msglen < 0 {
+				return ErrInvalidLengthAgent
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.CpuUsage == nil {
+				m.CpuUsage = &CpuUsage{}
+			}
+			if err := m.CpuUsage.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		case 2:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field MaxUsage", wireType)
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ThrottlingData", wireType)
 			}
-			m.MaxUsage = 0
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowAgent
@@ -10958,16 +11789,247 @@ func (m *MemoryData) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				m.MaxUsage |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-		case 3:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Failcnt", wireType)
+			if msglen < 0 {
+				return ErrInvalidLengthAgent
 			}
-			m.Failcnt = 0
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.ThrottlingData == nil {
+				m.ThrottlingData = &ThrottlingData{}
+			}
+			if err := m.ThrottlingData.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Psi", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthAgent
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Psi == nil {
+				m.Psi = &PSIStats{}
+			}
+			if err := m.Psi.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipAgent(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.XXX_unrecognized = append(m.XXX_unrecognized, dAtA[iNdEx:iNdEx+skippy]...)
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *PidsStats) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowAgent
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: PidsStats: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: PidsStats: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Current", wireType)
+			}
+			m.Current = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.Current |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 2:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Limit", wireType)
+			}
+			m.Limit = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.Limit |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		default:
+			iNdEx = preIndex
+			skippy, err := skipAgent(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.XXX_unrecognized = append(m.XXX_unrecognized, dAtA[iNdEx:iNdEx+skippy]...)
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *MemoryData) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowAgent
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: MemoryData: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: MemoryData: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Usage", wireType)
+			}
+			m.Usage = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.Usage |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 2:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field MaxUsage", wireType)
+			}
+			m.MaxUsage = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.MaxUsage |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 3:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Failcnt", wireType)
+			}
+			m.Failcnt = 0
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowAgent
@@ -11312,17 +12374,53 @@ func (m *MemoryStats) Unmarshal(dAtA []byte) error {
 			}
 			m.Stats[mapkey] = mapvalue
 			iNdEx = postIndex
-		default:
-			iNdEx = preIndex
-			skippy, err := skipAgent(dAtA[iNdEx:])
-			if err != nil {
-				return err
+		case 7:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Psi", wireType)
 			}
-			if (skippy < 0) || (iNdEx+skippy) < 0 {
-				return ErrInvalidLengthAgent
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
 			}
-			if (iNdEx + skippy) > l {
-				return io.ErrUnexpectedEOF
+			if msglen < 0 {
+				return ErrInvalidLengthAgent
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Psi == nil {
+				m.Psi = &PSIStats{}
+			}
+			if err := m.Psi.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipAgent(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
 			}
 			m.XXX_unrecognized = append(m.XXX_unrecognized, dAtA[iNdEx:iNdEx+skippy]...)
 			iNdEx += skippy
@@ -11775,6 +12873,42 @@ func (m *BlkioStats) Unmarshal(dAtA []byte) error {
 				return err
 			}
 			iNdEx = postIndex
+		case 9:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Psi", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthAgent
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Psi == nil {
+				m.Psi = &PSIStats{}
+			}
+			if err := m.Psi.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipAgent(dAtA[iNdEx:])
@@ -11883,6 +13017,25 @@ func (m *HugetlbStats) Unmarshal(dAtA []byte) error {
 					break
 				}
 			}
+		case 4:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Limit", wireType)
+			}
+			m.Limit = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.Limit |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
 		default:
 			iNdEx = preIndex
 			skippy, err := skipAgent(dAtA[iNdEx:])
@@ -12207,6 +13360,25 @@ func (m *CgroupStats) Unmarshal(dAtA []byte) error {
 			}
 			m.HugetlbStats[mapkey] = mapvalue
 			iNdEx = postIndex
+		case 6:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field CgroupVersion", wireType)
+			}
+			m.CgroupVersion = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.CgroupVersion |= uint32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
 		default:
 			iNdEx = preIndex
 			skippy, err := skipAgent(dAtA[iNdEx:])
@@ -13042,21 +14214,620 @@ func (m *CloseStdinRequest) Unmarshal(dAtA []byte) error {
 			if b < 0x80 {
 				break
 			}
-		}
-		fieldNum := int32(wire >> 3)
-		wireType := int(wire & 0x7)
-		if wireType == 4 {
-			return fmt.Errorf("proto: CloseStdinRequest: wiretype end group for non-group")
-		}
-		if fieldNum <= 0 {
-			return fmt.Errorf("proto: CloseStdinRequest: illegal tag %d (wire type %d)", fieldNum, wire)
-		}
-		switch fieldNum {
-		case 1:
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: CloseStdinRequest: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: CloseStdinRequest: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ContainerId", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthAgent
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.ContainerId = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ExecId", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthAgent
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.ExecId = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipAgent(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.XXX_unrecognized = append(m.XXX_unrecognized, dAtA[iNdEx:iNdEx+skippy]...)
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *TtyWinResizeRequest) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowAgent
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: TtyWinResizeRequest: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: TtyWinResizeRequest: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ContainerId", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthAgent
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.ContainerId = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ExecId", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthAgent
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.ExecId = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 3:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Row", wireType)
+			}
+			m.Row = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.Row |= uint32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 4:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Column", wireType)
+			}
+			m.Column = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.Column |= uint32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		default:
+			iNdEx = preIndex
+			skippy, err := skipAgent(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.XXX_unrecognized = append(m.XXX_unrecognized, dAtA[iNdEx:iNdEx+skippy]...)
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *KernelModule) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowAgent
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: KernelModule: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: KernelModule: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Name", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthAgent
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Name = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Parameters", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthAgent
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Parameters = append(m.Parameters, string(dAtA[iNdEx:postIndex]))
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipAgent(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.XXX_unrecognized = append(m.XXX_unrecognized, dAtA[iNdEx:iNdEx+skippy]...)
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *CreateSandboxRequest) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowAgent
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: CreateSandboxRequest: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: CreateSandboxRequest: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Hostname", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthAgent
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Hostname = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 2:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Dns", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthAgent
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Dns = append(m.Dns, string(dAtA[iNdEx:postIndex]))
+			iNdEx = postIndex
+		case 3:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Storages", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthAgent
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Storages = append(m.Storages, &Storage{})
+			if err := m.Storages[len(m.Storages)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 4:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field SandboxPidns", wireType)
+			}
+			var v int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				v |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			m.SandboxPidns = bool(v != 0)
+		case 5:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field SandboxId", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthAgent
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.SandboxId = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 6:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field GuestHookPath", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthAgent
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.GuestHookPath = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 7:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field KernelModules", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowAgent
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthAgent
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthAgent
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.KernelModules = append(m.KernelModules, &KernelModule{})
+			if err := m.KernelModules[len(m.KernelModules)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		case 8:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ContainerId", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field DnsConfig", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowAgent
@@ -13066,29 +14837,33 @@ func (m *CloseStdinRequest) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthAgent
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthAgent
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.ContainerId = string(dAtA[iNdEx:postIndex])
+			if m.DnsConfig == nil {
+				m.DnsConfig = &DNSConfig{}
+			}
+			if err := m.DnsConfig.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
-		case 2:
+		case 9:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ExecId", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Hosts", wireType)
 			}
-			var stringLen uint64
+			var msglen int
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowAgent
@@ -13098,23 +14873,25 @@ func (m *CloseStdinRequest) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
+				msglen |= int(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
+			if msglen < 0 {
 				return ErrInvalidLengthAgent
 			}
-			postIndex := iNdEx + intStringLen
+			postIndex := iNdEx + msglen
 			if postIndex < 0 {
 				return ErrInvalidLengthAgent
 			}
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.ExecId = string(dAtA[iNdEx:postIndex])
+			m.Hosts = append(m.Hosts, &HostEntry{})
+			if err := m.Hosts[len(m.Hosts)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
 			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
@@ -13138,7 +14915,7 @@ func (m *CloseStdinRequest) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *TtyWinResizeRequest) Unmarshal(dAtA []byte) error {
+func (m *DNSConfig) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -13161,15 +14938,15 @@ func (m *TtyWinResizeRequest) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: TtyWinResizeRequest: wiretype end group for non-group")
+			return fmt.Errorf("proto: DNSConfig: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: TtyWinResizeRequest: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: DNSConfig: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ContainerId", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Nameservers", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -13197,11 +14974,11 @@ func (m *TtyWinResizeRequest) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.ContainerId = string(dAtA[iNdEx:postIndex])
+			m.Nameservers = append(m.Nameservers, string(dAtA[iNdEx:postIndex]))
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field ExecId", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Searches", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -13229,13 +15006,13 @@ func (m *TtyWinResizeRequest) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.ExecId = string(dAtA[iNdEx:postIndex])
+			m.Searches = append(m.Searches, string(dAtA[iNdEx:postIndex]))
 			iNdEx = postIndex
 		case 3:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Row", wireType)
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Options", wireType)
 			}
-			m.Row = 0
+			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
 				if shift >= 64 {
 					return ErrIntOverflowAgent
@@ -13245,30 +15022,24 @@ func (m *TtyWinResizeRequest) Unmarshal(dAtA []byte) error {
 				}
 				b := dAtA[iNdEx]
 				iNdEx++
-				m.Row |= uint32(b&0x7F) << shift
+				stringLen |= uint64(b&0x7F) << shift
 				if b < 0x80 {
 					break
 				}
 			}
-		case 4:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Column", wireType)
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthAgent
 			}
-			m.Column = 0
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowAgent
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				m.Column |= uint32(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthAgent
 			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.Options = append(m.Options, string(dAtA[iNdEx:postIndex]))
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipAgent(dAtA[iNdEx:])
@@ -13291,7 +15062,7 @@ func (m *TtyWinResizeRequest) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *KernelModule) Unmarshal(dAtA []byte) error {
+func (m *HostEntry) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -13314,15 +15085,15 @@ func (m *KernelModule) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: KernelModule: wiretype end group for non-group")
+			return fmt.Errorf("proto: HostEntry: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: KernelModule: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: HostEntry: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Name", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Ip", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -13350,11 +15121,11 @@ func (m *KernelModule) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Name = string(dAtA[iNdEx:postIndex])
+			m.Ip = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
 		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Parameters", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Names", wireType)
 			}
 			var stringLen uint64
 			for shift := uint(0); ; shift += 7 {
@@ -13382,7 +15153,7 @@ func (m *KernelModule) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Parameters = append(m.Parameters, string(dAtA[iNdEx:postIndex]))
+			m.Names = append(m.Names, string(dAtA[iNdEx:postIndex]))
 			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
@@ -13406,7 +15177,7 @@ func (m *KernelModule) Unmarshal(dAtA []byte) error {
 	}
 	return nil
 }
-func (m *CreateSandboxRequest) Unmarshal(dAtA []byte) error {
+func (m *UpdateSandboxDNSRequest) Unmarshal(dAtA []byte) error {
 	l := len(dAtA)
 	iNdEx := 0
 	for iNdEx < l {
@@ -13429,79 +15200,15 @@ func (m *CreateSandboxRequest) Unmarshal(dAtA []byte) error {
 		fieldNum := int32(wire >> 3)
 		wireType := int(wire & 0x7)
 		if wireType == 4 {
-			return fmt.Errorf("proto: CreateSandboxRequest: wiretype end group for non-group")
+			return fmt.Errorf("proto: UpdateSandboxDNSRequest: wiretype end group for non-group")
 		}
 		if fieldNum <= 0 {
-			return fmt.Errorf("proto: CreateSandboxRequest: illegal tag %d (wire type %d)", fieldNum, wire)
+			return fmt.Errorf("proto: UpdateSandboxDNSRequest: illegal tag %d (wire type %d)", fieldNum, wire)
 		}
 		switch fieldNum {
 		case 1:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Hostname", wireType)
-			}
-			var stringLen uint64
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowAgent
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
-				return ErrInvalidLengthAgent
-			}
-			postIndex := iNdEx + intStringLen
-			if postIndex < 0 {
-				return ErrInvalidLengthAgent
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			m.Hostname = string(dAtA[iNdEx:postIndex])
-			iNdEx = postIndex
-		case 2:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Dns", wireType)
-			}
-			var stringLen uint64
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowAgent
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
-				return ErrInvalidLengthAgent
-			}
-			postIndex := iNdEx + intStringLen
-			if postIndex < 0 {
-				return ErrInvalidLengthAgent
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			m.Dns = append(m.Dns, string(dAtA[iNdEx:postIndex]))
-			iNdEx = postIndex
-		case 3:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field Storages", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field DnsConfig", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -13528,98 +15235,16 @@ func (m *CreateSandboxRequest) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.Storages = append(m.Storages, &Storage{})
-			if err := m.Storages[len(m.Storages)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
-				return err
-			}
-			iNdEx = postIndex
-		case 4:
-			if wireType != 0 {
-				return fmt.Errorf("proto: wrong wireType = %d for field SandboxPidns", wireType)
-			}
-			var v int
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowAgent
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				v |= int(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			m.SandboxPidns = bool(v != 0)
-		case 5:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field SandboxId", wireType)
-			}
-			var stringLen uint64
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowAgent
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
-				return ErrInvalidLengthAgent
-			}
-			postIndex := iNdEx + intStringLen
-			if postIndex < 0 {
-				return ErrInvalidLengthAgent
-			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
-			}
-			m.SandboxId = string(dAtA[iNdEx:postIndex])
-			iNdEx = postIndex
-		case 6:
-			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field GuestHookPath", wireType)
-			}
-			var stringLen uint64
-			for shift := uint(0); ; shift += 7 {
-				if shift >= 64 {
-					return ErrIntOverflowAgent
-				}
-				if iNdEx >= l {
-					return io.ErrUnexpectedEOF
-				}
-				b := dAtA[iNdEx]
-				iNdEx++
-				stringLen |= uint64(b&0x7F) << shift
-				if b < 0x80 {
-					break
-				}
-			}
-			intStringLen := int(stringLen)
-			if intStringLen < 0 {
-				return ErrInvalidLengthAgent
-			}
-			postIndex := iNdEx + intStringLen
-			if postIndex < 0 {
-				return ErrInvalidLengthAgent
+			if m.DnsConfig == nil {
+				m.DnsConfig = &DNSConfig{}
 			}
-			if postIndex > l {
-				return io.ErrUnexpectedEOF
+			if err := m.DnsConfig.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
 			}
-			m.GuestHookPath = string(dAtA[iNdEx:postIndex])
 			iNdEx = postIndex
-		case 7:
+		case 2:
 			if wireType != 2 {
-				return fmt.Errorf("proto: wrong wireType = %d for field KernelModules", wireType)
+				return fmt.Errorf("proto: wrong wireType = %d for field Hosts", wireType)
 			}
 			var msglen int
 			for shift := uint(0); ; shift += 7 {
@@ -13646,8 +15271,8 @@ func (m *CreateSandboxRequest) Unmarshal(dAtA []byte) error {
 			if postIndex > l {
 				return io.ErrUnexpectedEOF
 			}
-			m.KernelModules = append(m.KernelModules, &KernelModule{})
-			if err := m.KernelModules[len(m.KernelModules)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+			m.Hosts = append(m.Hosts, &HostEntry{})
+			if err := m.Hosts[len(m.Hosts)-1].Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
 				return err
 			}
 			iNdEx = postIndex
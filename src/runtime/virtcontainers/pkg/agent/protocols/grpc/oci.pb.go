@@ -810,10 +810,12 @@ type LinuxResources struct {
 	// Hugetlb limit (in bytes)
 	HugepageLimits []LinuxHugepageLimit `protobuf:"bytes,6,rep,name=HugepageLimits,proto3" json:"HugepageLimits"`
 	// Network restriction configuration
-	Network              *LinuxNetwork `protobuf:"bytes,7,opt,name=Network,proto3" json:"Network,omitempty"`
-	XXX_NoUnkeyedLiteral struct{}      `json:"-"`
-	XXX_unrecognized     []byte        `json:"-"`
-	XXX_sizecache        int32         `json:"-"`
+	Network *LinuxNetwork `protobuf:"bytes,7,opt,name=Network,proto3" json:"Network,omitempty"`
+	// Unified resources (cgroup v2 key/value pairs)
+	Unified              map[string]string `protobuf:"bytes,8,rep,name=Unified,proto3" json:"Unified,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
+	XXX_NoUnkeyedLiteral struct{}          `json:"-"`
+	XXX_unrecognized     []byte            `json:"-"`
+	XXX_sizecache        int32             `json:"-"`
 }
 
 func (m *LinuxResources) Reset()      { *m = LinuxResources{} }
@@ -1313,6 +1315,8 @@ type LinuxSeccomp struct {
 	Architectures        []string       `protobuf:"bytes,2,rep,name=Architectures,proto3" json:"Architectures,omitempty"`
 	Flags                []string       `protobuf:"bytes,3,rep,name=Flags,proto3" json:"Flags,omitempty"`
 	Syscalls             []LinuxSyscall `protobuf:"bytes,4,rep,name=Syscalls,proto3" json:"Syscalls"`
+	ListenerPath         string         `protobuf:"bytes,5,opt,name=ListenerPath,proto3" json:"ListenerPath,omitempty"`
+	ListenerMetadata     string         `protobuf:"bytes,6,opt,name=ListenerMetadata,proto3" json:"ListenerMetadata,omitempty"`
 	XXX_NoUnkeyedLiteral struct{}       `json:"-"`
 	XXX_unrecognized     []byte         `json:"-"`
 	XXX_sizecache        int32          `json:"-"`
@@ -1532,6 +1536,7 @@ func init() {
 	proto.RegisterType((*LinuxNamespace)(nil), "grpc.LinuxNamespace")
 	proto.RegisterType((*LinuxDevice)(nil), "grpc.LinuxDevice")
 	proto.RegisterType((*LinuxResources)(nil), "grpc.LinuxResources")
+	proto.RegisterMapType((map[string]string)(nil), "grpc.LinuxResources.UnifiedEntry")
 	proto.RegisterType((*LinuxMemory)(nil), "grpc.LinuxMemory")
 	proto.RegisterType((*LinuxCPU)(nil), "grpc.LinuxCPU")
 	proto.RegisterType((*LinuxWeightDevice)(nil), "grpc.LinuxWeightDevice")
@@ -1553,143 +1558,145 @@ func init() {
 }
 
 var fileDescriptor_82a9ef0098ca0b24 = []byte{
-	// 2163 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xa4, 0x58, 0xcd, 0x73, 0x1b, 0x49,
-	0x15, 0xcf, 0x48, 0xb2, 0x2c, 0xb5, 0x22, 0x67, 0xd3, 0x9b, 0xcd, 0x0e, 0x21, 0xa5, 0xf5, 0x0e,
-	0x29, 0x30, 0x10, 0xac, 0x22, 0x81, 0x25, 0x84, 0x8f, 0x2a, 0x59, 0x4e, 0x62, 0xd5, 0xc6, 0xb1,
-	0x68, 0xd9, 0x6b, 0xd8, 0xc3, 0x56, 0xb5, 0x47, 0x6d, 0xa9, 0xd7, 0xa3, 0xe9, 0xa9, 0x9e, 0x96,
-	0x1d, 0x73, 0x82, 0x1b, 0xff, 0x01, 0x67, 0x2e, 0x7c, 0xfc, 0x07, 0x14, 0x27, 0x38, 0x91, 0xe2,
-	0xc4, 0x91, 0x2a, 0xaa, 0x80, 0xf8, 0xce, 0x9d, 0x23, 0xf5, 0xfa, 0x63, 0xd4, 0x92, 0x6c, 0xd8,
-	0xc0, 0xad, 0xdf, 0x7b, 0xbf, 0xf7, 0xfa, 0xe3, 0x7d, 0xce, 0xa0, 0x17, 0x23, 0xae, 0xc6, 0xd3,
-	0xa3, 0xcd, 0x58, 0x4c, 0xda, 0x27, 0x54, 0xd1, 0xaf, 0xc5, 0x22, 0x55, 0x94, 0xa7, 0x4c, 0xe6,
-	0x4b, 0x74, 0x2e, 0xe3, 0x76, 0xc2, 0x8f, 0xf2, 0x76, 0x26, 0x85, 0x12, 0xb1, 0x48, 0xec, 0x2a,
-	0x6f, 0x8b, 0x98, 0x6f, 0xea, 0x25, 0xae, 0x8c, 0x64, 0x16, 0xdf, 0x89, 0x46, 0x62, 0x24, 0x8c,
-	0xf0, 0x68, 0x7a, 0xdc, 0x06, 0x4a, 0x13, 0x7a, 0x65, 0x90, 0xd1, 0x1f, 0xcb, 0xa8, 0x32, 0xc8,
-	0x58, 0x8c, 0x43, 0xb4, 0xfa, 0x11, 0x93, 0x39, 0x17, 0x69, 0x18, 0xac, 0x07, 0x1b, 0x75, 0xe2,
-	0x48, 0xfc, 0x25, 0xb4, 0xda, 0x97, 0x22, 0x66, 0x79, 0x1e, 0x96, 0xd6, 0x83, 0x8d, 0xc6, 0x83,
-	0xe6, 0x26, 0x98, 0xdf, 0xb4, 0x4c, 0xe2, 0xa4, 0xb8, 0x85, 0x2a, 0x44, 0x08, 0x15, 0x96, 0x35,
-	0x0a, 0x19, 0x14, 0x70, 0x88, 0xe6, 0xe3, 0x3b, 0xa8, 0xb6, 0x23, 0x72, 0x95, 0xd2, 0x09, 0x0b,
-	0x2b, 0x7a, 0x8f, 0x82, 0xc6, 0x5f, 0x46, 0xd5, 0x5d, 0x31, 0x4d, 0x55, 0x1e, 0xae, 0xac, 0x97,
-	0x37, 0x1a, 0x0f, 0x1a, 0x46, 0x5b, 0xf3, 0xb6, 0x2a, 0xaf, 0xfe, 0xf6, 0xde, 0x35, 0x62, 0x01,
-	0xf8, 0x7d, 0xb4, 0xb2, 0x23, 0xc4, 0x49, 0x1e, 0x56, 0xf5, 0x3e, 0x16, 0xa9, 0x59, 0xc4, 0x48,
-	0xf0, 0xf7, 0x50, 0xa3, 0x93, 0xa6, 0x42, 0x51, 0xc5, 0x45, 0x9a, 0x87, 0xab, 0xda, 0xe4, 0xe7,
-	0x0d, 0x10, 0x6e, 0xbb, 0xe9, 0x49, 0x9f, 0xa4, 0x4a, 0x9e, 0x13, 0x1f, 0x0f, 0x3b, 0x3c, 0xe7,
-	0xe9, 0xf4, 0x65, 0x58, 0xf3, 0x77, 0xd0, 0x2c, 0x62, 0x24, 0xf0, 0x28, 0x03, 0x91, 0x50, 0xc9,
-	0xf3, 0xb0, 0xee, 0x3f, 0x8a, 0x65, 0x12, 0x27, 0x05, 0xe0, 0x21, 0x4f, 0x87, 0xe2, 0x2c, 0x0f,
-	0x91, 0x0f, 0xb4, 0x4c, 0xe2, 0xa4, 0x77, 0xbe, 0x8f, 0xde, 0x5a, 0x3c, 0x15, 0x7e, 0x0b, 0x95,
-	0x4f, 0xd8, 0xb9, 0x75, 0x08, 0x2c, 0xf1, 0x2d, 0xb4, 0x72, 0x4a, 0x93, 0x29, 0xd3, 0xae, 0xa8,
-	0x13, 0x43, 0x3c, 0x2e, 0x3d, 0x0a, 0xa2, 0xdf, 0x95, 0x0b, 0x3f, 0xc1, 0x4b, 0xef, 0x33, 0x39,
-	0xe1, 0x29, 0x4d, 0xb4, 0x72, 0x8d, 0x14, 0x34, 0xfe, 0x2a, 0x6a, 0x74, 0x45, 0x9a, 0x8b, 0x84,
-	0x0d, 0xf8, 0x8f, 0x99, 0x75, 0x69, 0xdd, 0x1c, 0x6a, 0x4b, 0xbc, 0x24, 0xbe, 0x14, 0xdf, 0x43,
-	0x95, 0x83, 0x9c, 0xc9, 0x79, 0x97, 0x02, 0xc7, 0xfa, 0x44, 0x4b, 0x31, 0x46, 0x95, 0x8e, 0x1c,
-	0xe5, 0x61, 0x65, 0xbd, 0xbc, 0x51, 0x27, 0x7a, 0x0d, 0x47, 0x7f, 0x92, 0x9e, 0x6a, 0x6f, 0xd6,
-	0x09, 0x2c, 0x81, 0xd3, 0x3d, 0x1b, 0x6a, 0xaf, 0xd5, 0x09, 0x2c, 0xf1, 0x77, 0xd0, 0xf5, 0x2e,
-	0xcd, 0xe8, 0x11, 0x4f, 0xb8, 0xe2, 0x0c, 0xfc, 0x04, 0xbb, 0xbc, 0xeb, 0x3d, 0xb7, 0x2f, 0x26,
-	0x73, 0x60, 0xfc, 0x75, 0xb4, 0x4a, 0x12, 0x3e, 0xe1, 0x2a, 0x0f, 0x6b, 0xda, 0xbf, 0x37, 0x6d,
-	0x58, 0xee, 0x0d, 0x7a, 0x3f, 0x34, 0x12, 0x7b, 0x48, 0x87, 0xc3, 0x1b, 0xe8, 0xc6, 0x0b, 0xf1,
-	0x82, 0x9d, 0xf5, 0x25, 0x3f, 0xe5, 0x09, 0x1b, 0x31, 0xe3, 0xbc, 0x1a, 0x59, 0x64, 0x03, 0xb2,
-	0x93, 0x65, 0x54, 0x4e, 0x84, 0xec, 0x4b, 0x71, 0xcc, 0x13, 0xa6, 0xbd, 0x57, 0x27, 0x8b, 0x6c,
-	0xbc, 0x8e, 0x1a, 0x7b, 0x7b, 0xbb, 0x83, 0x58, 0x48, 0xd6, 0x19, 0x7e, 0x1a, 0x36, 0xd6, 0x83,
-	0x8d, 0x32, 0xf1, 0x59, 0x38, 0x42, 0xd7, 0x07, 0x2c, 0x81, 0xdb, 0x3c, 0xa7, 0x47, 0x2c, 0x09,
-	0xaf, 0x6b, 0x43, 0x73, 0xbc, 0xe8, 0x21, 0x2a, 0x6f, 0x89, 0x97, 0xf8, 0x36, 0xaa, 0xee, 0x30,
-	0x3e, 0x1a, 0x2b, 0xed, 0xb5, 0x26, 0xb1, 0x14, 0x78, 0xfd, 0x90, 0x0f, 0xd5, 0x58, 0x7b, 0xab,
-	0x49, 0x0c, 0x11, 0xa5, 0xc6, 0x39, 0xf0, 0xb0, 0x07, 0xbd, 0x6d, 0xab, 0x02, 0x4b, 0xe0, 0x3c,
-	0xeb, 0x6d, 0x5b, 0x34, 0x2c, 0xf1, 0x17, 0xd1, 0x5a, 0x67, 0x38, 0xe4, 0x10, 0x5b, 0x34, 0x79,
-	0xc6, 0x87, 0x79, 0x58, 0x5e, 0x2f, 0x6f, 0x34, 0xc9, 0x02, 0x17, 0x22, 0x07, 0x6c, 0xfa, 0x39,
-	0xea, 0xe8, 0xe8, 0x57, 0x01, 0xba, 0xb9, 0xe4, 0x15, 0xd0, 0xd8, 0x12, 0xd3, 0x74, 0xc8, 0xd3,
-	0x51, 0x18, 0x68, 0x6f, 0x17, 0x34, 0xbe, 0x8b, 0xea, 0x4f, 0x8e, 0x8f, 0x59, 0xac, 0xf8, 0x29,
-	0x44, 0x1a, 0x08, 0x67, 0x0c, 0x78, 0xba, 0x5e, 0x3a, 0x66, 0x92, 0x2b, 0x7a, 0x94, 0x30, 0x7d,
-	0xa0, 0x3a, 0xf1, 0x59, 0xa0, 0xdf, 0x87, 0xb8, 0x55, 0x8a, 0x0d, 0x6d, 0x74, 0xcd, 0x18, 0x50,
-	0xb2, 0x3a, 0x93, 0x23, 0xce, 0x52, 0x65, 0xc3, 0xcc, 0x91, 0x51, 0x0f, 0x35, 0xbc, 0x30, 0x80,
-	0xf8, 0xdc, 0x3f, 0xcf, 0x98, 0xcd, 0x23, 0xbd, 0x06, 0xde, 0x0e, 0x95, 0x43, 0xfd, 0x46, 0x15,
-	0xa2, 0xd7, 0xc0, 0x1b, 0x88, 0x63, 0x53, 0xc0, 0x2a, 0x44, 0xaf, 0x23, 0x81, 0x56, 0x74, 0xdd,
-	0x81, 0xd3, 0x0e, 0x59, 0xae, 0x78, 0xaa, 0x13, 0xd4, 0xda, 0xf2, 0x59, 0xe0, 0xbd, 0x5c, 0x4c,
-	0x65, 0xec, 0x92, 0xd3, 0x52, 0x60, 0x56, 0xc1, 0xf6, 0x65, 0xb3, 0x3d, 0xac, 0xe1, 0xec, 0x22,
-	0x33, 0xd5, 0xc9, 0xdc, 0xcb, 0x91, 0xd1, 0x07, 0xa6, 0x8a, 0x82, 0x56, 0x9f, 0xaa, 0xb1, 0x3b,
-	0x34, 0xac, 0xe1, 0xad, 0x09, 0xa3, 0x43, 0x91, 0x26, 0xe7, 0x7a, 0x8f, 0x1a, 0x29, 0xe8, 0xe8,
-	0x0f, 0x25, 0x5b, 0x17, 0xf1, 0x7d, 0x54, 0xeb, 0x4b, 0x96, 0x2b, 0x2a, 0x95, 0xf6, 0x48, 0x91,
-	0xb8, 0x20, 0xb6, 0x39, 0x51, 0x20, 0xf0, 0x26, 0xaa, 0xf7, 0x45, 0xae, 0x0c, 0xbc, 0x74, 0x05,
-	0x7c, 0x06, 0xd1, 0xd6, 0x35, 0x21, 0x32, 0xed, 0xb2, 0xcb, 0xad, 0x5b, 0x04, 0xfe, 0x00, 0x35,
-	0xbb, 0x92, 0x51, 0xc5, 0xc8, 0x34, 0x55, 0x5c, 0x07, 0xd5, 0xe5, 0x2a, 0xf3, 0x30, 0xfc, 0x18,
-	0xdd, 0x30, 0x8c, 0xae, 0xeb, 0x7c, 0xb6, 0x31, 0x2c, 0x6b, 0x2e, 0x02, 0xf1, 0x23, 0xb4, 0x36,
-	0x80, 0xa3, 0xce, 0x54, 0xab, 0x57, 0xa8, 0x2e, 0xe0, 0xa2, 0x8f, 0x51, 0x05, 0xa4, 0x97, 0xbe,
-	0xbd, 0x2b, 0x72, 0xa5, 0xe5, 0x22, 0x57, 0x9e, 0x15, 0xb9, 0x10, 0xad, 0xee, 0xf3, 0x09, 0x13,
-	0x53, 0xa5, 0xd3, 0xa7, 0x4c, 0x1c, 0x19, 0xfd, 0x66, 0xc5, 0x76, 0x15, 0xfc, 0x5d, 0xd4, 0x38,
-	0xe8, 0x6d, 0xef, 0xd2, 0x2c, 0xe3, 0xe9, 0x28, 0xb7, 0x2e, 0xba, 0xe5, 0x55, 0xbd, 0x42, 0x68,
-	0x8f, 0xe9, 0xc3, 0x41, 0xfb, 0x99, 0xa7, 0x5d, 0xfa, 0xef, 0xda, 0x1e, 0x1c, 0xb7, 0x51, 0x75,
-	0x70, 0x9e, 0xc7, 0x2a, 0xb1, 0xbe, 0xf3, 0x8b, 0xed, 0xa6, 0x91, 0x98, 0x86, 0x68, 0x61, 0xf8,
-	0x01, 0xaa, 0x13, 0x66, 0x02, 0x39, 0xd7, 0x57, 0x9a, 0xdf, 0xac, 0x90, 0x91, 0x19, 0x0c, 0x52,
-	0xa5, 0x3b, 0x92, 0x62, 0x9a, 0xe5, 0xfa, 0x15, 0x57, 0x4c, 0xaa, 0x78, 0x2c, 0xfc, 0x18, 0xa1,
-	0x17, 0x74, 0xc2, 0xf2, 0x8c, 0x82, 0xd9, 0xea, 0xd2, 0x1d, 0x0a, 0xa1, 0xbd, 0x83, 0x87, 0x86,
-	0xc2, 0xbf, 0xcd, 0x4e, 0x79, 0xcc, 0x5c, 0x63, 0xbf, 0xe9, 0x29, 0x1a, 0x89, 0x2b, 0xfc, 0x16,
-	0x87, 0xef, 0xa3, 0xd5, 0x01, 0x8b, 0x63, 0x31, 0xc9, 0x6c, 0x4b, 0xc7, 0x9e, 0x8a, 0x95, 0x10,
-	0x07, 0xc1, 0xf7, 0xd1, 0x4d, 0xc8, 0xc0, 0xe3, 0xbc, 0x2f, 0x45, 0x46, 0x47, 0x26, 0xdf, 0xeb,
-	0xfa, 0x12, 0xcb, 0x02, 0xb8, 0xec, 0x2e, 0xcd, 0x4f, 0xd8, 0x10, 0x2e, 0x06, 0x4d, 0x5e, 0x57,
-	0x31, 0x8f, 0x85, 0xef, 0xa1, 0xa6, 0xcb, 0x52, 0x83, 0x69, 0x68, 0xcc, 0x3c, 0x13, 0xb7, 0x10,
-	0xd2, 0x85, 0xc6, 0x6f, 0x12, 0x1e, 0x07, 0xb7, 0x51, 0xad, 0x97, 0x2a, 0x96, 0x90, 0xa1, 0x0a,
-	0x9b, 0xfa, 0x12, 0x6f, 0xfb, 0x4e, 0xb7, 0x22, 0x52, 0x80, 0xee, 0x7c, 0x1b, 0x35, 0x3c, 0x87,
-	0xbe, 0xd1, 0x2c, 0xf1, 0x5e, 0x31, 0xb4, 0x00, 0x68, 0x38, 0x9d, 0x4c, 0x9c, 0xa2, 0x21, 0x00,
-	0xe0, 0x06, 0x9c, 0xcb, 0x01, 0x9f, 0xa0, 0xb5, 0xf9, 0x60, 0xd4, 0xbd, 0x4d, 0xe4, 0xaa, 0x68,
-	0x54, 0x96, 0xd2, 0xc1, 0xe2, 0x12, 0xb0, 0xe8, 0x59, 0x3e, 0x4b, 0x97, 0x65, 0x18, 0x55, 0xca,
-	0x5a, 0xa4, 0xd7, 0xd1, 0x23, 0x6b, 0xbf, 0x88, 0x8b, 0xab, 0x8a, 0xbc, 0x8e, 0xc0, 0xd2, 0x2c,
-	0x8f, 0xa3, 0x5f, 0x04, 0xa8, 0xe1, 0x85, 0xca, 0x55, 0xb9, 0xae, 0x6d, 0x95, 0x3c, 0x5b, 0xb7,
-	0xd0, 0xca, 0x2e, 0xfd, 0x54, 0x98, 0x59, 0xa8, 0x4c, 0x0c, 0xa1, 0xb9, 0x3c, 0x15, 0xd2, 0x66,
-	0xbb, 0x21, 0xa0, 0x4e, 0x3f, 0xe5, 0x09, 0xdb, 0x15, 0x43, 0xa6, 0xa3, 0xbf, 0x49, 0x0a, 0xda,
-	0x75, 0xeb, 0xea, 0x52, 0xb7, 0x5e, 0x2d, 0xba, 0x75, 0xf4, 0xf7, 0x92, 0xbd, 0xde, 0x2c, 0xa7,
-	0xbe, 0x35, 0x8b, 0xfa, 0x60, 0x29, 0x73, 0x8d, 0xc4, 0x24, 0xd8, 0x62, 0xec, 0xc3, 0x64, 0xcd,
-	0x26, 0x42, 0x9e, 0xdb, 0x51, 0xcf, 0xcf, 0x16, 0x23, 0x20, 0x16, 0x80, 0xd7, 0x51, 0xb9, 0xdb,
-	0x3f, 0xb0, 0xc3, 0xde, 0x9a, 0x3f, 0x86, 0xf5, 0x0f, 0x08, 0x88, 0xf0, 0x17, 0x50, 0xa5, 0x0f,
-	0xc3, 0x83, 0x29, 0x04, 0x37, 0x3c, 0x08, 0xb0, 0x89, 0x16, 0x42, 0xb6, 0x6d, 0x25, 0x22, 0x3e,
-	0xe9, 0xed, 0xe9, 0xcb, 0xcf, 0x67, 0x9b, 0x95, 0x10, 0x07, 0xc1, 0x4f, 0xd1, 0xda, 0xce, 0x74,
-	0xc4, 0x32, 0x3a, 0x62, 0xcf, 0xcd, 0x38, 0x67, 0xca, 0x41, 0xe8, 0x29, 0xcd, 0x01, 0x5c, 0xed,
-	0x9e, 0xd7, 0x82, 0x5d, 0x5f, 0x30, 0x75, 0x26, 0xe4, 0x89, 0x9d, 0x23, 0xfd, 0x5d, 0xad, 0x84,
-	0x38, 0x48, 0xf4, 0x57, 0x17, 0x05, 0xf6, 0xea, 0xb7, 0xa0, 0x38, 0x4f, 0xb8, 0x19, 0xbc, 0xca,
-	0xc4, 0x10, 0x10, 0x9b, 0x84, 0xe5, 0x4c, 0x9e, 0x9a, 0x1a, 0x50, 0x32, 0xc3, 0x9d, 0xc7, 0xd2,
-	0xb1, 0x79, 0x46, 0x33, 0x1b, 0x14, 0x7a, 0x0d, 0x91, 0xfe, 0x21, 0x93, 0x29, 0x4b, 0x6c, 0x50,
-	0x58, 0x0a, 0xa6, 0x19, 0xb3, 0xda, 0xef, 0xf6, 0xf5, 0xcb, 0x94, 0xc9, 0x8c, 0x01, 0xf9, 0x0f,
-	0xda, 0x19, 0x4f, 0xe1, 0x4b, 0xab, 0xaa, 0x47, 0x10, 0x8f, 0x83, 0xbf, 0x82, 0xde, 0xda, 0xe6,
-	0x39, 0x8c, 0x45, 0x7b, 0x7b, 0xbb, 0x1f, 0xf2, 0x24, 0x61, 0x52, 0x5f, 0xb4, 0x46, 0x96, 0xf8,
-	0xd1, 0x9f, 0x02, 0x54, 0x73, 0x8e, 0x83, 0xe3, 0x0c, 0xc6, 0x54, 0xea, 0xc0, 0x01, 0xa3, 0x96,
-	0x82, 0x2b, 0xff, 0x60, 0x2a, 0x14, 0xb5, 0xd7, 0x32, 0x04, 0xa0, 0xfb, 0x4c, 0x72, 0x31, 0xb4,
-	0x53, 0x90, 0xa5, 0x60, 0x22, 0x26, 0x8c, 0x26, 0xd0, 0x9c, 0x67, 0xad, 0x1c, 0xf4, 0x16, 0xd9,
-	0x30, 0x6a, 0x3a, 0x96, 0xb5, 0xb4, 0xa2, 0x2d, 0x2d, 0x70, 0xe1, 0xe9, 0xba, 0xd9, 0x34, 0xb7,
-	0x1f, 0x04, 0x7a, 0x0d, 0xbc, 0x5d, 0x36, 0x31, 0x5f, 0x02, 0x75, 0xa2, 0xd7, 0xd1, 0x99, 0x9d,
-	0x3a, 0x0f, 0xf5, 0x2c, 0x6c, 0xb3, 0xb6, 0xc8, 0xc6, 0xe0, 0xd2, 0x6c, 0x2c, 0xf9, 0xd9, 0x78,
-	0x1b, 0x55, 0x8d, 0xae, 0xad, 0x20, 0x96, 0x82, 0x17, 0x7f, 0xce, 0xe8, 0xb1, 0x95, 0x55, 0xb4,
-	0xcc, 0xe3, 0x44, 0x07, 0xe8, 0x6d, 0xbd, 0xf1, 0xfe, 0x58, 0x0a, 0xa5, 0x12, 0xf6, 0x3f, 0x6c,
-	0x8d, 0x51, 0x85, 0x50, 0xc5, 0xdc, 0x44, 0x09, 0xeb, 0xe8, 0x9f, 0x65, 0x74, 0xdd, 0x4f, 0x05,
-	0xef, 0x7c, 0xc1, 0x7f, 0x38, 0x5f, 0x69, 0xf1, 0x7c, 0xb8, 0x83, 0xae, 0xfb, 0x6f, 0x72, 0x49,
-	0x47, 0xf7, 0xc5, 0x36, 0x6d, 0xe6, 0x54, 0xf0, 0x01, 0x7a, 0xc7, 0xdd, 0x0e, 0xba, 0xd1, 0x56,
-	0x96, 0x5b, 0x5b, 0x66, 0x4c, 0xfb, 0x9c, 0x67, 0x6b, 0xfe, 0x15, 0xac, 0xb5, 0xcb, 0xb5, 0xf1,
-	0x21, 0xba, 0xed, 0x04, 0x87, 0x92, 0x2b, 0x36, 0xb3, 0xbb, 0xf2, 0xd9, 0xec, 0x5e, 0xa1, 0xee,
-	0x1b, 0x86, 0x1d, 0x7b, 0x7b, 0xfd, 0x81, 0x35, 0x5c, 0x7d, 0x43, 0xc3, 0xf3, 0xea, 0xf8, 0x47,
-	0xe8, 0xdd, 0xb9, 0x2d, 0x3d, 0xcb, 0xab, 0x9f, 0xcd, 0xf2, 0x55, 0xfa, 0xd1, 0xfb, 0xa8, 0x5e,
-	0x54, 0xc8, 0xcb, 0xeb, 0x4c, 0xf4, 0x53, 0xf7, 0x65, 0xe5, 0x17, 0x72, 0xc0, 0x76, 0x92, 0x44,
-	0x9c, 0xd9, 0x4f, 0x78, 0x43, 0xfc, 0xdf, 0xbd, 0xe9, 0x36, 0xaa, 0x76, 0x62, 0xfd, 0x37, 0xc7,
-	0xcc, 0x65, 0x96, 0x8a, 0x12, 0x1b, 0x95, 0xb6, 0x42, 0xc2, 0x24, 0xdb, 0x4d, 0x68, 0x9e, 0x17,
-	0x0d, 0xdb, 0x91, 0x78, 0x0b, 0xa1, 0xbe, 0xe4, 0x42, 0x9a, 0x8f, 0x76, 0x33, 0x80, 0xde, 0x5d,
-	0x98, 0x45, 0xe4, 0x31, 0x8d, 0x99, 0x45, 0x9d, 0xbb, 0x21, 0x6e, 0xa6, 0x15, 0x3d, 0x45, 0x78,
-	0xb9, 0xb2, 0x43, 0xdf, 0xec, 0xd3, 0x11, 0xcb, 0xa1, 0xdb, 0x9b, 0x7e, 0x5c, 0xd0, 0xb3, 0x97,
-	0x33, 0x5f, 0x6c, 0xf6, 0xe5, 0x76, 0xd0, 0xed, 0xcb, 0xf7, 0x84, 0x77, 0x82, 0xe1, 0xc0, 0xf5,
-	0x75, 0x58, 0x6b, 0xfb, 0x56, 0x6e, 0xf3, 0xa9, 0xa0, 0xa3, 0x5f, 0x06, 0xf6, 0x01, 0xdc, 0x18,
-	0x78, 0x0f, 0x35, 0xb7, 0xd9, 0x31, 0x9d, 0x26, 0xaa, 0x13, 0x7b, 0x9f, 0x7c, 0xf3, 0x4c, 0x40,
-	0x75, 0x64, 0x3c, 0xe6, 0x8a, 0xc5, 0x6a, 0x2a, 0x99, 0xfb, 0x3e, 0x98, 0x67, 0xc2, 0xe1, 0x9f,
-	0x26, 0x74, 0x94, 0xdb, 0x4f, 0x05, 0x43, 0xe0, 0x6f, 0xa0, 0x1a, 0x4c, 0x68, 0x34, 0x49, 0x72,
-	0x9b, 0x70, 0x73, 0x73, 0xa9, 0x11, 0xb9, 0x4f, 0x2a, 0x87, 0x8c, 0x38, 0xba, 0xe1, 0x9f, 0xb3,
-	0x23, 0x47, 0x60, 0xbe, 0x97, 0x0e, 0xd9, 0x4b, 0x5b, 0xe1, 0x0d, 0x01, 0xdc, 0x8f, 0x8a, 0xf9,
-	0xae, 0x42, 0x0c, 0x01, 0x6f, 0xa0, 0x17, 0xfb, 0x67, 0xc2, 0x96, 0xa5, 0x82, 0xc6, 0x6b, 0xa8,
-	0xb4, 0x97, 0xd9, 0xef, 0xfe, 0xd2, 0x5e, 0x16, 0xfd, 0xbc, 0x78, 0x13, 0xb3, 0x39, 0x98, 0xd4,
-	0x13, 0x97, 0xfd, 0xd2, 0x37, 0x84, 0x09, 0xa9, 0xa2, 0x43, 0xea, 0x90, 0xd2, 0x6f, 0x73, 0x17,
-	0xd5, 0x98, 0x94, 0xa9, 0x90, 0xcc, 0x96, 0xde, 0x9d, 0x6b, 0xa4, 0xe0, 0xe0, 0xb6, 0xf7, 0xd7,
-	0xa8, 0xf1, 0xe0, 0x9d, 0xe5, 0x89, 0xbc, 0x23, 0xdd, 0x27, 0x8c, 0x06, 0x6e, 0x21, 0x54, 0x7b,
-	0x02, 0xca, 0x84, 0xa9, 0xe8, 0x9b, 0xa8, 0x39, 0x37, 0xf7, 0x82, 0x1f, 0x9e, 0x3f, 0xec, 0xd2,
-	0x78, 0xcc, 0x06, 0xf1, 0x98, 0x4d, 0xa8, 0xf3, 0xd6, 0x1c, 0x73, 0xeb, 0x67, 0xc1, 0xab, 0xd7,
-	0xad, 0x6b, 0x7f, 0x79, 0xdd, 0xba, 0xf6, 0xaf, 0xd7, 0xad, 0xe0, 0x27, 0x17, 0xad, 0xe0, 0xd7,
-	0x17, 0xad, 0xe0, 0xb7, 0x17, 0xad, 0xe0, 0xf7, 0x17, 0xad, 0xe0, 0xd5, 0x45, 0x2b, 0xf8, 0xf3,
-	0x45, 0x2b, 0xf8, 0xc7, 0x45, 0x2b, 0xf8, 0xf8, 0x93, 0x37, 0xfc, 0x27, 0x2b, 0x4d, 0xfb, 0x6b,
-	0x9f, 0x72, 0xa9, 0x3c, 0x51, 0x76, 0x32, 0x6a, 0xd3, 0x11, 0x4b, 0x95, 0xf7, 0xbf, 0x16, 0x6e,
-	0x7a, 0x54, 0xd5, 0xf4, 0xc3, 0x7f, 0x07, 0x00, 0x00, 0xff, 0xff, 0xce, 0xda, 0xe2, 0x21, 0xfc,
-	0x15, 0x00, 0x00,
+	// 2204 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xa5, 0x58, 0xcb, 0x6f, 0x24, 0x47,
+	0x19, 0xcf, 0x4c, 0xdb, 0xe3, 0x71, 0x8d, 0xed, 0xdd, 0xad, 0x6c, 0x36, 0xc3, 0xb2, 0xda, 0xec,
+	0x36, 0x08, 0xc2, 0xcb, 0x16, 0xbb, 0x10, 0x96, 0xe5, 0x21, 0x8d, 0x67, 0x1f, 0xb6, 0xe2, 0xc7,
+	0x50, 0x63, 0xc7, 0x90, 0x43, 0xa4, 0x76, 0x4f, 0xcd, 0xb8, 0xe3, 0x76, 0x57, 0xab, 0xbb, 0xc7,
+	0x5e, 0x73, 0x82, 0x1b, 0xff, 0x01, 0x67, 0x4e, 0xc0, 0x7f, 0x80, 0x38, 0xc1, 0x89, 0x15, 0x27,
+	0x8e, 0x91, 0x90, 0x10, 0xe1, 0xce, 0x15, 0x71, 0xe4, 0xfb, 0xaa, 0xbe, 0xea, 0xa9, 0x9e, 0x19,
+	0x93, 0x6c, 0x72, 0x68, 0xa9, 0xbe, 0x57, 0x3d, 0xbe, 0x57, 0xfd, 0xaa, 0xd9, 0xde, 0x28, 0x2a,
+	0x4e, 0xc6, 0xc7, 0xeb, 0xa1, 0x3a, 0xdb, 0x38, 0x0d, 0x8a, 0xe0, 0x5b, 0xa1, 0x4a, 0x8a, 0x20,
+	0x4a, 0x64, 0x96, 0xcf, 0xd0, 0x79, 0x16, 0x6e, 0xc4, 0xd1, 0x71, 0xbe, 0x91, 0x66, 0xaa, 0x50,
+	0xa1, 0x8a, 0x69, 0x94, 0x6f, 0xa8, 0x30, 0x5a, 0xd7, 0x43, 0xbe, 0x30, 0xca, 0xd2, 0xf0, 0xb6,
+	0x3f, 0x52, 0x23, 0x65, 0x84, 0xc7, 0xe3, 0xe1, 0x06, 0x52, 0x9a, 0xd0, 0x23, 0xa3, 0xe9, 0xff,
+	0xc5, 0x63, 0x0b, 0xfd, 0x54, 0x86, 0xbc, 0xcd, 0x96, 0xde, 0x83, 0xe9, 0x23, 0x95, 0xb4, 0x6b,
+	0xf7, 0x6a, 0x6f, 0x2f, 0x0b, 0x4b, 0xf2, 0xaf, 0xb2, 0xa5, 0x5e, 0xa6, 0x42, 0x99, 0xe7, 0xed,
+	0x3a, 0x48, 0x5a, 0x0f, 0x56, 0xd7, 0x71, 0xfa, 0x75, 0x62, 0x0a, 0x2b, 0xe5, 0x77, 0xd9, 0x82,
+	0x50, 0xaa, 0x68, 0x7b, 0x5a, 0x8b, 0x19, 0x2d, 0xe4, 0x08, 0xcd, 0xe7, 0xb7, 0x59, 0x73, 0x4b,
+	0xe5, 0x45, 0x12, 0x9c, 0xc9, 0xf6, 0x82, 0x5e, 0xa3, 0xa4, 0xf9, 0xd7, 0x58, 0x63, 0x57, 0x8d,
+	0x93, 0x22, 0x6f, 0x2f, 0xde, 0xf3, 0xc0, 0xba, 0x65, 0xac, 0x35, 0x6f, 0x73, 0xe1, 0xe5, 0x3f,
+	0xde, 0x7a, 0x4d, 0x90, 0x02, 0xbf, 0xcf, 0x16, 0xb7, 0x94, 0x3a, 0xcd, 0xdb, 0x0d, 0xbd, 0x0e,
+	0x69, 0x6a, 0x96, 0x30, 0x12, 0xfe, 0x23, 0xd6, 0xea, 0x24, 0x89, 0x2a, 0x82, 0x02, 0x0e, 0x90,
+	0xb7, 0x97, 0xf4, 0x94, 0x5f, 0x34, 0x8a, 0x78, 0xda, 0x75, 0x47, 0xfa, 0x34, 0x29, 0xb2, 0x4b,
+	0xe1, 0xea, 0xe3, 0x0a, 0x3b, 0x51, 0x32, 0x7e, 0xd1, 0x6e, 0xba, 0x2b, 0x68, 0x96, 0x30, 0x12,
+	0x74, 0x4a, 0x5f, 0xc5, 0x41, 0x16, 0xe5, 0xed, 0x65, 0xd7, 0x29, 0xc4, 0x14, 0x56, 0x8a, 0x8a,
+	0x47, 0x51, 0x32, 0x50, 0x17, 0x79, 0x9b, 0xb9, 0x8a, 0xc4, 0x14, 0x56, 0x7a, 0xfb, 0xc7, 0xec,
+	0xfa, 0xf4, 0xae, 0xf8, 0x75, 0xe6, 0x9d, 0xca, 0x4b, 0x0a, 0x08, 0x0e, 0xf9, 0x4d, 0xb6, 0x78,
+	0x1e, 0xc4, 0x63, 0xa9, 0x43, 0xb1, 0x2c, 0x0c, 0xf1, 0xb8, 0xfe, 0xa8, 0xe6, 0xff, 0xd1, 0x2b,
+	0xe3, 0x84, 0x9e, 0x3e, 0x90, 0xd9, 0x59, 0x94, 0x04, 0xb1, 0x36, 0x6e, 0x8a, 0x92, 0xe6, 0xdf,
+	0x60, 0xad, 0x2e, 0x2c, 0xa0, 0x62, 0xd9, 0x8f, 0x7e, 0x2e, 0x29, 0xa4, 0xcb, 0x66, 0x53, 0x9b,
+	0xea, 0x85, 0x70, 0xa5, 0xfc, 0xcb, 0x6c, 0xe1, 0x30, 0x97, 0x59, 0x35, 0xa4, 0xc8, 0xa1, 0x98,
+	0x68, 0x29, 0xe7, 0x6c, 0xa1, 0x93, 0x8d, 0x72, 0x08, 0xaa, 0x07, 0x7b, 0xd2, 0x63, 0xdc, 0xfa,
+	0xd3, 0xe4, 0x5c, 0x47, 0x13, 0xb6, 0x0e, 0x43, 0xe4, 0x74, 0x2f, 0x06, 0x3a, 0x6a, 0xc0, 0x81,
+	0x21, 0xff, 0x01, 0x5b, 0xe9, 0x06, 0x69, 0x70, 0x1c, 0xc5, 0x51, 0x11, 0x49, 0x8c, 0x13, 0xae,
+	0xf2, 0xa6, 0xe3, 0x6e, 0x57, 0x2c, 0x2a, 0xca, 0xfc, 0xdb, 0x6c, 0x49, 0xc4, 0xd1, 0x59, 0x04,
+	0x29, 0xd3, 0xd4, 0xf1, 0xbd, 0x41, 0x69, 0xb9, 0xdf, 0xdf, 0xfe, 0xa9, 0x91, 0xd0, 0x26, 0xad,
+	0x1e, 0x7f, 0x9b, 0x5d, 0xdb, 0x53, 0x7b, 0xf2, 0xa2, 0x97, 0x45, 0xe7, 0x51, 0x2c, 0x47, 0xd2,
+	0x04, 0xaf, 0x29, 0xa6, 0xd9, 0xa8, 0xd9, 0x49, 0xd3, 0x20, 0x3b, 0x53, 0x19, 0xf8, 0x74, 0x08,
+	0x5c, 0x1d, 0xbd, 0x65, 0x31, 0xcd, 0xe6, 0xf7, 0x58, 0x6b, 0x7f, 0x7f, 0xb7, 0x1f, 0xaa, 0x4c,
+	0x76, 0x06, 0x1f, 0xb6, 0x5b, 0xa0, 0xe5, 0x09, 0x97, 0xc5, 0x7d, 0xb6, 0xd2, 0x97, 0x31, 0x9e,
+	0x66, 0x27, 0x38, 0x96, 0x71, 0x7b, 0x45, 0x4f, 0x54, 0xe1, 0xf9, 0x0f, 0x99, 0x07, 0xbe, 0xe7,
+	0xb7, 0x58, 0x63, 0x4b, 0x46, 0xa3, 0x93, 0x42, 0x47, 0x6d, 0x55, 0x10, 0x85, 0x51, 0x3f, 0x8a,
+	0x06, 0xc5, 0x89, 0x8e, 0xd6, 0xaa, 0x30, 0x84, 0x9f, 0x98, 0xe0, 0xa0, 0x63, 0x0f, 0xb7, 0x9f,
+	0x90, 0x09, 0x0e, 0x91, 0xf3, 0x1c, 0x38, 0x46, 0x1b, 0x87, 0xfc, 0x2b, 0x6c, 0xad, 0x33, 0x18,
+	0x44, 0x98, 0x5b, 0x41, 0xfc, 0x3c, 0x1a, 0xe4, 0x10, 0x52, 0x0f, 0x84, 0x53, 0x5c, 0xcc, 0x1c,
+	0x9c, 0xd3, 0xad, 0x51, 0x4b, 0xfb, 0xbf, 0xad, 0xb1, 0x1b, 0x33, 0x51, 0x41, 0x8b, 0x4d, 0x28,
+	0xcc, 0x41, 0x94, 0x8c, 0x60, 0x0b, 0x18, 0xed, 0x92, 0xe6, 0x77, 0xd8, 0xf2, 0xd3, 0xe1, 0x50,
+	0x86, 0x45, 0x74, 0x8e, 0x99, 0x86, 0xc2, 0x09, 0x03, 0x5d, 0xb7, 0x9d, 0x9c, 0xc8, 0x2c, 0x2a,
+	0x82, 0x63, 0x70, 0xb0, 0xa7, 0xe5, 0x2e, 0x0b, 0xed, 0x7b, 0x98, 0xb7, 0x45, 0x21, 0x07, 0x94,
+	0x5d, 0x13, 0x06, 0xb6, 0xac, 0xce, 0xd9, 0x71, 0x24, 0x93, 0x82, 0xd2, 0xcc, 0x92, 0xfe, 0x36,
+	0x6b, 0x39, 0x69, 0x80, 0xf9, 0x79, 0x70, 0x99, 0x4a, 0xaa, 0x23, 0x3d, 0x46, 0xde, 0x56, 0x90,
+	0x0d, 0xb4, 0x8f, 0x16, 0x84, 0x1e, 0x23, 0xaf, 0xaf, 0x86, 0xa6, 0x81, 0x01, 0x0f, 0xc7, 0xbe,
+	0x62, 0x8b, 0xba, 0xef, 0xe0, 0x6e, 0x07, 0x32, 0x2f, 0xa0, 0x86, 0x8a, 0x49, 0x93, 0x74, 0x59,
+	0x18, 0xbd, 0x5c, 0x8d, 0xb3, 0xd0, 0x16, 0x27, 0x51, 0x38, 0x6d, 0x81, 0xcb, 0x7b, 0x66, 0x79,
+	0x1c, 0xe3, 0xde, 0x55, 0x6a, 0xba, 0x93, 0x39, 0x97, 0x25, 0xfd, 0x77, 0x4c, 0x17, 0x45, 0xab,
+	0x5e, 0x00, 0x21, 0xa7, 0x4d, 0xe3, 0x18, 0x7d, 0x2d, 0x64, 0x30, 0x50, 0x49, 0x7c, 0xa9, 0xd7,
+	0x80, 0xba, 0xb6, 0xb4, 0xff, 0xe7, 0x3a, 0xf5, 0x45, 0xfe, 0x4d, 0xd6, 0xec, 0x65, 0xb0, 0xaf,
+	0x20, 0x2b, 0x74, 0x44, 0xca, 0xc2, 0x45, 0x31, 0xd5, 0x44, 0xa9, 0xc1, 0xd7, 0xc1, 0xc7, 0xd0,
+	0x85, 0x8d, 0x7a, 0xfd, 0x0a, 0xf5, 0x89, 0x8a, 0x9e, 0x5d, 0x13, 0x2a, 0xd5, 0x21, 0x9b, 0x3f,
+	0x3b, 0x69, 0xf0, 0x77, 0xd8, 0x6a, 0x37, 0x93, 0x41, 0x21, 0x05, 0xf8, 0x30, 0xd2, 0x49, 0x35,
+	0xdf, 0xa4, 0xaa, 0xc6, 0x1f, 0xb3, 0x6b, 0x86, 0xd1, 0xb5, 0x37, 0x1f, 0x5d, 0x0c, 0xb3, 0x96,
+	0xd3, 0x8a, 0xfc, 0x11, 0x5b, 0xeb, 0xe3, 0x56, 0x27, 0xa6, 0x8d, 0x2b, 0x4c, 0xa7, 0xf4, 0xfc,
+	0xf7, 0x21, 0x29, 0x40, 0x3a, 0xd7, 0xf7, 0xb6, 0xc9, 0xd5, 0x67, 0x9b, 0x9c, 0x37, 0x69, 0x72,
+	0x10, 0xd7, 0x03, 0xd8, 0xbf, 0x1a, 0x17, 0xba, 0x7c, 0x3c, 0x61, 0x49, 0xff, 0xf7, 0x8b, 0x74,
+	0xab, 0xf0, 0x1f, 0xb2, 0x16, 0x14, 0xe9, 0x6e, 0x90, 0xa6, 0x50, 0x23, 0x39, 0x85, 0xe8, 0xa6,
+	0xd3, 0xf5, 0x4a, 0x21, 0x6d, 0xd3, 0x55, 0x47, 0xeb, 0xe7, 0x8e, 0x75, 0xfd, 0x93, 0xad, 0x1d,
+	0x75, 0xbe, 0xc1, 0x1a, 0xfd, 0xcb, 0x3c, 0x2c, 0x62, 0x8a, 0x9d, 0xdb, 0x6c, 0xd7, 0x8d, 0xc4,
+	0x5c, 0x88, 0xa4, 0xc6, 0x1f, 0xb0, 0x65, 0x21, 0x4d, 0x22, 0xe7, 0xfa, 0x48, 0xd5, 0xc5, 0x4a,
+	0x99, 0x98, 0xa8, 0x61, 0xa9, 0x74, 0x47, 0x99, 0x1a, 0xa7, 0xb9, 0xf6, 0xe2, 0xa2, 0x29, 0x15,
+	0x87, 0x05, 0xe1, 0x65, 0x7b, 0xd0, 0x52, 0xf2, 0x34, 0xc0, 0x69, 0x1b, 0x33, 0x67, 0x28, 0x85,
+	0x74, 0x06, 0x47, 0x1b, 0x1b, 0xff, 0x13, 0x79, 0x1e, 0x85, 0xd2, 0x5e, 0xec, 0x37, 0x1c, 0x43,
+	0x23, 0xb1, 0x8d, 0x9f, 0xf4, 0x20, 0x67, 0x97, 0xfa, 0x32, 0x04, 0x74, 0x95, 0xd2, 0x95, 0xce,
+	0x1d, 0x13, 0x92, 0x08, 0xab, 0x02, 0xda, 0x37, 0xb0, 0x02, 0x87, 0x39, 0xf4, 0xf8, 0x34, 0x18,
+	0x99, 0x7a, 0x5f, 0xd6, 0x87, 0x98, 0x15, 0xe0, 0x61, 0x77, 0x83, 0xfc, 0x54, 0x0e, 0xf0, 0x60,
+	0x78, 0xc9, 0xeb, 0x2e, 0xe6, 0xb0, 0xe0, 0x12, 0x5d, 0xb5, 0x55, 0x6a, 0x74, 0x5a, 0x5a, 0xa7,
+	0xca, 0x04, 0xf4, 0xc4, 0x74, 0xa3, 0x71, 0x2f, 0x09, 0x87, 0x03, 0x91, 0x6b, 0x6e, 0x27, 0x85,
+	0x8c, 0xc5, 0xa0, 0x68, 0xaf, 0xea, 0x43, 0xbc, 0xee, 0x06, 0x9d, 0x44, 0xa2, 0x54, 0xba, 0xfd,
+	0x7d, 0xd6, 0x72, 0x02, 0xfa, 0x4a, 0x58, 0xe2, 0xad, 0x12, 0xb4, 0xa0, 0xd2, 0x60, 0x7c, 0x76,
+	0x66, 0x0d, 0x0d, 0x81, 0x0a, 0x16, 0xe0, 0xcc, 0x57, 0xf8, 0x80, 0xad, 0x55, 0x93, 0x51, 0xdf,
+	0x6d, 0xd0, 0x15, 0xca, 0x8b, 0x8a, 0x28, 0x9d, 0x2c, 0xb6, 0x00, 0xcb, 0x3b, 0xcb, 0x65, 0xe9,
+	0xb6, 0x8c, 0x50, 0xc5, 0xd3, 0x22, 0x3d, 0xf6, 0x1f, 0xd1, 0xfc, 0x65, 0x5e, 0x5c, 0xd5, 0xe4,
+	0x75, 0x06, 0xd6, 0x27, 0x75, 0xec, 0xff, 0xa6, 0xc6, 0x5a, 0x4e, 0xaa, 0x5c, 0x55, 0xeb, 0x7a,
+	0xae, 0xba, 0x33, 0x17, 0x9c, 0x73, 0x37, 0xf8, 0x50, 0x19, 0x2c, 0xe4, 0x09, 0x43, 0x68, 0x6e,
+	0x94, 0x00, 0x77, 0x81, 0xb8, 0x48, 0x60, 0x9f, 0x7e, 0x06, 0xe0, 0x60, 0x57, 0x0d, 0xa4, 0xce,
+	0xfe, 0x55, 0x51, 0xd2, 0xf6, 0xb6, 0x6e, 0xcc, 0xdc, 0xd6, 0x4b, 0xe5, 0x6d, 0xed, 0x7f, 0xe4,
+	0xd1, 0xf1, 0x26, 0x35, 0xf5, 0xbd, 0x49, 0xd6, 0xd7, 0x66, 0x2a, 0xd7, 0x48, 0x4c, 0x81, 0x4d,
+	0xe7, 0x3e, 0x22, 0x6b, 0x09, 0x80, 0xe5, 0x92, 0xa0, 0x9e, 0x5b, 0x2d, 0x46, 0x20, 0x48, 0x01,
+	0x42, 0xe1, 0x75, 0x7b, 0x87, 0x04, 0xf6, 0xd6, 0x5c, 0x18, 0xd6, 0x3b, 0x14, 0x28, 0xe2, 0x5f,
+	0x02, 0x67, 0x21, 0x78, 0x30, 0x8d, 0xe0, 0x9a, 0xa3, 0x82, 0x6c, 0xa1, 0x85, 0x58, 0x6d, 0x9b,
+	0xb1, 0x0a, 0x4f, 0xb7, 0xf7, 0xf5, 0xe1, 0xab, 0xd5, 0x46, 0x12, 0x61, 0x55, 0xf8, 0x33, 0xb6,
+	0xb6, 0x35, 0x1e, 0x49, 0xa8, 0x27, 0xb9, 0x63, 0xe0, 0x9c, 0x69, 0x07, 0x6d, 0xc7, 0xa8, 0xa2,
+	0x60, 0x7b, 0x77, 0xd5, 0x0a, 0x57, 0xdd, 0x93, 0xc5, 0x85, 0xca, 0x4e, 0x09, 0x47, 0xba, 0xab,
+	0x92, 0x44, 0x58, 0x15, 0x80, 0x9e, 0x4b, 0x87, 0x49, 0x34, 0x8c, 0x00, 0x57, 0x18, 0xf4, 0x78,
+	0x7f, 0x5e, 0x53, 0x5b, 0x27, 0x1d, 0xd3, 0x12, 0xad, 0xc5, 0xed, 0xc7, 0x6c, 0xc5, 0x15, 0xbc,
+	0x52, 0x69, 0xfd, 0xdd, 0xa6, 0x1f, 0xf9, 0xfc, 0x26, 0xde, 0x0a, 0x70, 0x00, 0x6d, 0xed, 0x09,
+	0x43, 0x60, 0x51, 0xc0, 0x26, 0x64, 0x76, 0x6e, 0x9a, 0x4f, 0xdd, 0xa0, 0x4a, 0x87, 0xa5, 0x8b,
+	0xe2, 0x22, 0x48, 0x29, 0x1b, 0xf5, 0x18, 0x4b, 0xec, 0x5d, 0xc0, 0x6a, 0xd0, 0x3e, 0x4c, 0x36,
+	0x12, 0x85, 0x30, 0xca, 0x8c, 0x0e, 0xba, 0x3d, 0x1d, 0x12, 0x4f, 0x4c, 0x18, 0xd8, 0x78, 0xd0,
+	0x1a, 0x8a, 0x14, 0x9f, 0x78, 0x0d, 0x8d, 0x7d, 0x1c, 0x0e, 0xff, 0x3a, 0xbb, 0xfe, 0x24, 0xca,
+	0x11, 0x8f, 0x01, 0xaa, 0x7d, 0x37, 0x8a, 0x63, 0xb8, 0x50, 0x97, 0x34, 0xf8, 0x98, 0xe1, 0xfb,
+	0x7f, 0xad, 0xb1, 0xa6, 0xcd, 0x18, 0xdc, 0x4e, 0xff, 0x24, 0xc8, 0x74, 0xc6, 0xe2, 0xa4, 0x44,
+	0xe1, 0x91, 0x7f, 0x32, 0x86, 0x87, 0x0e, 0x1d, 0xcb, 0x10, 0xa8, 0x0d, 0xd0, 0x2e, 0x52, 0x03,
+	0x82, 0x5f, 0x44, 0x21, 0x14, 0x87, 0x46, 0x19, 0x23, 0x2a, 0x98, 0x60, 0x08, 0xb4, 0x9b, 0x66,
+	0x23, 0xc6, 0xb5, 0x2c, 0x9a, 0x69, 0x51, 0xcf, 0x34, 0xc5, 0x45, 0xd7, 0x75, 0xd3, 0x71, 0x4e,
+	0x2f, 0x11, 0x3d, 0x46, 0x1e, 0x04, 0xc4, 0x3c, 0x41, 0x80, 0x87, 0x63, 0xff, 0x82, 0xe0, 0xee,
+	0x91, 0x06, 0xe1, 0xd4, 0x2e, 0xca, 0x36, 0x50, 0x9b, 0xdb, 0x06, 0xea, 0x6e, 0x1b, 0x80, 0x23,
+	0x19, 0x5b, 0x6a, 0x5d, 0x44, 0xa1, 0xc7, 0x77, 0x64, 0x30, 0x24, 0xd9, 0x82, 0x96, 0x39, 0x1c,
+	0xff, 0x90, 0xbd, 0xae, 0x17, 0x3e, 0x38, 0x81, 0x37, 0x7a, 0x11, 0xcb, 0xcf, 0xb0, 0x34, 0x9c,
+	0x47, 0x00, 0x28, 0xb2, 0x50, 0x16, 0xc7, 0xfe, 0xbf, 0x3d, 0xb6, 0xe2, 0xd6, 0xa0, 0xb3, 0xbf,
+	0xda, 0xff, 0xd9, 0x5f, 0x7d, 0x7a, 0x7f, 0xbc, 0xc3, 0x56, 0x5c, 0x9f, 0xcc, 0x81, 0x12, 0xae,
+	0x98, 0xea, 0xb5, 0x62, 0xc2, 0x0f, 0xd9, 0x1b, 0xf6, 0x74, 0x78, 0x0d, 0x6e, 0xa6, 0x39, 0xcd,
+	0x65, 0xf0, 0xe1, 0x17, 0x9c, 0xb9, 0xaa, 0x5e, 0xa0, 0xd9, 0xe6, 0x5b, 0xf3, 0x23, 0x76, 0xcb,
+	0x0a, 0x8e, 0xe0, 0x11, 0x21, 0x27, 0xf3, 0x2e, 0x7e, 0xba, 0x79, 0xaf, 0x30, 0x77, 0x27, 0xc6,
+	0x15, 0xb7, 0xf7, 0x7b, 0x7d, 0x9a, 0xb8, 0xf1, 0x8a, 0x13, 0x57, 0xcd, 0xf9, 0xcf, 0xd8, 0x9b,
+	0x95, 0x25, 0x9d, 0x99, 0x97, 0x3e, 0xdd, 0xcc, 0x57, 0xd9, 0xfb, 0xf7, 0xd9, 0x72, 0xd9, 0x9a,
+	0xe7, 0xf7, 0x19, 0xff, 0x97, 0xf6, 0x49, 0xe7, 0xde, 0x20, 0xa8, 0xdb, 0x89, 0x63, 0x75, 0x41,
+	0xff, 0x0e, 0x0c, 0xf1, 0xb9, 0x2f, 0x45, 0xc8, 0xb6, 0x4e, 0xa8, 0x7f, 0x23, 0x19, 0x40, 0x48,
+	0x94, 0x1f, 0x53, 0x56, 0xda, 0xd6, 0x0c, 0x10, 0xba, 0x1b, 0x07, 0x79, 0x5e, 0x22, 0x05, 0x4b,
+	0xf2, 0x4d, 0xc6, 0xe0, 0x8d, 0xae, 0x32, 0xf3, 0xb7, 0xc0, 0x20, 0xdf, 0x3b, 0x53, 0x20, 0x28,
+	0x1b, 0x02, 0x18, 0x20, 0xad, 0x4b, 0x8b, 0x1e, 0x27, 0x56, 0xfe, 0x33, 0xc6, 0x67, 0xaf, 0x14,
+	0xbc, 0xb0, 0x7b, 0x40, 0xe4, 0x08, 0x33, 0x4c, 0x1b, 0x2f, 0xe9, 0x89, 0xe7, 0xcc, 0x53, 0x91,
+	0x3c, 0xb7, 0xc5, 0x6e, 0xcd, 0x5f, 0x13, 0xfd, 0x84, 0xa8, 0xc4, 0x02, 0x0a, 0x1c, 0xeb, 0xf9,
+	0x49, 0x4e, 0xf5, 0x54, 0xd2, 0xfe, 0x7f, 0x6a, 0xe4, 0x00, 0x8b, 0x3f, 0x01, 0x2f, 0x3e, 0x91,
+	0xc3, 0x60, 0x1c, 0x17, 0x9d, 0xd0, 0x79, 0x6b, 0x56, 0x99, 0xa8, 0xd5, 0xc9, 0xc2, 0x13, 0x08,
+	0x79, 0x58, 0x8c, 0x33, 0x69, 0x1f, 0x26, 0x55, 0x26, 0x6e, 0xfe, 0x59, 0x1c, 0x8c, 0x72, 0x7a,
+	0xa3, 0x18, 0x82, 0x7f, 0x87, 0x35, 0x11, 0x1a, 0x06, 0x71, 0x9c, 0x53, 0xc1, 0x55, 0x00, 0xb1,
+	0x11, 0xd9, 0xb7, 0x9c, 0xd5, 0xc4, 0x1f, 0x19, 0x3b, 0x51, 0x5e, 0x48, 0x40, 0x65, 0x0e, 0xae,
+	0xaf, 0xf0, 0xf0, 0xb2, 0xb0, 0xf4, 0xae, 0x2c, 0x82, 0x01, 0xfc, 0xbc, 0xa4, 0x3e, 0x3b, 0xc3,
+	0xf7, 0x23, 0x76, 0xcd, 0x3d, 0x37, 0xbc, 0xa8, 0x70, 0xbb, 0xdb, 0xc9, 0x40, 0xbe, 0xa0, 0x1b,
+	0xc3, 0x10, 0xc8, 0x7d, 0xaf, 0xbc, 0x4d, 0x81, 0xab, 0x09, 0xf4, 0xa9, 0x1e, 0x1c, 0x5c, 0x28,
+	0x6a, 0x73, 0x25, 0xcd, 0xd7, 0x58, 0x7d, 0x3f, 0xa5, 0x1f, 0x18, 0x30, 0xf2, 0x7f, 0x5d, 0xfa,
+	0xd8, 0x1c, 0x06, 0xa7, 0xd4, 0xd0, 0x91, 0x7e, 0x59, 0x18, 0xc2, 0xa4, 0x68, 0x79, 0xe3, 0xea,
+	0x14, 0xd5, 0xbe, 0xbe, 0xc3, 0x9a, 0x32, 0xcb, 0x20, 0x89, 0x25, 0xb5, 0xf2, 0x2d, 0xf0, 0x8b,
+	0xe5, 0x00, 0x32, 0x9f, 0xfc, 0xfe, 0x6a, 0x3d, 0x78, 0x63, 0xf6, 0x69, 0x01, 0x52, 0xfb, 0xbf,
+	0x0c, 0x15, 0x21, 0x8d, 0x9b, 0x4f, 0xd1, 0x58, 0xc8, 0xc2, 0xff, 0x2e, 0x5b, 0xad, 0x00, 0x78,
+	0x8c, 0xeb, 0xce, 0xc3, 0x6e, 0x10, 0x9e, 0xc8, 0x3e, 0x7c, 0x67, 0x81, 0x8d, 0x7e, 0x85, 0xb9,
+	0xf9, 0xab, 0xda, 0xcb, 0x8f, 0xef, 0xbe, 0xf6, 0x11, 0x7c, 0xff, 0xfd, 0xf8, 0x6e, 0xed, 0x17,
+	0xff, 0xba, 0x5b, 0xfb, 0x1d, 0x7c, 0x7f, 0x80, 0xef, 0x4f, 0xf0, 0xbd, 0x84, 0xef, 0x6f, 0xf0,
+	0xfd, 0x13, 0xbe, 0xf7, 0x3f, 0x78, 0xc5, 0x9f, 0xcb, 0x99, 0xb9, 0x4e, 0x37, 0xce, 0xa3, 0xac,
+	0x70, 0x44, 0xe9, 0xe9, 0x68, 0x03, 0x4a, 0x22, 0x29, 0x9c, 0x1f, 0xcf, 0x78, 0xd2, 0xe3, 0x86,
+	0xa6, 0x1f, 0xfe, 0x0f, 0x3b, 0x8f, 0xf3, 0xf9, 0xc5, 0x16, 0x00, 0x00,
 }
 
 func (this *Spec) Equal(that interface{}) bool {
@@ -2498,6 +2505,14 @@ func (this *LinuxResources) Equal(that interface{}) bool {
 	if !this.Network.Equal(that1.Network) {
 		return false
 	}
+	if len(this.Unified) != len(that1.Unified) {
+		return false
+	}
+	for i := range this.Unified {
+		if this.Unified[i] != that1.Unified[i] {
+			return false
+		}
+	}
 	if !bytes.Equal(this.XXX_unrecognized, that1.XXX_unrecognized) {
 		return false
 	}
@@ -2939,6 +2954,12 @@ func (this *LinuxSeccomp) Equal(that interface{}) bool {
 			return false
 		}
 	}
+	if this.ListenerPath != that1.ListenerPath {
+		return false
+	}
+	if this.ListenerMetadata != that1.ListenerMetadata {
+		return false
+	}
 	if !bytes.Equal(this.XXX_unrecognized, that1.XXX_unrecognized) {
 		return false
 	}
@@ -4270,6 +4291,25 @@ func (m *LinuxResources) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if len(m.Unified) > 0 {
+		for k := range m.Unified {
+			v := m.Unified[k]
+			baseI := i
+			i -= len(v)
+			copy(dAtA[i:], v)
+			i = encodeVarintOci(dAtA, i, uint64(len(v)))
+			i--
+			dAtA[i] = 0x12
+			i -= len(k)
+			copy(dAtA[i:], k)
+			i = encodeVarintOci(dAtA, i, uint64(len(k)))
+			i--
+			dAtA[i] = 0xa
+			i = encodeVarintOci(dAtA, i, uint64(baseI-i))
+			i--
+			dAtA[i] = 0x42
+		}
+	}
 	if m.Network != nil {
 		{
 			size, err := m.Network.MarshalToSizedBuffer(dAtA[:i])
@@ -4931,6 +4971,20 @@ func (m *LinuxSeccomp) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if len(m.ListenerMetadata) > 0 {
+		i -= len(m.ListenerMetadata)
+		copy(dAtA[i:], m.ListenerMetadata)
+		i = encodeVarintOci(dAtA, i, uint64(len(m.ListenerMetadata)))
+		i--
+		dAtA[i] = 0x32
+	}
+	if len(m.ListenerPath) > 0 {
+		i -= len(m.ListenerPath)
+		copy(dAtA[i:], m.ListenerPath)
+		i = encodeVarintOci(dAtA, i, uint64(len(m.ListenerPath)))
+		i--
+		dAtA[i] = 0x2a
+	}
 	if len(m.Syscalls) > 0 {
 		for iNdEx := len(m.Syscalls) - 1; iNdEx >= 0; iNdEx-- {
 			{
@@ -5568,8 +5622,15 @@ func NewPopulatedLinuxResources(r randyOci, easy bool) *LinuxResources {
 	if r.Intn(5) != 0 {
 		this.Network = NewPopulatedLinuxNetwork(r, easy)
 	}
+	if r.Intn(5) != 0 {
+		v45 := r.Intn(10)
+		this.Unified = make(map[string]string)
+		for i := 0; i < v45; i++ {
+			this.Unified[randStringOci(r)] = randStringOci(r)
+		}
+	}
 	if !easy && r.Intn(10) != 0 {
-		this.XXX_unrecognized = randUnrecognizedOci(r, 8)
+		this.XXX_unrecognized = randUnrecognizedOci(r, 9)
 	}
 	return this
 }
@@ -5665,43 +5726,43 @@ func NewPopulatedLinuxBlockIO(r randyOci, easy bool) *LinuxBlockIO {
 	this.Weight = uint32(r.Uint32())
 	this.LeafWeight = uint32(r.Uint32())
 	if r.Intn(5) != 0 {
-		v45 := r.Intn(5)
-		this.WeightDevice = make([]LinuxWeightDevice, v45)
-		for i := 0; i < v45; i++ {
-			v46 := NewPopulatedLinuxWeightDevice(r, easy)
-			this.WeightDevice[i] = *v46
+		v46 := r.Intn(5)
+		this.WeightDevice = make([]LinuxWeightDevice, v46)
+		for i := 0; i < v46; i++ {
+			v47 := NewPopulatedLinuxWeightDevice(r, easy)
+			this.WeightDevice[i] = *v47
 		}
 	}
 	if r.Intn(5) != 0 {
-		v47 := r.Intn(5)
-		this.ThrottleReadBpsDevice = make([]LinuxThrottleDevice, v47)
-		for i := 0; i < v47; i++ {
-			v48 := NewPopulatedLinuxThrottleDevice(r, easy)
-			this.ThrottleReadBpsDevice[i] = *v48
+		v48 := r.Intn(5)
+		this.ThrottleReadBpsDevice = make([]LinuxThrottleDevice, v48)
+		for i := 0; i < v48; i++ {
+			v49 := NewPopulatedLinuxThrottleDevice(r, easy)
+			this.ThrottleReadBpsDevice[i] = *v49
 		}
 	}
 	if r.Intn(5) != 0 {
-		v49 := r.Intn(5)
-		this.ThrottleWriteBpsDevice = make([]LinuxThrottleDevice, v49)
-		for i := 0; i < v49; i++ {
-			v50 := NewPopulatedLinuxThrottleDevice(r, easy)
-			this.ThrottleWriteBpsDevice[i] = *v50
+		v50 := r.Intn(5)
+		this.ThrottleWriteBpsDevice = make([]LinuxThrottleDevice, v50)
+		for i := 0; i < v50; i++ {
+			v51 := NewPopulatedLinuxThrottleDevice(r, easy)
+			this.ThrottleWriteBpsDevice[i] = *v51
 		}
 	}
 	if r.Intn(5) != 0 {
-		v51 := r.Intn(5)
-		this.ThrottleReadIOPSDevice = make([]LinuxThrottleDevice, v51)
-		for i := 0; i < v51; i++ {
-			v52 := NewPopulatedLinuxThrottleDevice(r, easy)
-			this.ThrottleReadIOPSDevice[i] = *v52
+		v52 := r.Intn(5)
+		this.ThrottleReadIOPSDevice = make([]LinuxThrottleDevice, v52)
+		for i := 0; i < v52; i++ {
+			v53 := NewPopulatedLinuxThrottleDevice(r, easy)
+			this.ThrottleReadIOPSDevice[i] = *v53
 		}
 	}
 	if r.Intn(5) != 0 {
-		v53 := r.Intn(5)
-		this.ThrottleWriteIOPSDevice = make([]LinuxThrottleDevice, v53)
-		for i := 0; i < v53; i++ {
-			v54 := NewPopulatedLinuxThrottleDevice(r, easy)
-			this.ThrottleWriteIOPSDevice[i] = *v54
+		v54 := r.Intn(5)
+		this.ThrottleWriteIOPSDevice = make([]LinuxThrottleDevice, v54)
+		for i := 0; i < v54; i++ {
+			v55 := NewPopulatedLinuxThrottleDevice(r, easy)
+			this.ThrottleWriteIOPSDevice[i] = *v55
 		}
 	}
 	if !easy && r.Intn(10) != 0 {
@@ -5745,11 +5806,11 @@ func NewPopulatedLinuxNetwork(r randyOci, easy bool) *LinuxNetwork {
 	this := &LinuxNetwork{}
 	this.ClassID = uint32(r.Uint32())
 	if r.Intn(5) != 0 {
-		v55 := r.Intn(5)
-		this.Priorities = make([]LinuxInterfacePriority, v55)
-		for i := 0; i < v55; i++ {
-			v56 := NewPopulatedLinuxInterfacePriority(r, easy)
-			this.Priorities[i] = *v56
+		v56 := r.Intn(5)
+		this.Priorities = make([]LinuxInterfacePriority, v56)
+		for i := 0; i < v56; i++ {
+			v57 := NewPopulatedLinuxInterfacePriority(r, easy)
+			this.Priorities[i] = *v57
 		}
 	}
 	if !easy && r.Intn(10) != 0 {
@@ -5781,26 +5842,28 @@ func NewPopulatedLinuxInterfacePriority(r randyOci, easy bool) *LinuxInterfacePr
 func NewPopulatedLinuxSeccomp(r randyOci, easy bool) *LinuxSeccomp {
 	this := &LinuxSeccomp{}
 	this.DefaultAction = string(randStringOci(r))
-	v57 := r.Intn(10)
-	this.Architectures = make([]string, v57)
-	for i := 0; i < v57; i++ {
-		this.Architectures[i] = string(randStringOci(r))
-	}
 	v58 := r.Intn(10)
-	this.Flags = make([]string, v58)
+	this.Architectures = make([]string, v58)
 	for i := 0; i < v58; i++ {
+		this.Architectures[i] = string(randStringOci(r))
+	}
+	v59 := r.Intn(10)
+	this.Flags = make([]string, v59)
+	for i := 0; i < v59; i++ {
 		this.Flags[i] = string(randStringOci(r))
 	}
 	if r.Intn(5) != 0 {
-		v59 := r.Intn(5)
-		this.Syscalls = make([]LinuxSyscall, v59)
-		for i := 0; i < v59; i++ {
-			v60 := NewPopulatedLinuxSyscall(r, easy)
-			this.Syscalls[i] = *v60
+		v60 := r.Intn(5)
+		this.Syscalls = make([]LinuxSyscall, v60)
+		for i := 0; i < v60; i++ {
+			v61 := NewPopulatedLinuxSyscall(r, easy)
+			this.Syscalls[i] = *v61
 		}
 	}
+	this.ListenerPath = string(randStringOci(r))
+	this.ListenerMetadata = string(randStringOci(r))
 	if !easy && r.Intn(10) != 0 {
-		this.XXX_unrecognized = randUnrecognizedOci(r, 5)
+		this.XXX_unrecognized = randUnrecognizedOci(r, 7)
 	}
 	return this
 }
@@ -5819,9 +5882,9 @@ func NewPopulatedLinuxSeccompArg(r randyOci, easy bool) *LinuxSeccompArg {
 
 func NewPopulatedLinuxSyscall(r randyOci, easy bool) *LinuxSyscall {
 	this := &LinuxSyscall{}
-	v61 := r.Intn(10)
-	this.Names = make([]string, v61)
-	for i := 0; i < v61; i++ {
+	v62 := r.Intn(10)
+	this.Names = make([]string, v62)
+	for i := 0; i < v62; i++ {
 		this.Names[i] = string(randStringOci(r))
 	}
 	this.Action = string(randStringOci(r))
@@ -5831,11 +5894,11 @@ func NewPopulatedLinuxSyscall(r randyOci, easy bool) *LinuxSyscall {
 		this.ErrnoRet = NewPopulatedLinuxSyscall_Errnoret(r, easy)
 	}
 	if r.Intn(5) != 0 {
-		v62 := r.Intn(5)
-		this.Args = make([]LinuxSeccompArg, v62)
-		for i := 0; i < v62; i++ {
-			v63 := NewPopulatedLinuxSeccompArg(r, easy)
-			this.Args[i] = *v63
+		v63 := r.Intn(5)
+		this.Args = make([]LinuxSeccompArg, v63)
+		for i := 0; i < v63; i++ {
+			v64 := NewPopulatedLinuxSeccompArg(r, easy)
+			this.Args[i] = *v64
 		}
 	}
 	if !easy && r.Intn(10) != 0 {
@@ -5877,9 +5940,9 @@ func randUTF8RuneOci(r randyOci) rune {
 	return rune(ru + 61)
 }
 func randStringOci(r randyOci) string {
-	v64 := r.Intn(100)
-	tmps := make([]rune, v64)
-	for i := 0; i < v64; i++ {
+	v65 := r.Intn(100)
+	tmps := make([]rune, v65)
+	for i := 0; i < v65; i++ {
 		tmps[i] = randUTF8RuneOci(r)
 	}
 	return string(tmps)
@@ -5901,11 +5964,11 @@ func randFieldOci(dAtA []byte, r randyOci, fieldNumber int, wire int) []byte {
 	switch wire {
 	case 0:
 		dAtA = encodeVarintPopulateOci(dAtA, uint64(key))
-		v65 := r.Int63()
+		v66 := r.Int63()
 		if r.Intn(2) == 0 {
-			v65 *= -1
+			v66 *= -1
 		}
-		dAtA = encodeVarintPopulateOci(dAtA, uint64(v65))
+		dAtA = encodeVarintPopulateOci(dAtA, uint64(v66))
 	case 1:
 		dAtA = encodeVarintPopulateOci(dAtA, uint64(key))
 		dAtA = append(dAtA, byte(r.Intn(256)), byte(r.Intn(256)), byte(r.Intn(256)), byte(r.Intn(256)), byte(r.Intn(256)), byte(r.Intn(256)), byte(r.Intn(256)), byte(r.Intn(256)))
@@ -6514,6 +6577,14 @@ func (m *LinuxResources) Size() (n int) {
 		l = m.Network.Size()
 		n += 1 + l + sovOci(uint64(l))
 	}
+	if len(m.Unified) > 0 {
+		for k, v := range m.Unified {
+			_ = k
+			_ = v
+			mapEntrySize := 1 + len(k) + sovOci(uint64(len(k))) + 1 + len(v) + sovOci(uint64(len(v)))
+			n += mapEntrySize + 1 + sovOci(uint64(mapEntrySize))
+		}
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -6812,6 +6883,14 @@ func (m *LinuxSeccomp) Size() (n int) {
 			n += 1 + l + sovOci(uint64(l))
 		}
 	}
+	l = len(m.ListenerPath)
+	if l > 0 {
+		n += 1 + l + sovOci(uint64(l))
+	}
+	l = len(m.ListenerMetadata)
+	if l > 0 {
+		n += 1 + l + sovOci(uint64(l))
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -7238,6 +7317,16 @@ func (this *LinuxResources) String() string {
 		repeatedStringForHugepageLimits += strings.Replace(strings.Replace(f.String(), "LinuxHugepageLimit", "LinuxHugepageLimit", 1), `&`, ``, 1) + ","
 	}
 	repeatedStringForHugepageLimits += "}"
+	keysForUnified := make([]string, 0, len(this.Unified))
+	for k, _ := range this.Unified {
+		keysForUnified = append(keysForUnified, k)
+	}
+	github_com_gogo_protobuf_sortkeys.Strings(keysForUnified)
+	mapStringForUnified := "map[string]string{"
+	for _, k := range keysForUnified {
+		mapStringForUnified += fmt.Sprintf("%v: %v,", k, this.Unified[k])
+	}
+	mapStringForUnified += "}"
 	s := strings.Join([]string{`&LinuxResources{`,
 		`Devices:` + repeatedStringForDevices + `,`,
 		`Memory:` + strings.Replace(this.Memory.String(), "LinuxMemory", "LinuxMemory", 1) + `,`,
@@ -7246,6 +7335,7 @@ func (this *LinuxResources) String() string {
 		`BlockIO:` + strings.Replace(this.BlockIO.String(), "LinuxBlockIO", "LinuxBlockIO", 1) + `,`,
 		`HugepageLimits:` + repeatedStringForHugepageLimits + `,`,
 		`Network:` + strings.Replace(this.Network.String(), "LinuxNetwork", "LinuxNetwork", 1) + `,`,
+		`Unified:` + mapStringForUnified + `,`,
 		`XXX_unrecognized:` + fmt.Sprintf("%v", this.XXX_unrecognized) + `,`,
 		`}`,
 	}, "")
@@ -7435,6 +7525,8 @@ func (this *LinuxSeccomp) String() string {
 		`Architectures:` + fmt.Sprintf("%v", this.Architectures) + `,`,
 		`Flags:` + fmt.Sprintf("%v", this.Flags) + `,`,
 		`Syscalls:` + repeatedStringForSyscalls + `,`,
+		`ListenerPath:` + fmt.Sprintf("%v", this.ListenerPath) + `,`,
+		`ListenerMetadata:` + fmt.Sprintf("%v", this.ListenerMetadata) + `,`,
 		`XXX_unrecognized:` + fmt.Sprintf("%v", this.XXX_unrecognized) + `,`,
 		`}`,
 	}, "")
@@ -11182,6 +11274,133 @@ func (m *LinuxResources) Unmarshal(dAtA []byte) error {
 				return err
 			}
 			iNdEx = postIndex
+		case 8:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Unified", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowOci
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthOci
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthOci
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Unified == nil {
+				m.Unified = make(map[string]string)
+			}
+			var mapkey string
+			var mapvalue string
+			for iNdEx < postIndex {
+				entryPreIndex := iNdEx
+				var wire uint64
+				for shift := uint(0); ; shift += 7 {
+					if shift >= 64 {
+						return ErrIntOverflowOci
+					}
+					if iNdEx >= l {
+						return io.ErrUnexpectedEOF
+					}
+					b := dAtA[iNdEx]
+					iNdEx++
+					wire |= uint64(b&0x7F) << shift
+					if b < 0x80 {
+						break
+					}
+				}
+				fieldNum := int32(wire >> 3)
+				if fieldNum == 1 {
+					var stringLenmapkey uint64
+					for shift := uint(0); ; shift += 7 {
+						if shift >= 64 {
+							return ErrIntOverflowOci
+						}
+						if iNdEx >= l {
+							return io.ErrUnexpectedEOF
+						}
+						b := dAtA[iNdEx]
+						iNdEx++
+						stringLenmapkey |= uint64(b&0x7F) << shift
+						if b < 0x80 {
+							break
+						}
+					}
+					intStringLenmapkey := int(stringLenmapkey)
+					if intStringLenmapkey < 0 {
+						return ErrInvalidLengthOci
+					}
+					postStringIndexmapkey := iNdEx + intStringLenmapkey
+					if postStringIndexmapkey < 0 {
+						return ErrInvalidLengthOci
+					}
+					if postStringIndexmapkey > l {
+						return io.ErrUnexpectedEOF
+					}
+					mapkey = string(dAtA[iNdEx:postStringIndexmapkey])
+					iNdEx = postStringIndexmapkey
+				} else if fieldNum == 2 {
+					var stringLenmapvalue uint64
+					for shift := uint(0); ; shift += 7 {
+						if shift >= 64 {
+							return ErrIntOverflowOci
+						}
+						if iNdEx >= l {
+							return io.ErrUnexpectedEOF
+						}
+						b := dAtA[iNdEx]
+						iNdEx++
+						stringLenmapvalue |= uint64(b&0x7F) << shift
+						if b < 0x80 {
+							break
+						}
+					}
+					intStringLenmapvalue := int(stringLenmapvalue)
+					if intStringLenmapvalue < 0 {
+						return ErrInvalidLengthOci
+					}
+					postStringIndexmapvalue := iNdEx + intStringLenmapvalue
+					if postStringIndexmapvalue < 0 {
+						return ErrInvalidLengthOci
+					}
+					if postStringIndexmapvalue > l {
+						return io.ErrUnexpectedEOF
+					}
+					mapvalue = string(dAtA[iNdEx:postStringIndexmapvalue])
+					iNdEx = postStringIndexmapvalue
+				} else {
+					iNdEx = entryPreIndex
+					skippy, err := skipOci(dAtA[iNdEx:])
+					if err != nil {
+						return err
+					}
+					if (skippy < 0) || (iNdEx+skippy) < 0 {
+						return ErrInvalidLengthOci
+					}
+					if (iNdEx + skippy) > postIndex {
+						return io.ErrUnexpectedEOF
+					}
+					iNdEx += skippy
+				}
+			}
+			m.Unified[mapkey] = mapvalue
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipOci(dAtA[iNdEx:])
@@ -12803,6 +13022,70 @@ func (m *LinuxSeccomp) Unmarshal(dAtA []byte) error {
 				return err
 			}
 			iNdEx = postIndex
+		case 5:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ListenerPath", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowOci
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthOci
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthOci
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.ListenerPath = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
+		case 6:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field ListenerMetadata", wireType)
+			}
+			var stringLen uint64
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowOci
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				stringLen |= uint64(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			intStringLen := int(stringLen)
+			if intStringLen < 0 {
+				return ErrInvalidLengthOci
+			}
+			postIndex := iNdEx + intStringLen
+			if postIndex < 0 {
+				return ErrInvalidLengthOci
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.ListenerMetadata = string(dAtA[iNdEx:postIndex])
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipOci(dAtA[iNdEx:])
@@ -131,11 +131,14 @@ type Interface struct {
 	// The expected values are the one that are defined by the netlink
 	// library, regarding each type of link. Here is a non exhaustive
 	// list: "veth", "macvtap", "vlan", "macvlan", "tap", ...
-	Type                 string   `protobuf:"bytes,7,opt,name=type,proto3" json:"type,omitempty"`
-	RawFlags             uint32   `protobuf:"varint,8,opt,name=raw_flags,json=rawFlags,proto3" json:"raw_flags,omitempty"`
-	XXX_NoUnkeyedLiteral struct{} `json:"-"`
-	XXX_unrecognized     []byte   `json:"-"`
-	XXX_sizecache        int32    `json:"-"`
+	Type     string `protobuf:"bytes,7,opt,name=type,proto3" json:"type,omitempty"`
+	RawFlags uint32 `protobuf:"varint,8,opt,name=raw_flags,json=rawFlags,proto3" json:"raw_flags,omitempty"`
+	// IPv6 settings of the host link, applied to the guest link. Not set
+	// when IPv6 is disabled on the host.
+	Ipv6Sysctls          *IPv6Sysctls `protobuf:"bytes,9,opt,name=ipv6_sysctls,json=ipv6Sysctls,proto3" json:"ipv6_sysctls,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}     `json:"-"`
+	XXX_unrecognized     []byte       `json:"-"`
+	XXX_sizecache        int32        `json:"-"`
 }
 
 func (m *Interface) Reset()      { *m = Interface{} }
@@ -170,6 +173,48 @@ func (m *Interface) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_Interface proto.InternalMessageInfo
 
+// The net.ipv6.conf.<link> sysctls of a link, with their sysctl values.
+type IPv6Sysctls struct {
+	DisableIpv6          uint32   `protobuf:"varint,1,opt,name=disable_ipv6,json=disableIpv6,proto3" json:"disable_ipv6,omitempty"`
+	AcceptRa             uint32   `protobuf:"varint,2,opt,name=accept_ra,json=acceptRa,proto3" json:"accept_ra,omitempty"`
+	Forwarding           uint32   `protobuf:"varint,3,opt,name=forwarding,proto3" json:"forwarding,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *IPv6Sysctls) Reset()      { *m = IPv6Sysctls{} }
+func (*IPv6Sysctls) ProtoMessage() {}
+func (*IPv6Sysctls) Descriptor() ([]byte, []int) {
+	return fileDescriptor_f715d0876e8f65d3, []int{2}
+}
+func (m *IPv6Sysctls) XXX_Unmarshal(b []byte) error {
+	return m.Unmarshal(b)
+}
+func (m *IPv6Sysctls) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	if deterministic {
+		return xxx_messageInfo_IPv6Sysctls.Marshal(b, m, deterministic)
+	} else {
+		b = b[:cap(b)]
+		n, err := m.MarshalToSizedBuffer(b)
+		if err != nil {
+			return nil, err
+		}
+		return b[:n], nil
+	}
+}
+func (m *IPv6Sysctls) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_IPv6Sysctls.Merge(m, src)
+}
+func (m *IPv6Sysctls) XXX_Size() int {
+	return m.Size()
+}
+func (m *IPv6Sysctls) XXX_DiscardUnknown() {
+	xxx_messageInfo_IPv6Sysctls.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_IPv6Sysctls proto.InternalMessageInfo
+
 type Route struct {
 	Dest                 string   `protobuf:"bytes,1,opt,name=dest,proto3" json:"dest,omitempty"`
 	Gateway              string   `protobuf:"bytes,2,opt,name=gateway,proto3" json:"gateway,omitempty"`
@@ -185,7 +230,7 @@ type Route struct {
 func (m *Route) Reset()      { *m = Route{} }
 func (*Route) ProtoMessage() {}
 func (*Route) Descriptor() ([]byte, []int) {
-	return fileDescriptor_f715d0876e8f65d3, []int{2}
+	return fileDescriptor_f715d0876e8f65d3, []int{3}
 }
 func (m *Route) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -228,7 +273,7 @@ type ARPNeighbor struct {
 func (m *ARPNeighbor) Reset()      { *m = ARPNeighbor{} }
 func (*ARPNeighbor) ProtoMessage() {}
 func (*ARPNeighbor) Descriptor() ([]byte, []int) {
-	return fileDescriptor_f715d0876e8f65d3, []int{3}
+	return fileDescriptor_f715d0876e8f65d3, []int{4}
 }
 func (m *ARPNeighbor) XXX_Unmarshal(b []byte) error {
 	return m.Unmarshal(b)
@@ -262,6 +307,7 @@ func init() {
 	proto.RegisterEnum("types.FSGroupChangePolicy", FSGroupChangePolicy_name, FSGroupChangePolicy_value)
 	proto.RegisterType((*IPAddress)(nil), "types.IPAddress")
 	proto.RegisterType((*Interface)(nil), "types.Interface")
+	proto.RegisterType((*IPv6Sysctls)(nil), "types.IPv6Sysctls")
 	proto.RegisterType((*Route)(nil), "types.Route")
 	proto.RegisterType((*ARPNeighbor)(nil), "types.ARPNeighbor")
 }
@@ -271,40 +317,45 @@ func init() {
 }
 
 var fileDescriptor_f715d0876e8f65d3 = []byte{
-	// 527 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0x94, 0x93, 0xcd, 0x8e, 0xd3, 0x30,
-	0x14, 0x85, 0xeb, 0x69, 0x93, 0x69, 0x5c, 0xcd, 0x10, 0x19, 0x34, 0x8a, 0x40, 0x8a, 0xaa, 0x6e,
-	0xa8, 0x46, 0xa2, 0x91, 0xca, 0xcf, 0xbe, 0x20, 0x15, 0x75, 0x01, 0x44, 0x66, 0x05, 0x1b, 0xe4,
-	0xa6, 0x6e, 0x62, 0x35, 0x89, 0x23, 0xdb, 0x69, 0xd5, 0x1d, 0x2f, 0xc2, 0x8e, 0x87, 0x99, 0x25,
-	0x4b, 0x96, 0x33, 0x7d, 0x12, 0x64, 0x3b, 0x2d, 0xe1, 0x47, 0x48, 0xac, 0x7a, 0xbf, 0x6b, 0xbb,
-	0xf7, 0x9c, 0xe3, 0x18, 0xc6, 0x29, 0x53, 0x59, 0xbd, 0x9c, 0x24, 0xbc, 0x88, 0x36, 0x44, 0x91,
-	0x27, 0x09, 0x2f, 0x15, 0x61, 0x25, 0x15, 0xf2, 0x0f, 0x96, 0x22, 0x89, 0x72, 0xb6, 0x94, 0x51,
-	0x25, 0xb8, 0xe2, 0x09, 0xcf, 0x9b, 0x4a, 0x46, 0x6a, 0x5f, 0x51, 0x39, 0x31, 0x80, 0x1c, 0x03,
-	0xa3, 0x25, 0xf4, 0x16, 0xf1, 0x6c, 0xb5, 0x12, 0x54, 0x4a, 0xf4, 0x18, 0xba, 0x6b, 0x52, 0xb0,
-	0x7c, 0x1f, 0x80, 0x21, 0x18, 0x5f, 0x4e, 0xef, 0x4d, 0xec, 0x89, 0x45, 0x3c, 0x37, 0x6d, 0xdc,
-	0x2c, 0xa3, 0x00, 0x9e, 0x13, 0x7b, 0x26, 0x38, 0x1b, 0x82, 0xb1, 0x87, 0x8f, 0x88, 0x10, 0xec,
-	0x15, 0x44, 0x6e, 0x82, 0xae, 0x69, 0x9b, 0x7a, 0x74, 0x0b, 0xa0, 0xb7, 0x28, 0x15, 0x15, 0x6b,
-	0x92, 0x50, 0x74, 0x05, 0xdd, 0x15, 0xdd, 0xb2, 0x84, 0x9a, 0x21, 0x1e, 0x6e, 0x48, 0x9f, 0x2c,
-	0x49, 0x41, 0x9b, 0x3f, 0x34, 0x35, 0x9a, 0xc2, 0xc1, 0x49, 0x1d, 0x95, 0x41, 0x77, 0xd8, 0x1d,
-	0x0f, 0xa6, 0xfe, 0x49, 0x55, 0xb3, 0x82, 0xdb, 0x9b, 0x90, 0x0f, 0xbb, 0x85, 0xaa, 0x83, 0xde,
-	0x10, 0x8c, 0x7b, 0x58, 0x97, 0x7a, 0x62, 0xb6, 0xd3, 0x1b, 0x02, 0xc7, 0x4e, 0xb4, 0xa4, 0x5d,
-	0x54, 0x09, 0x8b, 0x89, 0xca, 0x02, 0xd7, 0xba, 0x68, 0x50, 0x6b, 0xd1, 0x33, 0x82, 0x73, 0xab,
-	0x45, 0xd7, 0xe8, 0x11, 0xf4, 0x04, 0xd9, 0x7d, 0x5a, 0xe7, 0x24, 0x95, 0x41, 0x7f, 0x08, 0xc6,
-	0x17, 0xb8, 0x2f, 0xc8, 0x6e, 0xae, 0x79, 0xf4, 0x15, 0x40, 0x07, 0xf3, 0x5a, 0x19, 0x1b, 0x2b,
-	0x2a, 0x55, 0x63, 0xce, 0xd4, 0x7a, 0x50, 0x4a, 0x14, 0xdd, 0x91, 0xfd, 0x31, 0xae, 0x06, 0x5b,
-	0x61, 0x74, 0x7f, 0x09, 0xe3, 0x0a, 0xba, 0x92, 0xd7, 0x22, 0xa1, 0xc6, 0x87, 0x87, 0x1b, 0x42,
-	0x0f, 0xa0, 0x23, 0x13, 0x5e, 0x51, 0xe3, 0xe4, 0x02, 0x5b, 0x68, 0xdd, 0x9b, 0xfb, 0xcf, 0x7b,
-	0x1b, 0x7d, 0x01, 0x70, 0x30, 0xc3, 0xf1, 0x5b, 0xca, 0xd2, 0x6c, 0xc9, 0x85, 0xce, 0x57, 0xf1,
-	0x53, 0x78, 0x46, 0xf3, 0x5f, 0xf3, 0x6d, 0x6d, 0x6a, 0x49, 0x3e, 0xfb, 0x5d, 0x72, 0x9e, 0xeb,
-	0xcf, 0xe0, 0x68, 0xc5, 0x92, 0x91, 0xac, 0x88, 0xb2, 0x4e, 0x1c, 0x6c, 0x41, 0x77, 0x6d, 0x92,
-	0x8e, 0xed, 0x1a, 0xb8, 0x7e, 0x08, 0xfb, 0x47, 0xcd, 0xc8, 0x85, 0x67, 0xdb, 0x67, 0x7e, 0xc7,
-	0xfc, 0xbe, 0xf0, 0xc1, 0xf5, 0x73, 0x78, 0x7f, 0xfe, 0xfe, 0xb5, 0xe0, 0x75, 0xf5, 0x2a, 0x23,
-	0x65, 0x4a, 0x63, 0x9e, 0xb3, 0x64, 0x8f, 0x20, 0x74, 0x67, 0xf9, 0x8e, 0xec, 0xa5, 0xdf, 0x41,
-	0x08, 0x5e, 0xbe, 0x2b, 0x31, 0xe7, 0xea, 0x0d, 0x93, 0x05, 0x51, 0x49, 0xe6, 0x83, 0x97, 0xf2,
-	0xe6, 0x2e, 0xec, 0x7c, 0xbf, 0x0b, 0x3b, 0x9f, 0x0f, 0x21, 0xb8, 0x39, 0x84, 0xe0, 0xdb, 0x21,
-	0x04, 0xb7, 0x87, 0x10, 0x7c, 0xfc, 0xf0, 0x9f, 0x6f, 0x4a, 0xd4, 0xa5, 0x62, 0x05, 0x8d, 0xb6,
-	0x4c, 0xa8, 0xd6, 0x52, 0xb5, 0x49, 0x23, 0x92, 0xd2, 0x52, 0xfd, 0x7c, 0x6f, 0x4b, 0xd7, 0x94,
-	0x4f, 0x7f, 0x04, 0x00, 0x00, 0xff, 0xff, 0xd7, 0x28, 0x03, 0xf1, 0xb7, 0x03, 0x00, 0x00,
+	// 599 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0x95, 0x54, 0x4d, 0x8f, 0x12, 0x31,
+	0x18, 0xde, 0x01, 0x66, 0x16, 0xca, 0xb2, 0x92, 0x6a, 0xcc, 0x44, 0x13, 0xa2, 0x5c, 0xdc, 0x6c,
+	0x22, 0x24, 0xe8, 0x72, 0x47, 0x13, 0x0c, 0x07, 0x95, 0x74, 0x4f, 0x7a, 0x21, 0xa5, 0x94, 0x99,
+	0x86, 0x99, 0xe9, 0xa4, 0xed, 0x40, 0xb8, 0xf9, 0x2f, 0x3c, 0x79, 0xf3, 0xc7, 0xec, 0xd1, 0xa3,
+	0x47, 0xf5, 0x97, 0xd8, 0x8f, 0x01, 0xc7, 0x8f, 0x98, 0xec, 0xa1, 0xe1, 0x7d, 0x9e, 0xf7, 0x6d,
+	0xdf, 0xe7, 0xfd, 0x60, 0xc0, 0x3c, 0x62, 0x2a, 0x2e, 0x96, 0x03, 0xc2, 0xd3, 0xe1, 0x06, 0x2b,
+	0xfc, 0x94, 0xf0, 0x4c, 0x61, 0x96, 0x51, 0x21, 0xff, 0xc2, 0x52, 0x90, 0x61, 0xc2, 0x96, 0x72,
+	0x98, 0x0b, 0xae, 0x38, 0xe1, 0x49, 0x69, 0xc9, 0xa1, 0xda, 0xe7, 0x54, 0x0e, 0x2c, 0x80, 0xbe,
+	0x05, 0xfd, 0x25, 0x68, 0xcd, 0xe6, 0x93, 0xd5, 0x4a, 0x50, 0x29, 0xe1, 0x13, 0x10, 0xac, 0x71,
+	0xca, 0x92, 0x7d, 0xe8, 0x3d, 0xf2, 0x2e, 0xce, 0x47, 0x77, 0x06, 0xee, 0xc6, 0x6c, 0x3e, 0xb5,
+	0x34, 0x2a, 0xdd, 0x30, 0x04, 0xa7, 0xd8, 0xdd, 0x09, 0x6b, 0x3a, 0xb2, 0x85, 0x0e, 0x10, 0x42,
+	0xd0, 0x48, 0xb1, 0xdc, 0x84, 0x75, 0x4b, 0x5b, 0xbb, 0xff, 0xb1, 0xa6, 0x93, 0x64, 0x8a, 0x8a,
+	0x35, 0x26, 0x14, 0xde, 0x07, 0xc1, 0x8a, 0x6e, 0x19, 0xa1, 0x36, 0x49, 0x0b, 0x95, 0xc8, 0xdc,
+	0xcc, 0x70, 0x4a, 0xcb, 0x07, 0xad, 0x0d, 0x47, 0xa0, 0x7d, 0x54, 0x47, 0xa5, 0x7e, 0xb4, 0x7e,
+	0xd1, 0x1e, 0x75, 0x8f, 0xaa, 0x4a, 0x0f, 0xaa, 0x06, 0xc1, 0x2e, 0xa8, 0xa7, 0xaa, 0x08, 0x1b,
+	0xfa, 0x99, 0x06, 0x32, 0xa6, 0xc9, 0x18, 0xef, 0x4c, 0x40, 0xe8, 0xbb, 0x8c, 0x0e, 0x99, 0x2a,
+	0x72, 0xc2, 0xe6, 0x58, 0xc5, 0x61, 0xe0, 0xaa, 0x28, 0xa1, 0xd1, 0x62, 0x72, 0x84, 0xa7, 0x4e,
+	0x8b, 0xb1, 0xe1, 0x43, 0xd0, 0x12, 0x78, 0xb7, 0x58, 0x27, 0x38, 0x92, 0x61, 0x53, 0x3b, 0x3a,
+	0xa8, 0xa9, 0x89, 0xa9, 0xc1, 0xf0, 0x0a, 0x9c, 0xb1, 0x7c, 0x3b, 0x5e, 0xc8, 0xbd, 0x24, 0x2a,
+	0x91, 0x61, 0x4b, 0xfb, 0xdb, 0x23, 0x78, 0x54, 0xba, 0x1d, 0x5f, 0x3b, 0x0f, 0x6a, 0x9b, 0xb8,
+	0x12, 0xf4, 0x53, 0x53, 0xdf, 0x11, 0xc2, 0xc7, 0xe0, 0x6c, 0xc5, 0x24, 0x5e, 0x26, 0x74, 0x61,
+	0xa2, 0x6c, 0x83, 0x3a, 0xa8, 0x5d, 0x72, 0x33, 0x4d, 0x19, 0x15, 0x98, 0x10, 0x9a, 0xab, 0x85,
+	0xc0, 0xb6, 0x55, 0x5a, 0x85, 0x23, 0x10, 0x86, 0x3d, 0x00, 0xd6, 0x5c, 0xec, 0xb0, 0x58, 0xb1,
+	0x2c, 0xb2, 0x23, 0xe8, 0xa0, 0x0a, 0xd3, 0xff, 0xec, 0x01, 0x1f, 0xf1, 0x42, 0xd9, 0x66, 0xaf,
+	0xa8, 0x54, 0xe5, 0x08, 0xac, 0x6d, 0xda, 0x11, 0x61, 0x45, 0x77, 0x78, 0x7f, 0x18, 0x6a, 0x09,
+	0x2b, 0x23, 0xab, 0xff, 0x36, 0x32, 0xcd, 0x4b, 0x5e, 0x08, 0xcd, 0x37, 0x1c, 0xef, 0x10, 0xbc,
+	0x07, 0x7c, 0x49, 0xb8, 0xee, 0x9f, 0x6f, 0x25, 0x38, 0x50, 0xd9, 0xae, 0xe0, 0xbf, 0xdb, 0xd5,
+	0xff, 0xe4, 0x81, 0xf6, 0x04, 0xcd, 0xdf, 0x50, 0x16, 0xc5, 0x4b, 0x2e, 0xcc, 0x16, 0x28, 0x7e,
+	0x1c, 0xb1, 0xd5, 0xfc, 0xcf, 0x2d, 0xa8, 0x04, 0x55, 0x24, 0xd7, 0xfe, 0x94, 0x9c, 0x24, 0x66,
+	0x59, 0x0f, 0xa5, 0x38, 0x64, 0x25, 0x2b, 0x5d, 0xae, 0xad, 0xc4, 0x47, 0x0e, 0x18, 0xd6, 0xcd,
+	0xdb, 0x77, 0xac, 0x05, 0x97, 0x0f, 0x40, 0xf3, 0xa0, 0x19, 0x06, 0xa0, 0xb6, 0x7d, 0xde, 0x3d,
+	0xb1, 0xbf, 0xe3, 0xae, 0x77, 0x79, 0x05, 0xee, 0x4e, 0xaf, 0x5f, 0x09, 0x5e, 0xe4, 0x2f, 0x63,
+	0x9c, 0x45, 0x74, 0xce, 0x13, 0x46, 0xf6, 0x10, 0x80, 0x60, 0x92, 0xe8, 0x56, 0x4a, 0x1d, 0x0a,
+	0xc1, 0xf9, 0xdb, 0x0c, 0x71, 0xae, 0x5e, 0x33, 0x99, 0x62, 0x45, 0xe2, 0xae, 0xf7, 0x42, 0xde,
+	0x7c, 0xef, 0x9d, 0x7c, 0xd5, 0xe7, 0xc3, 0x8f, 0x9e, 0x77, 0xa3, 0xcf, 0x17, 0x7d, 0xbe, 0xe9,
+	0xf3, 0xfe, 0xdd, 0x2d, 0xff, 0xf9, 0xa2, 0xc8, 0x14, 0x4b, 0xe9, 0x70, 0xcb, 0x84, 0xaa, 0xb8,
+	0xf2, 0x4d, 0x34, 0xc4, 0x11, 0xcd, 0xd4, 0xaf, 0xaf, 0xc2, 0x32, 0xb0, 0xe6, 0xb3, 0x9f, 0xd1,
+	0xa1, 0x44, 0x92, 0x5d, 0x04, 0x00, 0x00,
 }
 
 func (m *IPAddress) Marshal() (dAtA []byte, err error) {
@@ -377,6 +428,18 @@ func (m *Interface) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 		i -= len(m.XXX_unrecognized)
 		copy(dAtA[i:], m.XXX_unrecognized)
 	}
+	if m.Ipv6Sysctls != nil {
+		{
+			size, err := m.Ipv6Sysctls.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintTypes(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x4a
+	}
 	if m.RawFlags != 0 {
 		i = encodeVarintTypes(dAtA, i, uint64(m.RawFlags))
 		i--
@@ -439,6 +502,48 @@ func (m *Interface) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	return len(dAtA) - i, nil
 }
 
+func (m *IPv6Sysctls) Marshal() (dAtA []byte, err error) {
+	size := m.Size()
+	dAtA = make([]byte, size)
+	n, err := m.MarshalToSizedBuffer(dAtA[:size])
+	if err != nil {
+		return nil, err
+	}
+	return dAtA[:n], nil
+}
+
+func (m *IPv6Sysctls) MarshalTo(dAtA []byte) (int, error) {
+	size := m.Size()
+	return m.MarshalToSizedBuffer(dAtA[:size])
+}
+
+func (m *IPv6Sysctls) MarshalToSizedBuffer(dAtA []byte) (int, error) {
+	i := len(dAtA)
+	_ = i
+	var l int
+	_ = l
+	if m.XXX_unrecognized != nil {
+		i -= len(m.XXX_unrecognized)
+		copy(dAtA[i:], m.XXX_unrecognized)
+	}
+	if m.Forwarding != 0 {
+		i = encodeVarintTypes(dAtA, i, uint64(m.Forwarding))
+		i--
+		dAtA[i] = 0x18
+	}
+	if m.AcceptRa != 0 {
+		i = encodeVarintTypes(dAtA, i, uint64(m.AcceptRa))
+		i--
+		dAtA[i] = 0x10
+	}
+	if m.DisableIpv6 != 0 {
+		i = encodeVarintTypes(dAtA, i, uint64(m.DisableIpv6))
+		i--
+		dAtA[i] = 0x8
+	}
+	return len(dAtA) - i, nil
+}
+
 func (m *Route) Marshal() (dAtA []byte, err error) {
 	size := m.Size()
 	dAtA = make([]byte, size)
@@ -639,6 +744,31 @@ func (m *Interface) Size() (n int) {
 	if m.RawFlags != 0 {
 		n += 1 + sovTypes(uint64(m.RawFlags))
 	}
+	if m.Ipv6Sysctls != nil {
+		l = m.Ipv6Sysctls.Size()
+		n += 1 + l + sovTypes(uint64(l))
+	}
+	if m.XXX_unrecognized != nil {
+		n += len(m.XXX_unrecognized)
+	}
+	return n
+}
+
+func (m *IPv6Sysctls) Size() (n int) {
+	if m == nil {
+		return 0
+	}
+	var l int
+	_ = l
+	if m.DisableIpv6 != 0 {
+		n += 1 + sovTypes(uint64(m.DisableIpv6))
+	}
+	if m.AcceptRa != 0 {
+		n += 1 + sovTypes(uint64(m.AcceptRa))
+	}
+	if m.Forwarding != 0 {
+		n += 1 + sovTypes(uint64(m.Forwarding))
+	}
 	if m.XXX_unrecognized != nil {
 		n += len(m.XXX_unrecognized)
 	}
@@ -746,6 +876,20 @@ func (this *Interface) String() string {
 		`PciPath:` + fmt.Sprintf("%v", this.PciPath) + `,`,
 		`Type:` + fmt.Sprintf("%v", this.Type) + `,`,
 		`RawFlags:` + fmt.Sprintf("%v", this.RawFlags) + `,`,
+		`Ipv6Sysctls:` + strings.Replace(this.Ipv6Sysctls.String(), "IPv6Sysctls", "IPv6Sysctls", 1) + `,`,
+		`XXX_unrecognized:` + fmt.Sprintf("%v", this.XXX_unrecognized) + `,`,
+		`}`,
+	}, "")
+	return s
+}
+func (this *IPv6Sysctls) String() string {
+	if this == nil {
+		return "nil"
+	}
+	s := strings.Join([]string{`&IPv6Sysctls{`,
+		`DisableIpv6:` + fmt.Sprintf("%v", this.DisableIpv6) + `,`,
+		`AcceptRa:` + fmt.Sprintf("%v", this.AcceptRa) + `,`,
+		`Forwarding:` + fmt.Sprintf("%v", this.Forwarding) + `,`,
 		`XXX_unrecognized:` + fmt.Sprintf("%v", this.XXX_unrecognized) + `,`,
 		`}`,
 	}, "")
@@ -1185,6 +1329,150 @@ func (m *Interface) Unmarshal(dAtA []byte) error {
 					break
 				}
 			}
+		case 9:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Ipv6Sysctls", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowTypes
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthTypes
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthTypes
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.Ipv6Sysctls == nil {
+				m.Ipv6Sysctls = &IPv6Sysctls{}
+			}
+			if err := m.Ipv6Sysctls.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
+		default:
+			iNdEx = preIndex
+			skippy, err := skipTypes(dAtA[iNdEx:])
+			if err != nil {
+				return err
+			}
+			if (skippy < 0) || (iNdEx+skippy) < 0 {
+				return ErrInvalidLengthTypes
+			}
+			if (iNdEx + skippy) > l {
+				return io.ErrUnexpectedEOF
+			}
+			m.XXX_unrecognized = append(m.XXX_unrecognized, dAtA[iNdEx:iNdEx+skippy]...)
+			iNdEx += skippy
+		}
+	}
+
+	if iNdEx > l {
+		return io.ErrUnexpectedEOF
+	}
+	return nil
+}
+func (m *IPv6Sysctls) Unmarshal(dAtA []byte) error {
+	l := len(dAtA)
+	iNdEx := 0
+	for iNdEx < l {
+		preIndex := iNdEx
+		var wire uint64
+		for shift := uint(0); ; shift += 7 {
+			if shift >= 64 {
+				return ErrIntOverflowTypes
+			}
+			if iNdEx >= l {
+				return io.ErrUnexpectedEOF
+			}
+			b := dAtA[iNdEx]
+			iNdEx++
+			wire |= uint64(b&0x7F) << shift
+			if b < 0x80 {
+				break
+			}
+		}
+		fieldNum := int32(wire >> 3)
+		wireType := int(wire & 0x7)
+		if wireType == 4 {
+			return fmt.Errorf("proto: IPv6Sysctls: wiretype end group for non-group")
+		}
+		if fieldNum <= 0 {
+			return fmt.Errorf("proto: IPv6Sysctls: illegal tag %d (wire type %d)", fieldNum, wire)
+		}
+		switch fieldNum {
+		case 1:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field DisableIpv6", wireType)
+			}
+			m.DisableIpv6 = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowTypes
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.DisableIpv6 |= uint32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 2:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field AcceptRa", wireType)
+			}
+			m.AcceptRa = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowTypes
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.AcceptRa |= uint32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+		case 3:
+			if wireType != 0 {
+				return fmt.Errorf("proto: wrong wireType = %d for field Forwarding", wireType)
+			}
+			m.Forwarding = 0
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowTypes
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				m.Forwarding |= uint32(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
 		default:
 			iNdEx = preIndex
 			skippy, err := skipTypes(dAtA[iNdEx:])
@@ -161,6 +161,10 @@ func (p *HybridVSockTTRPCMockImp) DestroySandbox(ctx context.Context, req *pb.De
 	return emptyResp, nil
 }
 
+func (p *HybridVSockTTRPCMockImp) UpdateSandboxDNS(ctx context.Context, req *pb.UpdateSandboxDNSRequest) (*gpb.Empty, error) {
+	return emptyResp, nil
+}
+
 func (p *HybridVSockTTRPCMockImp) UpdateInterface(ctx context.Context, req *pb.UpdateInterfaceRequest) (*aTypes.Interface, error) {
 	return &aTypes.Interface{}, nil
 }
@@ -1603,7 +1603,8 @@ func (s *Sandbox) Stats(ctx context.Context) (SandboxStats, error) {
 		stats.CgroupStats.CPUStats.CPUUsage.TotalUsage = mt.CPU.Usage.Total
 		stats.CgroupStats.MemoryStats.Usage.Usage = mt.Memory.Usage.Usage
 	case v2.Metrics:
-		stats.CgroupStats.CPUStats.CPUUsage.TotalUsage = mt.CPU.UsageUsec
+		// cgroup v2 reports the cpu time in us, keep it in ns like v1 and the guest stats
+		stats.CgroupStats.CPUStats.CPUUsage.TotalUsage = mt.CPU.UsageUsec * 1000
 		stats.CgroupStats.MemoryStats.Usage.Usage = mt.Memory.Usage
 	}
 
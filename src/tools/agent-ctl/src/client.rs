@@ -5,21 +5,30 @@
 
 // Description: Client side of ttRPC comms
 
+use crate::scenario::{self, Scenario};
 use crate::types::{Config, Options};
-use crate::utils;
-use anyhow::{anyhow, Result};
+use crate::utils::{self, Response};
+use anyhow::{anyhow, Context as _, Result};
 use byteorder::ByteOrder;
 use nix::sys::socket::{connect, socket, AddressFamily, SockAddr, SockFlag, SockType, UnixAddr};
 use protocols::agent::*;
 use protocols::agent_ttrpc::*;
 use protocols::health::*;
 use protocols::health_ttrpc::*;
-use slog::{debug, info};
-use std::io;
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use rustyline::{Editor, Helper};
+use slog::{debug, info, warn};
+use std::env;
+use std::fs;
 use std::io::Write; // XXX: for flush()
 use std::io::{BufRead, BufReader};
 use std::os::unix::io::{IntoRawFd, RawFd};
 use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
 use std::thread::sleep;
 use std::time::Duration;
 use ttrpc::context::Context;
@@ -57,13 +66,14 @@ fn clone_context(ctx: &Context) -> Context {
 //   arguments).
 // - 'options' can be read and written to, allowing commands to pass state to
 //   each other via well-known option names.
+// - The API response is returned, to be saved by handle_agent_cmd().
 type AgentCmdFp = fn(
     ctx: &Context,
     client: &AgentServiceClient,
     health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()>;
+) -> Result<Box<dyn Response>>;
 
 // Builtin command handler type
 type BuiltinCmdFp = fn(args: &str) -> (Result<()>, bool);
@@ -78,6 +88,8 @@ struct AgentCmd {
     name: &'static str,
     st: ServiceType,
     fp: AgentCmdFp,
+    // Names of the "name=value" arguments the command handles.
+    args: &'static [&'static str],
 }
 
 // XXX: Builtin command names *MUST* start with a lower-case letter.
@@ -96,6 +108,11 @@ const CMD_REPEAT: &str = "repeat";
 
 const DEFAULT_PROC_SIGNAL: &str = "SIGKILL";
 
+const INTERACTIVE_PROMPT: &str = "agent-ctl> ";
+
+// Name of the file below $HOME holding the interactive mode command history.
+const HISTORY_FILE: &str = ".kata-agent-ctl-history";
+
 const ERR_API_FAILED: &str = "API failed";
 
 // Value used as a "namespace" in the ttRPC Context's metadata.
@@ -110,181 +127,226 @@ static AGENT_CMDS: &[AgentCmd] = &[
         name: "AddARPNeighbors",
         st: ServiceType::Agent,
         fp: agent_cmd_sandbox_add_arp_neighbors,
+        args: &[],
     },
     AgentCmd {
         name: "AddSwap",
         st: ServiceType::Agent,
         fp: agent_cmd_sandbox_add_swap,
+        args: &[],
     },
     AgentCmd {
         name: "Check",
         st: ServiceType::Health,
         fp: agent_cmd_health_check,
+        args: &[],
     },
     AgentCmd {
         name: "Version",
         st: ServiceType::Health,
         fp: agent_cmd_health_version,
+        args: &[],
     },
     AgentCmd {
         name: "CloseStdin",
         st: ServiceType::Agent,
         fp: agent_cmd_container_close_stdin,
+        args: &["cid", "exec_id"],
     },
     AgentCmd {
         name: "CopyFile",
         st: ServiceType::Agent,
         fp: agent_cmd_sandbox_copy_file,
+        args: &[
+            "path",
+            "file_size",
+            "file_mode",
+            "dir_mode",
+            "uid",
+            "gid",
+            "offset",
+            "data",
+        ],
     },
     AgentCmd {
         name: "CreateContainer",
         st: ServiceType::Agent,
         fp: agent_cmd_container_create,
+        args: &["cid", "exec_id", "spec"],
     },
     AgentCmd {
         name: "CreateSandbox",
         st: ServiceType::Agent,
         fp: agent_cmd_sandbox_create,
+        args: &["sid"],
     },
     AgentCmd {
         name: "DestroySandbox",
         st: ServiceType::Agent,
         fp: agent_cmd_sandbox_destroy,
+        args: &[],
     },
     AgentCmd {
         name: "ExecProcess",
         st: ServiceType::Agent,
         fp: agent_cmd_container_exec,
+        args: &["cid", "exec_id", "spec"],
     },
     AgentCmd {
         name: "GetGuestDetails",
         st: ServiceType::Agent,
         fp: agent_cmd_sandbox_get_guest_details,
+        args: &[],
     },
     AgentCmd {
         name: "GetIptables",
         st: ServiceType::Agent,
         fp: agent_cmd_sandbox_get_ip_tables,
+        args: &[],
     },
     AgentCmd {
         name: "GetMetrics",
         st: ServiceType::Agent,
         fp: agent_cmd_sandbox_get_metrics,
+        args: &[],
     },
     AgentCmd {
         name: "GetOOMEvent",
         st: ServiceType::Agent,
         fp: agent_cmd_sandbox_get_oom_event,
+        args: &[],
     },
     AgentCmd {
         name: "GetVolumeStats",
         st: ServiceType::Agent,
         fp: agent_cmd_sandbox_get_volume_stats,
+        args: &[],
     },
     AgentCmd {
         name: "ListInterfaces",
         st: ServiceType::Agent,
         fp: agent_cmd_sandbox_list_interfaces,
+        args: &[],
     },
     AgentCmd {
         name: "ListRoutes",
         st: ServiceType::Agent,
         fp: agent_cmd_sandbox_list_routes,
+        args: &[],
     },
     AgentCmd {
         name: "MemHotplugByProbe",
         st: ServiceType::Agent,
         fp: agent_cmd_sandbox_mem_hotplug_by_probe,
+        args: &["memHotplugProbeAddr"],
     },
     AgentCmd {
         name: "OnlineCPUMem",
         st: ServiceType::Agent,
         fp: agent_cmd_sandbox_online_cpu_mem,
+        args: &["wait", "nb_cpus", "cpu_only"],
     },
     AgentCmd {
         name: "PauseContainer",
         st: ServiceType::Agent,
         fp: agent_cmd_container_pause,
+        args: &["cid"],
     },
     AgentCmd {
         name: "ReadStderr",
         st: ServiceType::Agent,
         fp: agent_cmd_container_read_stderr,
+        args: &["cid", "exec_id", "len"],
     },
     AgentCmd {
         name: "ReadStdout",
         st: ServiceType::Agent,
         fp: agent_cmd_container_read_stdout,
+        args: &["cid", "exec_id", "len"],
     },
     AgentCmd {
         name: "ReseedRandomDev",
         st: ServiceType::Agent,
         fp: agent_cmd_sandbox_reseed_random_dev,
+        args: &["data"],
     },
     AgentCmd {
         name: "RemoveContainer",
         st: ServiceType::Agent,
         fp: agent_cmd_container_remove,
+        args: &["cid"],
     },
     AgentCmd {
         name: "ResumeContainer",
         st: ServiceType::Agent,
         fp: agent_cmd_container_resume,
+        args: &["cid"],
     },
     AgentCmd {
         name: "SetGuestDateTime",
         st: ServiceType::Agent,
         fp: agent_cmd_sandbox_set_guest_date_time,
+        args: &["sec", "usec"],
     },
     AgentCmd {
         name: "SetIptables",
         st: ServiceType::Agent,
         fp: agent_cmd_sandbox_set_ip_tables,
+        args: &[],
     },
     AgentCmd {
         name: "SignalProcess",
         st: ServiceType::Agent,
         fp: agent_cmd_container_signal_process,
+        args: &["cid", "exec_id", "signal"],
     },
     AgentCmd {
         name: "StartContainer",
         st: ServiceType::Agent,
         fp: agent_cmd_container_start,
+        args: &["cid"],
     },
     AgentCmd {
         name: "StatsContainer",
         st: ServiceType::Agent,
         fp: agent_cmd_container_stats,
+        args: &["cid"],
     },
     AgentCmd {
         name: "TtyWinResize",
         st: ServiceType::Agent,
         fp: agent_cmd_container_tty_win_resize,
+        args: &["cid", "exec_id", "row", "column"],
     },
     AgentCmd {
         name: "UpdateContainer",
         st: ServiceType::Agent,
         fp: agent_cmd_sandbox_update_container,
+        args: &["cid"],
     },
     AgentCmd {
         name: "UpdateInterface",
         st: ServiceType::Agent,
         fp: agent_cmd_sandbox_update_interface,
+        args: &[],
     },
     AgentCmd {
         name: "UpdateRoutes",
         st: ServiceType::Agent,
         fp: agent_cmd_sandbox_update_routes,
+        args: &[],
     },
     AgentCmd {
         name: "WaitProcess",
         st: ServiceType::Agent,
         fp: agent_cmd_container_wait_process,
+        args: &["cid", "exec_id"],
     },
     AgentCmd {
         name: "WriteStdin",
         st: ServiceType::Agent,
         fp: agent_cmd_container_write_stdin,
+        args: &["cid", "exec_id", "data"],
     },
 ];
 
@@ -357,6 +419,13 @@ fn get_agent_cmd_func(name: &str) -> Result<AgentCmdFp> {
     Err(anyhow!("Invalid command: {:?}", name))
 }
 
+fn get_agent_cmd_args(name: &str) -> &'static [&'static str] {
+    match AGENT_CMDS.iter().find(|cmd| cmd.name.eq(name)) {
+        Some(cmd) => cmd.args,
+        None => &[],
+    }
+}
+
 fn get_builtin_cmd_details() -> Vec<String> {
     let mut cmds = Vec::new();
 
@@ -564,26 +633,6 @@ fn create_ttrpc_client(
     Ok(ttrpc::Client::new(fd))
 }
 
-fn kata_service_agent(
-    server_address: String,
-    hybrid_vsock_port: u64,
-    hybrid_vsock: bool,
-) -> Result<AgentServiceClient> {
-    let ttrpc_client = create_ttrpc_client(server_address, hybrid_vsock_port, hybrid_vsock)?;
-
-    Ok(AgentServiceClient::new(ttrpc_client))
-}
-
-fn kata_service_health(
-    server_address: String,
-    hybrid_vsock_port: u64,
-    hybrid_vsock: bool,
-) -> Result<HealthClient> {
-    let ttrpc_client = create_ttrpc_client(server_address, hybrid_vsock_port, hybrid_vsock)?;
-
-    Ok(HealthClient::new(ttrpc_client))
-}
-
 fn announce(cfg: &Config) {
     info!(sl!(), "announce"; "config" => format!("{:?}", cfg));
 }
@@ -611,19 +660,15 @@ pub fn client(cfg: &Config, commands: Vec<&str>) -> Result<()> {
 
     announce(cfg);
 
-    // Create separate connections for each of the services provided
-    // by the agent.
-    let client = kata_service_agent(
+    // Use a single connection for all the services provided by the agent.
+    let ttrpc_client = create_ttrpc_client(
         cfg.server_address.clone(),
         cfg.hybrid_vsock_port,
         cfg.hybrid_vsock,
     )?;
 
-    let health = kata_service_health(
-        cfg.server_address.clone(),
-        cfg.hybrid_vsock_port,
-        cfg.hybrid_vsock,
-    )?;
+    let client = AgentServiceClient::new(ttrpc_client.clone());
+    let health = HealthClient::new(ttrpc_client);
 
     let mut options = Options::new();
 
@@ -653,6 +698,10 @@ pub fn client(cfg: &Config, commands: Vec<&str>) -> Result<()> {
         return interactive_client_loop(cfg, &mut options, &client, &health, &ttrpc_ctx);
     }
 
+    if !cfg.scenario_file.is_empty() {
+        return run_scenario(cfg, &mut options, &client, &health, &ttrpc_ctx);
+    }
+
     let mut repeat_count = 1;
 
     for cmd in commands {
@@ -793,7 +842,8 @@ fn handle_agent_cmd(
         Err(e) => return (Err(e), false),
     };
 
-    let result = f(ctx, client, health, options, args);
+    let result = f(ctx, client, health, options, args)
+        .and_then(|reply| utils::save_response(options, reply.as_ref()));
     if result.is_err() {
         return (result, false);
     }
@@ -815,25 +865,51 @@ fn interactive_client_loop(
         return result.0;
     }
 
+    let mut editor = Editor::<CmdHelper>::new();
+    editor.set_helper(Some(CmdHelper));
+
+    let history_file = get_history_file();
+
+    if let Some(path) = &history_file {
+        // The file doesn't exist the first time the tool is run.
+        if let Err(e) = editor.load_history(path) {
+            debug!(sl!(), "failed to load history from {:?}: {:?}", path, e);
+        }
+    }
+
     let mut repeat_count: i64 = 1;
 
     loop {
-        let cmdline =
-            readline("Enter command").map_err(|e| anyhow!(e).context("failed to read line"))?;
+        let cmdline = match editor.readline(INTERACTIVE_PROMPT) {
+            Ok(line) => line.trim().to_string(),
+            // Ctrl-C only discards the current line
+            Err(ReadlineError::Interrupted) => continue,
+            // Ctrl-D
+            Err(ReadlineError::Eof) => break,
+            Err(e) => return Err(anyhow!(e).context("failed to read line")),
+        };
 
         if cmdline.is_empty() {
             continue;
         }
 
+        editor.add_history_entry(cmdline.as_str());
+
         if cmdline.starts_with(CMD_REPEAT) {
             repeat_count = get_repeat_count(&cmdline);
             continue;
         }
 
+        options.remove(utils::RESPONSE_OPTION);
+
         let (result, shutdown) =
             handle_cmd(cfg, client, health, ctx, repeat_count, options, &cmdline);
 
-        result.map_err(|e| anyhow!(e))?;
+        // Keep the session open on error so the command can be retried.
+        match result {
+            Ok(()) => print_response(options),
+            Err(e) => eprintln!("ERROR: {:#}", e),
+        }
 
         if shutdown {
             break;
@@ -843,24 +919,142 @@ fn interactive_client_loop(
         repeat_count = 1;
     }
 
+    if let Some(path) = &history_file {
+        if let Err(e) = editor.save_history(path) {
+            warn!(sl!(), "failed to save history to {:?}: {:?}", path, e);
+        }
+    }
+
     Ok(())
 }
 
-fn readline(prompt: &str) -> std::result::Result<String, String> {
-    print!("{}: ", prompt);
+fn get_history_file() -> Option<PathBuf> {
+    env::var_os("HOME").map(|home| PathBuf::from(home).join(HISTORY_FILE))
+}
+
+// Display the response of the last agent API call as indented JSON.
+fn print_response(options: &Options) {
+    let response = match options.get(utils::RESPONSE_OPTION) {
+        Some(r) => r,
+        None => return,
+    };
+
+    match serde_json::from_str::<serde_json::Value>(response)
+        .and_then(|v| serde_json::to_string_pretty(&v))
+    {
+        Ok(pretty) => println!("{}", pretty),
+        Err(_) => println!("{}", response),
+    }
+}
+
+// Command line completion for the interactive mode.
+struct CmdHelper;
+
+impl Completer for CmdHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &rustyline::Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        Ok(complete_cmdline(line, pos))
+    }
+}
+
+impl Hinter for CmdHelper {
+    type Hint = String;
+}
+
+impl Highlighter for CmdHelper {}
+
+impl Validator for CmdHelper {}
+
+impl Helper for CmdHelper {}
+
+// Return the position of the word being completed along with the possible
+// completions: the command names for the first word and the names of the
+// command arguments not yet specified for the others.
+fn complete_cmdline(line: &str, pos: usize) -> (usize, Vec<String>) {
+    let line = &line[..pos];
+
+    let start = line.rfind(char::is_whitespace).map_or(0, |i| i + 1);
+    let word = &line[start..];
+
+    let previous: Vec<&str> = line[..start].split_whitespace().collect();
+
+    let mut candidates: Vec<String> = match previous.first() {
+        None => AGENT_CMDS
+            .iter()
+            .map(|c| c.name)
+            .chain(BUILTIN_CMDS.iter().map(|c| c.name))
+            .filter(|name| name.starts_with(word))
+            .map(String::from)
+            .collect(),
+        // Values are not completed
+        Some(_) if word.contains('=') => Vec::new(),
+        Some(cmd) => get_agent_cmd_args(cmd)
+            .iter()
+            .map(|arg| format!("{}=", arg))
+            .filter(|arg| arg.starts_with(word))
+            .filter(|arg| !previous.iter().any(|w| w.starts_with(arg.as_str())))
+            .collect(),
+    };
+
+    candidates.sort();
+
+    (start, candidates)
+}
 
-    io::stdout()
-        .flush()
-        .map_err(|e| format!("failed to flush: {:?}", e))?;
+// Run the steps of the scenario file and write the results.
+fn run_scenario(
+    cfg: &Config,
+    options: &mut Options,
+    client: &AgentServiceClient,
+    health: &HealthClient,
+    ctx: &Context,
+) -> Result<()> {
+    let scenario = Scenario::load(&cfg.scenario_file)?;
+
+    // Unlike handle_cmd(), each step runs exactly once and errors are
+    // always returned so that the step can check them.
+    let result = scenario::run(
+        &scenario,
+        options,
+        cfg.ignore_errors,
+        |cmd, args, options| {
+            let (result, _) = if cmd.starts_with(char::is_lowercase) {
+                handle_builtin_cmd(cmd, args)
+            } else {
+                handle_agent_cmd(ctx, client, health, options, cmd, args)
+            };
+
+            result
+        },
+    );
+
+    let output = result.render(&cfg.result_format)?;
+
+    if cfg.result_file.is_empty() {
+        println!("{}", output);
+    } else {
+        fs::write(&cfg.result_file, output)
+            .with_context(|| format!("failed to write results to {:?}", cfg.result_file))?;
+    }
 
-    let mut line = String::new();
+    let failures = result.failures();
 
-    std::io::stdin()
-        .read_line(&mut line)
-        .map_err(|e| format!("failed to read line: {:?}", e))?;
+    if failures > 0 {
+        return Err(anyhow!(
+            "scenario {:?} failed: {} of {} steps failed",
+            scenario.name,
+            failures,
+            result.steps.len()
+        ));
+    }
 
-    // Remove NL
-    Ok(line.trim_end().to_string())
+    Ok(())
 }
 
 fn agent_cmd_health_check(
@@ -869,7 +1063,7 @@ fn agent_cmd_health_check(
     health: &HealthClient,
     _options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let req: CheckRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -878,12 +1072,12 @@ fn agent_cmd_health_check(
 
     let reply = health
         .check(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_health_version(
@@ -892,7 +1086,7 @@ fn agent_cmd_health_version(
     health: &HealthClient,
     _options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     // XXX: Yes, the API is actually broken!
     let req: CheckRequest = utils::make_request(args)?;
 
@@ -902,12 +1096,12 @@ fn agent_cmd_health_version(
 
     let reply = health
         .version(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_sandbox_create(
@@ -916,7 +1110,7 @@ fn agent_cmd_sandbox_create(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: CreateSandboxRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -932,12 +1126,12 @@ fn agent_cmd_sandbox_create(
 
     let reply = client
         .create_sandbox(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_sandbox_destroy(
@@ -946,7 +1140,7 @@ fn agent_cmd_sandbox_destroy(
     _health: &HealthClient,
     _options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let req: DestroySandboxRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -955,12 +1149,12 @@ fn agent_cmd_sandbox_destroy(
 
     let reply = client
         .destroy_sandbox(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_container_create(
@@ -969,7 +1163,7 @@ fn agent_cmd_container_create(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: CreateContainerRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -992,12 +1186,12 @@ fn agent_cmd_container_create(
 
     let reply = client
         .create_container(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_container_remove(
@@ -1006,7 +1200,7 @@ fn agent_cmd_container_remove(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: RemoveContainerRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1021,12 +1215,12 @@ fn agent_cmd_container_remove(
 
     let reply = client
         .remove_container(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_container_exec(
@@ -1035,7 +1229,7 @@ fn agent_cmd_container_exec(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: ExecProcessRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1071,12 +1265,12 @@ fn agent_cmd_container_exec(
 
     let reply = client
         .exec_process(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_container_stats(
@@ -1085,7 +1279,7 @@ fn agent_cmd_container_stats(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: StatsContainerRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1101,12 +1295,12 @@ fn agent_cmd_container_stats(
 
     let reply = client
         .stats_container(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_container_pause(
@@ -1115,7 +1309,7 @@ fn agent_cmd_container_pause(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: PauseContainerRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1131,12 +1325,12 @@ fn agent_cmd_container_pause(
 
     let reply = client
         .pause_container(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_container_resume(
@@ -1145,7 +1339,7 @@ fn agent_cmd_container_resume(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: ResumeContainerRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1161,12 +1355,12 @@ fn agent_cmd_container_resume(
 
     let reply = client
         .resume_container(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_container_start(
@@ -1175,7 +1369,7 @@ fn agent_cmd_container_start(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: StartContainerRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1191,12 +1385,12 @@ fn agent_cmd_container_start(
 
     let reply = client
         .start_container(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_sandbox_get_guest_details(
@@ -1205,7 +1399,7 @@ fn agent_cmd_sandbox_get_guest_details(
     _health: &HealthClient,
     _options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: GuestDetailsRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1221,12 +1415,12 @@ fn agent_cmd_sandbox_get_guest_details(
 
     let reply = client
         .get_guest_details(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_sandbox_get_ip_tables(
@@ -1235,7 +1429,7 @@ fn agent_cmd_sandbox_get_ip_tables(
     _health: &HealthClient,
     _options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let req: GetIPTablesRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1244,12 +1438,12 @@ fn agent_cmd_sandbox_get_ip_tables(
 
     let reply = client
         .get_ip_tables(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_container_wait_process(
@@ -1258,7 +1452,7 @@ fn agent_cmd_container_wait_process(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: WaitProcessRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1277,12 +1471,12 @@ fn agent_cmd_container_wait_process(
 
     let reply = client
         .wait_process(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_container_signal_process(
@@ -1291,7 +1485,7 @@ fn agent_cmd_container_signal_process(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: SignalProcessRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1320,12 +1514,12 @@ fn agent_cmd_container_signal_process(
 
     let reply = client
         .signal_process(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_sandbox_update_interface(
@@ -1334,7 +1528,7 @@ fn agent_cmd_sandbox_update_interface(
     _health: &HealthClient,
     _options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let req: UpdateInterfaceRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1342,7 +1536,7 @@ fn agent_cmd_sandbox_update_interface(
     debug!(sl!(), "sending request"; "request" => format!("{:?}", req));
     let reply = client
         .update_interface(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     // FIXME: Implement 'UpdateInterface' fully.
     eprintln!("FIXME: 'UpdateInterface' not fully implemented");
@@ -1350,7 +1544,7 @@ fn agent_cmd_sandbox_update_interface(
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_sandbox_update_routes(
@@ -1359,7 +1553,7 @@ fn agent_cmd_sandbox_update_routes(
     _health: &HealthClient,
     _options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let req: UpdateRoutesRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1368,7 +1562,7 @@ fn agent_cmd_sandbox_update_routes(
 
     let reply = client
         .update_routes(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     // FIXME: Implement 'UpdateRoutes' fully.
     eprintln!("FIXME: 'UpdateRoutes' not fully implemented");
@@ -1376,7 +1570,7 @@ fn agent_cmd_sandbox_update_routes(
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_sandbox_list_interfaces(
@@ -1385,7 +1579,7 @@ fn agent_cmd_sandbox_list_interfaces(
     _health: &HealthClient,
     _options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let req: ListInterfacesRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1394,12 +1588,12 @@ fn agent_cmd_sandbox_list_interfaces(
 
     let reply = client
         .list_interfaces(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_sandbox_list_routes(
@@ -1408,7 +1602,7 @@ fn agent_cmd_sandbox_list_routes(
     _health: &HealthClient,
     _options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let req: ListRoutesRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1417,12 +1611,12 @@ fn agent_cmd_sandbox_list_routes(
 
     let reply = client
         .list_routes(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_container_tty_win_resize(
@@ -1431,7 +1625,7 @@ fn agent_cmd_container_tty_win_resize(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: TtyWinResizeRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1469,12 +1663,12 @@ fn agent_cmd_container_tty_win_resize(
 
     let reply = client
         .tty_win_resize(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_container_close_stdin(
@@ -1483,7 +1677,7 @@ fn agent_cmd_container_close_stdin(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: CloseStdinRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1502,12 +1696,12 @@ fn agent_cmd_container_close_stdin(
 
     let reply = client
         .close_stdin(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_container_read_stdout(
@@ -1516,7 +1710,7 @@ fn agent_cmd_container_read_stdout(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: ReadStreamRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1544,12 +1738,12 @@ fn agent_cmd_container_read_stdout(
 
     let reply = client
         .read_stdout(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_container_read_stderr(
@@ -1558,7 +1752,7 @@ fn agent_cmd_container_read_stderr(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: ReadStreamRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1586,12 +1780,12 @@ fn agent_cmd_container_read_stderr(
 
     let reply = client
         .read_stderr(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_container_write_stdin(
@@ -1600,7 +1794,7 @@ fn agent_cmd_container_write_stdin(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: WriteStreamRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1623,12 +1817,12 @@ fn agent_cmd_container_write_stdin(
 
     let reply = client
         .write_stdin(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_sandbox_get_metrics(
@@ -1637,7 +1831,7 @@ fn agent_cmd_sandbox_get_metrics(
     _health: &HealthClient,
     _options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let req: GetMetricsRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1646,12 +1840,12 @@ fn agent_cmd_sandbox_get_metrics(
 
     let reply = client
         .get_metrics(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_sandbox_get_oom_event(
@@ -1660,7 +1854,7 @@ fn agent_cmd_sandbox_get_oom_event(
     _health: &HealthClient,
     _options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let req: GetOOMEventRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1669,12 +1863,12 @@ fn agent_cmd_sandbox_get_oom_event(
 
     let reply = client
         .get_oom_event(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_sandbox_get_volume_stats(
@@ -1683,7 +1877,7 @@ fn agent_cmd_sandbox_get_volume_stats(
     _health: &HealthClient,
     _options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let req: VolumeStatsRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1697,7 +1891,7 @@ fn agent_cmd_sandbox_get_volume_stats(
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_sandbox_copy_file(
@@ -1706,7 +1900,7 @@ fn agent_cmd_sandbox_copy_file(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: CopyFileRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1788,12 +1982,12 @@ fn agent_cmd_sandbox_copy_file(
 
     let reply = client
         .copy_file(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_sandbox_reseed_random_dev(
@@ -1802,7 +1996,7 @@ fn agent_cmd_sandbox_reseed_random_dev(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: ReseedRandomDevRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1820,12 +2014,12 @@ fn agent_cmd_sandbox_reseed_random_dev(
 
     let reply = client
         .reseed_random_dev(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_sandbox_online_cpu_mem(
@@ -1834,7 +2028,7 @@ fn agent_cmd_sandbox_online_cpu_mem(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: OnlineCPUMemRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1877,12 +2071,12 @@ fn agent_cmd_sandbox_online_cpu_mem(
 
     let reply = client
         .online_cpu_mem(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_sandbox_set_guest_date_time(
@@ -1891,7 +2085,7 @@ fn agent_cmd_sandbox_set_guest_date_time(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: SetGuestDateTimeRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1924,12 +2118,12 @@ fn agent_cmd_sandbox_set_guest_date_time(
 
     let reply = client
         .set_guest_date_time(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_sandbox_set_ip_tables(
@@ -1938,7 +2132,7 @@ fn agent_cmd_sandbox_set_ip_tables(
     _health: &HealthClient,
     _options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let req: SetIPTablesRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1952,7 +2146,7 @@ fn agent_cmd_sandbox_set_ip_tables(
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_sandbox_add_arp_neighbors(
@@ -1961,7 +2155,7 @@ fn agent_cmd_sandbox_add_arp_neighbors(
     _health: &HealthClient,
     _options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let req: AddARPNeighborsRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -1973,12 +2167,12 @@ fn agent_cmd_sandbox_add_arp_neighbors(
 
     let reply = client
         .add_arp_neighbors(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_sandbox_update_container(
@@ -1987,7 +2181,7 @@ fn agent_cmd_sandbox_update_container(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: UpdateContainerRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -2007,12 +2201,12 @@ fn agent_cmd_sandbox_update_container(
 
     let reply = client
         .update_container(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 fn agent_cmd_sandbox_mem_hotplug_by_probe(
@@ -2021,7 +2215,7 @@ fn agent_cmd_sandbox_mem_hotplug_by_probe(
     _health: &HealthClient,
     options: &mut Options,
     args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let mut req: MemHotplugByProbeRequest = utils::make_request(args)?;
 
     let ctx = clone_context(ctx);
@@ -2057,12 +2251,12 @@ fn agent_cmd_sandbox_mem_hotplug_by_probe(
 
     let reply = client
         .mem_hotplug_by_probe(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
 }
 
 #[inline]
@@ -2136,7 +2330,7 @@ fn agent_cmd_sandbox_add_swap(
     _health: &HealthClient,
     _options: &mut Options,
     _args: &str,
-) -> Result<()> {
+) -> Result<Box<dyn Response>> {
     let req = AddSwapRequest::default();
 
     let ctx = clone_context(ctx);
@@ -2145,7 +2339,7 @@ fn agent_cmd_sandbox_add_swap(
 
     let reply = client
         .add_swap(ctx, &req)
-        .map_err(|e| anyhow!("{:?}", e).context(ERR_API_FAILED))?;
+        .map_err(|e| anyhow!(e).context(ERR_API_FAILED))?;
 
     // FIXME: Implement 'AddSwap' fully.
     eprintln!("FIXME: 'AddSwap' not fully implemented");
@@ -2153,5 +2347,88 @@ fn agent_cmd_sandbox_add_swap(
     info!(sl!(), "response received";
         "response" => format!("{:?}", reply));
 
-    Ok(())
+    Ok(Box::new(reply))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complete_cmdline() {
+        #[derive(Debug)]
+        struct TestData<'a> {
+            line: &'a str,
+            start: usize,
+            candidates: Vec<&'a str>,
+        }
+
+        let tests = &[
+            TestData {
+                line: "",
+                start: 0,
+                candidates: Vec::new(),
+            },
+            TestData {
+                line: "Create",
+                start: 0,
+                candidates: vec!["CreateContainer", "CreateSandbox"],
+            },
+            TestData {
+                line: "re",
+                start: 0,
+                candidates: vec!["repeat"],
+            },
+            TestData {
+                line: "SignalProcess ",
+                start: 14,
+                candidates: vec!["cid=", "exec_id=", "signal="],
+            },
+            TestData {
+                line: "SignalProcess cid=foo s",
+                start: 22,
+                candidates: vec!["signal="],
+            },
+            TestData {
+                line: "SignalProcess cid=foo ",
+                start: 22,
+                candidates: vec!["exec_id=", "signal="],
+            },
+            TestData {
+                line: "SignalProcess cid=",
+                start: 14,
+                candidates: Vec::new(),
+            },
+            TestData {
+                line: "Check ",
+                start: 6,
+                candidates: Vec::new(),
+            },
+            TestData {
+                line: "NoSuchCommand ",
+                start: 14,
+                candidates: Vec::new(),
+            },
+        ];
+
+        for (i, d) in tests.iter().enumerate() {
+            let msg = format!("test[{}]: {:?}", i, d);
+
+            let (start, candidates) = complete_cmdline(d.line, d.line.len());
+
+            assert_eq!(start, d.start, "{}", msg);
+
+            if d.line.is_empty() {
+                // All commands are candidates
+                assert_eq!(
+                    candidates.len(),
+                    AGENT_CMDS.len() + BUILTIN_CMDS.len(),
+                    "{}",
+                    msg
+                );
+            } else {
+                assert_eq!(candidates, d.candidates, "{}", msg);
+            }
+        }
+    }
 }
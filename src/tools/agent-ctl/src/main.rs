@@ -22,6 +22,7 @@ macro_rules! sl {
 
 mod client;
 mod rpc;
+mod scenario;
 mod types;
 mod utils;
 
@@ -50,6 +51,7 @@ fn make_examples_text(program_name: &str) -> String {
     let container_id = "$container_id";
     let local_server_address = "unix:///tmp/local.socket";
     let sandbox_id = "$sandbox_id";
+    let scenario_file = "/tmp/scenario.toml";
     let vsock_server_address = "vsock://3:1024";
     let hybrid_vsock_server_address = "unix:///run/vc/vm/foo/clh.sock";
 
@@ -115,6 +117,14 @@ fn make_examples_text(program_name: &str) -> String {
   $ {program} connect --server-address "{vsock_server_address}" --bundle-dir {bundle:?} --cmd CreateContainer
   $ {program} connect --server-address "{vsock_server_address}" --cmd DestroySandbox
 
+- Enter commands interactively (press TAB to complete command and argument names):
+
+  $ {program} connect --server-address "{local_server_address}" --interactive
+
+- Run the API commands listed in a scenario file, writing the results in JUnit format:
+
+  $ {program} connect --server-address "{local_server_address}" --scenario {scenario_file} --result-format junit --result-file results.xml
+
 - Create a Container using a custom configuration file:
 
   $ {program} connect --server-address "{vsock_server_address}" --bundle-dir {bundle:?} --cmd 'CreateContainer spec={config_file_uri}'
@@ -126,6 +136,7 @@ fn make_examples_text(program_name: &str) -> String {
         local_server_address = local_server_address,
         program = program_name,
         sandbox_id = sandbox_id,
+        scenario_file = scenario_file,
         vsock_server_address = vsock_server_address,
         hybrid_vsock_server_address = hybrid_vsock_server_address,
     )
@@ -144,9 +155,11 @@ fn connect(name: &str, global_args: clap::ArgMatches) -> Result<()> {
         .ok_or_else(|| anyhow!("need server adddress"))?
         .to_string();
 
+    let scenario_file = args.value_of("scenario").unwrap_or("").to_string();
+
     let mut commands: Vec<&str> = Vec::new();
 
-    if !interactive {
+    if !interactive && scenario_file.is_empty() {
         commands = args
             .values_of("cmd")
             .ok_or_else(|| anyhow!("need commands to send to the server"))?
@@ -178,6 +191,13 @@ fn connect(name: &str, global_args: clap::ArgMatches) -> Result<()> {
     let hybrid_vsock = args.is_present("hybrid-vsock");
     let no_auto_values = args.is_present("no-auto-values");
 
+    let result_format = args
+        .value_of("result-format")
+        .ok_or_else(|| anyhow!("need result format"))?
+        .to_string();
+
+    let result_file = args.value_of("result-file").unwrap_or("").to_string();
+
     let cfg = Config {
         server_address,
         bundle_dir,
@@ -187,6 +207,9 @@ fn connect(name: &str, global_args: clap::ArgMatches) -> Result<()> {
         hybrid_vsock,
         ignore_errors,
         no_auto_values,
+        scenario_file,
+        result_format,
+        result_file,
     };
 
     let result = rpc::run(&logger, &cfg, commands);
@@ -258,7 +281,7 @@ fn real_main() -> Result<()> {
                     Arg::with_name("interactive")
                     .short("i")
                     .long("interactive")
-                    .help("Allow interactive client"),
+                    .help("Run an interactive shell, with command completion and history"),
                     )
                 .arg(
                     Arg::with_name("no-auto-values")
@@ -266,6 +289,30 @@ fn real_main() -> Result<()> {
                     .long("no-auto-values")
                     .help("Disable automatic generation of values for sandbox ID, container ID, etc"),
                     )
+                .arg(
+                    Arg::with_name("result-file")
+                    .long("result-file")
+                    .help("File to write the scenario results to [default: stdout]")
+                    .takes_value(true)
+                    .value_name("FILE"),
+                    )
+                .arg(
+                    Arg::with_name("result-format")
+                    .long("result-format")
+                    .help("Format of the scenario results")
+                    .possible_values(scenario::RESULT_FORMATS)
+                    .default_value(scenario::RESULT_FORMAT_JSON)
+                    .takes_value(true)
+                    .value_name("FORMAT"),
+                    )
+                .arg(
+                    Arg::with_name("scenario")
+                    .long("scenario")
+                    .help("TOML file listing the API commands to run and the results to expect")
+                    .takes_value(true)
+                    .value_name("FILE")
+                    .conflicts_with_all(&["cmd", "interactive"]),
+                    )
                 .arg(
                     Arg::with_name("server-address")
                     .long("server-address")
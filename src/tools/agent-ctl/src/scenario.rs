@@ -0,0 +1,634 @@
+// Copyright (c) 2023 Kata Contributors
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Description: Scripted scenarios of API calls with assertions.
+//
+// A scenario is a TOML file listing the commands to run in order. Each step
+// can capture values into variables, which later steps reference in their
+// arguments as "${name}", and can assert on the response fields or on the
+// ttRPC error code returned by the agent.
+
+use crate::types::Options;
+use crate::utils::RESPONSE_OPTION;
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use slog::info;
+use std::collections::BTreeMap;
+use std::fs;
+use std::time::Instant;
+
+pub const RESULT_FORMAT_JSON: &str = "json";
+pub const RESULT_FORMAT_JUNIT: &str = "junit";
+
+pub const RESULT_FORMATS: &[&str] = &[RESULT_FORMAT_JSON, RESULT_FORMAT_JUNIT];
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    #[serde(default)]
+    pub name: String,
+
+    // Initial variable values.
+    #[serde(default)]
+    pub vars: BTreeMap<String, String>,
+
+    #[serde(default, rename = "step")]
+    pub steps: Vec<Step>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Step {
+    #[serde(default)]
+    pub name: String,
+
+    // Agent API or builtin command name.
+    pub cmd: String,
+
+    // Command arguments, as accepted by the "--cmd" option.
+    #[serde(default)]
+    pub args: String,
+
+    // Variables to set, mapping the variable name to a value path.
+    #[serde(default)]
+    pub capture: BTreeMap<String, String>,
+
+    // Expected values, mapping a value path to the expected value.
+    #[serde(default)]
+    pub expect: BTreeMap<String, toml::Value>,
+
+    // Expected ttRPC status code name (for example "NOT_FOUND"). If set, the
+    // step fails unless the command fails with this code.
+    #[serde(default)]
+    pub expect_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StepStatus {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StepResult {
+    pub name: String,
+    pub cmd: String,
+    pub status: StepStatus,
+    pub duration_secs: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScenarioResult {
+    pub name: String,
+    pub steps: Vec<StepResult>,
+}
+
+impl Scenario {
+    pub fn load(path: &str) -> Result<Scenario> {
+        let contents =
+            fs::read_to_string(path).with_context(|| format!("read scenario {:?}", path))?;
+
+        let mut scenario = Scenario::parse(&contents)
+            .with_context(|| format!("invalid scenario file {:?}", path))?;
+
+        if scenario.name.is_empty() {
+            scenario.name = path.to_string();
+        }
+
+        Ok(scenario)
+    }
+
+    pub fn parse(contents: &str) -> Result<Scenario> {
+        let scenario: Scenario = toml::from_str(contents).map_err(|e| anyhow!(e))?;
+
+        if scenario.steps.is_empty() {
+            return Err(anyhow!("scenario has no steps"));
+        }
+
+        for (i, step) in scenario.steps.iter().enumerate() {
+            if step.cmd.is_empty() {
+                return Err(anyhow!("step {} has no command", i + 1));
+            }
+        }
+
+        Ok(scenario)
+    }
+}
+
+impl Step {
+    fn display_name(&self, index: usize) -> String {
+        if self.name.is_empty() {
+            format!("{}: {}", index + 1, self.cmd)
+        } else {
+            self.name.clone()
+        }
+    }
+}
+
+impl ScenarioResult {
+    pub fn failures(&self) -> usize {
+        self.count(StepStatus::Failed)
+    }
+
+    fn count(&self, status: StepStatus) -> usize {
+        self.steps.iter().filter(|s| s.status == status).count()
+    }
+
+    pub fn render(&self, format: &str) -> Result<String> {
+        match format {
+            RESULT_FORMAT_JSON => serde_json::to_string_pretty(self).map_err(|e| anyhow!(e)),
+            RESULT_FORMAT_JUNIT => Ok(self.to_junit()),
+            _ => Err(anyhow!("invalid result format: {:?}", format)),
+        }
+    }
+
+    fn to_junit(&self) -> String {
+        let total: f64 = self.steps.iter().map(|s| s.duration_secs).sum();
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites>\n");
+
+        xml.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.3}\">\n",
+            xml_escape(&self.name),
+            self.steps.len(),
+            self.failures(),
+            self.count(StepStatus::Skipped),
+            total
+        ));
+
+        for step in &self.steps {
+            xml.push_str(&format!(
+                "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\"",
+                xml_escape(&step.name),
+                xml_escape(&self.name),
+                step.duration_secs
+            ));
+
+            match step.status {
+                StepStatus::Passed => xml.push_str("/>\n"),
+                StepStatus::Skipped => xml.push_str(">\n      <skipped/>\n    </testcase>\n"),
+                StepStatus::Failed => {
+                    let message = xml_escape(step.message.as_deref().unwrap_or(""));
+
+                    xml.push_str(&format!(
+                        ">\n      <failure message=\"{}\">{}</failure>\n    </testcase>\n",
+                        message, message
+                    ));
+                }
+            }
+        }
+
+        xml.push_str("  </testsuite>\n</testsuites>\n");
+
+        xml
+    }
+}
+
+// Run all steps of the scenario, using 'exec' to run each command.
+//
+// Once a step fails, the remaining steps are skipped unless 'ignore_errors'
+// is set.
+pub fn run<F>(
+    scenario: &Scenario,
+    options: &mut Options,
+    ignore_errors: bool,
+    mut exec: F,
+) -> ScenarioResult
+where
+    F: FnMut(&str, &str, &mut Options) -> Result<()>,
+{
+    let mut vars = scenario.vars.clone();
+    let mut steps = Vec::new();
+    let mut failed = false;
+
+    for (i, step) in scenario.steps.iter().enumerate() {
+        let name = step.display_name(i);
+
+        if failed && !ignore_errors {
+            steps.push(StepResult {
+                name,
+                cmd: step.cmd.clone(),
+                status: StepStatus::Skipped,
+                duration_secs: 0.0,
+                message: None,
+            });
+
+            continue;
+        }
+
+        let start = Instant::now();
+        let result = run_step(step, &mut vars, options, &mut exec);
+        let duration_secs = start.elapsed().as_secs_f64();
+
+        let (status, message) = match result {
+            Ok(()) => (StepStatus::Passed, None),
+            Err(e) => {
+                failed = true;
+                (StepStatus::Failed, Some(format!("{:#}", e)))
+            }
+        };
+
+        info!(sl!(), "scenario step finished";
+            "step" => &name,
+            "status" => format!("{:?}", status),
+            "message" => message.as_deref().unwrap_or(""));
+
+        steps.push(StepResult {
+            name,
+            cmd: step.cmd.clone(),
+            status,
+            duration_secs,
+            message,
+        });
+    }
+
+    ScenarioResult {
+        name: scenario.name.clone(),
+        steps,
+    }
+}
+
+fn run_step<F>(
+    step: &Step,
+    vars: &mut BTreeMap<String, String>,
+    options: &mut Options,
+    exec: &mut F,
+) -> Result<()>
+where
+    F: FnMut(&str, &str, &mut Options) -> Result<()>,
+{
+    let args = substitute(&step.args, vars, options)?;
+
+    // Don't let a failing command expose the response of an earlier one.
+    options.remove(RESPONSE_OPTION);
+
+    let result = exec(&step.cmd, &args, options);
+
+    match (&step.expect_error, result) {
+        (None, Err(e)) => return Err(e),
+        (Some(code), Ok(())) => {
+            return Err(anyhow!("expected error {:?} but command succeeded", code))
+        }
+        (Some(code), Err(e)) => match error_code(&e) {
+            Some(actual) if actual.eq(code) => (),
+            Some(actual) => {
+                return Err(anyhow!(
+                    "expected error {:?}, got {:?}: {:#}",
+                    code,
+                    actual,
+                    e
+                ))
+            }
+            None => return Err(anyhow!("expected error {:?}, got: {:#}", code, e)),
+        },
+        (None, Ok(())) => (),
+    }
+
+    let doc = step_document(options)?;
+
+    for (var, path) in &step.capture {
+        let value = lookup(&doc, path)
+            .ok_or_else(|| anyhow!("cannot capture {:?}: no value at {:?}", var, path))?;
+
+        vars.insert(var.clone(), value_to_string(value));
+    }
+
+    for (path, expected) in &step.expect {
+        let expected = match expected {
+            toml::Value::String(s) => substitute(s, vars, options)?,
+            v => v.to_string(),
+        };
+
+        let actual = lookup(&doc, path)
+            .map(value_to_string)
+            .ok_or_else(|| anyhow!("expected {:?} = {:?}, but no value found", path, expected))?;
+
+        if actual != expected {
+            return Err(anyhow!(
+                "expected {:?} = {:?}, got {:?}",
+                path,
+                expected,
+                actual
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+// Value paths are resolved against this document, so that paths either
+// start with "response." (the command response) or "options." (the
+// options the commands use, for example "options.cid").
+fn step_document(options: &Options) -> Result<Value> {
+    let response = match options.get(RESPONSE_OPTION) {
+        Some(r) => serde_json::from_str(r).map_err(|e| anyhow!(e))?,
+        None => Value::Null,
+    };
+
+    let opts: serde_json::Map<String, Value> = options
+        .iter()
+        .filter(|(k, _)| k.as_str() != RESPONSE_OPTION)
+        .map(|(k, v)| (k.clone(), Value::String(v.clone())))
+        .collect();
+
+    Ok(json!({ "response": response, "options": opts }))
+}
+
+// Find the value for a dotted path like "response.interfaces.0.name".
+fn lookup<'a>(doc: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(doc, |value, key| match value {
+        Value::Object(map) => map.get(key),
+        Value::Array(list) => key.parse::<usize>().ok().and_then(|i| list.get(i)),
+        _ => None,
+    })
+}
+
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        v => v.to_string(),
+    }
+}
+
+// Replace "${name}" references with the value of the variable, falling
+// back to the option of the same name.
+fn substitute(s: &str, vars: &BTreeMap<String, String>, options: &Options) -> Result<String> {
+    let mut result = String::new();
+    let mut rest = s;
+
+    while let Some(start) = rest.find("${") {
+        let end = rest[start..]
+            .find('}')
+            .ok_or_else(|| anyhow!("unterminated variable reference in {:?}", s))?;
+
+        let name = &rest[start + 2..start + end];
+
+        let value = vars
+            .get(name)
+            .or_else(|| options.get(name))
+            .ok_or_else(|| anyhow!("unknown variable {:?}", name))?;
+
+        result.push_str(&rest[..start]);
+        result.push_str(value);
+
+        rest = &rest[start + end + 1..];
+    }
+
+    result.push_str(rest);
+
+    Ok(result)
+}
+
+// Return the name of the ttRPC status code of a failed API call.
+fn error_code(e: &anyhow::Error) -> Option<String> {
+    e.chain()
+        .find_map(|c| match c.downcast_ref::<ttrpc::Error>()? {
+            ttrpc::Error::RpcStatus(s) => Some(format!("{:?}", s.code())),
+            _ => None,
+        })
+}
+
+fn xml_escape(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '&' => "&amp;".to_string(),
+            '<' => "&lt;".to_string(),
+            '>' => "&gt;".to_string(),
+            '"' => "&quot;".to_string(),
+            '\'' => "&apos;".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ttrpc::error::get_rpc_status;
+
+    const SCENARIO: &str = r#"
+name = "lifecycle"
+
+[vars]
+sid = "sandbox"
+
+[[step]]
+cmd = "CreateContainer"
+args = "sid=${sid} cid=foo"
+capture = { cid = "options.cid" }
+
+[[step]]
+name = "exec"
+cmd = "ExecProcess"
+args = "cid=${cid}"
+expect = { "response.pid" = 42, "response.cid" = "${cid}" }
+
+[[step]]
+cmd = "RemoveContainer"
+expect_error = "NOT_FOUND"
+
+[[step]]
+cmd = "Check"
+"#;
+
+    fn fake_exec(cmd: &str, args: &str, options: &mut Options) -> Result<()> {
+        for arg in args.split_whitespace() {
+            if let Some((k, v)) = arg.split_once('=') {
+                options.insert(k.to_string(), v.to_string());
+            }
+        }
+
+        match cmd {
+            "ExecProcess" => {
+                let cid = options.get("cid").cloned().unwrap_or_default();
+                let reply = json!({ "pid": 42, "cid": cid });
+
+                options.insert(RESPONSE_OPTION.to_string(), reply.to_string());
+
+                Ok(())
+            }
+            "RemoveContainer" => {
+                let e = get_rpc_status(ttrpc::Code::NOT_FOUND, "no such container".to_string());
+
+                Err(anyhow!(e).context("API failed"))
+            }
+            "Check" => Err(anyhow!("connection reset")),
+            _ => Ok(()),
+        }
+    }
+
+    #[test]
+    fn test_scenario_parse() {
+        let scenario = Scenario::parse(SCENARIO).unwrap();
+
+        assert_eq!(scenario.name, "lifecycle");
+        assert_eq!(scenario.steps.len(), 4);
+        assert_eq!(scenario.steps[0].display_name(0), "1: CreateContainer");
+        assert_eq!(scenario.steps[1].display_name(1), "exec");
+        assert_eq!(
+            scenario.steps[2].expect_error,
+            Some("NOT_FOUND".to_string())
+        );
+
+        assert!(Scenario::parse("name = \"empty\"").is_err());
+        assert!(Scenario::parse("[[step]]\ncmd = \"\"").is_err());
+        assert!(Scenario::parse("[[step]]\ncmd = \"Check\"\nfoo = 1").is_err());
+    }
+
+    #[test]
+    fn test_scenario_run() {
+        let scenario = Scenario::parse(SCENARIO).unwrap();
+        let mut options = Options::new();
+
+        let result = run(&scenario, &mut options, true, fake_exec);
+
+        let status: Vec<StepStatus> = result.steps.iter().map(|s| s.status).collect();
+        assert_eq!(
+            status,
+            vec![
+                StepStatus::Passed,
+                StepStatus::Passed,
+                StepStatus::Passed,
+                StepStatus::Failed
+            ]
+        );
+        assert_eq!(result.failures(), 1);
+
+        // The steps after a failure are skipped by default
+        let scenario =
+            Scenario::parse("[[step]]\ncmd = \"Check\"\n[[step]]\ncmd = \"CreateContainer\"")
+                .unwrap();
+
+        let result = run(&scenario, &mut options, false, fake_exec);
+
+        assert_eq!(result.steps[0].status, StepStatus::Failed);
+        assert_eq!(result.steps[1].status, StepStatus::Skipped);
+    }
+
+    #[test]
+    fn test_scenario_step_failures() {
+        #[derive(Debug)]
+        struct TestData<'a> {
+            step: &'a str,
+            error: &'a str,
+        }
+
+        let tests = &[
+            TestData {
+                step: "cmd = \"ExecProcess\"\nexpect = { \"response.pid\" = 1 }",
+                error: "expected \"response.pid\" = \"1\", got \"42\"",
+            },
+            TestData {
+                step: "cmd = \"ExecProcess\"\nexpect = { \"response.foo\" = 1 }",
+                error: "no value found",
+            },
+            TestData {
+                step: "cmd = \"ExecProcess\"\nargs = \"cid=${bar}\"",
+                error: "unknown variable \"bar\"",
+            },
+            TestData {
+                step: "cmd = \"ExecProcess\"\nexpect_error = \"NOT_FOUND\"",
+                error: "but command succeeded",
+            },
+            TestData {
+                step: "cmd = \"RemoveContainer\"\nexpect_error = \"ALREADY_EXISTS\"",
+                error: "got \"NOT_FOUND\"",
+            },
+            TestData {
+                step: "cmd = \"Check\"\nexpect_error = \"NOT_FOUND\"",
+                error: "connection reset",
+            },
+            TestData {
+                step: "cmd = \"Check\"\ncapture = { pid = \"response.pid\" }",
+                error: "connection reset",
+            },
+        ];
+
+        for (i, d) in tests.iter().enumerate() {
+            let msg = format!("test[{}]: {:?}", i, d);
+
+            let scenario = Scenario::parse(&format!("[[step]]\n{}", d.step)).unwrap();
+            let mut options = Options::new();
+
+            let result = run(&scenario, &mut options, false, fake_exec);
+            let step = &result.steps[0];
+
+            assert_eq!(step.status, StepStatus::Failed, "{}", msg);
+
+            let message = step.message.as_ref().unwrap();
+            assert!(message.contains(d.error), "{}: {}", msg, message);
+        }
+    }
+
+    #[test]
+    fn test_substitute() {
+        let mut vars = BTreeMap::new();
+        vars.insert("cid".to_string(), "foo".to_string());
+
+        let mut options = Options::new();
+        options.insert("sid".to_string(), "bar".to_string());
+        options.insert("cid".to_string(), "baz".to_string());
+
+        assert_eq!(
+            substitute("cid=${cid} sid=${sid}", &vars, &options).unwrap(),
+            "cid=foo sid=bar"
+        );
+        assert_eq!(substitute("no vars", &vars, &options).unwrap(), "no vars");
+        assert!(substitute("cid=${cid", &vars, &options).is_err());
+        assert!(substitute("${unknown}", &vars, &options).is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        let result = ScenarioResult {
+            name: "a&b".to_string(),
+            steps: vec![
+                StepResult {
+                    name: "one".to_string(),
+                    cmd: "Check".to_string(),
+                    status: StepStatus::Passed,
+                    duration_secs: 0.5,
+                    message: None,
+                },
+                StepResult {
+                    name: "two".to_string(),
+                    cmd: "Check".to_string(),
+                    status: StepStatus::Failed,
+                    duration_secs: 0.25,
+                    message: Some("got \"<nothing>\"".to_string()),
+                },
+                StepResult {
+                    name: "three".to_string(),
+                    cmd: "Check".to_string(),
+                    status: StepStatus::Skipped,
+                    duration_secs: 0.0,
+                    message: None,
+                },
+            ],
+        };
+
+        let xml = result.render(RESULT_FORMAT_JUNIT).unwrap();
+        assert!(xml.contains(
+            "<testsuite name=\"a&amp;b\" tests=\"3\" failures=\"1\" skipped=\"1\" time=\"0.750\">"
+        ));
+        assert!(xml.contains("<testcase name=\"one\" classname=\"a&amp;b\" time=\"0.500\"/>"));
+        assert!(xml.contains("<failure message=\"got &quot;&lt;nothing&gt;&quot;\">"));
+        assert!(xml.contains("<skipped/>"));
+
+        let json: Value =
+            serde_json::from_str(&result.render(RESULT_FORMAT_JSON).unwrap()).unwrap();
+        assert_eq!(json["steps"][1]["status"], "failed");
+        assert_eq!(json["steps"][1]["message"], "got \"<nothing>\"");
+        assert!(json["steps"][0].get("message").is_none());
+
+        assert!(result.render("yaml").is_err());
+    }
+}
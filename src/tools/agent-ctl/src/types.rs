@@ -19,4 +19,7 @@ pub struct Config {
     pub hybrid_vsock: bool,
     pub ignore_errors: bool,
     pub no_auto_values: bool,
+    pub scenario_file: String,
+    pub result_format: String,
+    pub result_file: String,
 }
@@ -24,6 +24,7 @@ use protocols::oci::{
 };
 use rand::Rng;
 use serde::de::DeserializeOwned;
+use serde::Serialize;
 use slog::{debug, warn};
 use std::collections::HashMap;
 use std::fs::File;
@@ -35,6 +36,9 @@ const SANDBOX_ID_LEN: u8 = 64;
 
 const FILE_URI: &str = "file://";
 
+// Well-known option name holding the last API response (in JSON format).
+pub const RESPONSE_OPTION: &str = "response";
+
 // Length of the guests hostname
 const MIN_HOSTNAME_LEN: u8 = 8;
 
@@ -214,6 +218,25 @@ pub fn get_option(name: &str, options: &mut Options, args: &str) -> Result<Strin
     Ok(value)
 }
 
+// Response of an API call.
+pub trait Response {
+    fn to_json(&self) -> Result<String>;
+}
+
+impl<T: Serialize> Response for T {
+    fn to_json(&self) -> Result<String> {
+        serde_json::to_string(self).map_err(|e| anyhow!(e))
+    }
+}
+
+// Save the JSON form of an API response in the global options hash, making
+// the last response available to subsequent commands and scenario steps.
+pub fn save_response(options: &mut Options, reply: &dyn Response) -> Result<()> {
+    options.insert(RESPONSE_OPTION.to_string(), reply.to_json()?);
+
+    Ok(())
+}
+
 pub fn generate_random_hex_string(len: u32) -> String {
     const CHARSET: &[u8] = b"abcdef0123456789";
     let mut rng = rand::thread_rng();
@@ -538,6 +561,7 @@ fn resources_oci_to_ttrpc(res: &oci::LinuxResources) -> ttrpcLinuxResources {
         BlockIO: block_io,
         HugepageLimits: hugepage_limits,
         Network: network,
+        Unified: res.unified.clone(),
         ..Default::default()
     }
 }
@@ -599,6 +623,8 @@ fn seccomp_oci_to_ttrpc(sec: &oci::LinuxSeccomp) -> ttrpcLinuxSeccomp {
         ttrpc_syscalls.push(ttrpc_sys);
     }
     ttrpc_seccomp.set_Syscalls(ttrpc_syscalls);
+    ttrpc_seccomp.set_ListenerPath(sec.listener_path.clone());
+    ttrpc_seccomp.set_ListenerMetadata(sec.listener_metadata.clone());
     ttrpc_seccomp
 }
 fn intel_rdt_oci_to_ttrpc(ir: &oci::LinuxIntelRdt) -> ttrpcLinuxIntelRdt {
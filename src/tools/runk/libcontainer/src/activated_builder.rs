@@ -82,7 +82,7 @@ impl ActivatedContainer {
             .ok_or_else(|| anyhow!("spec config was not present"))?;
         self.adapt_exec_spec(spec, container.status.pid, logger)?;
         debug!(logger, "adapted spec: {:?}", spec);
-        validate_spec(spec, &self.console_socket)?;
+        validate_spec(spec, &self.console_socket, true)?;
 
         debug!(
             logger,
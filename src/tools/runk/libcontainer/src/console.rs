@@ -0,0 +1,168 @@
+// Copyright 2021-2022 Kata Contributors
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use anyhow::{anyhow, Context, Result};
+use nix::cmsg_space;
+use nix::errno::Errno;
+use nix::sys::socket::{recvmsg, ControlMessageOwned, MsgFlags};
+use nix::sys::termios::{cfmakeraw, tcgetattr, tcsetattr, SetArg, Termios};
+use nix::sys::uio::IoVec;
+use nix::unistd::isatty;
+use std::{
+    fs::{self, File},
+    os::unix::{
+        io::{AsRawFd, FromRawFd, RawFd},
+        net::UnixListener,
+    },
+    path::{Path, PathBuf},
+};
+
+pub const CONSOLE_SOCKET_NAME: &str = "console.sock";
+
+/// Returns the path of the console socket that runk creates by itself when a
+/// container runs in the foreground with a terminal.
+pub fn get_console_socket_path(state_root: &Path, id: &str) -> PathBuf {
+    state_root.join(id).join(CONSOLE_SOCKET_NAME)
+}
+
+/// A unix socket listener used to receive the master side of the pseudo terminal
+/// that the container init process allocates.
+#[derive(Debug)]
+pub struct ConsoleSocket {
+    path: PathBuf,
+    listener: UnixListener,
+}
+
+impl ConsoleSocket {
+    pub fn bind(path: &Path) -> Result<Self> {
+        let listener = UnixListener::bind(path)
+            .with_context(|| format!("failed to bind console socket {}", path.display()))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            listener,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Accept a connection from the container and receive the pty master fd
+    /// sent by rustjail::console::setup_master_console.
+    pub fn recv_master(&self) -> Result<File> {
+        let (stream, _) = self.listener.accept()?;
+        let mut buf = [0u8; 64];
+        let iov = [IoVec::from_mut_slice(&mut buf)];
+        let mut cmsg_buf = cmsg_space!([RawFd; 1]);
+        let msg = recvmsg(
+            stream.as_raw_fd(),
+            &iov,
+            Some(&mut cmsg_buf),
+            MsgFlags::empty(),
+        )?;
+
+        for cmsg in msg.cmsgs() {
+            if let ControlMessageOwned::ScmRights(fds) = cmsg {
+                if let Some(fd) = fds.first() {
+                    return Ok(unsafe { File::from_raw_fd(*fd) });
+                }
+            }
+        }
+        Err(anyhow!(
+            "no pty master fd was received from the console socket"
+        ))
+    }
+}
+
+impl Drop for ConsoleSocket {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Puts the terminal into raw mode and restores the original mode when dropped.
+#[derive(Debug)]
+pub struct RawTerminal {
+    fd: RawFd,
+    original: Termios,
+}
+
+impl RawTerminal {
+    /// Returns None if fd does not refer to a terminal.
+    pub fn new(fd: RawFd) -> Result<Option<Self>> {
+        if !isatty(fd)? {
+            return Ok(None);
+        }
+        let original = tcgetattr(fd)?;
+        let mut raw = original.clone();
+        cfmakeraw(&mut raw);
+        tcsetattr(fd, SetArg::TCSANOW, &raw)?;
+        Ok(Some(Self { fd, original }))
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        let _ = tcsetattr(self.fd, SetArg::TCSANOW, &self.original);
+    }
+}
+
+/// Copy the window size of the terminal `from` to the pseudo terminal `to`.
+pub fn resize_pty(from: RawFd, to: RawFd) -> Result<()> {
+    let mut ws: libc::winsize = unsafe { std::mem::zeroed() };
+    let ret = unsafe { libc::ioctl(from, libc::TIOCGWINSZ, &mut ws) };
+    Errno::result(ret).map_err(|e| anyhow!(e).context("ioctl TIOCGWINSZ"))?;
+    let ret = unsafe { libc::ioctl(to, libc::TIOCSWINSZ, &ws) };
+    Errno::result(ret).map_err(|e| anyhow!(e).context("ioctl TIOCSWINSZ"))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nix::sys::socket::{sendmsg, ControlMessage};
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixStream;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_get_console_socket_path() {
+        assert_eq!(
+            get_console_socket_path(Path::new("/state"), "test"),
+            PathBuf::from("/state/test/console.sock")
+        );
+    }
+
+    #[test]
+    fn test_console_socket_recv_master() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join(CONSOLE_SOCKET_NAME);
+        let socket = ConsoleSocket::bind(&path).unwrap();
+        assert!(path.exists());
+
+        // Send one end of a socket pair in place of a pty master.
+        let (mut local, remote) = UnixStream::pair().unwrap();
+        let client = UnixStream::connect(&path).unwrap();
+        let iov = [IoVec::from_slice(b"/dev/ptmx")];
+        let fds = [remote.as_raw_fd()];
+        sendmsg(
+            client.as_raw_fd(),
+            &iov,
+            &[ControlMessage::ScmRights(&fds)],
+            MsgFlags::empty(),
+            None,
+        )
+        .unwrap();
+
+        let mut master = socket.recv_master().unwrap();
+        master.write_all(b"hello").unwrap();
+        let mut buf = [0u8; 5];
+        local.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"hello");
+
+        drop(socket);
+        assert!(!path.exists());
+    }
+}
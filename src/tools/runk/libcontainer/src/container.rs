@@ -15,9 +15,10 @@ use nix::{
     sys::signal::SIGKILL,
     unistd::{chdir, unlink, Pid},
 };
-use oci::{ContainerState, State as OCIState};
+use oci::{ContainerState, LinuxResources, State as OCIState};
 use procfs;
-use rustjail::cgroups::fs::Manager as CgroupManager;
+use protocols::agent::CgroupStats;
+use rustjail::cgroups::{fs::Manager as CgroupManager, notifier, Manager};
 use rustjail::{
     container::{BaseContainer, LinuxContainer, EXEC_FIFO_FILENAME},
     process::{Process, ProcessOperations},
@@ -30,6 +31,7 @@ use std::{
     fs,
     path::{Path, PathBuf},
 };
+use tokio::sync::mpsc::Receiver;
 
 use kata_sys_util::hooks::HookStates;
 
@@ -198,6 +200,97 @@ impl Container {
         remove_cgroup_dir(&self.cgroup)?;
         self.status.remove_dir()
     }
+
+    /// Collect the cgroup statistics of the container.
+    pub fn stats(&self) -> Result<CgroupStats> {
+        if self.state == ContainerState::Stopped {
+            return Err(anyhow!(
+                "container {} is not running: current status is: {:?}",
+                self.status.id,
+                self.state
+            ));
+        }
+        self.cgroup_manager()?.get_stats()
+    }
+
+    /// Apply the new resource constraints to the cgroup of a live container and
+    /// record them in the saved status so that subsequent commands see them.
+    pub fn update(&mut self, resources: &LinuxResources) -> Result<()> {
+        if self.state == ContainerState::Stopped {
+            return Err(anyhow!(
+                "failed to update container: current status is: {:?}",
+                self.state
+            ));
+        }
+        self.cgroup_manager()?.set(resources, true)?;
+
+        let linux = self
+            .status
+            .config
+            .spec
+            .as_mut()
+            .and_then(|spec| spec.linux.as_mut())
+            .ok_or_else(|| anyhow!("linux config was not present"))?;
+        linux.resources = Some(merge_resources(linux.resources.take(), resources));
+        self.status.save()
+    }
+
+    /// Returns a channel that receives the container id when an OOM event occurs
+    /// in the container's cgroup. The channel is closed once the cgroup becomes empty.
+    pub async fn oom_notifier(&self) -> Result<Receiver<String>> {
+        let cg_dir = self.cgroup_manager()?.get_cgroup_path("memory")?;
+        notifier::notify_oom(&self.status.id, cg_dir).await
+    }
+
+    // CgroupManager.cgroup is skipped on serialization, so build a new manager
+    // from the saved cgroup path instead of using the one in the status.
+    fn cgroup_manager(&self) -> Result<CgroupManager> {
+        CgroupManager::new(&self.status.cgroup_manager.cpath)
+    }
+}
+
+// Overlay the specified resources on top of the current ones. Only the resources
+// set by the update are replaced, just like runc does.
+fn merge_resources(current: Option<LinuxResources>, update: &LinuxResources) -> LinuxResources {
+    let mut merged = current.unwrap_or_default();
+    if let Some(memory) = update.memory.as_ref() {
+        let m = merged.memory.get_or_insert_with(Default::default);
+        m.limit = memory.limit.or(m.limit);
+        m.reservation = memory.reservation.or(m.reservation);
+        m.swap = memory.swap.or(m.swap);
+        m.kernel = memory.kernel.or(m.kernel);
+        m.kernel_tcp = memory.kernel_tcp.or(m.kernel_tcp);
+        m.swappiness = memory.swappiness.or(m.swappiness);
+        m.disable_oom_killer = memory.disable_oom_killer.or(m.disable_oom_killer);
+    }
+    if let Some(cpu) = update.cpu.as_ref() {
+        let c = merged.cpu.get_or_insert_with(Default::default);
+        c.shares = cpu.shares.or(c.shares);
+        c.quota = cpu.quota.or(c.quota);
+        c.period = cpu.period.or(c.period);
+        c.realtime_runtime = cpu.realtime_runtime.or(c.realtime_runtime);
+        c.realtime_period = cpu.realtime_period.or(c.realtime_period);
+        if !cpu.cpus.is_empty() {
+            c.cpus = cpu.cpus.clone();
+        }
+        if !cpu.mems.is_empty() {
+            c.mems = cpu.mems.clone();
+        }
+    }
+    if update.pids.is_some() {
+        merged.pids = update.pids.clone();
+    }
+    if update.block_io.is_some() {
+        merged.block_io = update.block_io.clone();
+    }
+    if !update.hugepage_limits.is_empty() {
+        merged.hugepage_limits = update.hugepage_limits.clone();
+    }
+    if update.network.is_some() {
+        merged.network = update.network.clone();
+    }
+    merged.unified.extend(update.unified.clone());
+    merged
 }
 
 /// Used to run a process. If init is set, it will create a container and run the process in it.
@@ -405,6 +498,7 @@ pub fn get_fifo_path(status: &Status) -> PathBuf {
 mod tests {
     use super::*;
     use crate::utils::test_utils::*;
+    use oci::{LinuxCpu, LinuxMemory, LinuxPids};
     use rustjail::container::EXEC_FIFO_FILENAME;
     use std::path::PathBuf;
 
@@ -423,4 +517,47 @@ mod tests {
 
         assert_eq!(get_fifo_path(&status), test_data);
     }
+
+    #[test]
+    fn test_merge_resources() {
+        let current = LinuxResources {
+            memory: Some(LinuxMemory {
+                limit: Some(1024),
+                swappiness: Some(10),
+                ..Default::default()
+            }),
+            cpu: Some(LinuxCpu {
+                shares: Some(1024),
+                cpus: "0-1".to_string(),
+                ..Default::default()
+            }),
+            pids: Some(LinuxPids { limit: 10 }),
+            ..Default::default()
+        };
+        let update = LinuxResources {
+            memory: Some(LinuxMemory {
+                limit: Some(2048),
+                ..Default::default()
+            }),
+            cpu: Some(LinuxCpu {
+                quota: Some(50000),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let merged = merge_resources(Some(current), &update);
+        let memory = merged.memory.unwrap();
+        assert_eq!(memory.limit, Some(2048));
+        assert_eq!(memory.swappiness, Some(10));
+        let cpu = merged.cpu.unwrap();
+        assert_eq!(cpu.shares, Some(1024));
+        assert_eq!(cpu.quota, Some(50000));
+        assert_eq!(cpu.cpus, "0-1");
+        assert_eq!(merged.pids.unwrap().limit, 10);
+
+        let merged = merge_resources(None, &update);
+        assert_eq!(merged.memory.unwrap().limit, Some(2048));
+        assert!(merged.pids.is_none());
+    }
 }
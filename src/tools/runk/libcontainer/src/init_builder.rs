@@ -22,6 +22,10 @@ pub struct InitContainer {
     root: PathBuf,
     console_socket: Option<PathBuf>,
     pid_file: Option<PathBuf>,
+    /// Whether runk exits right after launching the container. If not set,
+    /// runk stays as the parent of the container and forwards its stdio.
+    #[builder(default = "true")]
+    detach: bool,
 }
 
 impl InitContainerBuilder {
@@ -56,7 +60,7 @@ impl InitContainer {
         // Only absolute rootfs path is valid when creating LinuxContainer later.
         canonicalize_spec_root(&mut spec, &bundle_canon)?;
         debug!(logger, "load spec from config file: {:?}", spec);
-        validate_spec(&spec, &self.console_socket)?;
+        validate_spec(&spec, &self.console_socket, self.detach)?;
 
         let config = CreateOpts {
             cgroup_name: "".to_string(),
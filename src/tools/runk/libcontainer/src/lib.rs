@@ -5,6 +5,7 @@
 
 pub mod activated_builder;
 pub mod cgroup;
+pub mod console;
 pub mod container;
 pub mod created_builder;
 pub mod init_builder;
@@ -52,13 +52,14 @@ pub fn canonicalize_spec_root(spec: &mut Spec, bundle_canon: &Path) -> Result<()
     Ok(())
 }
 
-/// Check whether spec is valid. Now runk only support detach mode.
-pub fn validate_spec(spec: &Spec, console_socket: &Option<PathBuf>) -> Result<()> {
+/// Check whether spec is valid.
+pub fn validate_spec(spec: &Spec, console_socket: &Option<PathBuf>, detach: bool) -> Result<()> {
     validate_process_spec(&spec.process)?;
     if let Some(process) = spec.process.as_ref() {
-        // runk always launches containers with detached mode, so users have to
+        // In detached mode, runk exits after launching the container, so users have to
         // use a console socket with run or create operation when a terminal is used.
-        if process.terminal && console_socket.is_none() {
+        // In foreground mode, runk allocates the console socket by itself.
+        if detach && process.terminal && console_socket.is_none() {
             return Err(anyhow!(
                 "cannot allocate a pseudo-TTY without setting a console socket"
             ));
@@ -287,4 +288,23 @@ pub(crate) mod test_utils {
         invalid_process.cwd = "test/".to_string();
         assert!(validate_process_spec(&Some(invalid_process)).is_err());
     }
+
+    #[test]
+    fn test_validate_spec_terminal() {
+        let spec = Spec {
+            process: Some(Process {
+                terminal: true,
+                args: vec!["sh".to_string()],
+                cwd: "/".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let console_socket = Some(PathBuf::from(TEST_CONSOLE_SOCKET_PATH));
+
+        assert!(validate_spec(&spec, &None, true).is_err());
+        assert!(validate_spec(&spec, &console_socket, true).is_ok());
+        // In foreground mode, runk creates a console socket by itself.
+        assert!(validate_spec(&spec, &None, false).is_ok());
+    }
 }
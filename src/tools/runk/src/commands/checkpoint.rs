@@ -0,0 +1,83 @@
+// Copyright 2021-2022 Kata Contributors
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use anyhow::{anyhow, Result};
+use libcontainer::container::Container;
+use liboci_cli::Checkpoint;
+use oci::ContainerState;
+use slog::{info, Logger};
+use std::{fs, path::Path, process::Command};
+
+const CRIU_BINARY: &str = "criu";
+
+pub fn run(opts: Checkpoint, root: &Path, logger: &Logger) -> Result<()> {
+    let container = Container::load(root, &opts.container_id)?;
+    if container.state != ContainerState::Running && container.state != ContainerState::Paused {
+        return Err(anyhow!(
+            "failed to checkpoint container: current status is: {:?}",
+            container.state
+        ));
+    }
+
+    fs::create_dir_all(&opts.image_path)?;
+
+    let args = get_criu_dump_args(&opts, container.status.pid, &container.status.rootfs);
+    let output = Command::new(CRIU_BINARY)
+        .args(&args)
+        .output()
+        .map_err(|e| {
+            anyhow!(
+                "failed to run {}: {}; checkpoint requires CRIU to be installed",
+                CRIU_BINARY,
+                e
+            )
+        })?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "criu dump failed: {}",
+            std::str::from_utf8(&output.stderr)?
+        ));
+    }
+
+    // The container process has been killed by criu unless --leave-running is specified.
+    if !opts.leave_running {
+        container.destroy()?;
+    }
+
+    info!(&logger, "checkpoint command finished successfully");
+    Ok(())
+}
+
+fn get_criu_dump_args(opts: &Checkpoint, pid: i32, rootfs: &str) -> Vec<String> {
+    let mut args = vec![
+        "dump".to_string(),
+        "--tree".to_string(),
+        pid.to_string(),
+        "--images-dir".to_string(),
+        opts.image_path.display().to_string(),
+        "--root".to_string(),
+        rootfs.to_string(),
+        "--manage-cgroups".to_string(),
+    ];
+    if let Some(work_path) = opts.work_path.as_ref() {
+        args.push("--work-dir".to_string());
+        args.push(work_path.display().to_string());
+    }
+
+    let flags = [
+        (opts.leave_running, "--leave-running"),
+        (opts.tcp_established, "--tcp-established"),
+        (opts.ext_unix_sk, "--ext-unix-sk"),
+        (opts.shell_job, "--shell-job"),
+        (opts.file_locks, "--file-locks"),
+    ];
+    for (enabled, flag) in flags {
+        if enabled {
+            args.push(flag.to_string());
+        }
+    }
+
+    args
+}
@@ -0,0 +1,397 @@
+// Copyright 2021-2022 Kata Contributors
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use anyhow::{anyhow, Result};
+use clap::Parser;
+use libcontainer::container::Container;
+use oci::ContainerState;
+use protocols::agent::{BlkioStatsEntry, CgroupStats, MemoryData};
+use serde::Serialize;
+use slog::{info, Logger};
+use std::{collections::HashMap, path::Path, time::Duration};
+
+const EVENT_TYPE_STATS: &str = "stats";
+const EVENT_TYPE_OOM: &str = "oom";
+
+/// Show resource statistics for the container
+// The same as liboci-cli, except that a zero interval is rejected.
+#[derive(Parser, Debug)]
+pub struct Events {
+    /// Sets the stats collection interval in seconds (default: 5s)
+    #[clap(long, default_value = "5", parse(try_from_str = parse_interval))]
+    pub interval: u32,
+    /// Display the container stats only once
+    #[clap(long)]
+    pub stats: bool,
+    /// Name of the container instance
+    #[clap(forbid_empty_values = true, required = true)]
+    pub container_id: String,
+}
+
+fn parse_interval(s: &str) -> Result<u32> {
+    match s.parse::<u32>()? {
+        0 => Err(anyhow!("the interval must be greater than 0")),
+        n => Ok(n),
+    }
+}
+
+// Each event is printed as a JSON line in the same format as `runc events`.
+#[derive(Serialize, Debug)]
+pub struct Event<'a> {
+    #[serde(rename = "type")]
+    pub event_type: &'a str,
+    pub id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Stats>,
+}
+
+// The stats layout of runc (libcontainer/types.Stats), converted from the
+// CgroupStats collected by rustjail.
+#[derive(Serialize, Debug, Default)]
+pub struct Stats {
+    pub cpu: Cpu,
+    pub memory: Memory,
+    pub pids: Pids,
+    pub blkio: Blkio,
+    pub hugetlb: HashMap<String, Hugetlb>,
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct Cpu {
+    pub usage: CpuUsage,
+    pub throttling: Throttling,
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct CpuUsage {
+    #[serde(skip_serializing_if = "is_zero")]
+    pub total: u64,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub percpu: Vec<u64>,
+    pub kernel: u64,
+    pub user: u64,
+}
+
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Throttling {
+    #[serde(skip_serializing_if = "is_zero")]
+    pub periods: u64,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub throttled_periods: u64,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub throttled_time: u64,
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct Memory {
+    #[serde(skip_serializing_if = "is_zero")]
+    pub cache: u64,
+    pub usage: MemoryEntry,
+    pub swap: MemoryEntry,
+    pub kernel: MemoryEntry,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub raw: HashMap<String, u64>,
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct MemoryEntry {
+    pub limit: u64,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub usage: u64,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub max: u64,
+    pub failcnt: u64,
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct Pids {
+    #[serde(skip_serializing_if = "is_zero")]
+    pub current: u64,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub limit: u64,
+}
+
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Blkio {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub io_service_bytes_recursive: Vec<BlkioEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub io_serviced_recursive: Vec<BlkioEntry>,
+    #[serde(rename = "ioQueueRecursive", skip_serializing_if = "Vec::is_empty")]
+    pub io_queued_recursive: Vec<BlkioEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub io_service_time_recursive: Vec<BlkioEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub io_wait_time_recursive: Vec<BlkioEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub io_merged_recursive: Vec<BlkioEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub io_time_recursive: Vec<BlkioEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub sectors_recursive: Vec<BlkioEntry>,
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct BlkioEntry {
+    #[serde(skip_serializing_if = "is_zero")]
+    pub major: u64,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub minor: u64,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub op: String,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub value: u64,
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct Hugetlb {
+    #[serde(skip_serializing_if = "is_zero")]
+    pub usage: u64,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub max: u64,
+    pub failcnt: u64,
+}
+
+fn is_zero(v: &u64) -> bool {
+    *v == 0
+}
+
+impl From<&MemoryData> for MemoryEntry {
+    fn from(data: &MemoryData) -> Self {
+        Self {
+            limit: data.limit,
+            usage: data.usage,
+            max: data.max_usage,
+            failcnt: data.failcnt,
+        }
+    }
+}
+
+fn blkio_entries(entries: &[BlkioStatsEntry]) -> Vec<BlkioEntry> {
+    entries
+        .iter()
+        .map(|e| BlkioEntry {
+            major: e.major,
+            minor: e.minor,
+            op: e.op.clone(),
+            value: e.value,
+        })
+        .collect()
+}
+
+impl From<CgroupStats> for Stats {
+    fn from(stats: CgroupStats) -> Self {
+        let cpu = &stats.cpu_stats;
+        let memory = &stats.memory_stats;
+        let blkio = &stats.blkio_stats;
+
+        Self {
+            cpu: Cpu {
+                usage: CpuUsage {
+                    total: cpu.cpu_usage.total_usage,
+                    percpu: cpu.cpu_usage.percpu_usage.clone(),
+                    kernel: cpu.cpu_usage.usage_in_kernelmode,
+                    user: cpu.cpu_usage.usage_in_usermode,
+                },
+                throttling: Throttling {
+                    periods: cpu.throttling_data.periods,
+                    throttled_periods: cpu.throttling_data.throttled_periods,
+                    throttled_time: cpu.throttling_data.throttled_time,
+                },
+            },
+            memory: Memory {
+                cache: memory.cache,
+                usage: MemoryEntry::from(&*memory.usage),
+                swap: MemoryEntry::from(&*memory.swap_usage),
+                kernel: MemoryEntry::from(&*memory.kernel_usage),
+                raw: memory.stats.clone(),
+            },
+            pids: Pids {
+                current: stats.pids_stats.current,
+                limit: stats.pids_stats.limit,
+            },
+            blkio: Blkio {
+                io_service_bytes_recursive: blkio_entries(&blkio.io_service_bytes_recursive),
+                io_serviced_recursive: blkio_entries(&blkio.io_serviced_recursive),
+                io_queued_recursive: blkio_entries(&blkio.io_queued_recursive),
+                io_service_time_recursive: blkio_entries(&blkio.io_service_time_recursive),
+                io_wait_time_recursive: blkio_entries(&blkio.io_wait_time_recursive),
+                io_merged_recursive: blkio_entries(&blkio.io_merged_recursive),
+                io_time_recursive: blkio_entries(&blkio.io_time_recursive),
+                sectors_recursive: blkio_entries(&blkio.sectors_recursive),
+            },
+            hugetlb: stats
+                .hugetlb_stats
+                .iter()
+                .map(|(size, h)| {
+                    (
+                        size.clone(),
+                        Hugetlb {
+                            usage: h.usage,
+                            max: h.max_usage,
+                            failcnt: h.failcnt,
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<'a> Event<'a> {
+    pub fn stats(id: &'a str, stats: CgroupStats) -> Self {
+        Self {
+            event_type: EVENT_TYPE_STATS,
+            id,
+            data: Some(stats.into()),
+        }
+    }
+
+    pub fn oom(id: &'a str) -> Self {
+        Self {
+            event_type: EVENT_TYPE_OOM,
+            id,
+            data: None,
+        }
+    }
+}
+
+pub async fn run(opts: Events, root: &Path, logger: &Logger) -> Result<()> {
+    let container_id = opts.container_id.as_str();
+    let container = Container::load(root, container_id)?;
+
+    if opts.stats {
+        print_event(&Event::stats(container_id, container.stats()?))?;
+        info!(&logger, "events command finished successfully");
+        return Ok(());
+    }
+
+    let mut oom_receiver = Some(container.oom_notifier().await?);
+    let mut interval = tokio::time::interval(Duration::from_secs(opts.interval.into()));
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {
+                // Reload the container every time to catch up with its current state.
+                let container = Container::load(root, container_id)?;
+                if container.state == ContainerState::Stopped {
+                    break;
+                }
+                print_event(&Event::stats(container_id, container.stats()?))?;
+            }
+            oom = recv_oom(&mut oom_receiver) => {
+                match oom {
+                    Some(_) => print_event(&Event::oom(container_id))?,
+                    // The notifier closes the channel when the cgroup has no processes,
+                    // so stop waiting for OOM events but keep reporting stats until
+                    // the container is stopped.
+                    None => oom_receiver = None,
+                }
+            }
+        }
+    }
+
+    info!(&logger, "events command finished successfully");
+    Ok(())
+}
+
+async fn recv_oom(receiver: &mut Option<tokio::sync::mpsc::Receiver<String>>) -> Option<String> {
+    match receiver.as_mut() {
+        Some(r) => r.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
+fn print_event(event: &Event) -> Result<()> {
+    println!("{}", serde_json::to_string(event)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Parser, Debug)]
+    struct TestCli {
+        #[clap(flatten)]
+        events: Events,
+    }
+
+    #[test]
+    fn test_parse_interval() {
+        let cli = TestCli::try_parse_from(["events", "test"]).unwrap();
+        assert_eq!(cli.events.interval, 5);
+
+        let cli = TestCli::try_parse_from(["events", "--interval", "10", "test"]).unwrap();
+        assert_eq!(cli.events.interval, 10);
+
+        assert!(TestCli::try_parse_from(["events", "--interval", "0", "test"]).is_err());
+        assert!(TestCli::try_parse_from(["events", "--interval", "-1", "test"]).is_err());
+    }
+
+    #[test]
+    fn test_event_serialization() {
+        let event = Event::oom("test");
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"type":"oom","id":"test"}"#
+        );
+
+        let event = Event::stats("test", CgroupStats::default());
+        let value: serde_json::Value = serde_json::to_value(&event).unwrap();
+        assert_eq!(value["type"], "stats");
+        assert_eq!(value["id"], "test");
+        assert!(value["data"]["cpu"]["usage"].is_object());
+        assert!(value["data"]["memory"]["usage"].is_object());
+        assert!(value["data"]["hugetlb"].is_object());
+    }
+
+    #[test]
+    fn test_stats_conversion() {
+        let mut stats = CgroupStats::default();
+        stats.mut_cpu_stats().mut_cpu_usage().total_usage = 100;
+        stats.mut_cpu_stats().mut_cpu_usage().percpu_usage = vec![40, 60];
+        stats.mut_cpu_stats().mut_throttling_data().throttled_time = 7;
+        stats.mut_memory_stats().cache = 4096;
+        stats.mut_memory_stats().mut_usage().usage = 1024;
+        stats.mut_memory_stats().mut_usage().limit = 2048;
+        stats.mut_pids_stats().current = 3;
+        stats.mut_blkio_stats().io_queued_recursive = vec![BlkioStatsEntry {
+            major: 8,
+            minor: 0,
+            op: "Read".to_string(),
+            value: 5,
+            ..Default::default()
+        }];
+        stats.hugetlb_stats.insert(
+            "2MB".to_string(),
+            protocols::agent::HugetlbStats {
+                usage: 1,
+                max_usage: 2,
+                ..Default::default()
+            },
+        );
+
+        let value = serde_json::to_value(Stats::from(stats)).unwrap();
+        assert_eq!(value["cpu"]["usage"]["total"], 100);
+        assert_eq!(value["cpu"]["usage"]["percpu"], serde_json::json!([40, 60]));
+        assert_eq!(value["cpu"]["usage"]["kernel"], 0);
+        assert_eq!(value["cpu"]["throttling"]["throttledTime"], 7);
+        assert!(value["cpu"]["throttling"].get("periods").is_none());
+        assert_eq!(value["memory"]["cache"], 4096);
+        assert_eq!(value["memory"]["usage"]["usage"], 1024);
+        assert_eq!(value["memory"]["usage"]["limit"], 2048);
+        assert_eq!(value["memory"]["swap"]["failcnt"], 0);
+        assert!(value["memory"].get("raw").is_none());
+        assert_eq!(value["pids"]["current"], 3);
+        assert_eq!(value["blkio"]["ioQueueRecursive"][0]["op"], "Read");
+        assert_eq!(value["blkio"]["ioQueueRecursive"][0]["major"], 8);
+        assert!(value["blkio"]["ioQueueRecursive"][0].get("minor").is_none());
+        assert_eq!(value["hugetlb"]["2MB"]["max"], 2);
+        assert_eq!(value["hugetlb"]["2MB"]["failcnt"], 0);
+    }
+}
@@ -0,0 +1,178 @@
+// Copyright 2021-2022 Kata Contributors
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use anyhow::Result;
+use rustjail::{apparmor, container::NAMESPACES, selinux};
+use serde::Serialize;
+use slog::{info, Logger};
+
+const OCI_VERSION_MIN: &str = "1.0.0";
+const OCI_VERSION_MAX: &str = "1.0.2-dev";
+
+// Hooks that are executed by runk via rustjail.
+const HOOKS: &[&str] = &[
+    "prestart",
+    "createRuntime",
+    "createContainer",
+    "startContainer",
+    "poststart",
+    "poststop",
+];
+
+const SECCOMP_ACTIONS: &[&str] = &[
+    "SCMP_ACT_KILL",
+    "SCMP_ACT_KILL_PROCESS",
+    "SCMP_ACT_KILL_THREAD",
+    "SCMP_ACT_TRAP",
+    "SCMP_ACT_ERRNO",
+    "SCMP_ACT_TRACE",
+    "SCMP_ACT_ALLOW",
+    "SCMP_ACT_LOG",
+    "SCMP_ACT_NOTIFY",
+];
+
+const SECCOMP_OPERATORS: &[&str] = &[
+    "SCMP_CMP_NE",
+    "SCMP_CMP_LT",
+    "SCMP_CMP_LE",
+    "SCMP_CMP_EQ",
+    "SCMP_CMP_GE",
+    "SCMP_CMP_GT",
+    "SCMP_CMP_MASKED_EQ",
+];
+
+const SECCOMP_FLAGS: &[&str] = &[
+    "SECCOMP_FILTER_FLAG_TSYNC",
+    "SECCOMP_FILTER_FLAG_LOG",
+    "SECCOMP_FILTER_FLAG_SPEC_ALLOW",
+];
+
+// The features structure defined in the OCI runtime spec.
+// https://github.com/opencontainers/runtime-spec/blob/main/features.md
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Features {
+    pub oci_version_min: String,
+    pub oci_version_max: String,
+    pub hooks: Vec<String>,
+    pub linux: LinuxFeatures,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LinuxFeatures {
+    pub namespaces: Vec<String>,
+    pub cgroup: CgroupFeatures,
+    pub seccomp: SeccompFeatures,
+    pub apparmor: LsmFeatures,
+    pub selinux: LsmFeatures,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CgroupFeatures {
+    pub v1: bool,
+    pub v2: bool,
+    pub systemd: bool,
+}
+
+#[derive(Serialize, Debug)]
+pub struct LsmFeatures {
+    pub enabled: bool,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SeccompFeatures {
+    pub enabled: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub operators: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub supported_flags: Vec<String>,
+}
+
+fn to_strings(list: &[&str]) -> Vec<String> {
+    list.iter().map(|s| s.to_string()).collect()
+}
+
+pub fn get_features() -> Features {
+    let mut namespaces: Vec<String> = NAMESPACES.keys().map(|s| s.to_string()).collect();
+    namespaces.sort();
+
+    let seccomp_enabled = cfg!(feature = "seccomp");
+    let seccomp = if seccomp_enabled {
+        SeccompFeatures {
+            enabled: true,
+            actions: to_strings(SECCOMP_ACTIONS),
+            operators: to_strings(SECCOMP_OPERATORS),
+            supported_flags: to_strings(SECCOMP_FLAGS),
+        }
+    } else {
+        SeccompFeatures {
+            enabled: false,
+            actions: vec![],
+            operators: vec![],
+            supported_flags: vec![],
+        }
+    };
+
+    Features {
+        oci_version_min: OCI_VERSION_MIN.to_string(),
+        oci_version_max: OCI_VERSION_MAX.to_string(),
+        hooks: to_strings(HOOKS),
+        linux: LinuxFeatures {
+            namespaces,
+            cgroup: CgroupFeatures {
+                v1: true,
+                v2: true,
+                // runk manages cgroups through cgroupfs only.
+                systemd: false,
+            },
+            seccomp,
+            // rustjail applies the AppArmor profile and the SELinux label of the
+            // process, which requires the LSM to be enabled on the host.
+            apparmor: LsmFeatures {
+                enabled: apparmor::is_enabled().unwrap_or(false),
+            },
+            selinux: LsmFeatures {
+                enabled: selinux::is_enabled().unwrap_or(false),
+            },
+        },
+    }
+}
+
+pub fn run(logger: &Logger) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(&get_features())?);
+
+    info!(&logger, "features command finished successfully");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_features() {
+        let features = get_features();
+        assert_eq!(features.linux.namespaces.len(), NAMESPACES.len());
+        assert!(features.linux.namespaces.contains(&"network".to_string()));
+        assert_eq!(features.linux.seccomp.enabled, cfg!(feature = "seccomp"));
+        assert_eq!(
+            features.linux.apparmor.enabled,
+            apparmor::is_enabled().unwrap()
+        );
+        assert_eq!(
+            features.linux.selinux.enabled,
+            selinux::is_enabled().unwrap()
+        );
+
+        let value = serde_json::to_value(&features).unwrap();
+        assert_eq!(value["ociVersionMin"], OCI_VERSION_MIN);
+        assert_eq!(value["linux"]["cgroup"]["v2"], true);
+    }
+}
@@ -3,25 +3,359 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-use anyhow::Result;
-use libcontainer::{container::ContainerAction, init_builder::InitContainerBuilder};
-use liboci_cli::Run;
-use slog::{info, Logger};
-use std::path::Path;
+use anyhow::{anyhow, Result};
+use clap::Parser;
+use libcontainer::{
+    console::{get_console_socket_path, resize_pty, ConsoleSocket, RawTerminal},
+    container::{Container, ContainerAction},
+    init_builder::InitContainerBuilder,
+};
+use nix::{
+    errno::Errno,
+    poll::{poll, PollFd, PollFlags},
+    sys::{
+        signal::{kill, Signal},
+        wait::{waitpid, WaitPidFlag, WaitStatus},
+    },
+    unistd::{self, Pid},
+};
+use slog::{debug, info, warn, Logger};
+use std::{
+    fs::File,
+    io::{self, Write},
+    os::unix::io::{AsRawFd, RawFd},
+    path::Path,
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+use tokio::{
+    signal::unix::{signal, SignalKind},
+    sync::mpsc::{channel, Receiver},
+};
 
-pub async fn run(opts: Run, root: &Path, logger: &Logger) -> Result<()> {
+// Signals handled in foreground mode. SIGCHLD and SIGWINCH are handled by
+// runk itself, and the others are relayed to the container init process.
+const HANDLED_SIGNALS: &[Signal] = &[
+    Signal::SIGHUP,
+    Signal::SIGINT,
+    Signal::SIGQUIT,
+    Signal::SIGTERM,
+    Signal::SIGUSR1,
+    Signal::SIGUSR2,
+    Signal::SIGCHLD,
+    Signal::SIGWINCH,
+];
+
+// How long to wait for the remaining output of the container after it exits.
+// The pty master only reports EOF once every slave fd is closed, which never
+// happens if the container leaves a process holding its terminal behind.
+const OUTPUT_DRAIN_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Create a container and immediately start it
+#[derive(Parser, Debug)]
+pub struct Run {
+    #[clap(flatten)]
+    pub base: liboci_cli::Run,
+    /// Detach from the container's process (default)
+    #[clap(short, long)]
+    pub detach: bool,
+    /// Stay in the foreground, forward stdio and signals to the container,
+    /// and exit with the container's exit status
+    #[clap(long, conflicts_with = "detach")]
+    pub foreground: bool,
+}
+
+pub async fn run(opts: Run, root: &Path, logger: &Logger) -> Result<i32> {
+    let id = opts.base.container_id;
+    let console_socket = opts.base.console_socket;
     let mut launcher = InitContainerBuilder::default()
-        .id(opts.container_id)
-        .bundle(opts.bundle)
+        .id(id.clone())
+        .bundle(opts.base.bundle)
         .root(root.to_path_buf())
-        .console_socket(opts.console_socket)
-        .pid_file(opts.pid_file)
+        .console_socket(console_socket.clone())
+        .pid_file(opts.base.pid_file)
+        .detach(!opts.foreground)
         .build()?
         .create_launcher(logger)?;
 
+    if !opts.foreground {
+        launcher.launch(ContainerAction::Run, logger).await?;
+        info!(&logger, "run command finished successfully");
+        return Ok(0);
+    }
+
+    // The container process is a grandchild of runk, so become a subreaper
+    // to be able to wait for it.
+    set_child_subreaper()?;
+
+    let terminal = launcher
+        .runner
+        .config
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.process.as_ref())
+        .map(|process| process.terminal)
+        .unwrap_or(false);
+    let console = if terminal && console_socket.is_none() {
+        let socket = ConsoleSocket::bind(&get_console_socket_path(root, &id))?;
+        launcher.runner.set_console_socket(socket.path())?;
+        Some(socket)
+    } else {
+        None
+    };
+
+    // Install the signal handlers before launching the container, so that the
+    // signals received while it is starting are relayed once it runs.
+    let signals = relay_signals()?;
+
     launcher.launch(ContainerAction::Run, logger).await?;
+    let pid = Pid::from_raw(launcher.runner.init_process_pid);
+    debug!(
+        logger,
+        "container {} runs in the foreground, pid {}", id, pid
+    );
+
+    let master = console.map(|c| c.recv_master()).transpose()?;
+    let exit_code = wait_foreground(pid, master, signals, logger).await?;
+
+    // Just like runc, remove the container after it exits in foreground mode.
+    Container::load(root, &id)?.delete(true, logger).await?;
 
     info!(&logger, "run command finished successfully");
+    Ok(exit_code)
+}
 
+fn set_child_subreaper() -> Result<()> {
+    let ret = unsafe { libc::prctl(libc::PR_SET_CHILD_SUBREAPER, 1, 0, 0, 0) };
+    Errno::result(ret).map_err(|e| anyhow!(e).context("failed to set child subreaper"))?;
     Ok(())
 }
+
+// Relay signals to the container and forward stdio through the pty master if any,
+// until the container init process exits. Returns the exit code of the container.
+async fn wait_foreground(
+    pid: Pid,
+    master: Option<File>,
+    mut signals: Receiver<Signal>,
+    logger: &Logger,
+) -> Result<i32> {
+    // Keep the caller's terminal in raw mode while it is attached to the container.
+    let _raw_terminal = match master.as_ref() {
+        Some(_) => RawTerminal::new(io::stdin().as_raw_fd())?,
+        None => None,
+    };
+    let forwarder = match master {
+        Some(master) => {
+            let _ = resize_pty(io::stdin().as_raw_fd(), master.as_raw_fd());
+            Some(PtyForwarder::new(master)?)
+        }
+        None => None,
+    };
+
+    // The container may have exited before the launcher returned.
+    let mut exit_code = reap_children(pid)?;
+    while exit_code.is_none() {
+        let sig = match signals.recv().await {
+            Some(sig) => sig,
+            None => return Err(anyhow!("signal handlers were closed unexpectedly")),
+        };
+        match sig {
+            Signal::SIGCHLD => exit_code = reap_children(pid)?,
+            Signal::SIGWINCH => {
+                if let Some(f) = forwarder.as_ref() {
+                    let _ = resize_pty(io::stdin().as_raw_fd(), f.master_fd);
+                }
+            }
+            _ => {
+                debug!(logger, "forwarding signal {} to container", sig);
+                if let Err(e) = kill(pid, sig) {
+                    warn!(logger, "failed to forward signal {}: {:?}", sig, e);
+                }
+            }
+        }
+    }
+
+    // Flush the remaining output of the container before returning.
+    if let Some(f) = forwarder {
+        f.stop(logger);
+    }
+
+    Ok(exit_code.unwrap_or_default())
+}
+
+fn relay_signals() -> Result<Receiver<Signal>> {
+    let (tx, rx) = channel(32);
+    for sig in HANDLED_SIGNALS {
+        let mut stream = signal(SignalKind::from_raw(*sig as i32))?;
+        let tx = tx.clone();
+        let sig = *sig;
+        tokio::spawn(async move {
+            while stream.recv().await.is_some() {
+                if tx.send(sig).await.is_err() {
+                    break;
+                }
+            }
+        });
+    }
+    Ok(rx)
+}
+
+// Copies stdin to the pty master and the pty master to stdout.
+struct PtyForwarder {
+    master_fd: RawFd,
+    input: thread::JoinHandle<()>,
+    // Writing to this pipe stops the input thread, which is otherwise blocked
+    // reading stdin.
+    stop_input: RawFd,
+    output: thread::JoinHandle<()>,
+    output_done: mpsc::Receiver<()>,
+}
+
+impl PtyForwarder {
+    fn new(master: File) -> Result<Self> {
+        let master_fd = master.as_raw_fd();
+        let input_master = master.try_clone()?;
+        let (stop_rfd, stop_wfd) = unistd::pipe()?;
+
+        let input = thread::spawn(move || {
+            copy_input(input_master, stop_rfd);
+            let _ = unistd::close(stop_rfd);
+        });
+
+        let (done_tx, output_done) = mpsc::channel();
+        let mut output_master = master;
+        let output = thread::spawn(move || {
+            let mut stdout = io::stdout();
+            // Reading the master fails with EIO once all the slave fds are closed.
+            let _ = io::copy(&mut output_master, &mut stdout);
+            let _ = stdout.flush();
+            let _ = done_tx.send(());
+        });
+
+        Ok(Self {
+            master_fd,
+            input,
+            stop_input: stop_wfd,
+            output,
+            output_done,
+        })
+    }
+
+    fn stop(self, logger: &Logger) {
+        let _ = unistd::write(self.stop_input, &[0]);
+        let _ = unistd::close(self.stop_input);
+        let _ = self.input.join();
+
+        match self.output_done.recv_timeout(OUTPUT_DRAIN_TIMEOUT) {
+            Ok(_) => {
+                let _ = self.output.join();
+            }
+            Err(_) => warn!(
+                logger,
+                "the container terminal is still open, stop forwarding its output"
+            ),
+        }
+    }
+}
+
+fn copy_input(mut master: File, stop_fd: RawFd) {
+    let stdin = io::stdin().as_raw_fd();
+    let mut buf = [0u8; 4096];
+
+    loop {
+        let mut fds = [
+            PollFd::new(stdin, PollFlags::POLLIN),
+            PollFd::new(stop_fd, PollFlags::POLLIN),
+        ];
+        match poll(&mut fds, -1) {
+            Ok(_) => (),
+            Err(Errno::EINTR) => continue,
+            Err(_) => return,
+        }
+
+        let ready = |fd: &PollFd| matches!(fd.revents(), Some(r) if !r.is_empty());
+        if ready(&fds[1]) {
+            return;
+        }
+        if !ready(&fds[0]) {
+            continue;
+        }
+
+        match unistd::read(stdin, &mut buf) {
+            // EOF on stdin
+            Ok(0) => return,
+            Ok(n) => {
+                if master.write_all(&buf[..n]).is_err() {
+                    return;
+                }
+            }
+            Err(Errno::EINTR) | Err(Errno::EAGAIN) => continue,
+            Err(_) => return,
+        }
+    }
+}
+
+// Reap all the exited children and return the exit code if the container
+// init process is one of them.
+fn reap_children(pid: Pid) -> Result<Option<i32>> {
+    loop {
+        match waitpid(Pid::from_raw(-1), Some(WaitPidFlag::WNOHANG)) {
+            Ok(WaitStatus::StillAlive) | Err(Errno::ECHILD) => return Ok(None),
+            Ok(status) => {
+                if status.pid() == Some(pid) {
+                    return Ok(exit_code_from_status(status));
+                }
+            }
+            Err(Errno::EINTR) => continue,
+            Err(e) => return Err(anyhow!(e).context("failed to wait for children")),
+        }
+    }
+}
+
+fn exit_code_from_status(status: WaitStatus) -> Option<i32> {
+    match status {
+        WaitStatus::Exited(_, code) => Some(code),
+        // Follow the shell convention for processes killed by a signal.
+        WaitStatus::Signaled(_, sig, _) => Some(128 + sig as i32),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Parser, Debug)]
+    struct TestCli {
+        #[clap(flatten)]
+        run: Run,
+    }
+
+    #[test]
+    fn test_run_mode() {
+        let cli = TestCli::try_parse_from(["run", "test"]).unwrap();
+        assert!(!cli.run.foreground);
+
+        let cli = TestCli::try_parse_from(["run", "--foreground", "test"]).unwrap();
+        assert!(cli.run.foreground);
+
+        assert!(TestCli::try_parse_from(["run", "--detach", "test"]).is_ok());
+        assert!(TestCli::try_parse_from(["run", "-d", "--foreground", "test"]).is_err());
+    }
+
+    #[test]
+    fn test_exit_code_from_status() {
+        let pid = Pid::from_raw(1);
+        assert_eq!(exit_code_from_status(WaitStatus::Exited(pid, 0)), Some(0));
+        assert_eq!(exit_code_from_status(WaitStatus::Exited(pid, 3)), Some(3));
+        assert_eq!(
+            exit_code_from_status(WaitStatus::Signaled(pid, Signal::SIGKILL, false)),
+            Some(137)
+        );
+        assert_eq!(
+            exit_code_from_status(WaitStatus::Stopped(pid, Signal::SIGSTOP)),
+            None
+        );
+    }
+}
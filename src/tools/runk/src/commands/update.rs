@@ -0,0 +1,218 @@
+// Copyright 2021-2022 Kata Contributors
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use anyhow::{anyhow, Result};
+use clap::Parser;
+use libcontainer::container::Container;
+use oci::{LinuxBlockIo, LinuxCpu, LinuxMemory, LinuxPids, LinuxResources};
+use slog::{info, Logger};
+use std::{fs::File, io, path::Path, path::PathBuf};
+
+// The range of the blkio weight accepted by the kernel.
+const BLKIO_WEIGHT_MIN: u64 = 10;
+const BLKIO_WEIGHT_MAX: u64 = 1000;
+
+/// Update running container resource constraints
+// The same options as `runc update`, liboci-cli only provides --resources and --pids-limit.
+#[derive(Parser, Debug)]
+pub struct Update {
+    #[clap(forbid_empty_values = true, required = true)]
+    pub container_id: String,
+    /// Read the new resource limits from the given json file. Use - to read from stdin.
+    /// If this option is used, all other options are ignored
+    #[clap(short, long)]
+    pub resources: Option<PathBuf>,
+    /// Specifies per cgroup weight, range is from 10 to 1000
+    #[clap(long)]
+    pub blkio_weight: Option<u64>,
+    /// CPU CFS period to be used for hardcapping (in usecs). 0 to use system default
+    #[clap(long)]
+    pub cpu_period: Option<u64>,
+    /// CPU CFS hardcap limit (in usecs). Allowed cpu time in a given period
+    #[clap(long)]
+    pub cpu_quota: Option<i64>,
+    /// CPU realtime period to be used for hardcapping (in usecs). 0 to use system default
+    #[clap(long)]
+    pub cpu_rt_period: Option<u64>,
+    /// CPU realtime hardcap limit (in usecs). Allowed cpu time in a given period
+    #[clap(long)]
+    pub cpu_rt_runtime: Option<i64>,
+    /// CPU shares (relative weight vs. other containers)
+    #[clap(long)]
+    pub cpu_share: Option<u64>,
+    /// CPU(s) to use
+    #[clap(long)]
+    pub cpuset_cpus: Option<String>,
+    /// Memory node(s) to use
+    #[clap(long)]
+    pub cpuset_mems: Option<String>,
+    /// Memory limit (in bytes)
+    #[clap(long)]
+    pub memory: Option<i64>,
+    /// Memory reservation or soft_limit (in bytes)
+    #[clap(long)]
+    pub memory_reservation: Option<i64>,
+    /// Total memory usage (memory + swap); set '-1' to enable unlimited swap
+    #[clap(long, allow_hyphen_values = true)]
+    pub memory_swap: Option<i64>,
+    /// Maximum number of pids allowed in the container
+    #[clap(long)]
+    pub pids_limit: Option<i64>,
+    /// The string of Intel RDT/CAT L3 cache schema
+    #[clap(long)]
+    pub l3_cache_schema: Option<String>,
+    /// The string of Intel RDT/MBA memory bandwidth schema
+    #[clap(long)]
+    pub mem_bw_schema: Option<String>,
+}
+
+pub fn run(opts: Update, root: &Path, logger: &Logger) -> Result<()> {
+    let mut container = Container::load(root, &opts.container_id)?;
+    let resources = get_resources(&opts)?;
+    container.update(&resources)?;
+
+    info!(&logger, "update command finished successfully");
+    Ok(())
+}
+
+// Read the resources from the file (or stdin with "-") specified with --resources.
+// Otherwise, build them from the other command line options just like runc.
+fn get_resources(opts: &Update) -> Result<LinuxResources> {
+    if let Some(path) = opts.resources.as_ref() {
+        let resources = if path == Path::new("-") {
+            serde_json::from_reader(io::stdin())?
+        } else {
+            serde_json::from_reader(File::open(path)?)?
+        };
+        return Ok(resources);
+    }
+
+    if opts.l3_cache_schema.is_some() || opts.mem_bw_schema.is_some() {
+        return Err(anyhow!("Intel RDT is not supported by runk"));
+    }
+
+    let mut resources = LinuxResources::default();
+
+    if let Some(weight) = opts.blkio_weight {
+        if !(BLKIO_WEIGHT_MIN..=BLKIO_WEIGHT_MAX).contains(&weight) {
+            return Err(anyhow!(
+                "invalid blkio weight {}, the range is from {} to {}",
+                weight,
+                BLKIO_WEIGHT_MIN,
+                BLKIO_WEIGHT_MAX
+            ));
+        }
+        resources.block_io = Some(LinuxBlockIo {
+            weight: Some(weight as u16),
+            ..Default::default()
+        });
+    }
+
+    let cpu = LinuxCpu {
+        shares: opts.cpu_share,
+        quota: opts.cpu_quota,
+        period: opts.cpu_period,
+        realtime_runtime: opts.cpu_rt_runtime,
+        realtime_period: opts.cpu_rt_period,
+        cpus: opts.cpuset_cpus.clone().unwrap_or_default(),
+        mems: opts.cpuset_mems.clone().unwrap_or_default(),
+    };
+    if cpu != LinuxCpu::default() {
+        resources.cpu = Some(cpu);
+    }
+
+    let memory = LinuxMemory {
+        limit: opts.memory,
+        reservation: opts.memory_reservation,
+        swap: opts.memory_swap,
+        ..Default::default()
+    };
+    if memory != LinuxMemory::default() {
+        resources.memory = Some(memory);
+    }
+
+    if let Some(limit) = opts.pids_limit {
+        resources.pids = Some(LinuxPids { limit });
+    }
+
+    Ok(resources)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[derive(Parser, Debug)]
+    struct TestCli {
+        #[clap(flatten)]
+        update: Update,
+    }
+
+    fn parse_update(args: &[&str]) -> Update {
+        let mut argv = vec!["update"];
+        argv.extend_from_slice(args);
+        TestCli::parse_from(argv).update
+    }
+
+    #[test]
+    fn test_get_resources_from_options() {
+        let opts = parse_update(&[
+            "--memory",
+            "1048576",
+            "--cpu-quota",
+            "50000",
+            "--cpu-period",
+            "100000",
+            "--cpuset-cpus",
+            "0-1",
+            "--pids-limit",
+            "100",
+            "test",
+        ]);
+        let resources = get_resources(&opts).unwrap();
+
+        assert_eq!(resources.memory.unwrap().limit, Some(1048576));
+        let cpu = resources.cpu.unwrap();
+        assert_eq!(cpu.quota, Some(50000));
+        assert_eq!(cpu.period, Some(100000));
+        assert_eq!(cpu.cpus, "0-1");
+        assert!(cpu.shares.is_none());
+        assert_eq!(resources.pids.unwrap().limit, 100);
+        assert!(resources.block_io.is_none());
+    }
+
+    #[test]
+    fn test_get_resources_blkio_weight() {
+        let resources = get_resources(&parse_update(&["--blkio-weight", "500", "test"])).unwrap();
+        assert_eq!(resources.block_io.unwrap().weight, Some(500));
+
+        for weight in ["0", "5", "1001", "65546"] {
+            let opts = parse_update(&["--blkio-weight", weight, "test"]);
+            assert!(
+                get_resources(&opts).is_err(),
+                "weight {} must be rejected",
+                weight
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_resources_from_file() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(
+            file,
+            r#"{{"memory": {{"limit": 2048}}, "pids": {{"limit": 5}}}}"#
+        )
+        .unwrap();
+        let path = file.path().to_str().unwrap().to_string();
+
+        let opts = parse_update(&["--resources", &path, "--memory", "1024", "test"]);
+        let resources = get_resources(&opts).unwrap();
+
+        assert_eq!(resources.memory.unwrap().limit, Some(2048));
+        assert_eq!(resources.pids.unwrap().limit, 5);
+    }
+}
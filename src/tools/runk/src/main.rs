@@ -3,10 +3,12 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use clap::{crate_description, crate_name, Parser};
-use liboci_cli::{CommonCmd, GlobalOpts};
-use liboci_cli::{Create, Delete, Kill, Start, State};
+use liboci_cli::GlobalOpts;
+use liboci_cli::{
+    Checkpoint, Create, Delete, Exec, Kill, List, Pause, Ps, Resume, Spec, Start, State,
+};
 use slog::{o, Logger};
 use slog_async::AsyncGuard;
 use std::{
@@ -28,6 +30,8 @@ enum SubCommand {
     Common(CommonCmd),
     /// Launch an init process (do not call it outside of runk)
     Init {},
+    /// Show the enabled features of runk in JSON format
+    Features {},
 }
 
 // Copy from https://github.com/containers/youki/blob/v0.0.3/crates/liboci-cli/src/lib.rs#L38-L44
@@ -40,6 +44,23 @@ pub enum StandardCmd {
     Kill(Kill),
 }
 
+// Copy from https://github.com/containers/youki/blob/v0.0.4/crates/liboci-cli/src/lib.rs
+// except for the events, run and update commands, which have runk specific options.
+#[derive(Parser, Debug)]
+pub enum CommonCmd {
+    Checkpoint(Checkpoint),
+    Events(commands::events::Events),
+    Exec(Exec),
+    List(List),
+    Pause(Pause),
+    #[clap(allow_hyphen_values = true)]
+    Ps(Ps),
+    Resume(Resume),
+    Run(commands::run::Run),
+    Update(commands::update::Update),
+    Spec(Spec),
+}
+
 #[derive(Parser, Debug)]
 #[clap(version, author, about = crate_description!())]
 struct Cli {
@@ -49,7 +70,7 @@ struct Cli {
     subcmd: SubCommand,
 }
 
-async fn cmd_run(subcmd: SubCommand, root_path: &Path, logger: &Logger) -> Result<()> {
+async fn cmd_run(subcmd: SubCommand, root_path: &Path, logger: &Logger) -> Result<i32> {
     match subcmd {
         SubCommand::Standard(cmd) => match cmd {
             StandardCmd::Create(create) => commands::create::run(create, root_path, logger).await,
@@ -59,19 +80,25 @@ async fn cmd_run(subcmd: SubCommand, root_path: &Path, logger: &Logger) -> Resul
             StandardCmd::Kill(kill) => commands::kill::run(kill, root_path, logger),
         },
         SubCommand::Common(cmd) => match cmd {
-            CommonCmd::Run(run) => commands::run::run(run, root_path, logger).await,
+            // The run command in the foreground mode exits with the container's exit status.
+            CommonCmd::Run(run) => return commands::run::run(run, root_path, logger).await,
             CommonCmd::Spec(spec) => commands::spec::run(spec, logger),
             CommonCmd::List(list) => commands::list::run(list, root_path, logger),
             CommonCmd::Exec(exec) => commands::exec::run(exec, root_path, logger).await,
             CommonCmd::Ps(ps) => commands::ps::run(ps, root_path, logger),
             CommonCmd::Pause(pause) => commands::pause::run(pause, root_path, logger),
             CommonCmd::Resume(resume) => commands::resume::run(resume, root_path, logger),
-            _ => {
-                return Err(anyhow!("command is not implemented yet"));
+            CommonCmd::Events(events) => commands::events::run(events, root_path, logger).await,
+            CommonCmd::Update(update) => commands::update::run(update, root_path, logger),
+            CommonCmd::Checkpoint(checkpoint) => {
+                commands::checkpoint::run(checkpoint, root_path, logger)
             }
         },
+        SubCommand::Features {} => commands::features::run(logger),
         _ => unreachable!(),
-    }
+    }?;
+
+    Ok(0)
 }
 
 fn setup_logger(
@@ -97,7 +124,7 @@ fn setup_logger(
     }
 }
 
-async fn real_main() -> Result<()> {
+async fn real_main() -> Result<i32> {
     let cli = Cli::parse();
 
     if let SubCommand::Init {} = cli.subcmd {
@@ -119,17 +146,16 @@ async fn real_main() -> Result<()> {
 
     let (logger, _async_guard) = setup_logger(cli.global.log, log_level)?;
 
-    cmd_run(cli.subcmd, &root_path, &logger).await?;
-
-    Ok(())
+    cmd_run(cli.subcmd, &root_path, &logger).await
 }
 
 #[tokio::main]
 async fn main() {
-    if let Err(e) = real_main().await {
-        eprintln!("ERROR: {}", e);
-        exit(1);
+    match real_main().await {
+        Ok(code) => exit(code),
+        Err(e) => {
+            eprintln!("ERROR: {}", e);
+            exit(1);
+        }
     }
-
-    exit(0);
 }
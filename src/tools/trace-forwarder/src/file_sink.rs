@@ -0,0 +1,107 @@
+// Copyright (c) 2023 Kata Contributors
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// The file sink appends each trace span to a file as a single line of JSON,
+// allowing the spans to be analysed offline.
+
+use async_trait::async_trait;
+use opentelemetry::sdk::export::trace::{ExportResult, SpanData, SpanExporter};
+use opentelemetry::sdk::export::ExportError;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("serialisation error: {0}")]
+    SerialisationError(#[from] serde_json::Error),
+    #[error("I/O error: {0}")]
+    IOError(#[from] std::io::Error),
+}
+
+impl ExportError for Error {
+    fn exporter_name(&self) -> &'static str {
+        "file"
+    }
+}
+
+#[derive(Debug)]
+pub struct FileSinkExporter {
+    writer: BufWriter<File>,
+}
+
+impl FileSinkExporter {
+    pub fn new(path: &str) -> Result<Self, Error> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        Ok(FileSinkExporter {
+            writer: BufWriter::new(file),
+        })
+    }
+
+    fn write_batch(&mut self, batch: &[SpanData]) -> Result<(), Error> {
+        for span in batch {
+            serde_json::to_writer(&mut self.writer, span)?;
+            self.writer.write_all(b"\n")?;
+        }
+
+        // Spans arrive slowly, make each batch visible immediately.
+        self.writer.flush()?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SpanExporter for FileSinkExporter {
+    async fn export(&mut self, batch: Vec<SpanData>) -> ExportResult {
+        self.write_batch(&batch)?;
+
+        Ok(())
+    }
+
+    fn shutdown(&mut self) {
+        let _ = self.writer.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracer::tests::make_spans;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_file_sink_exporter() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("spans.json");
+        let path = path.to_str().unwrap();
+
+        let mut exporter = FileSinkExporter::new(path).unwrap();
+
+        let result = futures::executor::block_on(exporter.export(make_spans(&["foo", "bar"])));
+        assert!(result.is_ok(), "{:?}", result);
+
+        // A new exporter appends to the existing file
+        let mut exporter = FileSinkExporter::new(path).unwrap();
+
+        let result = futures::executor::block_on(exporter.export(make_spans(&["baz"])));
+        assert!(result.is_ok(), "{:?}", result);
+
+        let contents = fs::read_to_string(path).unwrap();
+        let names: Vec<String> = contents
+            .lines()
+            .map(|l| {
+                let span: SpanData = serde_json::from_str(l).unwrap();
+                span.name.to_string()
+            })
+            .collect();
+
+        assert_eq!(names, vec!["foo", "bar", "baz"]);
+
+        assert!(FileSinkExporter::new(dir.path().join("foo/bar").to_str().unwrap()).is_err());
+    }
+}
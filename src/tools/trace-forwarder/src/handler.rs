@@ -91,7 +91,7 @@ async fn handle_trace_data<'a>(
         } else {
             let batch = vec![span_data];
 
-            // Call low-level Jaeger exporter to send the trace span immediately.
+            // Call the low-level exporter to send the trace span immediately.
             let result = exporter.export(batch).await;
 
             if result.is_err() {
@@ -26,6 +26,7 @@ const DEFAULT_KATA_VSOCK_TRACING_PORT: &str = "10240";
 const DEFAULT_JAEGER_HOST: &str = "127.0.0.1";
 const DEFAULT_JAEGER_PORT: &str = "6831";
 
+mod file_sink;
 mod handler;
 mod server;
 mod tracer;
@@ -35,13 +36,16 @@ use crate::utils::{
     make_hybrid_socket_path, str_to_vsock_cid, str_to_vsock_port, VSOCK_CID_ANY_STR,
 };
 use server::VsockType;
+use std::str::FromStr;
+use tracer::{ExporterConfig, ExporterType, EXPORTER_JAEGER, EXPORTER_TYPES};
 
-fn announce(logger: &Logger, version: &str, dump_only: bool) {
+fn announce(logger: &Logger, version: &str, exporter: &str, dump_only: bool) {
     let commit = env::var("VERSION_COMMIT").map_or(String::new(), |s| s);
 
     info!(logger, "announce";
     "commit-version" => commit.as_str(),
     "version" =>  version,
+    "exporter" => exporter,
     "dump-only" => dump_only);
 }
 
@@ -51,6 +55,11 @@ fn make_description_text() -> String {
     Kata Containers component that runs on the host and forwards
     trace data from the container to a trace collector on the host.
 
+    The trace spans can be sent to a Jaeger agent, to an OpenTelemetry
+    collector using OTLP (over gRPC or HTTP), or appended to a file as
+    JSON lines for offline analysis. Use --exporter to select where spans
+    are sent.
+
     This tool requires agent tracing to be enabled in the Kata
     configuration file. It uses VSOCK to listen for trace data originating
     from the Kata agent running inside the Kata Container.
@@ -97,6 +106,14 @@ fn make_examples_text(program_name: &str) -> String {
 
     $ {program} --trace-name {trace_name:?}
 
+- Example sending the spans to an OpenTelemetry collector using OTLP over gRPC:
+
+    $ {program} --exporter otlp-grpc --otlp-endpoint http://127.0.0.1:4317
+
+- Example writing the spans to a file:
+
+    $ {program} --exporter file --output-file /tmp/kata-agent-spans.json
+
 - Example assuming cloud-hypervisor is the Kata configured hypervisor
   and the sandbox _about_ to be created will be called {sandbox_id:?}:
 
@@ -147,6 +164,14 @@ fn real_main() -> Result<()> {
                 .long("dump-only")
                 .help("Disable forwarding of spans and write to stdout (for testing)"),
         )
+        .arg(
+            Arg::with_name("exporter")
+                .long("exporter")
+                .help("Where to send the trace spans")
+                .takes_value(true)
+                .possible_values(EXPORTER_TYPES)
+                .default_value(EXPORTER_JAEGER),
+        )
         .arg(
             Arg::with_name("trace-name")
                 .long("trace-name")
@@ -169,6 +194,23 @@ fn real_main() -> Result<()> {
                 .takes_value(true)
                 .default_value(DEFAULT_JAEGER_PORT),
         )
+        .arg(
+            Arg::with_name("otlp-endpoint")
+                .long("otlp-endpoint")
+                .help(&format!(
+                    "OpenTelemetry collector URL (default {:?} for otlp-grpc, {:?} for otlp-http)",
+                    tracer::DEFAULT_OTLP_GRPC_ENDPOINT,
+                    tracer::DEFAULT_OTLP_HTTP_ENDPOINT
+                ))
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("output-file")
+                .long("output-file")
+                .help("File to append the trace spans to (file exporter only)")
+                .takes_value(true)
+                .required_if("exporter", tracer::EXPORTER_FILE),
+        )
         .arg(
             Arg::with_name("log-level")
                 .long("log-level")
@@ -217,7 +259,12 @@ fn real_main() -> Result<()> {
 
     let dump_only = args.is_present("dump-only");
 
-    announce(&logger, version, dump_only);
+    // Cannot fail as a default has been specified
+    let exporter_name = args.value_of("exporter").unwrap();
+
+    let exporter_type = ExporterType::from_str(exporter_name)?;
+
+    announce(&logger, version, exporter_name, dump_only);
 
     let trace_name: &str = args
         .value_of("trace-name")
@@ -260,14 +307,20 @@ fn real_main() -> Result<()> {
         return Err(anyhow!("Jaeger host cannot be blank"));
     }
 
-    let server = server::VsockTraceServer::new(
-        &logger,
-        vsock,
-        jaeger_host,
+    let otlp_endpoint = args
+        .value_of("otlp-endpoint")
+        .unwrap_or_else(|| exporter_type.default_endpoint());
+
+    let exporter_config = ExporterConfig {
+        exporter_type,
+        service_name: trace_name.to_string(),
+        jaeger_host: jaeger_host.to_string(),
         jaeger_port,
-        trace_name,
-        dump_only,
-    );
+        otlp_endpoint: otlp_endpoint.to_string(),
+        output_file: args.value_of("output-file").unwrap_or_default().to_string(),
+    };
+
+    let server = server::VsockTraceServer::new(&logger, vsock, exporter_config, dump_only);
 
     let result = server.start();
 
@@ -30,9 +30,7 @@ pub enum VsockType {
 pub struct VsockTraceServer {
     pub vsock: VsockType,
 
-    pub jaeger_host: String,
-    pub jaeger_port: u32,
-    pub jaeger_service_name: String,
+    pub exporter_config: tracer::ExporterConfig,
 
     pub logger: Logger,
     pub dump_only: bool,
@@ -42,43 +40,33 @@ impl VsockTraceServer {
     pub fn new(
         logger: &Logger,
         vsock: VsockType,
-        jaeger_host: &str,
-        jaeger_port: u32,
-        jaeger_service_name: &str,
+        exporter_config: tracer::ExporterConfig,
         dump_only: bool,
     ) -> Self {
         let logger = logger.new(o!("subsystem" => "server"));
 
         VsockTraceServer {
             vsock,
-            jaeger_host: jaeger_host.to_string(),
-            jaeger_port,
-            jaeger_service_name: jaeger_service_name.to_string(),
+            exporter_config,
             logger,
             dump_only,
         }
     }
 
     pub fn start(&self) -> Result<()> {
-        let result = tracer::create_jaeger_trace_exporter(
-            self.jaeger_service_name.clone(),
-            self.jaeger_host.clone(),
-            self.jaeger_port,
-        );
-
-        let mut exporter = result?;
+        let mut exporter = tracer::create_trace_exporter(&self.exporter_config)?;
 
         match &self.vsock {
             VsockType::Standard { port, cid } => start_std_vsock(
                 self.logger.clone(),
-                &mut exporter,
+                exporter.as_mut(),
                 *port,
                 *cid,
                 self.dump_only,
             ),
             VsockType::Hybrid { socket_path } => start_hybrid_vsock(
                 self.logger.clone(),
-                &mut exporter,
+                exporter.as_mut(),
                 socket_path,
                 self.dump_only,
             ),
@@ -3,8 +3,110 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
+use anyhow::{anyhow, Context, Result};
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{Request, Response};
+use opentelemetry::sdk::export::trace::{ExportResult, SpanData, SpanExporter};
 use opentelemetry::KeyValue;
+use opentelemetry_http::{HttpClient, HttpError};
 use std::net::SocketAddr;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::file_sink::FileSinkExporter;
+
+pub const EXPORTER_JAEGER: &str = "jaeger";
+pub const EXPORTER_OTLP_GRPC: &str = "otlp-grpc";
+pub const EXPORTER_OTLP_HTTP: &str = "otlp-http";
+pub const EXPORTER_FILE: &str = "file";
+
+pub const EXPORTER_TYPES: &[&str] = &[
+    EXPORTER_JAEGER,
+    EXPORTER_OTLP_GRPC,
+    EXPORTER_OTLP_HTTP,
+    EXPORTER_FILE,
+];
+
+pub const DEFAULT_OTLP_GRPC_ENDPOINT: &str = "http://127.0.0.1:4317";
+pub const DEFAULT_OTLP_HTTP_ENDPOINT: &str = "http://127.0.0.1:4318/v1/traces";
+
+// Time allowed for a collector to accept a batch of spans.
+const OTLP_EXPORT_TIMEOUT_SECS: u64 = 10;
+
+pub const ERR_OUTPUT_FILE_EMPTY: &str = "File exporter needs an output file";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExporterType {
+    Jaeger,
+    OtlpGrpc,
+    OtlpHttp,
+    File,
+}
+
+impl FromStr for ExporterType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            EXPORTER_JAEGER => Ok(ExporterType::Jaeger),
+            EXPORTER_OTLP_GRPC => Ok(ExporterType::OtlpGrpc),
+            EXPORTER_OTLP_HTTP => Ok(ExporterType::OtlpHttp),
+            EXPORTER_FILE => Ok(ExporterType::File),
+            _ => Err(anyhow!("invalid exporter type: {:?}", s)),
+        }
+    }
+}
+
+impl ExporterType {
+    // The collector address used when the user does not specify one
+    // (only meaningful for the OTLP exporters).
+    pub fn default_endpoint(&self) -> &'static str {
+        match self {
+            ExporterType::OtlpGrpc => DEFAULT_OTLP_GRPC_ENDPOINT,
+            ExporterType::OtlpHttp => DEFAULT_OTLP_HTTP_ENDPOINT,
+            _ => "",
+        }
+    }
+}
+
+// Details of where the trace spans read from the agent are sent to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExporterConfig {
+    pub exporter_type: ExporterType,
+    pub service_name: String,
+
+    // Jaeger agent address
+    pub jaeger_host: String,
+    pub jaeger_port: u32,
+
+    // OTLP collector address (URL)
+    pub otlp_endpoint: String,
+
+    // JSON lines file the file exporter appends to
+    pub output_file: String,
+}
+
+pub fn create_trace_exporter(config: &ExporterConfig) -> Result<Box<dyn SpanExporter>> {
+    let exporter: Box<dyn SpanExporter> = match config.exporter_type {
+        ExporterType::Jaeger => Box::new(create_jaeger_trace_exporter(
+            config.service_name.clone(),
+            config.jaeger_host.clone(),
+            config.jaeger_port,
+        )?),
+        ExporterType::OtlpGrpc => Box::new(OtlpExporter::new_grpc(&config.otlp_endpoint)?),
+        ExporterType::OtlpHttp => Box::new(OtlpExporter::new_http(&config.otlp_endpoint)?),
+        ExporterType::File => {
+            if config.output_file.is_empty() {
+                return Err(anyhow!(ERR_OUTPUT_FILE_EMPTY));
+            }
+
+            Box::new(FileSinkExporter::new(&config.output_file)?)
+        }
+    };
+
+    Ok(exporter)
+}
 
 pub fn create_jaeger_trace_exporter(
     jaeger_service_name: String,
@@ -42,3 +144,412 @@ pub fn create_jaeger_trace_exporter(
 
     Ok(exporter)
 }
+
+// The OTLP exporters are built on tonic and reqwest, which need a tokio
+// runtime. The forwarder handles connections synchronously, so the exporter
+// owns a runtime to drive each export on.
+#[derive(Debug)]
+pub struct OtlpExporter {
+    runtime: tokio::runtime::Runtime,
+    exporter: opentelemetry_otlp::TraceExporter,
+}
+
+impl OtlpExporter {
+    // Export with OTLP over gRPC.
+    pub fn new_grpc(endpoint: &str) -> Result<Self> {
+        if endpoint.is_empty() {
+            return Err(anyhow!("OTLP endpoint cannot be blank"));
+        }
+
+        Self::new(endpoint, opentelemetry_otlp::Protocol::Grpc, |config| {
+            Ok(opentelemetry_otlp::TraceExporter::new_tonic(
+                config,
+                opentelemetry_otlp::TonicConfig::default(),
+            )?)
+        })
+    }
+
+    // Export with OTLP over HTTP, using the binary protobuf encoding.
+    pub fn new_http(endpoint: &str) -> Result<Self> {
+        let url = url::Url::parse(endpoint)
+            .with_context(|| format!("invalid OTLP endpoint {:?}", endpoint))?;
+        if url.scheme() != "http" && url.scheme() != "https" {
+            return Err(anyhow!(
+                "invalid OTLP endpoint {:?}: expected an http or https URL",
+                endpoint
+            ));
+        }
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(OTLP_EXPORT_TIMEOUT_SECS))
+            .build()
+            .context("failed to create HTTP client for OTLP exporter")?;
+
+        Self::new(
+            endpoint,
+            opentelemetry_otlp::Protocol::HttpBinary,
+            |config| {
+                Ok(opentelemetry_otlp::TraceExporter::new_http(
+                    config,
+                    opentelemetry_otlp::HttpConfig {
+                        client: Some(Box::new(CollectorClient(client))),
+                        headers: None,
+                    },
+                )?)
+            },
+        )
+    }
+
+    fn new<F>(endpoint: &str, protocol: opentelemetry_otlp::Protocol, build: F) -> Result<Self>
+    where
+        F: FnOnce(opentelemetry_otlp::ExporterConfig) -> Result<opentelemetry_otlp::TraceExporter>,
+    {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .context("failed to create runtime for OTLP exporter")?;
+
+        let config = opentelemetry_otlp::ExporterConfig {
+            endpoint: endpoint.to_string(),
+            protocol,
+            timeout: Duration::from_secs(OTLP_EXPORT_TIMEOUT_SECS),
+        };
+
+        // The connections are established lazily but the clients must be
+        // created in the context of a runtime.
+        let exporter = {
+            let _guard = runtime.enter();
+
+            build(config)
+        }
+        .context("failed to create OTLP exporter")?;
+
+        Ok(OtlpExporter { runtime, exporter })
+    }
+}
+
+#[async_trait]
+impl SpanExporter for OtlpExporter {
+    async fn export(&mut self, batch: Vec<SpanData>) -> ExportResult {
+        let exporter = &mut self.exporter;
+
+        self.runtime.block_on(exporter.export(batch))
+    }
+
+    fn shutdown(&mut self) {
+        self.exporter.shutdown();
+    }
+}
+
+// HTTP client of the OTLP exporter, which does not check the response
+// status: fail the export if the collector does not accept the spans.
+#[derive(Debug)]
+struct CollectorClient(reqwest::Client);
+
+#[async_trait]
+impl HttpClient for CollectorClient {
+    async fn send(&self, request: Request<Vec<u8>>) -> Result<Response<Bytes>, HttpError> {
+        let response = HttpClient::send(&self.0, request).await?;
+
+        if !response.status().is_success() {
+            return Err(format!("collector rejected spans: {}", response.status()).into());
+        }
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use opentelemetry::trace::{Tracer, TracerProvider};
+    use opentelemetry_otlp::proto::collector::trace::v1::ExportTraceServiceRequest;
+    use prost::Message;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread::{self, JoinHandle};
+
+    // Exporter used to capture the spans generated by the SDK.
+    #[derive(Debug, Default, Clone)]
+    struct CollectingExporter(Arc<Mutex<Vec<SpanData>>>);
+
+    #[async_trait]
+    impl SpanExporter for CollectingExporter {
+        async fn export(&mut self, batch: Vec<SpanData>) -> ExportResult {
+            self.0.lock().unwrap().extend(batch);
+            Ok(())
+        }
+    }
+
+    // Create finished spans, as they would be received from the agent.
+    pub fn make_spans(names: &[&'static str]) -> Vec<SpanData> {
+        let collector = CollectingExporter::default();
+
+        let provider = opentelemetry::sdk::trace::TracerProvider::builder()
+            .with_simple_exporter(collector.clone())
+            .build();
+
+        let tracer = provider.get_tracer("kata-agent", None);
+
+        for name in names {
+            // The span ends when dropped
+            let _span = tracer
+                .span_builder(*name)
+                .with_attributes(vec![KeyValue::new("sandbox-id", "foo")])
+                .start(&tracer);
+        }
+
+        // Dropping the provider flushes the spans to the exporter
+        drop(tracer);
+        drop(provider);
+
+        let mut spans = collector.0.lock().unwrap();
+
+        spans.drain(..).collect()
+    }
+
+    fn make_config(exporter_type: ExporterType) -> ExporterConfig {
+        ExporterConfig {
+            exporter_type,
+            service_name: "kata-agent".into(),
+            jaeger_host: "127.0.0.1".into(),
+            jaeger_port: 6831,
+            otlp_endpoint: exporter_type.default_endpoint().into(),
+            output_file: "".into(),
+        }
+    }
+
+    #[test]
+    fn test_exporter_type_from_str() {
+        assert_eq!(
+            ExporterType::from_str(EXPORTER_JAEGER).unwrap(),
+            ExporterType::Jaeger
+        );
+        assert_eq!(
+            ExporterType::from_str(EXPORTER_OTLP_GRPC).unwrap(),
+            ExporterType::OtlpGrpc
+        );
+        assert_eq!(
+            ExporterType::from_str(EXPORTER_OTLP_HTTP).unwrap(),
+            ExporterType::OtlpHttp
+        );
+        assert_eq!(
+            ExporterType::from_str(EXPORTER_FILE).unwrap(),
+            ExporterType::File
+        );
+
+        assert!(ExporterType::from_str("").is_err());
+        assert!(ExporterType::from_str("zipkin").is_err());
+
+        for t in EXPORTER_TYPES {
+            assert!(ExporterType::from_str(t).is_ok(), "{}", t);
+        }
+    }
+
+    #[test]
+    fn test_create_trace_exporter() {
+        let config = make_config(ExporterType::File);
+        let result = create_trace_exporter(&config);
+        assert_eq!(
+            format!("{:?}", result.err().unwrap()),
+            format!("{:?}", anyhow!(ERR_OUTPUT_FILE_EMPTY))
+        );
+
+        let mut config = make_config(ExporterType::OtlpGrpc);
+        config.otlp_endpoint = "".into();
+        assert!(create_trace_exporter(&config).is_err());
+
+        for endpoint in &[
+            "",
+            "localhost:4318",
+            "127.0.0.1:4318",
+            "ftp://127.0.0.1:4318",
+        ] {
+            let mut config = make_config(ExporterType::OtlpHttp);
+            config.otlp_endpoint = endpoint.to_string();
+            assert!(create_trace_exporter(&config).is_err(), "{:?}", endpoint);
+        }
+
+        for t in &[
+            ExporterType::Jaeger,
+            ExporterType::OtlpGrpc,
+            ExporterType::OtlpHttp,
+        ] {
+            let config = make_config(*t);
+            assert!(create_trace_exporter(&config).is_ok(), "{:?}", t);
+        }
+    }
+
+    mod collector {
+        use opentelemetry_otlp::proto::collector::trace::v1::trace_service_server::{
+            TraceService, TraceServiceServer,
+        };
+        use opentelemetry_otlp::proto::collector::trace::v1::{
+            ExportTraceServiceRequest, ExportTraceServiceResponse,
+        };
+        use std::net::SocketAddr;
+        use std::sync::{Arc, Mutex};
+        use tokio::runtime::Runtime;
+
+        // In-process OTLP gRPC collector recording the requests it receives.
+        #[derive(Debug, Default, Clone)]
+        pub struct StubCollector {
+            pub requests: Arc<Mutex<Vec<ExportTraceServiceRequest>>>,
+        }
+
+        #[tonic::async_trait]
+        impl TraceService for StubCollector {
+            async fn export(
+                &self,
+                request: tonic::Request<ExportTraceServiceRequest>,
+            ) -> Result<tonic::Response<ExportTraceServiceResponse>, tonic::Status> {
+                self.requests.lock().unwrap().push(request.into_inner());
+
+                Ok(tonic::Response::new(ExportTraceServiceResponse::default()))
+            }
+        }
+
+        impl StubCollector {
+            // Serve on an ephemeral port. The server stops when the
+            // returned runtime is dropped.
+            pub fn start(&self) -> (Runtime, SocketAddr) {
+                let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+                let addr = listener.local_addr().unwrap();
+                listener.set_nonblocking(true).unwrap();
+
+                let service = TraceServiceServer::new(self.clone());
+                let runtime = Runtime::new().unwrap();
+
+                runtime.spawn(async move {
+                    let listener = tokio::net::TcpListener::from_std(listener).unwrap();
+                    let incoming = tokio_stream::wrappers::TcpListenerStream::new(listener);
+
+                    tonic::transport::Server::builder()
+                        .add_service(service)
+                        .serve_with_incoming(incoming)
+                        .await
+                });
+
+                (runtime, addr)
+            }
+        }
+    }
+
+    #[test]
+    fn test_otlp_grpc_exporter() {
+        let collector = collector::StubCollector::default();
+        let (_runtime, addr) = collector.start();
+
+        let mut config = make_config(ExporterType::OtlpGrpc);
+        config.otlp_endpoint = format!("http://{}", addr);
+
+        let mut exporter = create_trace_exporter(&config).unwrap();
+
+        let spans = make_spans(&["create_container", "start_container"]);
+        assert_eq!(spans.len(), 2);
+
+        let result = futures::executor::block_on(exporter.export(spans));
+        assert!(result.is_ok(), "{:?}", result);
+
+        let requests = collector.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+
+        let mut names: Vec<String> = requests[0]
+            .resource_spans
+            .iter()
+            .flat_map(|r| r.instrumentation_library_spans.iter())
+            .flat_map(|l| l.spans.iter())
+            .map(|s| s.name.clone())
+            .collect();
+        names.sort();
+
+        assert_eq!(names, vec!["create_container", "start_container"]);
+    }
+
+    // In-process OTLP HTTP collector accepting a single request and replying
+    // with the specified status. Returns the path and the body of the request.
+    fn stub_http_collector(status: &'static str) -> (String, JoinHandle<(String, Vec<u8>)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}/v1/traces", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end().to_lowercase();
+                if line.is_empty() {
+                    break;
+                }
+
+                if let Some(len) = line.strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+            }
+
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+
+            let response = format!("HTTP/1.1 {}\r\nContent-Length: 0\r\n\r\n", status);
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+
+            (request_line.trim_end().to_string(), body)
+        });
+
+        (endpoint, handle)
+    }
+
+    #[test]
+    fn test_otlp_http_exporter() {
+        let (endpoint, handle) = stub_http_collector("200 OK");
+
+        let mut config = make_config(ExporterType::OtlpHttp);
+        config.otlp_endpoint = endpoint;
+
+        let mut exporter = create_trace_exporter(&config).unwrap();
+
+        let result = futures::executor::block_on(
+            exporter.export(make_spans(&["create_container", "start_container"])),
+        );
+        assert!(result.is_ok(), "{:?}", result);
+
+        let (request_line, body) = handle.join().unwrap();
+        assert_eq!(request_line, "POST /v1/traces HTTP/1.1");
+
+        // Same layout as the requests of the gRPC exporter
+        let request = ExportTraceServiceRequest::decode(body.as_slice()).unwrap();
+
+        let mut names: Vec<String> = request
+            .resource_spans
+            .iter()
+            .flat_map(|r| r.instrumentation_library_spans.iter())
+            .flat_map(|l| l.spans.iter())
+            .map(|s| s.name.clone())
+            .collect();
+        names.sort();
+
+        assert_eq!(names, vec!["create_container", "start_container"]);
+    }
+
+    #[test]
+    fn test_otlp_http_exporter_rejected() {
+        let (endpoint, handle) = stub_http_collector("503 Service Unavailable");
+
+        let mut config = make_config(ExporterType::OtlpHttp);
+        config.otlp_endpoint = endpoint;
+
+        let mut exporter = create_trace_exporter(&config).unwrap();
+
+        let result = futures::executor::block_on(exporter.export(make_spans(&["foo"])));
+        assert!(result.is_err());
+
+        handle.join().unwrap();
+    }
+}